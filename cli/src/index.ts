@@ -21,9 +21,9 @@ import privacy from "./privacy";
 
 const program = new Command();
 
-// SSS-2 Program ID (Devnet)
+// SSS-2 transfer hook program ID (sss-transfer-hook, Devnet)
 const SSS2_PROGRAM_ID = new PublicKey(
-  "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+  "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
 );
 
 // CLI Config
@@ -60,7 +60,7 @@ async function example3() {
     config: configPDA,
     authority,
     target: badActor.publicKey,
-    reason: "Suspicious activity detected",
+    reasonCode: "fraud_investigation",
   });
 
   // 5. Verify blacklist
@@ -100,7 +100,7 @@ async function example4() {
     config: configPDA,
     authority,
     target: badActor.publicKey,
-    reason: "Sanctions list",
+    reasonCode: "sanctions_match",
   });
   console.log("✓ Address blacklisted by compliance officer");
 
@@ -78,7 +78,7 @@ async function example5() {
     config: configPDA,
     authority,
     target: badActor.publicKey,
-    reason: "Emergency: Suspected fraud",
+    reasonCode: "fraud_investigation",
   });
   console.log("✓ Bad actor blacklisted");
 
@@ -89,14 +89,15 @@ async function example5() {
   );
   console.log("  Blacklist PDA:", blacklistPDA.toString());
 
-  // Seize all assets
+  // Seize against case #1, opened by a seizer and approved by the multisig
+  const seizureCase = compliance.getSeizureCasePDA(configPDA, new BN(1));
   await compliance.seize({
     config: configPDA,
-    authority, // Must be permanent delegate
+    authority, // Permanent delegate, hook authority or seizer
     source: badActor.publicKey,
     treasury: treasury.publicKey,
     mint,
-    reason: "Emergency asset recovery",
+    seizureCase,
   });
   console.log("✓ Assets seized and transferred to treasury");
 
@@ -5,7 +5,7 @@
  */
 
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
-import { ComplianceModule } from "../sdk/src";
+import { ComplianceModule, ReasonCode } from "../sdk/src";
 
 async function main() {
   console.log("=== Example 08: Batch Compliance Operations ===\n");
@@ -35,12 +35,12 @@ async function main() {
     Keypair.generate().publicKey,
   ];
 
-  const reasons = [
-    "Suspicious activity detected",
-    "Known scam address",
-    "Compliance violation",
-    "Sanctions list match",
-    "Fraud investigation",
+  const reasons: ReasonCode[] = [
+    "fraud_investigation",
+    "other",
+    "court_order",
+    "sanctions_match",
+    "fraud_investigation",
   ];
 
   console.log(`\nAddresses to blacklist: ${suspiciousAddresses.length}`);
@@ -1,25 +1,344 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::{
+    address_lookup_table,
+    bpf_loader_upgradeable,
+    ed25519_program,
+    secp256k1_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
 use anchor_spl::token_2022::{self, Token2022};
-use anchor_spl::token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022_extensions::{spl_token_metadata_interface, token_metadata_initialize, transfer_fee, TokenMetadataInitialize};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    metadata_pointer::MetadataPointer,
+    transfer_hook::TransferHook,
+    BaseStateWithExtensions,
+    StateWithExtensions,
+};
+use anchor_spl::token_interface::{self, Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount, TokenInterface};
+
+// Transfer hook program, read by onboard_account/offboard_account
+pub mod sss_transfer_hook_program {
+    use anchor_lang::prelude::*;
+    declare_id!("By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB");
+    
+    // Pre-migration list entries: address + flag + bump
+    pub const LEGACY_ENTRY_LEN: usize = 8 + 32 + 1 + 1;
+    
+    // List layouts, mirrored from sss-transfer-hook and read with load_hook_account
+    #[derive(AnchorDeserialize)]
+    pub struct WhitelistEntry {
+        pub address: Pubkey,
+        pub whitelist_type: u8,
+        pub added_by: Pubkey,
+        pub created_at: i64,
+        pub bump: u8,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct KycRecord {
+        pub address: Pubkey,
+        pub tier: u8,                    // 0 = KycTier::None
+        pub provider: Pubkey,
+        pub expires_at: Option<i64>,
+        pub day: i64,
+        pub day_volume: u64,
+        pub updated_at: i64,
+        pub bump: u8,
+        pub nonce: u64,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct BlacklistEntry {
+        pub address: Pubkey,
+        pub reason_code: u8,
+        pub reference: Option<[u8; 32]>,
+        pub blacklisted_by: Pubkey,
+        pub created_at: i64,
+        pub is_active: bool,
+        pub bump: u8,
+        pub entry_kind: u8,
+        pub expires_at: Option<i64>,
+        pub restriction_level: u8,       // 0 = RestrictionLevel::Blocked
+    }
+    
+    // Blacklist entries written before reason codes, told apart by length
+    #[derive(AnchorDeserialize)]
+    pub struct BlacklistEntryV1 {
+        pub address: Pubkey,
+        pub reason: String,
+        pub blacklisted_by: Pubkey,
+        pub created_at: i64,
+        pub is_active: bool,
+        pub bump: u8,
+        pub entry_kind: u8,
+        pub expires_at: Option<i64>,
+        pub restriction_level: u8,
+    }
+    
+    pub const BLACKLIST_ENTRY_V1_LEN: usize = 8 + 200;
+    
+    #[derive(AnchorDeserialize)]
+    pub struct LegalHold {
+        pub config: Pubkey,
+        pub address: Pubkey,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct ClawbackHold {
+        pub config: Pubkey,
+        pub case: Pubkey,
+        pub target: Pubkey,
+        pub amount: u64,
+        pub freeze_account: bool,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct MarketMakerRebate {
+        pub config: Pubkey,
+        pub owner: Pubkey,
+        pub rebate_bps: u16,
+        pub monthly_cap: u64,
+        pub active: bool,
+        pub period_start: i64,
+        pub period_accrued: u64,
+        pub settled: u64,
+        pub bump: u8,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct Referrer {
+        pub config: Pubkey,
+        pub referrer: Pubkey,
+        pub share_bps: u16,
+        pub earned: u64,
+        pub bump: u8,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct FeeCredit {
+        pub config: Pubkey,
+        pub owner: Pubkey,
+        pub credited: u64,
+        pub bump: u8,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct SeizureCase {
+        pub config: Pubkey,
+        pub case_id: u64,
+        pub target: Pubkey,
+        pub target_owner: Pubkey,
+        pub amount: u64,
+        pub reason_code: u8,
+        pub legal_reference: [u8; 32],
+        pub status: u8,
+        pub opened_by: Pubkey,
+        pub opened_at: i64,
+        pub approved_at: i64,
+        pub bump: u8,
+        pub destroy: bool,
+        pub exceptional_destination: Option<Pubkey>,
+        pub destination_unlocks_at: i64,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct RestitutionAccount {
+        pub config: Pubkey,
+        pub token_account: Pubkey,
+    }
+    
+    // Length of a rebate accrual period, mirrored from the hook
+    pub const REBATE_PERIOD: i64 = 30 * 86_400;
+}
 
 // === ACCOUNT STRUCTURES ===
 
-#[account]
+// Read by nearly every instruction and by the hook on every transfer, so
+// zero-copy. Flags are 0/1 bytes, name and symbol are zero-padded UTF-8 and
+// pending_authority is Pubkey::default() when unset.
+#[account(zero_copy)]
 pub struct StablecoinState {
     pub authority: Pubkey,           // Master authority
     pub mint: Pubkey,                // Token mint
-    pub name: String,                // Token name
-    pub symbol: String,              // Token symbol
-    pub decimals: u8,                // Token decimals
+    pub pending_authority: Pubkey,   // Two-step transfer target (default = none)
+    pub upgrade_authority: Pubkey,   // Expected ProgramData upgrade authority (default = immutable)
     pub total_supply: u64,           // Current supply
-    pub is_paused: bool,             // Emergency pause
-    pub features: u8,                // Feature flags
     pub supply_cap: u64,             // Maximum supply (0 = unlimited)
     pub epoch_quota: u64,            // Per-epoch mint limit
     pub current_epoch_minted: u64,   // This epoch minted amount
-    pub current_epoch_start: i64,    // Epoch start timestamp
-    pub pending_authority: Option<Pubkey>, // Two-step transfer target
+    pub epoch_length: u64,           // Epoch length in epoch_mode units (0 = one day of unix seconds)
+    pub current_epoch_start: i64,    // Epoch start, in epoch_mode units (the epoch id)
+    pub sunset_at: i64,              // When sunset began (0 = active)
+    pub name: [u8; 32],              // Token name
+    pub symbol: [u8; 10],            // Token symbol
+    pub master_count: u16,           // Number of ROLE_MASTER holders
+    pub decimals: u8,                // Token decimals
+    pub is_paused: u8,               // Emergency pause
+    pub features: u8,                // Feature flags
     pub bump: u8,                    // PDA bump
+    pub is_sunset: u8,               // Minting permanently disabled
+    pub redemption_only: u8,         // Hook should only allow transfers to treasury
+    pub locks: u8,                   // Irreversible parameter locks (LOCK_*)
+    pub renounced: u8,               // Renounced mint authorities (RENOUNCED_*)
+    pub upgrade_policy: u8,          // UPGRADE_POLICY_*
+    pub circuit_breaker_armed: u8,   // Mints must pass the CircuitBreaker account
+    pub circuit_tripped: u8,         // Mint velocity breached; minting halted until reset
+    pub epoch_mode: u8,              // EpochMode the mint epoch is measured in
+    pub _reserved: [u64; 9],
+}
+
+impl StablecoinState {
+    pub const SPACE: usize = 8 + std::mem::size_of::<StablecoinState>();
+    
+    pub fn name(&self) -> String {
+        padded_str(&self.name)
+    }
+    
+    pub fn symbol(&self) -> String {
+        padded_str(&self.symbol)
+    }
+    
+    pub fn is_paused(&self) -> bool {
+        self.is_paused != 0
+    }
+    
+    pub fn is_sunset(&self) -> bool {
+        self.is_sunset != 0
+    }
+    
+    pub fn redemption_only(&self) -> bool {
+        self.redemption_only != 0
+    }
+    
+    pub fn circuit_breaker_armed(&self) -> bool {
+        self.circuit_breaker_armed != 0
+    }
+    
+    pub fn circuit_tripped(&self) -> bool {
+        self.circuit_tripped != 0
+    }
+    
+    pub fn pending_authority(&self) -> Option<Pubkey> {
+        (self.pending_authority != Pubkey::default()).then_some(self.pending_authority)
+    }
+    
+    pub fn epoch_mode(&self) -> EpochMode {
+        EpochMode::from(self.epoch_mode)
+    }
+}
+
+// Zero-padded copy of `value`, which the caller has length-checked
+pub fn padded<const N: usize>(value: &str) -> [u8; N] {
+    let mut out = [0u8; N];
+    out[..value.len()].copy_from_slice(value.as_bytes());
+    out
+}
+
+// `value` up to its zero padding
+pub fn padded_str(value: &[u8]) -> String {
+    let len = value.iter().position(|b| *b == 0).unwrap_or(value.len());
+    String::from_utf8_lossy(&value[..len]).into_owned()
+}
+
+// Layout of stablecoins created before StablecoinState went zero-copy.
+// Shares the StablecoinState discriminator; told apart by length.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StablecoinStateV1 {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: u64,
+    pub is_paused: bool,
+    pub features: u8,
+    pub supply_cap: u64,
+    pub epoch_quota: u64,
+    pub current_epoch_minted: u64,
+    pub current_epoch_start: i64,
+    pub pending_authority: Option<Pubkey>,
+    pub bump: u8,
+    pub master_count: u16,
+    pub is_sunset: bool,
+    pub redemption_only: bool,
+    pub sunset_at: i64,
+    pub locks: u8,
+    pub renounced: u8,
+    pub upgrade_authority: Pubkey,
+    pub upgrade_policy: u8,
+    pub circuit_breaker_armed: bool,
+    pub circuit_tripped: bool,
+    pub epoch_mode: EpochMode,
+    pub epoch_length: u64,
+}
+
+impl StablecoinStateV1 {
+    pub const SPACE: usize = 8 + 256;
+    // Size the original initialize allocated, before the Borsh layout gained
+    // the sunset, lock and upgrade fields
+    pub const BASELINE_SPACE: usize = 8 + 200;
+}
+
+// Deserialize a StablecoinState still in the Borsh layout, at either legacy
+// size. Fields a baseline-sized account never wrote read as zero, except
+// master_count: such a stablecoin still has the master role initialize gave.
+// Accounts already migrated, or not owned by this program, yield `None`.
+pub fn load_stablecoin_state_v1(info: &AccountInfo) -> Result<Option<StablecoinStateV1>> {
+    let len = info.data_len();
+    if info.owner != &crate::ID
+        || (len != StablecoinStateV1::SPACE && len != StablecoinStateV1::BASELINE_SPACE)
+    {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    if data[..8] != StablecoinState::DISCRIMINATOR {
+        return Ok(None);
+    }
+    let mut padded = [0u8; StablecoinStateV1::SPACE - 8];
+    padded[..len - 8].copy_from_slice(&data[8..]);
+    let mut v1 = StablecoinStateV1::deserialize(&mut &padded[..])?;
+    if len == StablecoinStateV1::BASELINE_SPACE {
+        v1.master_count = v1.master_count.max(1);
+    }
+    Ok(Some(v1))
+}
+
+impl From<StablecoinStateV1> for StablecoinState {
+    fn from(v1: StablecoinStateV1) -> Self {
+        Self {
+            authority: v1.authority,
+            mint: v1.mint,
+            pending_authority: v1.pending_authority.unwrap_or_default(),
+            upgrade_authority: v1.upgrade_authority,
+            total_supply: v1.total_supply,
+            supply_cap: v1.supply_cap,
+            epoch_quota: v1.epoch_quota,
+            current_epoch_minted: v1.current_epoch_minted,
+            epoch_length: v1.epoch_length,
+            current_epoch_start: v1.current_epoch_start,
+            sunset_at: v1.sunset_at,
+            name: padded(&v1.name),
+            symbol: padded(&v1.symbol),
+            master_count: v1.master_count,
+            decimals: v1.decimals,
+            is_paused: u8::from(v1.is_paused),
+            features: v1.features,
+            bump: v1.bump,
+            is_sunset: u8::from(v1.is_sunset),
+            redemption_only: u8::from(v1.redemption_only),
+            locks: v1.locks,
+            renounced: v1.renounced,
+            upgrade_policy: v1.upgrade_policy,
+            circuit_breaker_armed: u8::from(v1.circuit_breaker_armed),
+            circuit_tripped: u8::from(v1.circuit_tripped),
+            epoch_mode: v1.epoch_mode as u8,
+            _reserved: [0; 9],
+        }
+    }
 }
 
 #[account]
@@ -28,6 +347,10 @@ pub struct RoleAccount {
     pub roles: u8,                   // Bitmask of roles
     pub stablecoin: Pubkey,          // Associated stablecoin
     pub bump: u8,                    // PDA bump
+    pub expires_at: i64,             // Session keys lose their roles here (0 = permanent)
+    pub per_op_limit: u64,           // Session keys: max minted/burned per 24h (0 = unlimited)
+    pub window_start: i64,           // Start of the current per_op_limit window
+    pub window_used: u64,            // Minted/burned in the current window
 }
 
 #[account]
@@ -45,6 +368,22 @@ pub struct MultisigConfig {
     pub threshold: u8,               // Required approvals
     pub signers: Vec<Pubkey>,        // Authorized signers
     pub bump: u8,
+    pub secp_signers: Vec<[u8; 20]>, // secp256k1 (Ethereum-style) addresses of MPC/hardware signers
+    pub max_payload_len: u16,        // Max proposal instruction_data (0 = DEFAULT_MAX_PROPOSAL_PAYLOAD)
+}
+
+impl MultisigConfig {
+    // Account space for the given signer counts, never below the original 8 + 200
+    pub fn space(signers: usize, secp_signers: usize) -> usize {
+        (8 + 200).max(8 + 32 + 1 + 4 + 32 * signers + 1 + 4 + 20 * secp_signers + 2)
+    }
+    
+    pub fn max_payload(&self) -> usize {
+        match self.max_payload_len {
+            0 => DEFAULT_MAX_PROPOSAL_PAYLOAD,
+            len => len as usize,
+        }
+    }
 }
 
 #[account]
@@ -57,1019 +396,10015 @@ pub struct MultisigProposal {
     pub created_at: i64,               // Proposal time
     pub expires_at: i64,             // Expiration time
     pub bump: u8,
+    pub secp_approvals: Vec<[u8; 20]>, // secp256k1 signers who approved
 }
 
-// === ROLE CONSTANTS ===
-pub const ROLE_MASTER: u8 = 1;       // Full control
-pub const ROLE_MINTER: u8 = 2;       // Can mint
-pub const ROLE_BURNER: u8 = 4;       // Can burn
-pub const ROLE_PAUSER: u8 = 8;       // Can pause/unpause
-pub const ROLE_BLACKLISTER: u8 = 16; // Can manage blacklist
-pub const ROLE_SEIZER: u8 = 32;      // Can seize tokens
-pub const ROLE_FREEZER: u8 = 64;     // Can freeze/thaw individual accounts (SSS-2)
+impl MultisigProposal {
+    // Account space for a payload and approval counts. Proposals start sized
+    // for the threshold and grow as approvals beyond it arrive.
+    pub fn space(payload: usize, approvals: usize, secp_approvals: usize) -> usize {
+        8 + 32 + 32 + 4 + payload + 4 + 32 * approvals + 1 + 8 + 8 + 1 + 4 + 20 * secp_approvals
+    }
+}
 
-// === ERROR CODES ===
-#[error_code]
-pub enum StablecoinError {
-    #[msg("Unauthorized: Insufficient role permissions")]
-    Unauthorized = 6000,
-    #[msg("Contract is paused")]
-    ContractPaused,
-    #[msg("Invalid mint amount")]
-    InvalidAmount,
-    #[msg("Minter quota exceeded")]
-    QuotaExceeded,
-    #[msg("Role already assigned")]
-    RoleAlreadyAssigned,
-    #[msg("Math overflow")]
-    MathOverflow,
-    #[msg("Invalid authority")]
-    InvalidAuthority,
-    #[msg("Compliance not enabled")]
-    ComplianceNotEnabled,
-    #[msg("Already initialized")]
-    AlreadyInitialized,
-    #[msg("Insufficient balance")]
-    InsufficientBalance,
-    #[msg("Supply cap exceeded")]
-    SupplyCapExceeded,
-    #[msg("Epoch quota exceeded")]
-    EpochQuotaExceeded,
-    #[msg("Token name too long (max 32 chars)")]
-    NameTooLong,
-    #[msg("Token symbol too long (max 10 chars)")]
-    SymbolTooLong,
-    #[msg("Invalid role bitmask")]
-    InvalidRole,
+#[account]
+pub struct Treasury {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub token_account: Pubkey,       // Program-owned treasury ATA
+    pub timelock_seconds: i64,       // Delay between proposal and withdrawal
+    pub total_withdrawn: u64,        // Running total paid out
+    pub withdrawal_count: u64,       // Number of withdrawals
+    pub bump: u8,
+    pub fees_pending: u64,           // Fee portion of the balance awaiting distribution
+    pub total_fees_distributed: u64, // Running total routed to fee recipients
+    pub total_fees_harvested: u64,   // Running total of withheld fees swept in
 }
 
-// === EVENTS ===
-#[event]
-pub struct StablecoinInitialized {
-    pub mint: Pubkey,
-    pub authority: Pubkey,
-    pub name: String,
-    pub symbol: String,
-    pub timestamp: i64,
+// Loss-absorbing buffer fed by a share of every fee distribution. The token
+// account is an ATA owned by the insurance_authority PDA.
+#[account]
+pub struct InsuranceFund {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub token_account: Pubkey,       // Program-owned insurance ATA
+    pub allocation_bps: u16,         // Share of fees_pending routed here by distribute_fees
+    pub timelock_seconds: i64,       // Delay between proposal and draw
+    pub total_allocated: u64,        // Running total received from fees
+    pub total_drawn: u64,            // Running total paid out
+    pub draw_count: u64,             // Number of draws
+    pub bump: u8,
 }
 
-#[event]
-pub struct TokensMinted {
-    pub minter: Pubkey,
-    pub recipient: Pubkey,
-    pub amount: u64,
-    pub timestamp: i64,
+// Limits for the permissionless burn_from_treasury crank
+#[account]
+pub struct BurnPolicy {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub enabled: bool,
+    pub max_per_epoch: u64,          // Max burned per 24h epoch (0 = unlimited)
+    pub epoch_start: i64,            // Start of the current burn epoch
+    pub epoch_burned: u64,           // Burned in the current epoch
+    pub total_burned: u64,           // Running total burned from the treasury
+    pub bump: u8,
 }
 
-#[event]
-pub struct TokensBurned {
-    pub burner: Pubkey,
-    pub owner: Pubkey,
-    pub amount: u64,
-    pub timestamp: i64,
+// Rebates paid to a market maker so far. The hook's MarketMakerRebate
+// tracks what has accrued; the difference is what claim_rebate pays out.
+#[account]
+pub struct RebateClaim {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub owner: Pubkey,               // Market maker wallet
+    pub claimed: u64,                // Running total paid out
+    pub last_claimed_at: i64,
+    pub bump: u8,
 }
 
-#[event]
-pub struct AccountFrozen {
-    pub pauser: Pubkey,
-    pub account: Pubkey,
-    pub timestamp: i64,
+// Referral fees paid to a partner so far, against the hook Referrer's earned
+#[account]
+pub struct ReferralClaim {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub referrer: Pubkey,            // Partner wallet
+    pub claimed: u64,                // Running total paid out
+    pub last_claimed_at: i64,
+    pub bump: u8,
 }
 
-#[event]
-pub struct AccountThawed {
-    pub pauser: Pubkey,
-    pub account: Pubkey,
-    pub timestamp: i64,
+// Withheld fees paid back to an owner so far, against the hook FeeCredit's
+// credited total
+#[account]
+pub struct FeeCreditClaim {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub owner: Pubkey,               // Wallet the credit belongs to
+    pub claimed: u64,                // Running total paid out
+    pub last_claimed_at: i64,
+    pub bump: u8,
 }
 
-#[event]
-pub struct StablecoinPaused {
-    pub pauser: Pubkey,
-    pub timestamp: i64,
+// Claim PDAs recording what pay_claim_from_treasury has paid a claimant
+pub trait TreasuryClaim {
+    fn claimed(&self) -> u64;
+    fn record_payment(&mut self, stablecoin: Pubkey, claimant: Pubkey, bump: u8, amount: u64, now: i64) -> Result<()>;
 }
 
-#[event]
-pub struct StablecoinUnpaused {
-    pub pauser: Pubkey,
-    pub timestamp: i64,
+impl TreasuryClaim for RebateClaim {
+    fn claimed(&self) -> u64 {
+        self.claimed
+    }
+    
+    fn record_payment(&mut self, stablecoin: Pubkey, claimant: Pubkey, bump: u8, amount: u64, now: i64) -> Result<()> {
+        self.stablecoin = stablecoin;
+        self.owner = claimant;
+        self.claimed = self.claimed.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+        self.last_claimed_at = now;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
-#[event]
-pub struct RolesUpdated {
-    pub authority: Pubkey,
-    pub target: Pubkey,
-    pub new_roles: u8,
-    pub timestamp: i64,
+impl TreasuryClaim for ReferralClaim {
+    fn claimed(&self) -> u64 {
+        self.claimed
+    }
+    
+    fn record_payment(&mut self, stablecoin: Pubkey, claimant: Pubkey, bump: u8, amount: u64, now: i64) -> Result<()> {
+        self.stablecoin = stablecoin;
+        self.referrer = claimant;
+        self.claimed = self.claimed.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+        self.last_claimed_at = now;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
-#[event]
-pub struct MinterQuotaUpdated {
-    pub authority: Pubkey,
-    pub minter: Pubkey,
-    pub new_quota: u64,
-    pub timestamp: i64,
+impl TreasuryClaim for FeeCreditClaim {
+    fn claimed(&self) -> u64 {
+        self.claimed
+    }
+    
+    fn record_payment(&mut self, stablecoin: Pubkey, claimant: Pubkey, bump: u8, amount: u64, now: i64) -> Result<()> {
+        self.stablecoin = stablecoin;
+        self.owner = claimant;
+        self.claimed = self.claimed.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+        self.last_claimed_at = now;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
-#[event]
-pub struct AuthorityTransferStarted {
-    pub previous_authority: Pubkey,
-    pub pending_authority: Pubkey,
-    pub timestamp: i64,
+#[account]
+pub struct FeeDistribution {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub recipients: Vec<FeeRecipient>, // Shares must sum to 10_000 bps
+    pub total_distributed: u64,      // Running total paid out by distribute_fees
+    pub last_distributed_at: i64,    // Last crank time
+    pub bump: u8,
 }
 
-#[event]
-pub struct AuthorityTransferred {
-    pub previous_authority: Pubkey,
-    pub new_authority: Pubkey,
-    pub timestamp: i64,
+// Every stablecoin an issuer authority has created. Entries are indexed
+// 0..count so integrators can page through them without getProgramAccounts.
+#[account]
+pub struct IssuerRegistry {
+    pub authority: Pubkey,           // Issuer that ran initialize
+    pub count: u64,                  // Number of registry entries
+    pub bump: u8,
 }
 
-#[event]
-pub struct BatchMinted {
-    pub minter: Pubkey,
-    pub recipients: u16,
-    pub total_amount: u64,
-    pub timestamp: i64,
+#[account]
+pub struct IssuerRegistryEntry {
+    pub registry: Pubkey,            // Owning IssuerRegistry
+    pub index: u64,                  // Position in the registry
+    pub mint: Pubkey,                // Stablecoin mint
+    pub stablecoin: Pubkey,          // StablecoinState PDA
+    pub created_at: i64,
+    pub bump: u8,
 }
 
-#[event]
-pub struct MultisigProposalCreated {
-    pub proposal: Pubkey,
-    pub proposer: Pubkey,
-    pub timestamp: i64,
+// Conversion route between two stablecoins of this program, priced by a
+// registered oracle key that pushes `rate` with update_fx_rate
+#[account]
+pub struct FxPair {
+    pub source_stablecoin: Pubkey,   // StablecoinState burned from
+    pub destination_stablecoin: Pubkey, // StablecoinState minted into
+    pub oracle: Pubkey,              // Only signer allowed to post rates
+    pub rate: u64,                   // Destination tokens per source token, scaled by FX_RATE_SCALE
+    pub rate_updated_at: i64,        // When `rate` was posted
+    pub max_rate_age: i64,           // Seconds before a rate is too stale to swap at
+    pub spread_bps: u16,             // Share of the output minted to the destination treasury
+    pub daily_limit: u64,            // Max source amount swapped per UTC day (0 = unlimited)
+    pub current_day: i64,            // UTC day index of day_volume
+    pub day_volume: u64,             // Source amount swapped on current_day
+    pub enabled: bool,
+    pub bump: u8,
 }
 
-#[event]
-pub struct MultisigProposalApproved {
-    pub proposal: Pubkey,
-    pub approver: Pubkey,
-    pub approvals: u8,
-    pub threshold: u8,
-    pub timestamp: i64,
+// Deposit-to-earn pool. Deposits are tracked as shares of the pool ATA; interest
+// is minted into the ATA by accrual and raises `index`, the value of one share.
+#[account]
+pub struct SavingsPool {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub token_account: Pubkey,       // Program-owned savings ATA
+    pub rate_admin: Pubkey,          // Only signer allowed to change rate_bps
+    pub rate_bps: u16,               // Annual simple rate applied between accruals
+    pub index: u128,                 // Tokens per share, scaled by SAVINGS_INDEX_SCALE
+    pub total_shares: u64,           // Shares outstanding across all positions
+    pub last_accrued_at: i64,        // Last time interest was minted
+    pub funding_cap: u64,            // Max interest ever minted into the pool (0 = none)
+    pub total_interest_minted: u64,  // Running total minted by accrual
+    pub bump: u8,
 }
 
-#[event]
-pub struct MultisigProposalExecuted {
-    pub proposal: Pubkey,
-    pub executor: Pubkey,
-    pub timestamp: i64,
+#[account]
+pub struct SavingsPosition {
+    pub pool: Pubkey,                // Owning SavingsPool
+    pub owner: Pubkey,               // Depositor
+    pub shares: u64,                 // Pool shares held
+    pub bump: u8,
 }
 
-// === PROGRAM ===
-declare_id!("8JpbyYEJXLeWoPJcLsHWg64bDtwFZXhPoubVJPeH11aH");
+// Promotional reward paid pro-rata to holders in an off-chain balance snapshot.
+// The vault is an ATA of the campaign PDA for any SPL or Token-2022 mint.
+#[account]
+pub struct RewardCampaign {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub campaign_id: u64,            // Issuer-chosen identifier
+    pub root: [u8; 32],              // Merkle root of (owner, balance) leaves
+    pub snapshot_slot: u64,          // Slot the balances were taken at
+    pub total_balance: u64,          // Sum of all snapshot balances
+    pub reward_mint: Pubkey,         // Token paid out
+    pub vault: Pubkey,               // Campaign-owned reward ATA
+    pub reward_amount: u64,          // Funded at creation, split by balance share
+    pub claimed_amount: u64,         // Running total claimed
+    pub claim_count: u64,            // Number of claims
+    pub expires_at: i64,             // Claims close; remainder becomes sweepable
+    pub swept: bool,
+    pub bump: u8,
+}
 
-#[program]
-pub mod sss_token {
-    use super::*;
+#[account]
+pub struct RewardClaim {
+    pub campaign: Pubkey,            // Owning RewardCampaign
+    pub owner: Pubkey,               // Holder that claimed
+    pub amount: u64,                 // Reward paid
+    pub claimed_at: i64,
+    pub bump: u8,
+}
 
-    // === INITIALIZE ===
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        name: String,
-        symbol: String,
-        decimals: u8,
-        enable_transfer_hook: bool,
-        enable_permanent_delegate: bool,
-    ) -> Result<()> {
-        require!(name.len() <= 32, StablecoinError::InvalidAmount); // TODO: add NameTooLong variant
-        require!(symbol.len() <= 10, StablecoinError::InvalidAmount); // TODO: add SymbolTooLong variant
+// Linear per-second payment from `payer` to `recipient`, escrowed in an ATA
+// owned by the stream PDA. Escrow transfers go through the transfer hook.
+#[account]
+pub struct PaymentStream {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub payer: Pubkey,               // Funder, refunded the unvested part on cancel
+    pub recipient: Pubkey,           // Receives the vested amount
+    pub escrow: Pubkey,              // Stream-owned escrow ATA
+    pub stream_id: u64,              // Payer-chosen identifier
+    pub amount: u64,                 // Total escrowed at creation
+    pub withdrawn: u64,              // Paid to the recipient so far
+    pub refunded: u64,               // Returned to the payer on cancel
+    pub start_time: i64,             // Vesting starts
+    pub end_time: i64,               // Fully vested
+    pub canceled_at: i64,            // Vesting stops here (0 = active)
+    pub bump: u8,
+}
 
-        // Initialize stablecoin state
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        stablecoin.authority = ctx.accounts.authority.key();
-        stablecoin.mint = ctx.accounts.mint.key();
-        stablecoin.name = name.clone();
-        stablecoin.symbol = symbol.clone();
-        stablecoin.decimals = decimals;
-        stablecoin.total_supply = 0;
-        stablecoin.is_paused = false;
-        stablecoin.features = 0;
-        stablecoin.supply_cap = 0;          // 0 = unlimited
-        stablecoin.epoch_quota = 0;         // 0 = unlimited
-        stablecoin.current_epoch_minted = 0;
-        stablecoin.current_epoch_start = Clock::get()?.unix_timestamp;
-        stablecoin.pending_authority = None;
-        if enable_transfer_hook {
-            stablecoin.features |= 1;
-        }
-        if enable_permanent_delegate {
-            stablecoin.features |= 2;
-        }
-        stablecoin.bump = ctx.bumps.stablecoin_state;
+// Offline key (e.g. HSM-held) whose ed25519-signed vouchers mint through
+// mint_with_voucher, with its own quota like a MinterInfo
+#[account]
+pub struct VoucherSigner {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub signer: Pubkey,              // ed25519 key signing vouchers
+    pub quota: u64,                  // Max amount minted by vouchers
+    pub minted: u64,                 // Already minted by vouchers
+    pub enabled: bool,
+    pub bump: u8,
+}
 
-        // Initialize master role for creator
-        let master_role = &mut ctx.accounts.master_role;
-        master_role.owner = ctx.accounts.authority.key();
-        master_role.roles = ROLE_MASTER | ROLE_MINTER | ROLE_BURNER | ROLE_PAUSER | ROLE_BLACKLISTER | ROLE_SEIZER;
-        master_role.stablecoin = stablecoin.key();
-        master_role.bump = ctx.bumps.master_role;
+// Written by revoke_key, `["key_rotation", state, old_key]`. Links a
+// compromised key to its replacement and records what was revoked.
+#[account]
+pub struct KeyRotation {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub old_key: Pubkey,             // Compromised key
+    pub new_key: Pubkey,             // Replacement (default = none)
+    pub revoked_roles: u8,           // Roles the old key held
+    pub multisig_member: bool,       // Old key was a multisig signer
+    pub voucher_signer: bool,        // Old key was a voucher signer
+    pub revoked_by: Pubkey,          // Master that revoked it
+    pub revoked_at: i64,
+    pub bump: u8,
+}
 
-        emit!(StablecoinInitialized {
-            mint: ctx.accounts.mint.key(),
-            authority: ctx.accounts.authority.key(),
-            name,
-            symbol,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// Mint velocity limits, `["circuit_breaker", state]`. A mint breaching them
+// trips StablecoinState::circuit_tripped instead of minting.
+#[account]
+pub struct CircuitBreaker {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub guardian: Pubkey,            // May reset a tripped breaker (as may any master)
+    pub max_mint_per_hour: u64,      // Rolling hourly mint limit (0 = off)
+    pub max_single_mint_bps: u16,    // Largest single mint as bps of supply (0 = off)
+    pub hour_start: i64,             // Start of the current hourly window
+    pub hour_minted: u64,            // Minted in the current hourly window
+    pub tripped_at: i64,             // Last trip time
+    pub trip_count: u64,
+    pub bump: u8,
+}
 
-        Ok(())
-    }
+// Pending rescue of tokens stranded on a program PDA,
+// `["rescue", state, source_token_account]`. Executable after RESCUE_TIMELOCK_SECONDS.
+#[account]
+pub struct RescueRequest {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub source: RescueSource,        // PDA holding the stranded tokens
+    pub source_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,         // Token account receiving the rescue
+    pub amount: u64,
+    pub requested_by: Pubkey,
+    pub unlocks_at: i64,
+    pub bump: u8,
+}
 
-    // === MINT ===
-    pub fn mint(
-        ctx: Context<MintTokens>,
-        amount: u64,
-    ) -> Result<()> {
-        // Read values we need before any mutable borrow
-        let is_paused = ctx.accounts.stablecoin_state.is_paused;
-        let supply_cap = ctx.accounts.stablecoin_state.supply_cap;
-        let epoch_quota = ctx.accounts.stablecoin_state.epoch_quota;
-        let epoch_start = ctx.accounts.stablecoin_state.current_epoch_start;
-        let total_supply = ctx.accounts.stablecoin_state.total_supply;
-        let stablecoin_key = ctx.accounts.stablecoin_state.key();
-        let role_bits = ctx.accounts.minter_role.roles;
-        
-        require!(!is_paused, StablecoinError::ContractPaused);
-        require!(amount > 0, StablecoinError::InvalidAmount);
-        
-        // Check minter role
-        require!(
-            role_bits & ROLE_MINTER != 0 || role_bits & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        // Check quota if not master
-        if role_bits & ROLE_MASTER == 0 {
-            let minter_info = &ctx.accounts.minter_info;
-            let new_minted = minter_info.minted.checked_add(amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-            require!(
-                new_minted <= minter_info.quota,
-                StablecoinError::QuotaExceeded
-            );
-        }
-        
-        // Check supply cap
-        let new_supply = total_supply.checked_add(amount)
-            .ok_or(StablecoinError::MathOverflow)?;
-        if supply_cap > 0 {
-            require!(new_supply <= supply_cap, StablecoinError::SupplyCapExceeded);
-        }
-        
-        // Check epoch quota
-        if epoch_quota > 0 {
-            let current_time = Clock::get()?.unix_timestamp;
-            let epoch_elapsed = current_time - epoch_start;
-            
-            // If epoch passed (24 hours = 86400 seconds), reset
-            if epoch_elapsed >= 86400 {
-                let stablecoin_mut = &mut ctx.accounts.stablecoin_state;
-                stablecoin_mut.current_epoch_minted = 0;
-                stablecoin_mut.current_epoch_start = current_time;
-            }
-            
-            let epoch_new_total = ctx.accounts.stablecoin_state.current_epoch_minted
-                .checked_add(amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-            require!(
-                epoch_new_total <= epoch_quota,
-                StablecoinError::EpochQuotaExceeded
-            );
-        }
+// Record of a burn_frozen destruction, `["destruction_case", state, proposal]`
+#[account]
+pub struct DestructionCase {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub token_account: Pubkey,       // Account burned from
+    pub owner: Pubkey,               // Its owner at burn time
+    pub amount: u64,                 // Amount destroyed
+    pub seizer: Pubkey,              // Seizer that executed the burn
+    pub proposal: Pubkey,            // Multisig proposal approving it
+    pub was_frozen: bool,            // Thawed and re-frozen around the burn
+    pub executed_at: i64,
+    pub bump: u8,
+}
 
-        let mint_authority_bump = ctx.bumps.mint_authority;
-        // CPI to mint tokens
-        token_2022::mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token_2022::MintTo {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: ctx.accounts.recipient_account.to_account_info(),
-                    authority: ctx.accounts.mint_authority.to_account_info(),
-                },
-                &[&[b"mint_authority", stablecoin_key.as_ref(), &[mint_authority_bump]]],
-            ),
-            amount,
-        )?;
+// Lamport pool paying roll_epoch cranks, `["crank_fund", state]`. Anyone can
+// top it up with a plain lamport transfer.
+#[account]
+pub struct CrankFund {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub bounty_lamports: u64,        // Paid per successful roll_epoch (0 = none)
+    pub total_paid: u64,             // Lamports paid out so far
+    pub bump: u8,
+}
 
-        // Update state
-        let stablecoin_mut = &mut ctx.accounts.stablecoin_state;
-        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_add(amount)
-            .ok_or(StablecoinError::MathOverflow)?;
+// One page of privileged-party keys, `["holder_index", state, kind, page]`.
+// Role holders (HOLDER_INDEX_ROLES) are kept by update_roles/close_role,
+// minters (HOLDER_INDEX_MINTERS) by update_minter_quota/close_minter_info.
+#[account]
+pub struct HolderIndex {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub kind: u8,                    // HOLDER_INDEX_ROLES or HOLDER_INDEX_MINTERS
+    pub page: u8,
+    pub holders: Vec<Pubkey>,        // Up to HOLDER_INDEX_PAGE_SIZE keys
+    pub bump: u8,
+}
 
-        // Update minter quota if applicable
-        if role_bits & ROLE_MASTER == 0 {
-            let minter_info = &mut ctx.accounts.minter_info;
-            minter_info.minted = minter_info.minted.checked_add(amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-        }
-        
-        // Update epoch minted
-        stablecoin_mut.current_epoch_minted = stablecoin_mut.current_epoch_minted
-            .checked_add(amount)
-            .ok_or(StablecoinError::MathOverflow)?;
+// The issuer's address lookup tables, `["lookup_tables", state]`. The PDA is
+// the authority of every table it lists, so tables are only created and
+// extended through create_lookup_table/extend_lookup_table.
+#[account]
+pub struct LookupTableRegistry {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub tables: Vec<Pubkey>,         // Up to MAX_LOOKUP_TABLES tables
+    pub bump: u8,
+}
 
-        emit!(TokensMinted {
-            minter: ctx.accounts.minter.key(),
-            recipient: ctx.accounts.recipient_account.key(),
-            amount,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// Per-minter mint activity, `["minter_stats", minter, mint]`. Keeps the last
+// MINTER_STATS_DAYS days in a ring indexed by day % MINTER_STATS_DAYS.
+// Zero-copy since every mint writes it.
+#[account(zero_copy)]
+pub struct MinterDailyStats {
+    pub minter: Pubkey,              // Minter address
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub total_mints: u64,            // Mint instructions since creation
+    pub total_volume: u64,           // Tokens minted since creation
+    pub days: [DayStats; MINTER_STATS_DAYS],
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
 
-        Ok(())
-    }
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: i64,                    // unix_timestamp / 86400
+    pub volume: u64,
+    pub mint_count: u32,
+    pub _padding: [u8; 4],
+}
 
-    // === BURN ===
-    pub fn burn(
-        ctx: Context<BurnTokens>,
-        amount: u64,
-    ) -> Result<()> {
-        let stablecoin = &ctx.accounts.stablecoin_state;
-        
-        require!(!stablecoin.is_paused, StablecoinError::ContractPaused);
-        require!(amount > 0, StablecoinError::InvalidAmount);
-        
-        // Check burner role or self-burn
-        let is_burner = ctx.accounts.burner_role.roles & ROLE_BURNER != 0 
-            || ctx.accounts.burner_role.roles & ROLE_MASTER != 0;
-        let is_owner = ctx.accounts.token_account.owner == ctx.accounts.burner.key();
-        require!(is_burner || is_owner, StablecoinError::Unauthorized);
+// Registered monitoring key allowed to raise anomaly flags,
+// `["monitor", state, monitor]`
+#[account]
+pub struct Monitor {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub monitor: Pubkey,             // Crank/monitor key
+    pub enabled: bool,
+    pub bump: u8,
+}
 
-        // CPI to burn tokens
-        if is_burner {
-            // Burner can burn from any account
-            token_2022::burn(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    token_2022::Burn {
-                        mint: ctx.accounts.mint.to_account_info(),
-                        from: ctx.accounts.token_account.to_account_info(),
-                        authority: ctx.accounts.burn_authority.to_account_info(),
-                    },
-                    &[&[b"burn_authority", stablecoin.key().as_ref(), &[ctx.bumps.burn_authority]]],
-                ),
-                amount,
-            )?;
-        } else {
-            // Owner burns their own tokens
-            token_2022::burn(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    token_2022::Burn {
-                        mint: ctx.accounts.mint.to_account_info(),
-                        from: ctx.accounts.token_account.to_account_info(),
-                        authority: ctx.accounts.burner.to_account_info(),
-                    },
-                ),
-                amount,
-            )?;
-        }
+// Anomaly flags raised by monitors, `["anomaly_state", state]`. Automation
+// (circuit breaker guardians, hook pausers) keys off `flags`.
+#[account]
+pub struct AnomalyState {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub flags: u8,                   // Raised ANOMALY_* flags
+    pub acknowledged: u8,            // Raised flags an operator has acknowledged
+    pub last_flag: u8,               // Most recently raised flag
+    pub last_value: u64,             // Monitor-supplied measurement for last_flag
+    pub last_raised_by: Pubkey,
+    pub last_raised_at: i64,
+    pub raise_count: u64,
+    pub bump: u8,
+}
 
-        // Update state
-        let stablecoin_mut = &mut ctx.accounts.stablecoin_state;
-        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_sub(amount)
-            .ok_or(StablecoinError::MathOverflow)?;
+// Latest reserve attestation, `["reserve_attestation", state]`. The attestor
+// replaces every bucket in one report; ReservesAttested events keep the
+// history, so past monthly reports can be rebuilt from the chain.
+#[account]
+pub struct ReserveAttestation {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub attestor: Pubkey,            // Key allowed to post reports
+    pub buckets: Vec<ReserveBucket>, // Up to MAX_RESERVE_BUCKETS
+    pub report_hash: [u8; 32],       // Hash of the off-chain attestation report
+    pub attested_supply: u64,        // total_supply when the report was posted
+    pub updated_at: i64,
+    pub bump: u8,
+    pub auditor: [u8; 20],           // secp256k1 (Ethereum-style) address of the audit firm
+    pub audited_reserves: u64,       // Latest auditor-signed reserve total
+    pub audited_period: u64,         // Period it covers; must increase with each report
+    pub audited_report_hash: [u8; 32],
+    pub audited_at: i64,
+}
 
-        emit!(TokensBurned {
-            burner: ctx.accounts.burner.key(),
-            owner: ctx.accounts.token_account.owner,
-            amount,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReserveBucket {
+    pub kind: ReserveKind,
+    pub amount: u64,                 // In the stablecoin's base units
+    pub custodian_hash: [u8; 32],    // Hash of the custodian's identifier
+    pub attested_at: i64,            // When the custodian confirmed the balance
+}
 
-        Ok(())
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReserveKind {
+    Cash,
+    TreasuryBills,
+    Repo,
+    Other,
+}
+
+impl ReserveAttestation {
+    pub const SPACE: usize = 8 + 32 + 32 + 4 + MAX_RESERVE_BUCKETS * (1 + 8 + 32 + 8) + 32 + 8 + 8 + 1
+        + 20 + 8 + 8 + 32 + 8;
+    
+    pub fn total_reserves(&self) -> Result<u64> {
+        self.buckets.iter().try_fold(0u64, |total, bucket| {
+            total.checked_add(bucket.amount).ok_or(error!(StablecoinError::MathOverflow))
+        })
     }
+}
 
-    // === FREEZE ===
-    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
-        let stablecoin = &ctx.accounts.stablecoin_state;
-        
-        require!(!stablecoin.is_paused, StablecoinError::ContractPaused);
-        
-        // Check pauser role
-        require!(
-            ctx.accounts.pauser_role.roles & ROLE_PAUSER != 0
-            || ctx.accounts.pauser_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
+// Reserves over supply in basis points; None while nothing is outstanding
+pub fn coverage_bps(reserves: u64, supply: u64) -> Option<u64> {
+    if supply == 0 {
+        return None;
+    }
+    Some((reserves as u128 * 10_000 / supply as u128).min(u64::MAX as u128) as u64)
+}
 
-        // CPI to freeze account
-        token_2022::freeze_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token_2022::FreezeAccount {
-                    account: ctx.accounts.token_account.to_account_info(),
-                    mint: ctx.accounts.mint.to_account_info(),
-                    authority: ctx.accounts.freeze_authority.to_account_info(),
-                },
-                &[&[b"freeze_authority", stablecoin.key().as_ref(), &[ctx.bumps.freeze_authority]]],
-            ),
-        )?;
+// DAO governance allowed to call execute_from_governance, `["governance", state]`.
+// `governance` is an spl-governance (Realms) Governance account owned by
+// `governance_program`; it only signs when that program executes an approved
+// proposal transaction.
+#[account]
+pub struct GovernanceConfig {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub governance_program: Pubkey,  // spl-governance program (Realms deployment)
+    pub governance: Pubkey,          // Governance account (default = disabled)
+    pub bump: u8,
+}
 
-        emit!(AccountFrozen {
-            pauser: ctx.accounts.pauser.key(),
-            account: ctx.accounts.token_account.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// Used-nonce bitmap for one page of NONCES_PER_BITMAP nonces signed by `signer`,
+// `["nonce_bitmap", signer, page]`. Shared by every signed-message subsystem.
+#[account(zero_copy)]
+pub struct NonceBitmap {
+    pub signer: Pubkey,              // Key whose nonces are tracked
+    pub page: u64,                   // nonce / NONCES_PER_BITMAP
+    pub bits: [u8; 256],             // Bit (nonce % NONCES_PER_BITMAP) set once used
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
 
-        Ok(())
+impl NonceBitmap {
+    // Marks `nonce` used; false if it already was
+    pub fn test_and_set(&mut self, nonce: u64) -> bool {
+        let bit = (nonce % NONCES_PER_BITMAP) as usize;
+        let (byte, mask) = (bit / 8, 1u8 << (bit % 8));
+        let fresh = self.bits[byte] & mask == 0;
+        self.bits[byte] |= mask;
+        fresh
     }
+}
 
-    // === THAW ===
-    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
-        let stablecoin = &ctx.accounts.stablecoin_state;
-        
-        // Check pauser role
-        require!(
-            ctx.accounts.pauser_role.roles & ROLE_PAUSER != 0
-            || ctx.accounts.pauser_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
+// Program PDAs rescue_tokens can move stranded tokens out of
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RescueSource {
+    MintAuthority,
+    BurnAuthority,
+    FreezeAuthority,
+    TreasuryAuthority,
+    InsuranceAuthority,
+    SavingsAuthority,
+}
 
-        // CPI to thaw account
-        token_2022::thaw_account(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token_2022::ThawAccount {
-                    account: ctx.accounts.token_account.to_account_info(),
-                    mint: ctx.accounts.mint.to_account_info(),
-                    authority: ctx.accounts.freeze_authority.to_account_info(),
-                },
-                &[&[b"freeze_authority", stablecoin.key().as_ref(), &[ctx.bumps.freeze_authority]]],
-            ),
-        )?;
+impl RescueSource {
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            RescueSource::MintAuthority => b"mint_authority",
+            RescueSource::BurnAuthority => b"burn_authority",
+            RescueSource::FreezeAuthority => b"freeze_authority",
+            RescueSource::TreasuryAuthority => b"treasury_authority",
+            RescueSource::InsuranceAuthority => b"insurance_authority",
+            RescueSource::SavingsAuthority => b"savings_authority",
+        }
+    }
+    
+    // Whether the PDA's own-stablecoin balance is program-managed (treasury,
+    // insurance fund, savings pool) and so never rescuable
+    pub fn manages_stablecoin(&self) -> bool {
+        matches!(
+            self,
+            RescueSource::TreasuryAuthority | RescueSource::InsuranceAuthority | RescueSource::SavingsAuthority
+        )
+    }
+}
 
-        emit!(AccountThawed {
-            pauser: ctx.accounts.pauser.key(),
-            account: ctx.accounts.token_account.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// Program-owned token accounts, such as legacy treasuries, that
+// sweep_to_treasury may consolidate into the canonical treasury ATA.
+// `["sweep_allowlist", state]`
+#[account]
+pub struct SweepAllowlist {
+    pub stablecoin: Pubkey,          // Associated stablecoin
+    pub sources: Vec<SweepSource>,   // Up to MAX_SWEEP_SOURCES
+    pub total_swept: u64,            // Running total consolidated into the treasury
+    pub bump: u8,
+}
 
-        Ok(())
-    }
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SweepSource {
+    pub token_account: Pubkey,
+    pub source: RescueSource,        // PDA that owns token_account
+}
 
-    // === PAUSE/UNPAUSE ===
-    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        
-        // Check pauser role
-        require!(
-            ctx.accounts.pauser_role.roles & ROLE_PAUSER != 0
-            || ctx.accounts.pauser_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
+impl SweepAllowlist {
+    pub const SPACE: usize = 8 + 32 + 4 + MAX_SWEEP_SOURCES * (32 + 1) + 8 + 1;
+}
 
-        stablecoin.is_paused = paused;
+// Clock for the mint epoch quota. Slots and Solana epochs don't drift with
+// clock sysvar skew and are easier to advance in tests.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EpochMode {
+    #[default]
+    UnixSeconds,
+    Slots,
+    SolanaEpoch,
+}
 
-        if paused {
-            emit!(StablecoinPaused {
-                pauser: ctx.accounts.pauser.key(),
-                timestamp: Clock::get()?.unix_timestamp,
-            });
-        } else {
-            emit!(StablecoinUnpaused {
-                pauser: ctx.accounts.pauser.key(),
-                timestamp: Clock::get()?.unix_timestamp,
-            });
+impl From<u8> for EpochMode {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => EpochMode::Slots,
+            2 => EpochMode::SolanaEpoch,
+            _ => EpochMode::UnixSeconds,
         }
-
-        Ok(())
     }
+}
 
-    // === ROLE MANAGEMENT ===
-    pub fn update_roles(
-        ctx: Context<UpdateRoles>,
-        new_roles: u8,
-    ) -> Result<()> {
-        // Check master role
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
+// Launch policy for initialize_v2, applied in the same transaction that
+// creates the stablecoin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct InitConfig {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub enable_transfer_hook: bool,
+    pub enable_permanent_delegate: bool,
+    pub supply_cap: u64,             // 0 = unlimited
+    pub epoch_quota: u64,            // 0 = unlimited
+    pub epoch_mode: EpochMode,
+    pub epoch_length: u64,           // 0 = one day of unix seconds (UnixSeconds only)
+    pub start_paused: bool,          // Launch paused; a pauser lifts it once setup is checked
+    pub uri: String,                 // Token-2022 metadata URI (empty = no metadata)
+    pub transfer_hook_program: Option<Pubkey>, // Required program in the mint's TransferHook extension
+}
 
-        let role_account = &mut ctx.accounts.target_role;
-        role_account.roles = new_roles;
+// Changes a DAO can make through execute_from_governance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum GovernanceAction {
+    SetSupplyCap { new_cap: u64 },
+    SetFeeDistribution { recipients: Vec<FeeRecipient> },
+    // target_role must already exist; create new role accounts with update_roles
+    SetRoles { target: Pubkey, roles: u8 },
+}
 
-        emit!(RolesUpdated {
-            authority: ctx.accounts.authority.key(),
-            target: ctx.accounts.target.key(),
-            new_roles,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeRecipient {
+    pub token_account: Pubkey,       // Destination token account
+    pub share_bps: u16,              // Share of each distribution
+}
 
-        Ok(())
-    }
+// Typed payloads for MultisigProposal.instruction_data. Instructions that need
+// multisig approval compare the proposal payload against the action they perform.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ProposalAction {
+    TreasuryWithdraw { destination: Pubkey, amount: u64 },
+    // Consumed by the transfer hook's approve_seizure_case
+    ApproveSeizure { case: Pubkey },
+    InsuranceDraw { destination: Pubkey, amount: u64 },
+    BurnFrozen { token_account: Pubkey, amount: u64 },
+}
 
-    // === MINTER QUOTA ===
-    pub fn update_minter_quota(
-        ctx: Context<UpdateMinterQuota>,
-        new_quota: u64,
-    ) -> Result<()> {
-        // Check master role
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
+// === VIEW SNAPSHOTS (returned via set_return_data) ===
 
-        let minter_info = &mut ctx.accounts.minter_info;
-        minter_info.quota = new_quota;
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SupplyInfo {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub total_supply: u64,
+    pub supply_cap: u64,             // 0 = unlimited
+    pub epoch_quota: u64,            // 0 = unlimited
+    pub current_epoch_minted: u64,
+    pub current_epoch_start: i64,
+    pub is_paused: bool,
+    pub features: u8,
+    pub epoch_mode: EpochMode,
+}
 
-        emit!(MinterQuotaUpdated {
-            authority: ctx.accounts.authority.key(),
-            minter: ctx.accounts.minter.key(),
-            new_quota,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReserveInfo {
+    pub stablecoin: Pubkey,
+    pub buckets: Vec<ReserveBucket>,
+    pub total_reserves: u64,
+    pub total_supply: u64,           // Current supply
+    pub coverage_bps: Option<u64>,   // Reserves over current supply
+    pub attested_supply: u64,        // Supply when the report was posted
+    pub attested_coverage_bps: Option<u64>,
+    pub report_hash: [u8; 32],
+    pub updated_at: i64,
+    pub audited_reserves: u64,       // Latest auditor-signed total
+    pub audited_coverage_bps: Option<u64>, // Audited reserves over current supply
+    pub audited_period: u64,
+    pub audited_report_hash: [u8; 32],
+    pub audited_at: i64,
+}
 
-        Ok(())
-    }
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoleInfo {
+    pub owner: Pubkey,
+    pub stablecoin: Pubkey,
+    pub roles: u8,
+}
 
-    // === TRANSFER AUTHORITY ===
-    pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        
-        // Only current authority can transfer
-        require!(
-            ctx.accounts.authority.key() == stablecoin.authority,
-            StablecoinError::InvalidAuthority
-        );
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MinterQuotaInfo {
+    pub minter: Pubkey,
+    pub stablecoin: Pubkey,
+    pub quota: u64,
+    pub minted: u64,
+    pub remaining: u64,
+}
 
-        let pending = ctx.accounts.new_authority.key();
-        stablecoin.pending_authority = Some(pending);
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MinterStatsInfo {
+    pub minter: Pubkey,
+    pub stablecoin: Pubkey,
+    pub total_mints: u64,
+    pub total_volume: u64,
+    pub today: DayStats,
+    pub days: Vec<DayStats>,         // Retained days, most recent first
+}
 
-        emit!(AuthorityTransferStarted {
-            previous_authority: stablecoin.authority,
-            pending_authority: pending,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// === ROLE CONSTANTS ===
+pub const ROLE_MASTER: u8 = 1;       // Full control
+pub const ROLE_MINTER: u8 = 2;       // Can mint
+pub const ROLE_BURNER: u8 = 4;       // Can burn
+pub const ROLE_PAUSER: u8 = 8;       // Can pause/unpause
+pub const ROLE_BLACKLISTER: u8 = 16; // Can manage blacklist
+pub const ROLE_SEIZER: u8 = 32;      // Can seize tokens
+pub const ROLE_FREEZER: u8 = 64;     // Can freeze/thaw individual accounts (SSS-2)
+pub const ROLE_LABELER: u8 = 128;    // Can tag addresses with counterparty categories (SSS-2)
 
-        Ok(())
-    }
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+pub const DEFAULT_MAX_PROPOSAL_PAYLOAD: usize = 512; // Proposal payload cap until the master sets one
+pub const MAX_PROPOSAL_PAYLOAD: usize = 4096;        // Upper bound for MultisigConfig::max_payload_len
+pub const MAX_OWNER_ACCOUNTS: usize = 20; // Token accounts per freeze/thaw_accounts_for_owner
+pub const MAX_SWEEP_SOURCES: usize = 16;  // Token accounts per SweepAllowlist
+pub const MAX_RESERVE_BUCKETS: usize = 8; // Buckets per ReserveAttestation
+pub const HOLDER_INDEX_ROLES: u8 = 0;
+pub const HOLDER_INDEX_MINTERS: u8 = 1;
+pub const HOLDER_INDEX_PAGE_SIZE: usize = 32; // Keys per HolderIndex page
+pub const MAX_LOOKUP_TABLES: usize = 8;          // Tables per LookupTableRegistry
+pub const MAX_LOOKUP_TABLE_EXTEND: usize = 30;   // Addresses per extend_lookup_table
+pub const MINTER_STATS_DAYS: usize = 7; // Days retained in MinterDailyStats
+pub const RESCUE_TIMELOCK_SECONDS: i64 = 2 * 86400; // Delay between request_rescue and rescue_tokens
+pub const MAX_INSURANCE_ALLOCATION_BPS: u16 = 5_000; // 50% of distributed fees
+pub const MAX_SEIZURE_SPLITS: usize = 8;  // Destinations per seize_tokens_split
 
-    // === ACCEPT AUTHORITY ===
-    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        
-        let pending = stablecoin.pending_authority
-            .ok_or(StablecoinError::InvalidAuthority)?;
-            
-        require!(
-            ctx.accounts.pending_authority.key() == pending,
-            StablecoinError::InvalidAuthority
-        );
+// === FX ===
+pub const FX_RATE_SCALE: u64 = 1_000_000_000; // FxPair::rate fixed-point scale
+pub const MAX_FX_SPREAD_BPS: u16 = 1_000;     // 10%
 
-        let previous_authority = stablecoin.authority;
-        stablecoin.authority = ctx.accounts.pending_authority.key();
-        stablecoin.pending_authority = None;
+// === SAVINGS ===
+pub const SAVINGS_INDEX_SCALE: u128 = 1_000_000_000_000; // SavingsPool::index fixed-point scale
+pub const MAX_SAVINGS_RATE_BPS: u16 = 2_000;             // 20% APR
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
 
-        emit!(AuthorityTransferred {
-            previous_authority,
-            new_authority: ctx.accounts.pending_authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+// === NONCE BITMAPS ===
+pub const NONCES_PER_BITMAP: u64 = 2048; // Bits in NonceBitmap::bits
 
-        Ok(())
-    }
-    
-    // === UPDATE SUPPLY CAP ===
-    pub fn update_supply_cap(
-        ctx: Context<UpdateFeatures>,
-        new_cap: u64,
-    ) -> Result<()> {
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        stablecoin.supply_cap = new_cap;
-        
-        Ok(())
-    }
-    
-    // === UPDATE EPOCH QUOTA ===
-    pub fn update_epoch_quota(
-        ctx: Context<UpdateFeatures>,
-        new_quota: u64,
-    ) -> Result<()> {
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        stablecoin.epoch_quota = new_quota;
-        
-        Ok(())
-    }
-    
-    // === ENABLE MINT CLOSE AUTHORITY ===
-    pub fn enable_mint_close_authority(ctx: Context<UpdateFeatures>) -> Result<()> {
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        stablecoin.features |= 4; // Bit 2 = MintCloseAuthority
-        
-        Ok(())
-    }
-    
-    // === ENABLE DEFAULT ACCOUNT STATE ===
-    pub fn enable_default_account_state(ctx: Context<UpdateFeatures>) -> Result<()> {
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        let stablecoin = &mut ctx.accounts.stablecoin_state;
-        stablecoin.features |= 8; // Bit 3 = DefaultAccountState
-        
-        Ok(())
-    }
-    
-    // === BATCH MINT ===
-    // Recipients' token accounts are passed as remaining_accounts (in order matching amounts)
-    pub fn batch_mint<'a>(
-        ctx: Context<'_, '_, 'a, 'a, BatchMint<'a>>,
-        amounts: Vec<u64>,
-    ) -> Result<()> {
-        let n = amounts.len();
-        require!(n > 0 && n <= 10, StablecoinError::InvalidAmount);
-        require!(ctx.remaining_accounts.len() == n, StablecoinError::InvalidAmount);
-        
-        // Read values before any mutable borrow
-        let is_paused = ctx.accounts.stablecoin_state.is_paused;
-        let supply_cap = ctx.accounts.stablecoin_state.supply_cap;
-        let epoch_quota = ctx.accounts.stablecoin_state.epoch_quota;
-        let epoch_start = ctx.accounts.stablecoin_state.current_epoch_start;
-        let total_supply = ctx.accounts.stablecoin_state.total_supply;
-        let stablecoin_key = ctx.accounts.stablecoin_state.key();
-        let role_bits = ctx.accounts.minter_role.roles;
-        
-        require!(!is_paused, StablecoinError::ContractPaused);
-        
-        // Check minter role
-        require!(
-            role_bits & ROLE_MINTER != 0 || role_bits & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        
-        let mut total_amount: u64 = 0;
-        for amount in amounts.iter() {
-            require!(*amount > 0, StablecoinError::InvalidAmount);
-            total_amount = total_amount.checked_add(*amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-        }
-        
-        // Check quota if not master
-        if role_bits & ROLE_MASTER == 0 {
-            let minter_info = &ctx.accounts.minter_info;
-            let new_minted = minter_info.minted.checked_add(total_amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-            require!(
-                new_minted <= minter_info.quota,
-                StablecoinError::QuotaExceeded
-            );
-        }
-        
-        // Check supply cap
-        let new_supply = total_supply.checked_add(total_amount)
-            .ok_or(StablecoinError::MathOverflow)?;
-        if supply_cap > 0 {
-            require!(new_supply <= supply_cap, StablecoinError::SupplyCapExceeded);
-        }
-        
+// === PARAMETER LOCKS (irreversible) ===
+pub const LOCK_SUPPLY_CAP: u8 = 1;   // Cap can never be removed or raised
+pub const LOCK_EPOCH_QUOTA: u8 = 2;  // Epoch quota can never be removed or raised
+pub const LOCK_SEIZER_ROLE: u8 = 4;  // ROLE_SEIZER can never be granted again
+pub const LOCK_FEATURES: u8 = 8;     // No further feature flags can be enabled
+pub const LOCK_ALL: u8 = LOCK_SUPPLY_CAP | LOCK_EPOCH_QUOTA | LOCK_SEIZER_ROLE | LOCK_FEATURES;
+
+// === RENOUNCED AUTHORITIES ===
+pub const RENOUNCED_FREEZE: u8 = 1;              // Mint freeze authority set to None
+pub const RENOUNCED_PERMANENT_DELEGATE: u8 = 2;   // Permanent delegate set to None
+
+// === ANOMALY FLAGS ===
+pub const ANOMALY_SUPPLY_DIVERGENCE: u8 = 1; // On-chain supply disagrees with reserves/mint
+pub const ANOMALY_ORACLE_STALE: u8 = 2;      // Price or reserve oracle stopped updating
+pub const ANOMALY_FEE_SPIKE: u8 = 4;         // Fee revenue far outside its usual range
+pub const ANOMALY_ALL: u8 = ANOMALY_SUPPLY_DIVERGENCE | ANOMALY_ORACLE_STALE | ANOMALY_FEE_SPIKE;
+
+// === UPGRADE AUTHORITY POLICY ===
+pub const UPGRADE_POLICY_NONE: u8 = 0;      // Nothing recorded
+pub const UPGRADE_POLICY_RECORDED: u8 = 1;  // Must match upgrade_authority
+pub const UPGRADE_POLICY_MULTISIG: u8 = 2;  // upgrade_authority must be the multisig PDA
+
+// === ERROR CODES ===
+#[error_code]
+pub enum StablecoinError {
+    #[msg("Unauthorized: Insufficient role permissions")]
+    Unauthorized = 6000,
+    #[msg("Contract is paused")]
+    ContractPaused,
+    #[msg("Invalid mint amount")]
+    InvalidAmount,
+    #[msg("Minter quota exceeded")]
+    QuotaExceeded,
+    #[msg("Role already assigned")]
+    RoleAlreadyAssigned,
+    #[msg("Math overflow")]
+    MathOverflow,
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+    #[msg("Compliance not enabled")]
+    ComplianceNotEnabled,
+    #[msg("Already initialized")]
+    AlreadyInitialized,
+    #[msg("Insufficient balance")]
+    InsufficientBalance,
+    #[msg("Supply cap exceeded")]
+    SupplyCapExceeded,
+    #[msg("Epoch quota exceeded")]
+    EpochQuotaExceeded,
+    #[msg("Token name too long (max 32 chars)")]
+    NameTooLong,
+    #[msg("Token symbol too long (max 10 chars)")]
+    SymbolTooLong,
+    #[msg("Invalid role bitmask")]
+    InvalidRole,
+    #[msg("Mint account does not match stablecoin state")]
+    InvalidMint,
+    #[msg("Invariant violated: tracked supply differs from mint supply")]
+    SupplyMismatch,
+    #[msg("Invariant violated: epoch minted exceeds epoch quota")]
+    EpochMintedExceedsQuota,
+    #[msg("Invariant violated: no master role holder")]
+    NoMasterAuthority,
+    #[msg("Invariant violated: supply above supply cap")]
+    SupplyAboveCap,
+    #[msg("Cannot remove the last master role holder")]
+    LastMasterRole,
+    #[msg("Supply must be zero")]
+    SupplyNotZero,
+    #[msg("Proposal is still pending")]
+    ProposalStillPending,
+    #[msg("Stablecoin is being sunset")]
+    StablecoinSunset,
+    #[msg("Stablecoin is not in sunset")]
+    NotSunset,
+    #[msg("Parameter is permanently locked")]
+    ParameterLocked,
+    #[msg("Invalid lock flag")]
+    InvalidLockFlag,
+    #[msg("Authority has been renounced")]
+    AuthorityRenounced,
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+    #[msg("Proposal already executed")]
+    AlreadyExecuted,
+    #[msg("Approval threshold not met")]
+    ThresholdNotMet,
+    #[msg("Proposal payload does not match the requested action")]
+    ProposalMismatch,
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Invalid fee distribution: up to 5 recipients whose shares sum to 10000 bps")]
+    InvalidFeeDistribution,
+    #[msg("No fees pending distribution")]
+    NothingToDistribute,
+    #[msg("Default frozen account state is not enabled")]
+    DefaultStateNotEnabled,
+    #[msg("Owner has no active whitelist entry or KYC record")]
+    NotOnboarded,
+    #[msg("Owner is still whitelisted or KYC verified")]
+    StillOnboarded,
+    #[msg("Invalid transfer hook account")]
+    InvalidHookAccount,
+    #[msg("Invalid FX pair configuration")]
+    InvalidFxPair,
+    #[msg("FX pair is disabled")]
+    FxPairDisabled,
+    #[msg("FX rate is missing or stale")]
+    StaleFxRate,
+    #[msg("FX pair daily limit exceeded")]
+    FxDailyLimitExceeded,
+    #[msg("Swap output below the requested minimum")]
+    SlippageExceeded,
+    #[msg("Insurance allocation exceeds 5000 bps")]
+    InvalidInsuranceAllocation,
+    #[msg("Insurance fund token account missing or mismatched")]
+    InvalidInsuranceFund,
+    #[msg("Treasury burn policy is disabled")]
+    BurnPolicyDisabled,
+    #[msg("Treasury burn epoch limit exceeded")]
+    BurnLimitExceeded,
+    #[msg("Savings rate exceeds 2000 bps")]
+    InvalidSavingsRate,
+    #[msg("Invalid reward campaign parameters")]
+    InvalidRewardCampaign,
+    #[msg("Reward proof does not match the snapshot root")]
+    InvalidRewardProof,
+    #[msg("Reward campaign has expired or been swept")]
+    RewardCampaignClosed,
+    #[msg("Reward campaign is still open for claims")]
+    RewardCampaignActive,
+    #[msg("Invalid stream schedule")]
+    InvalidStream,
+    #[msg("Stream has been canceled")]
+    StreamCanceled,
+    #[msg("Nothing has vested since the last withdrawal")]
+    NothingVested,
+    #[msg("Missing or invalid ed25519 voucher signature")]
+    InvalidVoucherSignature,
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+    #[msg("Voucher nonce already used")]
+    VoucherNonceUsed,
+    #[msg("Voucher signer is disabled")]
+    VoucherSignerDisabled,
+    #[msg("Invalid session key: needs non-master roles and a future expiry")]
+    InvalidSessionKey,
+    #[msg("Session key 24h limit exceeded")]
+    SessionLimitExceeded,
+    #[msg("Invalid key rotation")]
+    InvalidKeyRotation,
+    #[msg("Missing or invalid secp256k1 approval signature")]
+    InvalidSecpSignature,
+    #[msg("Caller is not the configured governance")]
+    InvalidGovernance,
+    #[msg("Program upgrade authority does not match the recorded one")]
+    UpgradeAuthorityMismatch,
+    #[msg("Circuit breaker tripped: minting halted until reset")]
+    CircuitTripped,
+    #[msg("Circuit breaker account missing or invalid")]
+    InvalidCircuitBreaker,
+    #[msg("Invalid anomaly flag")]
+    InvalidAnomalyFlag,
+    #[msg("Mint epoch has not elapsed yet")]
+    EpochNotDue,
+    #[msg("Tokens are program-managed or the rescue request does not match")]
+    InvalidRescue,
+    #[msg("Holder index page is full or does not match")]
+    InvalidHolderIndex,
+    #[msg("Lookup table is not registered, the registry is full or the address batch is invalid")]
+    InvalidLookupTable,
+    #[msg("Mint's TransferHook extension does not name the configured hook program")]
+    InvalidHookLinkage,
+    #[msg("Mint lacks a metadata pointer to itself, or the mint authority was not passed")]
+    InvalidMetadata,
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal payload exceeds the multisig's limit")]
+    ProposalTooLarge,
+    #[msg("No settled rebate is available to claim")]
+    NoRebateDue,
+    #[msg("No settled referral fees are available to claim")]
+    NoReferralFeesDue,
+    #[msg("Token account is not an allowlisted program-owned sweep source")]
+    InvalidSweepSource,
+    #[msg("Reserve report has too many buckets or a future attestation time")]
+    InvalidReserveAttestation,
+    #[msg("Signed attestation is for a past period or no auditor is registered")]
+    StaleAttestation,
+    #[msg("Swap party is blacklisted, under a legal or clawback hold, or frozen")]
+    SwapRestricted,
+    #[msg("Every other master role account must be closed with the stablecoin")]
+    RolesOutstanding,
+    #[msg("No fee credit is available to claim")]
+    NoFeeCreditDue,
+    #[msg("Seizure case is not an approved case for this account")]
+    InvalidSeizureCase,
+    #[msg("Seized funds may only go to the treasury, a restitution account or the case's exceptional destination")]
+    InvalidSeizureDestination,
+    #[msg("The case's exceptional destination is still timelocked")]
+    SeizureDestinationLocked,
+    #[msg("Stablecoin state is not in the legacy Borsh layout")]
+    NotLegacyState,
+}
+
+// === EVENTS ===
+#[event]
+pub struct StablecoinInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub registry_index: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub minter: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub epoch_id: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensBurned {
+    pub burner: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub pauser: Pubkey,
+    pub account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub pauser: Pubkey,
+    pub account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RescueRequested {
+    pub authority: Pubkey,
+    pub source_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub unlocks_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensRescued {
+    pub authority: Pubkey,
+    pub source_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FrozenFundsBurned {
+    pub seizer: Pubkey,
+    pub destruction_case: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnerAccountsFrozen {
+    pub pauser: Pubkey,
+    pub owner: Pubkey,
+    pub accounts: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnerAccountsThawed {
+    pub pauser: Pubkey,
+    pub owner: Pubkey,
+    pub accounts: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountOnboarded {
+    pub caller: Pubkey,
+    pub owner: Pubkey,
+    pub account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountOffboarded {
+    pub caller: Pubkey,
+    pub owner: Pubkey,
+    pub account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StablecoinPaused {
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StablecoinUnpaused {
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub authority: Pubkey,
+    pub target: Pubkey,
+    pub new_roles: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MonitorUpdated {
+    pub authority: Pubkey,
+    pub monitor: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AnomalyRaised {
+    pub monitor: Pubkey,
+    pub flag: u8,
+    pub value: u64,
+    pub flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AnomalyAcknowledged {
+    pub authority: Pubkey,
+    pub flag: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AnomalyCleared {
+    pub authority: Pubkey,
+    pub flag: u8,
+    pub flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerConfigured {
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    pub max_mint_per_hour: u64,
+    pub max_single_mint_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub stablecoin: Pubkey,
+    pub minter: Pubkey,
+    pub amount: u64,
+    pub hour_minted: u64,
+    pub total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakerReset {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpgradeAuthorityRecorded {
+    pub authority: Pubkey,
+    pub upgrade_authority: Pubkey,
+    pub policy: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpgradeAuthorityVerified {
+    pub upgrade_authority: Pubkey,
+    pub policy: u8,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceUpdated {
+    pub authority: Pubkey,
+    pub governance_program: Pubkey,
+    pub governance: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceActionExecuted {
+    pub governance: Pubkey,
+    pub action: GovernanceAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeyRevoked {
+    pub authority: Pubkey,
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub revoked_roles: u8,
+    pub multisig_member: bool,
+    pub voucher_signer: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionKeyCreated {
+    pub authority: Pubkey,
+    pub session_key: Pubkey,
+    pub roles: u8,
+    pub expires_at: i64,
+    pub per_op_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterQuotaUpdated {
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub new_quota: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferStarted {
+    pub previous_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchMinted {
+    pub minter: Pubkey,
+    pub recipients: u16,
+    pub total_amount: u64,
+    pub epoch_id: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SecpProposalApproved {
+    pub proposal: Pubkey,
+    pub eth_address: [u8; 20],
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SecpSignersUpdated {
+    pub authority: Pubkey,
+    pub secp_signers: Vec<[u8; 20]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterLockApplied {
+    pub authority: Pubkey,
+    pub flag: u8,
+    pub locks: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityRenounced {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub renounced: u8,               // RENOUNCED_* bit that was applied
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SunsetStarted {
+    pub authority: Pubkey,
+    pub redemption_only: bool,
+    pub total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StateAccountClosed {
+    pub authority: Pubkey,
+    pub account: Pubkey,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SupplyCapUpdated {
+    pub authority: Pubkey,
+    pub old_cap: u64,
+    pub new_cap: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochQuotaUpdated {
+    pub authority: Pubkey,
+    pub old_quota: u64,
+    pub new_quota: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochRolled {
+    pub cranker: Pubkey,
+    pub epoch_id: i64,
+    pub previous_epoch_minted: u64,
+    pub bounty_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrankBountyUpdated {
+    pub authority: Pubkey,
+    pub bounty_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochModeUpdated {
+    pub authority: Pubkey,
+    pub epoch_mode: EpochMode,
+    pub epoch_length: u64,
+    pub epoch_id: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintCloseAuthorityEnabled {
+    pub authority: Pubkey,
+    pub old_features: u8,
+    pub new_features: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DefaultAccountStateEnabled {
+    pub authority: Pubkey,
+    pub old_features: u8,
+    pub new_features: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeatureFlagChanged {
+    pub authority: Pubkey,
+    pub flag: u8,                    // Feature bit that changed
+    pub old_features: u8,
+    pub new_features: u8,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryInitialized {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub token_account: Pubkey,
+    pub timelock_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub proposal: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SweepSourceUpdated {
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub source: Option<RescueSource>, // None when removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasurySwept {
+    pub cranker: Pubkey,
+    pub harvested: u64,              // Withheld fees harvested
+    pub source_token_account: Option<Pubkey>,
+    pub swept: u64,                  // Balance consolidated from source_token_account
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservesAttested {
+    pub attestor: Pubkey,
+    pub buckets: Vec<ReserveBucket>,
+    pub total_reserves: u64,
+    pub attested_supply: u64,
+    pub coverage_bps: Option<u64>,
+    pub report_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignedAttestationSubmitted {
+    pub auditor: [u8; 20],
+    pub reserve_amount: u64,
+    pub period: u64,
+    pub report_hash: [u8; 32],
+    pub coverage_bps: Option<u64>,   // Against total_supply at submission
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesHarvested {
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub total_fees_harvested: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeDistributionUpdated {
+    pub authority: Pubkey,
+    pub recipients: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesDistributed {
+    pub cranker: Pubkey,
+    pub total_amount: u64,
+    pub recipients: u8,
+    pub insurance_amount: u64,       // Portion routed to the insurance fund
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RebateClaimed {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeCreditClaimed {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensSeized {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub seized_by: Pubkey,
+    pub reason_code: u8,
+    pub reference: Option<[u8; 32]>,
+    pub case: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizedTokensBurned {
+    pub from: Pubkey,
+    pub amount: u64,
+    pub seized_by: Pubkey,
+    pub reason_code: u8,
+    pub reference: Option<[u8; 32]>,
+    pub case: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BurnPolicyUpdated {
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub max_per_epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryBurned {
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub fees_burned: u64,            // Portion taken from fees_pending
+    pub epoch_burned: u64,
+    pub total_burned: u64,
+    pub total_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SavingsPoolConfigured {
+    pub authority: Pubkey,
+    pub pool: Pubkey,
+    pub rate_admin: Pubkey,
+    pub funding_cap: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SavingsRateUpdated {
+    pub rate_admin: Pubkey,
+    pub old_rate_bps: u16,
+    pub new_rate_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SavingsInterestAccrued {
+    pub pool: Pubkey,
+    pub interest: u64,
+    pub index: u128,
+    pub total_interest_minted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SavingsDeposited {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub index: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SavingsWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub index: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardCampaignCreated {
+    pub authority: Pubkey,
+    pub campaign: Pubkey,
+    pub campaign_id: u64,
+    pub root: [u8; 32],
+    pub snapshot_slot: u64,
+    pub reward_mint: Pubkey,
+    pub reward_amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub campaign: Pubkey,
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardCampaignSwept {
+    pub authority: Pubkey,
+    pub campaign: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamCreated {
+    pub stream: Pubkey,
+    pub payer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamWithdrawn {
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamCanceled {
+    pub stream: Pubkey,
+    pub canceled_by: Pubkey,
+    pub paid_to_recipient: u64,
+    pub refunded_to_payer: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherSignerUpdated {
+    pub authority: Pubkey,
+    pub signer: Pubkey,
+    pub quota: u64,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherMinted {
+    pub signer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub submitted_by: Pubkey,
+    pub epoch_id: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceFundInitialized {
+    pub authority: Pubkey,
+    pub insurance_fund: Pubkey,
+    pub token_account: Pubkey,
+    pub allocation_bps: u16,
+    pub timelock_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceAllocationUpdated {
+    pub authority: Pubkey,
+    pub old_allocation_bps: u16,
+    pub new_allocation_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceDrawn {
+    pub proposal: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_drawn: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxPairConfigured {
+    pub authority: Pubkey,
+    pub pair: Pubkey,
+    pub oracle: Pubkey,
+    pub spread_bps: u16,
+    pub daily_limit: u64,
+    pub max_rate_age: i64,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxRateUpdated {
+    pub pair: Pubkey,
+    pub oracle: Pubkey,
+    pub rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxSwapExecuted {
+    pub user: Pubkey,
+    pub pair: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub spread: u64,
+    pub rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LookupTableUpdated {
+    pub stablecoin: Pubkey,
+    pub table: Pubkey,
+    pub added: u32,                  // Addresses added; 0 when the table was created
+    pub timestamp: i64,
+}
+
+// === HELPERS ===
+
+// Writes Token-2022 metadata into the mint for initialize_v2. The mint must
+// carry a MetadataPointer to itself; the authority tops up the rent for the
+// grown account and stays the metadata update authority.
+fn init_token_metadata(ctx: &Context<Initialize>, name: String, symbol: String, uri: String) -> Result<()> {
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let mint_authority = ctx
+        .accounts
+        .mint_authority
+        .as_ref()
+        .ok_or(StablecoinError::InvalidMetadata)?;
+    let new_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<token_2022::spl_token_2022::state::Mint>::unpack(&data)?;
+        let pointer = mint
+            .get_extension::<MetadataPointer>()
+            .map_err(|_| StablecoinError::InvalidMetadata)?;
+        require!(
+            Option::<Pubkey>::from(pointer.metadata_address) == Some(mint_info.key()),
+            StablecoinError::InvalidMetadata
+        );
+        let metadata = spl_token_metadata_interface::state::TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            ..Default::default()
+        };
+        data.len() + metadata.tlv_size_of()?
+    };
+    let shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(mint_info.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    let stablecoin_key = ctx.accounts.stablecoin_state.key();
+    let bump = ctx.bumps.mint_authority.ok_or(StablecoinError::InvalidMetadata)?;
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: ctx.accounts.authority.to_account_info(),
+                mint_authority: mint_authority.to_account_info(),
+                mint: mint_info,
+            },
+            &[&[b"mint_authority", stablecoin_key.as_ref(), &[bump]]],
+        ),
+        name,
+        symbol,
+        uri,
+    )
+}
+
+// Converts `amount` source base units into destination base units at `rate`
+// (destination tokens per source token, scaled by FX_RATE_SCALE).
+pub fn fx_convert(amount: u64, rate: u64, source_decimals: u8, destination_decimals: u8) -> Result<u64> {
+    let scaled = (amount as u128)
+        .checked_mul(rate as u128)
+        .and_then(|v| v.checked_mul(10u128.checked_pow(destination_decimals as u32)?))
+        .ok_or(StablecoinError::MathOverflow)?;
+    let divisor = 10u128
+        .checked_pow(source_decimals as u32)
+        .and_then(|v| v.checked_mul(FX_RATE_SCALE as u128))
+        .ok_or(StablecoinError::MathOverflow)?;
+    u64::try_from(scaled / divisor).map_err(|_| error!(StablecoinError::MathOverflow))
+}
+
+// Whether `role` grants any role in `mask` right now. Session key roles lapse
+// at expires_at; permanent roles have expires_at == 0.
+pub fn has_role(role: &RoleAccount, mask: u8) -> Result<bool> {
+    if role.roles & mask == 0 {
+        return Ok(false);
+    }
+    Ok(role.expires_at == 0 || Clock::get()?.unix_timestamp < role.expires_at)
+}
+
+// Charges `amount` against a session key's rolling 24h per_op_limit
+pub fn charge_session_limit(role: &mut RoleAccount, amount: u64, now: i64) -> Result<()> {
+    if role.per_op_limit == 0 {
+        return Ok(());
+    }
+    if now - role.window_start >= 86400 {
+        role.window_used = 0;
+        role.window_start = now;
+    }
+    let used = role.window_used.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    require!(used <= role.per_op_limit, StablecoinError::SessionLimitExceeded);
+    role.window_used = used;
+    Ok(())
+}
+
+// Fails unless `program_data`'s upgrade authority is `expected`, where the
+// default key means the program must be immutable
+pub fn check_upgrade_authority(program_data: &ProgramData, expected: &Pubkey) -> Result<()> {
+    let actual = program_data.upgrade_authority_address.unwrap_or_default();
+    require_keys_eq!(actual, *expected, StablecoinError::UpgradeAuthorityMismatch);
+    Ok(())
+}
+
+// Sets `role`'s bitmask, honoring LOCK_SEIZER_ROLE and keeping master_count in
+// sync when ROLE_MASTER is granted or revoked
+pub fn apply_roles(stablecoin: &mut StablecoinState, role: &mut RoleAccount, new_roles: u8) -> Result<()> {
+    if stablecoin.locks & LOCK_SEIZER_ROLE != 0 {
+        require!(
+            new_roles & ROLE_SEIZER == 0 || role.roles & ROLE_SEIZER != 0,
+            StablecoinError::ParameterLocked
+        );
+    }
+    
+    let had_master = role.roles & ROLE_MASTER != 0;
+    let has_master = new_roles & ROLE_MASTER != 0;
+    if has_master && !had_master {
+        stablecoin.master_count = stablecoin.master_count.checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+    } else if had_master && !has_master {
+        stablecoin.master_count = stablecoin.master_count.saturating_sub(1);
+    }
+    role.roles = new_roles;
+    Ok(())
+}
+
+// Sets the supply cap, only ever tightening it once LOCK_SUPPLY_CAP is set.
+// Returns the old cap.
+pub fn apply_supply_cap(stablecoin: &mut StablecoinState, new_cap: u64) -> Result<u64> {
+    let old_cap = stablecoin.supply_cap;
+    if stablecoin.locks & LOCK_SUPPLY_CAP != 0 {
+        require!(new_cap > 0 && new_cap <= old_cap, StablecoinError::ParameterLocked);
+    }
+    stablecoin.supply_cap = new_cap;
+    Ok(old_cap)
+}
+
+pub fn validate_fee_recipients(recipients: &[FeeRecipient]) -> Result<()> {
+    require!(
+        !recipients.is_empty() && recipients.len() <= MAX_FEE_RECIPIENTS,
+        StablecoinError::InvalidFeeDistribution
+    );
+    let total_bps: u32 = recipients.iter().map(|r| r.share_bps as u32).sum();
+    require!(total_bps == 10_000, StablecoinError::InvalidFeeDistribution);
+    Ok(())
+}
+
+// Runs a mint of `amount` through the circuit breaker. Returns false if it
+// breaches a velocity limit, in which case the breaker is now tripped and the
+// caller must return Ok without minting so the trip persists.
+pub fn check_circuit_breaker(
+    stablecoin_state: &AccountLoader<StablecoinState>,
+    breaker: Option<&mut Account<CircuitBreaker>>,
+    minter: Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<bool> {
+    let mut stablecoin = stablecoin_state.load_mut()?;
+    require!(!stablecoin.circuit_tripped(), StablecoinError::CircuitTripped);
+    if !stablecoin.circuit_breaker_armed() {
+        return Ok(true);
+    }
+    let breaker = breaker.ok_or(StablecoinError::InvalidCircuitBreaker)?;
+    
+    if now - breaker.hour_start >= 3600 {
+        breaker.hour_minted = 0;
+        breaker.hour_start = now;
+    }
+    let hour_minted = breaker.hour_minted.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    let hourly_breach = breaker.max_mint_per_hour > 0 && hour_minted > breaker.max_mint_per_hour;
+    // A first mint into zero supply has no meaningful percentage
+    let single_breach = breaker.max_single_mint_bps > 0
+        && stablecoin.total_supply > 0
+        && amount as u128 * 10_000 > stablecoin.total_supply as u128 * breaker.max_single_mint_bps as u128;
+    
+    if hourly_breach || single_breach {
+        stablecoin.circuit_tripped = 1;
+        breaker.tripped_at = now;
+        breaker.trip_count = breaker.trip_count.saturating_add(1);
+        emit!(CircuitBreakerTripped {
+            stablecoin: stablecoin_state.key(),
+            minter,
+            amount,
+            hour_minted,
+            total_supply: stablecoin.total_supply,
+            timestamp: now,
+        });
+        return Ok(false);
+    }
+    breaker.hour_minted = hour_minted;
+    Ok(true)
+}
+
+// Current point on the mint epoch clock, in `mode` units
+pub fn epoch_clock(mode: EpochMode, clock: &Clock) -> i64 {
+    match mode {
+        EpochMode::UnixSeconds => clock.unix_timestamp,
+        EpochMode::Slots => clock.slot as i64,
+        EpochMode::SolanaEpoch => clock.epoch as i64,
+    }
+}
+
+// Starts a new mint epoch once the current one has run epoch_length units,
+// resetting current_epoch_minted. Returns the epoch id (its start).
+pub fn roll_mint_epoch(stablecoin: &mut StablecoinState) -> Result<i64> {
+    let now = epoch_clock(stablecoin.epoch_mode(), &Clock::get()?);
+    let length = match (stablecoin.epoch_mode(), stablecoin.epoch_length) {
+        (EpochMode::UnixSeconds, 0) => 86400,
+        (_, length) => length.max(1) as i64,
+    };
+    if now - stablecoin.current_epoch_start >= length {
+        stablecoin.current_epoch_minted = 0;
+        stablecoin.current_epoch_start = now;
+    }
+    Ok(stablecoin.current_epoch_start)
+}
+
+// Adds `key` to or drops it from an index page. Callers pass the page already
+// holding the key, or one with room when it is new.
+pub fn update_holder_index(index: Option<&mut Account<HolderIndex>>, key: Pubkey, listed: bool) -> Result<()> {
+    let Some(index) = index else {
+        return Ok(());
+    };
+    let position = index.holders.iter().position(|k| *k == key);
+    match (position, listed) {
+        (None, true) => {
+            require!(index.holders.len() < HOLDER_INDEX_PAGE_SIZE, StablecoinError::InvalidHolderIndex);
+            index.holders.push(key);
+        }
+        (Some(i), false) => {
+            index.holders.swap_remove(i);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// Adds `count` mints totalling `amount` to today's slot, recycling the slot
+// if it still holds an older day.
+pub fn record_minter_stats(stats: &mut MinterDailyStats, count: u32, amount: u64, now: i64) -> Result<()> {
+    let day = now.div_euclid(86400);
+    let slot = &mut stats.days[day.rem_euclid(MINTER_STATS_DAYS as i64) as usize];
+    if slot.day != day {
+        *slot = DayStats { day, ..DayStats::default() };
+    }
+    slot.mint_count = slot.mint_count.saturating_add(count);
+    slot.volume = slot.volume.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    stats.total_mints = stats.total_mints.saturating_add(count as u64);
+    stats.total_volume = stats.total_volume.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    Ok(())
+}
+
+// Applies the supply cap and epoch quota to a mint of `amount`, rolling the
+// epoch over when due, and records the new supply. Returns the epoch id.
+pub fn record_mint(stablecoin: &mut StablecoinState, amount: u64) -> Result<i64> {
+    require!(!stablecoin.circuit_tripped(), StablecoinError::CircuitTripped);
+    let new_supply = stablecoin.total_supply.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    if stablecoin.supply_cap > 0 {
+        require!(new_supply <= stablecoin.supply_cap, StablecoinError::SupplyCapExceeded);
+    }
+    
+    let epoch_id = roll_mint_epoch(stablecoin)?;
+    let epoch_minted = stablecoin.current_epoch_minted.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    if stablecoin.epoch_quota > 0 {
+        require!(epoch_minted <= stablecoin.epoch_quota, StablecoinError::EpochQuotaExceeded);
+    }
+    
+    stablecoin.total_supply = new_supply;
+    stablecoin.current_epoch_minted = epoch_minted;
+    Ok(epoch_id)
+}
+
+// Interest owed to the savings pool since its last accrual at `rate_bps`,
+// limited by the pool's funding cap and the stablecoin supply cap. Advances
+// the index and supply for the returned amount, which the caller mints into
+// the pool token account. Nothing accrues while the stablecoin is paused,
+// sunset or has its circuit breaker tripped; that time is forfeited.
+pub fn accrue_savings(pool: &mut SavingsPool, stablecoin: &mut StablecoinState, now: i64) -> Result<u64> {
+    let elapsed = now.saturating_sub(pool.last_accrued_at).max(0) as u128;
+    pool.last_accrued_at = now;
+    if pool.total_shares == 0 || pool.rate_bps == 0 || elapsed == 0 {
+        return Ok(0);
+    }
+    if stablecoin.is_paused() || stablecoin.is_sunset() || stablecoin.circuit_tripped() {
+        return Ok(0);
+    }
+    
+    let assets = (pool.total_shares as u128)
+        .checked_mul(pool.index)
+        .ok_or(StablecoinError::MathOverflow)?
+        / SAVINGS_INDEX_SCALE;
+    let owed = assets
+        .checked_mul(pool.rate_bps as u128)
+        .and_then(|v| v.checked_mul(elapsed))
+        .ok_or(StablecoinError::MathOverflow)?
+        / (10_000 * SECONDS_PER_YEAR as u128);
+    let mut interest = u64::try_from(owed).unwrap_or(u64::MAX)
+        .min(pool.funding_cap.saturating_sub(pool.total_interest_minted));
+    if stablecoin.supply_cap > 0 {
+        interest = interest.min(stablecoin.supply_cap.saturating_sub(stablecoin.total_supply));
+    }
+    if interest == 0 {
+        return Ok(0);
+    }
+    
+    pool.index = pool.index
+        .checked_add((interest as u128) * SAVINGS_INDEX_SCALE / pool.total_shares as u128)
+        .ok_or(StablecoinError::MathOverflow)?;
+    pool.total_interest_minted = pool.total_interest_minted.checked_add(interest)
+        .ok_or(StablecoinError::MathOverflow)?;
+    stablecoin.total_supply = stablecoin.total_supply.checked_add(interest)
+        .ok_or(StablecoinError::MathOverflow)?;
+    Ok(interest)
+}
+
+// Accrues the savings pool and mints the interest into its token account
+pub fn settle_savings<'info>(
+    pool: &mut Account<'info, SavingsPool>,
+    stablecoin: &mut AccountLoader<'info, StablecoinState>,
+    mint: AccountInfo<'info>,
+    pool_token_account: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    mint_authority_bump: u8,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let interest = accrue_savings(pool, &mut *stablecoin.load_mut()?, now)?;
+    if interest == 0 {
+        return Ok(());
+    }
+    
+    let stablecoin_key = stablecoin.key();
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program,
+            token_2022::MintTo {
+                mint,
+                to: pool_token_account,
+                authority: mint_authority,
+            },
+            &[&[b"mint_authority", stablecoin_key.as_ref(), &[mint_authority_bump]]],
+        ),
+        interest,
+    )?;
+    
+    emit!(SavingsInterestAccrued {
+        pool: pool.key(),
+        interest,
+        index: pool.index,
+        total_interest_minted: pool.total_interest_minted,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+// Verify that (`owner`, `balance`) is a leaf of a reward snapshot tree. Leaves are
+// `keccak(0x00 || owner || balance_le)`, nodes `keccak(0x01 || min(a, b) || max(a, b))`.
+pub fn verify_reward_proof(root: &[u8; 32], owner: &Pubkey, balance: u64, proof: &[[u8; 32]]) -> bool {
+    let mut node = keccak::hashv(&[&[0u8], owner.as_ref(), &balance.to_le_bytes()]).to_bytes();
+    for sibling in proof {
+        let (a, b) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        node = keccak::hashv(&[&[1u8], &a, &b]).to_bytes();
+    }
+    node == *root
+}
+
+// Amount of `stream` vested at `now`, frozen at canceled_at once canceled
+pub fn stream_vested(stream: &PaymentStream, now: i64) -> Result<u64> {
+    let until = if stream.canceled_at != 0 { stream.canceled_at } else { now };
+    if until <= stream.start_time {
+        return Ok(0);
+    }
+    if until >= stream.end_time {
+        return Ok(stream.amount);
+    }
+    let elapsed = (until - stream.start_time) as u128;
+    let duration = (stream.end_time - stream.start_time) as u128;
+    let vested = (stream.amount as u128)
+        .checked_mul(elapsed)
+        .ok_or(StablecoinError::MathOverflow)?
+        / duration;
+    Ok(vested as u64)
+}
+
+// Harvests withheld fees from `sources` into the mint, then withdraws all of
+// the mint's withheld fees into the treasury ATA. Returns the amount received.
+pub fn harvest_withheld_to_treasury<'info>(
+    token_program: &Program<'info, Token2022>,
+    mint: &InterfaceAccount<'info, InterfaceMint>,
+    treasury_token_account: &mut InterfaceAccount<'info, InterfaceTokenAccount>,
+    treasury_authority: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    sources: &[AccountInfo<'info>],
+) -> Result<u64> {
+    if !sources.is_empty() {
+        transfer_fee::harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                token_program.to_account_info(),
+                transfer_fee::HarvestWithheldTokensToMint {
+                    token_program_id: token_program.to_account_info(),
+                    mint: mint.to_account_info(),
+                },
+            ),
+            sources.to_vec(),
+        )?;
+    }
+    
+    let balance_before = treasury_token_account.amount;
+    transfer_fee::withdraw_withheld_tokens_from_mint(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer_fee::WithdrawWithheldTokensFromMint {
+                token_program_id: token_program.to_account_info(),
+                mint: mint.to_account_info(),
+                destination: treasury_token_account.to_account_info(),
+                authority: treasury_authority.clone(),
+            },
+            signer_seeds,
+        ),
+    )?;
+    treasury_token_account.reload()?;
+    let harvested = treasury_token_account.amount
+        .checked_sub(balance_before)
+        .ok_or(StablecoinError::MathOverflow)?;
+    Ok(harvested)
+}
+
+// Pay a claimant what `claim` still has due against `total_due`, out of the
+// treasury's pending fees and through the transfer hook, then record it on the
+// treasury and the claim. Anything fees_pending can't cover stays claimable;
+// with nothing to pay this fails with `nothing_due`. Returns the amount paid.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_claim_from_treasury<'info, C: TreasuryClaim>(
+    token_program: &AccountInfo<'info>,
+    treasury_token_account: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, InterfaceMint>,
+    destination: AccountInfo<'info>,
+    treasury_authority: AccountInfo<'info>,
+    treasury_authority_bump: u8,
+    extra_accounts: &[AccountInfo<'info>],
+    stablecoin: Pubkey,
+    treasury: &mut Treasury,
+    claim: &mut C,
+    claim_bump: u8,
+    claimant: Pubkey,
+    total_due: u64,
+    nothing_due: StablecoinError,
+) -> Result<u64> {
+    let amount = total_due
+        .saturating_sub(claim.claimed())
+        .min(treasury.fees_pending);
+    if amount == 0 {
+        return Err(nothing_due.into());
+    }
+    
+    transfer_with_hook(
+        token_program,
+        treasury_token_account,
+        mint.to_account_info(),
+        destination,
+        treasury_authority,
+        extra_accounts,
+        amount,
+        mint.decimals,
+        &[&[b"treasury_authority", stablecoin.as_ref(), &[treasury_authority_bump]]],
+    )?;
+    
+    treasury.fees_pending = treasury.fees_pending.checked_sub(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    treasury.total_fees_distributed = treasury.total_fees_distributed.checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    claim.record_payment(stablecoin, claimant, claim_bump, amount, Clock::get()?.unix_timestamp)?;
+    Ok(amount)
+}
+
+// Token-2022 transfer_checked that forwards `extra_accounts` so the mint's
+// transfer hook resolves its extra account metas.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_hook<'info>(
+    token_program: &AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    extra_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked(
+        token_program.key,
+        from,
+        mint,
+        to,
+        authority,
+        extra_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+// Reads the hook SeizureCase for `source_account` and settles it through the
+// hook's consume_seizure_case, which checks it is approved (or a clawback past
+// its dispute window) and closes it. Returns the case as it stood.
+pub fn consume_seizure_case(
+    accounts: &SeizeTokens,
+    delegate_bump: u8,
+) -> Result<sss_transfer_hook_program::SeizureCase> {
+    let case = load_hook_account::<sss_transfer_hook_program::SeizureCase>(
+        &accounts.seizure_case,
+        "SeizureCase",
+    )?
+    .ok_or(StablecoinError::InvalidSeizureCase)?;
+    require_keys_eq!(case.target, accounts.source_account.key(), StablecoinError::InvalidSeizureCase);
+    require!(
+        case.amount > 0 && case.amount <= accounts.source_account.amount,
+        StablecoinError::InsufficientBalance
+    );
+    
+    // Anchor reads an absent optional account as the program id itself
+    let hook_program = accounts.hook_program.to_account_info();
+    let clawback_hold = accounts
+        .clawback_hold
+        .as_ref()
+        .map(|info| info.to_account_info())
+        .unwrap_or_else(|| hook_program.clone());
+    let clawback_initiator = accounts
+        .clawback_initiator
+        .as_ref()
+        .map(|info| info.to_account_info())
+        .unwrap_or_else(|| hook_program.clone());
+    let infos = [
+        accounts.permanent_delegate.to_account_info(),
+        accounts.seizer.to_account_info(),
+        accounts.hook_config.to_account_info(),
+        accounts.seizure_case.to_account_info(),
+        clawback_hold,
+        clawback_initiator,
+        hook_program,
+    ];
+    let metas = vec![
+        AccountMeta::new_readonly(infos[0].key(), true),
+        AccountMeta::new(infos[1].key(), false),
+        AccountMeta::new_readonly(infos[2].key(), false),
+        AccountMeta::new(infos[3].key(), false),
+        AccountMeta::new(infos[4].key(), false),
+        AccountMeta::new(infos[5].key(), false),
+    ];
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: sss_transfer_hook_program::ID,
+        accounts: metas,
+        data: anchor_lang::solana_program::hash::hash(b"global:consume_seizure_case").to_bytes()[..8].to_vec(),
+    };
+    let stablecoin_key = accounts.stablecoin_state.key();
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &infos,
+        &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[delegate_bump]]],
+    )?;
+    Ok(case)
+}
+
+// Seized funds may only go to the treasury, a registered restitution account,
+// or the case's exceptional destination once its timelock passes
+pub fn check_seizure_destination(
+    stablecoin: &Pubkey,
+    case: &sss_transfer_hook_program::SeizureCase,
+    destination: &Pubkey,
+    destination_owner: &Pubkey,
+    restitution_entry: Option<&AccountInfo>,
+    now: i64,
+) -> Result<()> {
+    let (treasury_authority, _) =
+        Pubkey::find_program_address(&[b"treasury_authority", stablecoin.as_ref()], &crate::ID);
+    if *destination_owner == treasury_authority {
+        return Ok(());
+    }
+    if case.exceptional_destination == Some(*destination) {
+        require!(now >= case.destination_unlocks_at, StablecoinError::SeizureDestinationLocked);
+        return Ok(());
+    }
+    let entry = match restitution_entry {
+        Some(info) => load_hook_account::<sss_transfer_hook_program::RestitutionAccount>(info, "RestitutionAccount")?,
+        None => None,
+    };
+    match entry {
+        Some(e) if e.config == case.config && e.token_account == *destination => Ok(()),
+        _ => err!(StablecoinError::InvalidSeizureDestination),
+    }
+}
+
+// Has the hook settle a dormant account's escheatment (policy, flag and
+// notice period) through its consume_escheatment, signed by the permanent delegate
+pub fn consume_escheatment(accounts: &EscheatDormantAccount, delegate_bump: u8) -> Result<()> {
+    let infos = [
+        accounts.permanent_delegate.to_account_info(),
+        accounts.authority.to_account_info(),
+        accounts.hook_config.to_account_info(),
+        accounts.escheatment_policy.to_account_info(),
+        accounts.token_account.to_account_info(),
+        accounts.activity_record.to_account_info(),
+        accounts.escheatment_treasury.to_account_info(),
+        accounts.hook_program.to_account_info(),
+    ];
+    let metas = vec![
+        AccountMeta::new_readonly(infos[0].key(), true),
+        AccountMeta::new_readonly(infos[1].key(), false),
+        AccountMeta::new_readonly(infos[2].key(), false),
+        AccountMeta::new_readonly(infos[3].key(), false),
+        AccountMeta::new_readonly(infos[4].key(), false),
+        AccountMeta::new(infos[5].key(), false),
+        AccountMeta::new_readonly(infos[6].key(), false),
+    ];
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: sss_transfer_hook_program::ID,
+        accounts: metas,
+        data: anchor_lang::solana_program::hash::hash(b"global:consume_escheatment").to_bytes()[..8].to_vec(),
+    };
+    let stablecoin_key = accounts.stablecoin_state.key();
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &infos,
+        &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[delegate_bump]]],
+    )?;
+    Ok(())
+}
+
+// Signer seeds of the transfer delegate PDA holders approve on their token
+// accounts for sponsored transfers and allowances
+fn transfer_delegate_seeds<'a>(stablecoin: &'a Pubkey, bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
+    [b"transfer_delegate", stablecoin.as_ref(), bump]
+}
+
+// Has the hook authorize a relayed transfer (ed25519 intent, nonce, relayer)
+// through its consume_sponsored_transfer, signed by the transfer delegate
+pub fn consume_sponsored_transfer(
+    accounts: &ExecuteSponsoredTransfer,
+    delegate_bump: u8,
+    amount: u64,
+    fee: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let infos = [
+        accounts.transfer_delegate.to_account_info(),
+        accounts.relayer.to_account_info(),
+        accounts.hook_config.to_account_info(),
+        accounts.relayer_entry.to_account_info(),
+        accounts.source_account.to_account_info(),
+        accounts.destination_account.to_account_info(),
+        accounts.sponsor_nonce.to_account_info(),
+        accounts.instructions.to_account_info(),
+        accounts.system_program.to_account_info(),
+        accounts.hook_program.to_account_info(),
+    ];
+    let metas = vec![
+        AccountMeta::new_readonly(infos[0].key(), true),
+        AccountMeta::new(infos[1].key(), true),
+        AccountMeta::new_readonly(infos[2].key(), false),
+        AccountMeta::new(infos[3].key(), false),
+        AccountMeta::new_readonly(infos[4].key(), false),
+        AccountMeta::new_readonly(infos[5].key(), false),
+        AccountMeta::new(infos[6].key(), false),
+        AccountMeta::new_readonly(infos[7].key(), false),
+        AccountMeta::new_readonly(infos[8].key(), false),
+    ];
+    let mut data = anchor_lang::solana_program::hash::hash(b"global:consume_sponsored_transfer").to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&fee.to_le_bytes());
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&expires_at.to_le_bytes());
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: sss_transfer_hook_program::ID,
+        accounts: metas,
+        data,
+    };
+    let stablecoin_key = accounts.stablecoin_state.key();
+    let bump = [delegate_bump];
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &infos,
+        &[&transfer_delegate_seeds(&stablecoin_key, &bump)],
+    )?;
+    Ok(())
+}
+
+// Has the hook spend `amount` of the spender's allowance through its
+// spend_allowance, signed by the transfer delegate
+pub fn spend_allowance(accounts: &TransferFrom, delegate_bump: u8, amount: u64) -> Result<()> {
+    let infos = [
+        accounts.transfer_delegate.to_account_info(),
+        accounts.spender.to_account_info(),
+        accounts.hook_config.to_account_info(),
+        accounts.source_account.to_account_info(),
+        accounts.allowance.to_account_info(),
+        accounts.hook_program.to_account_info(),
+    ];
+    let metas = vec![
+        AccountMeta::new_readonly(infos[0].key(), true),
+        AccountMeta::new_readonly(infos[1].key(), true),
+        AccountMeta::new_readonly(infos[2].key(), false),
+        AccountMeta::new_readonly(infos[3].key(), false),
+        AccountMeta::new(infos[4].key(), false),
+    ];
+    let mut data = anchor_lang::solana_program::hash::hash(b"global:spend_allowance").to_bytes()[..8].to_vec();
+    data.extend_from_slice(accounts.destination_account.key().as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: sss_transfer_hook_program::ID,
+        accounts: metas,
+        data,
+    };
+    let stablecoin_key = accounts.stablecoin_state.key();
+    let bump = [delegate_bump];
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &infos,
+        &[&transfer_delegate_seeds(&stablecoin_key, &bump)],
+    )?;
+    Ok(())
+}
+
+// Loads a NonceBitmap created with init_if_needed, initializing it on first use
+pub fn load_nonce_bitmap<'a>(
+    loader: &'a AccountLoader<NonceBitmap>,
+    signer: &Pubkey,
+    nonce: u64,
+    bump: u8,
+) -> Result<std::cell::RefMut<'a, NonceBitmap>> {
+    let fresh = loader.as_ref().try_borrow_data()?[..8] == [0u8; 8];
+    if !fresh {
+        return loader.load_mut();
+    }
+    let mut bitmap = loader.load_init()?;
+    bitmap.signer = *signer;
+    bitmap.page = nonce / NONCES_PER_BITMAP;
+    bitmap.bump = bump;
+    Ok(bitmap)
+}
+
+// Message a voucher signer signs:
+// `"voucher" || stablecoin_state || recipient || amount || expiry || nonce` (integers LE)
+pub fn voucher_message(stablecoin: &Pubkey, recipient: &Pubkey, amount: u64, expiry: i64, nonce: u64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(7 + 32 * 2 + 8 * 3);
+    message.extend_from_slice(b"voucher");
+    message.extend_from_slice(stablecoin.as_ref());
+    message.extend_from_slice(recipient.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&expiry.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+// Require that the instruction preceding this one is an ed25519 program
+// instruction verifying exactly one signature by `signer` over `message`,
+// with all data inline.
+pub fn verify_ed25519_instruction(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, StablecoinError::InvalidVoucherSignature);
+    let ix = load_instruction_at_checked(current as usize - 1, instructions)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, StablecoinError::InvalidVoucherSignature);
+    
+    // Header: count (u8), padding (u8), then one Ed25519SignatureOffsets (7 x u16)
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, StablecoinError::InvalidVoucherSignature);
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+    // Signature, key and message must all live in the ed25519 instruction itself
+    for index_at in [4, 8, 14] {
+        require!(read_u16(index_at) == u16::MAX as usize, StablecoinError::InvalidVoucherSignature);
+    }
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(StablecoinError::InvalidVoucherSignature)?;
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(StablecoinError::InvalidVoucherSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed == message,
+        StablecoinError::InvalidVoucherSignature
+    );
+    Ok(())
+}
+
+// Validates that an approved multisig proposal authorizes exactly `action`,
+// that `timelock_seconds` have passed since it was created, and marks it executed.
+// Approvals from keys still in the signer set, ed25519 and secp256k1 alike.
+// Approvals left by a key that revoke_key removed stop counting.
+pub fn live_approvals(config: &MultisigConfig, proposal: &MultisigProposal) -> u8 {
+    let ed25519 = proposal.approvals.iter()
+        .filter(|approver| config.signers.contains(approver))
+        .count();
+    let secp = proposal.secp_approvals.iter()
+        .filter(|approver| config.secp_signers.contains(approver))
+        .count();
+    (ed25519 + secp) as u8
+}
+
+pub fn consume_proposal(
+    config: &MultisigConfig,
+    config_key: Pubkey,
+    proposal: &mut MultisigProposal,
+    action: &ProposalAction,
+    timelock_seconds: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(proposal.config == config_key, StablecoinError::ProposalMismatch);
+    require!(!proposal.executed, StablecoinError::AlreadyExecuted);
+    require!(now < proposal.expires_at, StablecoinError::ProposalExpired);
+    require!(
+        live_approvals(config, proposal) >= config.threshold,
+        StablecoinError::ThresholdNotMet
+    );
+    require!(
+        proposal.instruction_data == action.try_to_vec()?,
+        StablecoinError::ProposalMismatch
+    );
+    let unlocks_at = proposal.created_at.checked_add(timelock_seconds)
+        .ok_or(StablecoinError::MathOverflow)?;
+    require!(now >= unlocks_at, StablecoinError::TimelockNotElapsed);
+    
+    proposal.executed = true;
+    Ok(())
+}
+
+// Message a secp256k1 multisig signer signs: `"approve" || proposal`
+pub fn secp_approval_message(proposal: &Pubkey) -> Vec<u8> {
+    [b"approve".as_ref(), proposal.as_ref()].concat()
+}
+
+// Message an auditor signs for a reserve report:
+// `"reserves" || stablecoin || reserve_amount (u64 LE) || period (u64 LE) || report_hash`
+pub fn reserve_attestation_message(
+    stablecoin: &Pubkey,
+    reserve_amount: u64,
+    period: u64,
+    report_hash: &[u8; 32],
+) -> Vec<u8> {
+    [
+        b"reserves".as_ref(),
+        stablecoin.as_ref(),
+        &reserve_amount.to_le_bytes(),
+        &period.to_le_bytes(),
+        report_hash.as_ref(),
+    ]
+    .concat()
+}
+
+// Require that the instruction preceding this one is a secp256k1 program
+// instruction verifying exactly one signature over `message`, with all data
+// inline. Returns the recovered Ethereum-style signer address.
+pub fn verify_secp256k1_instruction(instructions: &AccountInfo, message: &[u8]) -> Result<[u8; 20]> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, StablecoinError::InvalidSecpSignature);
+    let ix_index = current - 1;
+    let ix = load_instruction_at_checked(ix_index as usize, instructions)?;
+    require_keys_eq!(ix.program_id, secp256k1_program::ID, StablecoinError::InvalidSecpSignature);
+    
+    // Header: count (u8), then one SecpSignatureOffsets (11 bytes)
+    let data = &ix.data;
+    require!(data.len() >= 12 && data[0] == 1, StablecoinError::InvalidSecpSignature);
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let eth_address_offset = read_u16(4);
+    let message_offset = read_u16(7);
+    let message_size = read_u16(9);
+    // Signature, address and message must all live in the secp256k1 instruction itself
+    for index_at in [3, 6, 11] {
+        require!(data[index_at] as u16 == ix_index, StablecoinError::InvalidSecpSignature);
+    }
+    
+    let eth_address: [u8; 20] = data
+        .get(eth_address_offset..eth_address_offset + 20)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(StablecoinError::InvalidSecpSignature)?;
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(StablecoinError::InvalidSecpSignature)?;
+    require!(signed == message, StablecoinError::InvalidSecpSignature);
+    Ok(eth_address)
+}
+
+// Freezes (or thaws) every token account in `token_accounts`, each of which
+// must belong to `owner` and the stablecoin's mint. Accounts already in the
+// target state are skipped. Returns how many changed.
+pub fn set_owner_accounts_frozen<'a>(
+    accounts: &FreezeOwnerAccounts<'a>,
+    token_accounts: &'a [AccountInfo<'a>],
+    owner: &Pubkey,
+    freeze_bump: u8,
+    freeze: bool,
+) -> Result<u8> {
+    require!(
+        !token_accounts.is_empty() && token_accounts.len() <= MAX_OWNER_ACCOUNTS,
+        StablecoinError::InvalidAmount
+    );
+    let stablecoin_key = accounts.stablecoin_state.key();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[b"freeze_authority", stablecoin_key.as_ref(), &[freeze_bump]]];
+    
+    let mut changed = 0u8;
+    for info in token_accounts {
+        let token_account = InterfaceAccount::<InterfaceTokenAccount>::try_from(info)?;
+        require_keys_eq!(token_account.owner, *owner, StablecoinError::Unauthorized);
+        require_keys_eq!(token_account.mint, accounts.mint.key(), StablecoinError::InvalidMint);
+        if token_account.is_frozen() == freeze {
+            continue;
+        }
+        
+        let cpi_program = accounts.token_program.to_account_info();
+        let mint = accounts.mint.to_account_info();
+        let authority = accounts.freeze_authority.to_account_info();
+        if freeze {
+            token_2022::freeze_account(CpiContext::new_with_signer(
+                cpi_program,
+                token_2022::FreezeAccount { account: info.clone(), mint, authority },
+                signer_seeds,
+            ))?;
+        } else {
+            token_2022::thaw_account(CpiContext::new_with_signer(
+                cpi_program,
+                token_2022::ThawAccount { account: info.clone(), mint, authority },
+                signer_seeds,
+            ))?;
+        }
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+// Checks that `source_token_account` is owned by `source`'s PDA and is not a
+// program-managed stablecoin balance. Returns the PDA bump.
+pub fn check_rescue_source(
+    stablecoin: &AccountLoader<StablecoinState>,
+    source: RescueSource,
+    source_token_account: &InterfaceTokenAccount,
+) -> Result<u8> {
+    let stablecoin_key = stablecoin.key();
+    let (authority, bump) = Pubkey::find_program_address(&[source.seed(), stablecoin_key.as_ref()], &crate::ID);
+    require_keys_eq!(source_token_account.owner, authority, StablecoinError::InvalidRescue);
+    require!(
+        !(source.manages_stablecoin() && source_token_account.mint == stablecoin.load()?.mint),
+        StablecoinError::InvalidRescue
+    );
+    Ok(bump)
+}
+
+// Deserialize a transfer hook account after checking its owner and Anchor
+// discriminator. Uninitialized PDAs yield `None`.
+pub fn load_hook_account<T: AnchorDeserialize>(info: &AccountInfo, name: &str) -> Result<Option<T>> {
+    if info.owner != &sss_transfer_hook_program::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    let discriminator =
+        anchor_lang::solana_program::hash::hash(format!("account:{}", name).as_bytes()).to_bytes();
+    require!(
+        data.len() >= 8 && data[..8] == discriminator[..8],
+        StablecoinError::InvalidHookAccount
+    );
+    T::deserialize(&mut &data[8..])
+        .map(Some)
+        .map_err(|_| error!(StablecoinError::InvalidHookAccount))
+}
+
+// Whether `owner` holds an active hook whitelist entry or a KYC record whose
+// tier has not lapsed at `now`. Both accounts are PDAs checked by the caller.
+pub fn is_onboarded(
+    whitelist_entry: &AccountInfo,
+    kyc_record: &AccountInfo,
+    owner: &Pubkey,
+    now: i64,
+) -> Result<bool> {
+    let whitelisted = if whitelist_entry.owner == &sss_transfer_hook_program::ID
+        && whitelist_entry.data_len() == sss_transfer_hook_program::LEGACY_ENTRY_LEN
+    {
+        let data = whitelist_entry.try_borrow_data()?;
+        require!(data[8..40] == owner.to_bytes(), StablecoinError::InvalidHookAccount);
+        data[40] != 0
+    } else {
+        match load_hook_account::<sss_transfer_hook_program::WhitelistEntry>(whitelist_entry, "WhitelistEntry")? {
+            Some(entry) => {
+                require_keys_eq!(entry.address, *owner, StablecoinError::InvalidHookAccount);
+                true
+            }
+            None => false,
+        }
+    };
+    if whitelisted {
+        return Ok(true);
+    }
+    
+    let Some(kyc) = load_hook_account::<sss_transfer_hook_program::KycRecord>(kyc_record, "KycRecord")? else {
+        return Ok(false);
+    };
+    require_keys_eq!(kyc.address, *owner, StablecoinError::InvalidHookAccount);
+    let lapsed = matches!(kyc.expires_at, Some(expiry) if expiry <= now);
+    Ok(kyc.tier != 0 && !lapsed)
+}
+
+// Whether the hook blacklist PDA `entry` blocks `address` at `now`, in any of
+// the hook's entry layouts. FlagOnly entries only flag transfers and pass.
+pub fn is_hook_blacklisted(entry: &AccountInfo, address: &Pubkey, now: i64) -> Result<bool> {
+    use sss_transfer_hook_program::{BlacklistEntry, BlacklistEntryV1, BLACKLIST_ENTRY_V1_LEN, LEGACY_ENTRY_LEN};
+    if entry.owner != &sss_transfer_hook_program::ID || entry.data_is_empty() {
+        return Ok(false);
+    }
+    let (listed, active, expires_at, restriction_level) = match entry.data_len() {
+        LEGACY_ENTRY_LEN => {
+            let data = entry.try_borrow_data()?;
+            require!(data[8..40] == address.to_bytes(), StablecoinError::InvalidHookAccount);
+            return Ok(data[40] != 0);
+        }
+        BLACKLIST_ENTRY_V1_LEN => {
+            let v1 = load_hook_account::<BlacklistEntryV1>(entry, "BlacklistEntry")?
+                .ok_or(StablecoinError::InvalidHookAccount)?;
+            (v1.address, v1.is_active, v1.expires_at, v1.restriction_level)
+        }
+        _ => {
+            let e = load_hook_account::<BlacklistEntry>(entry, "BlacklistEntry")?
+                .ok_or(StablecoinError::InvalidHookAccount)?;
+            (e.address, e.is_active, e.expires_at, e.restriction_level)
+        }
+    };
+    require_keys_eq!(listed, *address, StablecoinError::InvalidHookAccount);
+    let expired = matches!(expires_at, Some(expiry) if expiry <= now);
+    Ok(active && !expired && restriction_level == 0)
+}
+
+// === PROGRAM ===
+declare_id!("8JpbyYEJXLeWoPJcLsHWg64bDtwFZXhPoubVJPeH11aH");
+
+#[program]
+pub mod sss_token {
+    use super::*;
+
+    // === INITIALIZE ===
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        enable_transfer_hook: bool,
+        enable_permanent_delegate: bool,
+    ) -> Result<()> {
+        require!(name.len() <= 32, StablecoinError::NameTooLong);
+        require!(symbol.len() <= 10, StablecoinError::SymbolTooLong);
+
+        initialize_v2(
+            ctx,
+            InitConfig {
+                name,
+                symbol,
+                decimals,
+                enable_transfer_hook,
+                enable_permanent_delegate,
+                ..InitConfig::default()
+            },
+        )
+    }
+
+    // === INITIALIZE V2 ===
+    // Creates the stablecoin with its caps, epoch quota, pause state, metadata
+    // and hook linkage set atomically, leaving no window where it runs on
+    // defaults.
+    pub fn initialize_v2(ctx: Context<Initialize>, config: InitConfig) -> Result<()> {
+        require!(config.name.len() <= 32, StablecoinError::NameTooLong);
+        require!(config.symbol.len() <= 10, StablecoinError::SymbolTooLong);
+        require!(
+            config.epoch_length > 0 || config.epoch_mode == EpochMode::UnixSeconds,
+            StablecoinError::InvalidAmount
+        );
+        let InitConfig { name, symbol, decimals, .. } = config.clone();
+        let clock = Clock::get()?;
+
+        // The hook must already be wired into the mint it is meant to guard
+        if let Some(hook_program) = config.transfer_hook_program {
+            let mint_info = ctx.accounts.mint.to_account_info();
+            let data = mint_info.try_borrow_data()?;
+            let mint = StateWithExtensions::<token_2022::spl_token_2022::state::Mint>::unpack(&data)?;
+            let linked = mint
+                .get_extension::<TransferHook>()
+                .ok()
+                .and_then(|hook| Option::<Pubkey>::from(hook.program_id));
+            require!(linked == Some(hook_program), StablecoinError::InvalidHookLinkage);
+        }
+
+        // Initialize stablecoin state
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_init()?;
+        stablecoin.authority = ctx.accounts.authority.key();
+        stablecoin.mint = ctx.accounts.mint.key();
+        stablecoin.name = padded(&name);
+        stablecoin.symbol = padded(&symbol);
+        stablecoin.decimals = decimals;
+        stablecoin.is_paused = u8::from(config.start_paused);
+        stablecoin.supply_cap = config.supply_cap;
+        stablecoin.epoch_quota = config.epoch_quota;
+        stablecoin.epoch_mode = config.epoch_mode as u8;
+        stablecoin.epoch_length = config.epoch_length;
+        stablecoin.current_epoch_start = epoch_clock(config.epoch_mode, &clock);
+        if config.enable_transfer_hook || config.transfer_hook_program.is_some() {
+            stablecoin.features |= 1;
+        }
+        if config.enable_permanent_delegate {
+            stablecoin.features |= 2;
+        }
+        stablecoin.bump = ctx.bumps.stablecoin_state;
+        stablecoin.master_count = 1;
+
+        // Initialize master role for creator
+        let master_role = &mut ctx.accounts.master_role;
+        master_role.owner = ctx.accounts.authority.key();
+        master_role.roles = ROLE_MASTER | ROLE_MINTER | ROLE_BURNER | ROLE_PAUSER | ROLE_BLACKLISTER | ROLE_SEIZER;
+        master_role.stablecoin = ctx.accounts.stablecoin_state.key();
+        master_role.bump = ctx.bumps.master_role;
+
+        // List the stablecoin under its issuer
+        let now = Clock::get()?.unix_timestamp;
+        let registry = &mut ctx.accounts.issuer_registry;
+        let registry_index = registry.count;
+        registry.authority = ctx.accounts.authority.key();
+        registry.count = registry_index.checked_add(1).ok_or(StablecoinError::MathOverflow)?;
+        registry.bump = ctx.bumps.issuer_registry;
+
+        let entry = &mut ctx.accounts.registry_entry;
+        entry.registry = registry.key();
+        entry.index = registry_index;
+        entry.mint = ctx.accounts.mint.key();
+        entry.stablecoin = ctx.accounts.stablecoin_state.key();
+        entry.created_at = now;
+        entry.bump = ctx.bumps.registry_entry;
+
+        if !config.uri.is_empty() {
+            init_token_metadata(&ctx, name.clone(), symbol.clone(), config.uri)?;
+        }
+        if config.start_paused {
+            emit!(StablecoinPaused {
+                pauser: ctx.accounts.authority.key(),
+                timestamp: now,
+            });
+        }
+
+        emit!(StablecoinInitialized {
+            mint: ctx.accounts.mint.key(),
+            authority: ctx.accounts.authority.key(),
+            name,
+            symbol,
+            registry_index,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    // === MINT ===
+    pub fn mint(
+        ctx: Context<MintTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        // Read values we need before any mutable borrow
+        let state = *ctx.accounts.stablecoin_state.load()?;
+        let supply_cap = state.supply_cap;
+        let epoch_quota = state.epoch_quota;
+        let total_supply = state.total_supply;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let role_bits = ctx.accounts.minter_role.roles;
+        
+        require!(!state.is_paused(), StablecoinError::ContractPaused);
+        require!(!state.is_sunset(), StablecoinError::StablecoinSunset);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        // Check minter role
+        require!(
+            has_role(&ctx.accounts.minter_role, ROLE_MINTER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        if !check_circuit_breaker(
+            &ctx.accounts.stablecoin_state,
+            ctx.accounts.circuit_breaker.as_mut(),
+            ctx.accounts.minter.key(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        )? {
+            return Ok(());
+        }
+        charge_session_limit(&mut ctx.accounts.minter_role, amount, Clock::get()?.unix_timestamp)?;
+        
+        // Check quota if not master
+        if role_bits & ROLE_MASTER == 0 {
+            let minter_info = &ctx.accounts.minter_info;
+            let new_minted = minter_info.minted.checked_add(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                new_minted <= minter_info.quota,
+                StablecoinError::QuotaExceeded
+            );
+        }
+        
+        // Check supply cap
+        let new_supply = total_supply.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        if supply_cap > 0 {
+            require!(new_supply <= supply_cap, StablecoinError::SupplyCapExceeded);
+        }
+        
+        // Check epoch quota
+        let mut stablecoin_mut = ctx.accounts.stablecoin_state.load_mut()?;
+        let epoch_id = roll_mint_epoch(&mut stablecoin_mut)?;
+        if epoch_quota > 0 {
+            let epoch_new_total = stablecoin_mut.current_epoch_minted
+                .checked_add(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                epoch_new_total <= epoch_quota,
+                StablecoinError::EpochQuotaExceeded
+            );
+        }
+
+        let mint_authority_bump = ctx.bumps.mint_authority;
+        // CPI to mint tokens
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &[&[b"mint_authority", stablecoin_key.as_ref(), &[mint_authority_bump]]],
+            ),
+            amount,
+        )?;
+
+        // Update state
+        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        // Update minter quota if applicable
+        if role_bits & ROLE_MASTER == 0 {
+            let minter_info = &mut ctx.accounts.minter_info;
+            minter_info.minted = minter_info.minted.checked_add(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        
+        // Update epoch minted
+        stablecoin_mut.current_epoch_minted = stablecoin_mut.current_epoch_minted
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        if let Some(stats) = &ctx.accounts.minter_stats {
+            record_minter_stats(&mut *stats.load_mut()?, 1, amount, Clock::get()?.unix_timestamp)?;
+        }
+
+        emit!(TokensMinted {
+            minter: ctx.accounts.minter.key(),
+            recipient: ctx.accounts.recipient_account.key(),
+            amount,
+            epoch_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === BURN ===
+    pub fn burn(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(!stablecoin.is_paused(), StablecoinError::ContractPaused);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        // Check burner role or self-burn
+        let is_burner = has_role(&ctx.accounts.burner_role, ROLE_BURNER | ROLE_MASTER)?;
+        let is_owner = ctx.accounts.token_account.owner == ctx.accounts.burner.key();
+        require!(is_burner || is_owner, StablecoinError::Unauthorized);
+
+        if is_burner {
+            charge_session_limit(&mut ctx.accounts.burner_role, amount, Clock::get()?.unix_timestamp)?;
+        }
+
+        // CPI to burn tokens
+        if is_burner {
+            // Burner can burn from any account
+            token_2022::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.token_account.to_account_info(),
+                        authority: ctx.accounts.burn_authority.to_account_info(),
+                    },
+                    &[&[b"burn_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.burn_authority]]],
+                ),
+                amount,
+            )?;
+        } else {
+            // Owner burns their own tokens
+            token_2022::burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.token_account.to_account_info(),
+                        authority: ctx.accounts.burner.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+
+        // Update state
+        let mut stablecoin_mut = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_sub(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(TokensBurned {
+            burner: ctx.accounts.burner.key(),
+            owner: ctx.accounts.token_account.owner,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === RESCUE: REQUEST ===
+    // Starts the timelock for moving tokens sent to a program PDA by mistake
+    pub fn request_rescue(
+        ctx: Context<RequestRescue>,
+        source: RescueSource,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            amount > 0 && amount <= ctx.accounts.source_token_account.amount,
+            StablecoinError::InvalidAmount
+        );
+        check_rescue_source(&ctx.accounts.stablecoin_state, source, &ctx.accounts.source_token_account)?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let request = &mut ctx.accounts.rescue_request;
+        request.stablecoin = ctx.accounts.stablecoin_state.key();
+        request.source = source;
+        request.source_token_account = ctx.accounts.source_token_account.key();
+        request.mint = ctx.accounts.source_token_account.mint;
+        request.destination = ctx.accounts.destination.key();
+        request.amount = amount;
+        request.requested_by = ctx.accounts.authority.key();
+        request.unlocks_at = now + RESCUE_TIMELOCK_SECONDS;
+        request.bump = ctx.bumps.rescue_request;
+        
+        emit!(RescueRequested {
+            authority: ctx.accounts.authority.key(),
+            source_token_account: request.source_token_account,
+            mint: request.mint,
+            destination: request.destination,
+            amount,
+            unlocks_at: request.unlocks_at,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === RESCUE: EXECUTE ===
+    // Moves the requested tokens once the timelock has passed and closes the
+    // request. remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn rescue_tokens<'a>(
+        ctx: Context<'_, '_, 'a, 'a, RescueTokens<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let request = &ctx.accounts.rescue_request;
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= request.unlocks_at, StablecoinError::TimelockNotElapsed);
+        require!(
+            request.amount == amount
+                && request.mint == ctx.accounts.mint.key()
+                && request.destination == ctx.accounts.destination.key(),
+            StablecoinError::InvalidRescue
+        );
+        let bump = check_rescue_source(
+            &ctx.accounts.stablecoin_state,
+            request.source,
+            &ctx.accounts.source_token_account,
+        )?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination.to_account_info(),
+            ctx.accounts.source_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&[request.source.seed(), stablecoin_key.as_ref(), &[bump]]],
+        )?;
+        
+        emit!(TokensRescued {
+            authority: ctx.accounts.authority.key(),
+            source_token_account: request.source_token_account,
+            mint: request.mint,
+            destination: request.destination,
+            amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === RESCUE: CANCEL ===
+    pub fn cancel_rescue(ctx: Context<CancelRescue>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.rescue_request.key(),
+            recipient: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === BURN FROZEN ===
+    // Destroys unbacked or stolen funds in a (usually frozen) account: thaws
+    // it, burns via the burn authority and re-freezes it in one instruction.
+    // Needs ROLE_SEIZER and a passed BurnFrozen multisig proposal.
+    pub fn burn_frozen(ctx: Context<BurnFrozen>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            has_role(&ctx.accounts.seizer_role, ROLE_SEIZER)?,
+            StablecoinError::Unauthorized
+        );
+        let was_frozen = ctx.accounts.token_account.is_frozen();
+        require!(
+            !was_frozen || ctx.accounts.stablecoin_state.load()?.renounced & RENOUNCED_FREEZE == 0,
+            StablecoinError::AuthorityRenounced
+        );
+        require!(ctx.accounts.token_account.amount >= amount, StablecoinError::InsufficientBalance);
+        
+        let action = ProposalAction::BurnFrozen {
+            token_account: ctx.accounts.token_account.key(),
+            amount,
+        };
+        consume_proposal(
+            &ctx.accounts.multisig_config,
+            ctx.accounts.multisig_config.key(),
+            &mut ctx.accounts.proposal,
+            &action,
+            0,
+        )?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let freeze_seeds: &[&[&[u8]]] =
+            &[&[b"freeze_authority", stablecoin_key.as_ref(), &[ctx.bumps.freeze_authority]]];
+        if was_frozen {
+            token_2022::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::ThawAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                freeze_seeds,
+            ))?;
+        }
+        token_2022::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.token_account.to_account_info(),
+                    authority: ctx.accounts.burn_authority.to_account_info(),
+                },
+                &[&[b"burn_authority", stablecoin_key.as_ref(), &[ctx.bumps.burn_authority]]],
+            ),
+            amount,
+        )?;
+        if was_frozen {
+            token_2022::freeze_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::FreezeAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                freeze_seeds,
+            ))?;
+        }
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin.total_supply = stablecoin.total_supply.checked_sub(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let case = &mut ctx.accounts.destruction_case;
+        case.stablecoin = stablecoin_key;
+        case.token_account = ctx.accounts.token_account.key();
+        case.owner = ctx.accounts.token_account.owner;
+        case.amount = amount;
+        case.seizer = ctx.accounts.seizer.key();
+        case.proposal = ctx.accounts.proposal.key();
+        case.was_frozen = was_frozen;
+        case.executed_at = now;
+        case.bump = ctx.bumps.destruction_case;
+        
+        emit!(FrozenFundsBurned {
+            seizer: ctx.accounts.seizer.key(),
+            destruction_case: case.key(),
+            token_account: case.token_account,
+            owner: case.owner,
+            amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    // === SEIZE ===
+    // Executes an approved transfer hook seizure case with the permanent
+    // delegate PDA: destroy cases are burned, the rest move to `destination`.
+    // The hook can't do this itself, as its transfer would re-enter it;
+    // from here the transfer runs the hook, whose extra accounts come in
+    // remaining_accounts. Needs ROLE_SEIZER.
+    pub fn seize_tokens<'a>(ctx: Context<'_, '_, 'a, 'a, SeizeTokens<'a>>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.seizer_role, ROLE_SEIZER)?,
+            StablecoinError::Unauthorized
+        );
+        let case = consume_seizure_case(ctx.accounts, ctx.bumps.permanent_delegate)?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let delegate_seeds: &[&[&[u8]]] =
+            &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[ctx.bumps.permanent_delegate]]];
+        if case.destroy {
+            // Counterfeit or unbacked funds leave circulation
+            token_2022::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.source_account.to_account_info(),
+                        authority: ctx.accounts.permanent_delegate.to_account_info(),
+                    },
+                    delegate_seeds,
+                ),
+                case.amount,
+            )?;
+            let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+            stablecoin.total_supply = stablecoin.total_supply.checked_sub(case.amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            
+            emit!(SeizedTokensBurned {
+                from: case.target_owner,
+                amount: case.amount,
+                seized_by: ctx.accounts.seizer.key(),
+                reason_code: case.reason_code,
+                reference: Some(case.legal_reference),
+                case: ctx.accounts.seizure_case.key(),
+                timestamp: now,
+            });
+            return Ok(());
+        }
+        
+        let destination = ctx
+            .accounts
+            .destination
+            .as_ref()
+            .ok_or(StablecoinError::InvalidSeizureDestination)?;
+        require_keys_neq!(
+            destination.key(),
+            ctx.accounts.source_account.key(),
+            StablecoinError::InvalidSeizureDestination
+        );
+        check_seizure_destination(
+            &stablecoin_key,
+            &case,
+            &destination.key(),
+            &destination.owner,
+            ctx.accounts.restitution_entry.as_deref(),
+            now,
+        )?;
+        
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination.to_account_info(),
+            ctx.accounts.permanent_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            case.amount,
+            ctx.accounts.mint.decimals,
+            delegate_seeds,
+        )?;
+        
+        emit!(TokensSeized {
+            from: case.target_owner,
+            to: destination.owner,
+            amount: case.amount,
+            seized_by: ctx.accounts.seizer.key(),
+            reason_code: case.reason_code,
+            reference: Some(case.legal_reference),
+            case: ctx.accounts.seizure_case.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === SEIZE SPLIT ===
+    // Executes an approved seizure case across several destinations, e.g.
+    // victim restitution accounts. remaining_accounts start with one pair per
+    // amount, the destination then its RestitutionAccount PDA, followed by
+    // the hook's extra accounts. The amounts must add up to the case amount.
+    pub fn seize_tokens_split<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SeizeTokens<'a>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.seizer_role, ROLE_SEIZER)?,
+            StablecoinError::Unauthorized
+        );
+        let pairs = amounts.len() * 2;
+        require!(
+            !amounts.is_empty()
+                && amounts.len() <= MAX_SEIZURE_SPLITS
+                && ctx.remaining_accounts.len() >= pairs,
+            StablecoinError::InvalidAmount
+        );
+        let mut total: u64 = 0;
+        for amount in &amounts {
+            total = total.checked_add(*amount).ok_or(StablecoinError::MathOverflow)?;
+        }
+        let case = consume_seizure_case(ctx.accounts, ctx.bumps.permanent_delegate)?;
+        require!(!case.destroy, StablecoinError::InvalidSeizureCase);
+        require!(total == case.amount, StablecoinError::InvalidAmount);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let mint_key = ctx.accounts.mint.key();
+        let source_key = ctx.accounts.source_account.key();
+        let (destinations, hook_accounts) = ctx.remaining_accounts.split_at(pairs);
+        for (pair, amount) in destinations.chunks(2).zip(amounts) {
+            let destination = InterfaceAccount::<InterfaceTokenAccount>::try_from(&pair[0])?;
+            require_keys_eq!(destination.mint, mint_key, StablecoinError::InvalidMint);
+            require_keys_neq!(destination.key(), source_key, StablecoinError::InvalidSeizureDestination);
+            check_seizure_destination(
+                &stablecoin_key,
+                &case,
+                &destination.key(),
+                &destination.owner,
+                Some(&pair[1]),
+                now,
+            )?;
+            
+            transfer_with_hook(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.source_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                pair[0].clone(),
+                ctx.accounts.permanent_delegate.to_account_info(),
+                hook_accounts,
+                amount,
+                ctx.accounts.mint.decimals,
+                &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[ctx.bumps.permanent_delegate]]],
+            )?;
+            
+            emit!(TokensSeized {
+                from: case.target_owner,
+                to: destination.owner,
+                amount,
+                seized_by: ctx.accounts.seizer.key(),
+                reason_code: case.reason_code,
+                reference: Some(case.legal_reference),
+                case: ctx.accounts.seizure_case.key(),
+                timestamp: now,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    // === SPONSORED TRANSFER ===
+    // Relays a transfer the holder signed off-chain; the relayer pays the SOL
+    // and takes `fee` in tokens. The holder must have approved the transfer
+    // delegate PDA on `source_account`. Remaining accounts: the hook's extra
+    // accounts for both transfers.
+    pub fn execute_sponsored_transfer<'a>(
+        ctx: Context<'_, '_, 'a, 'a, ExecuteSponsoredTransfer<'a>>,
+        amount: u64,
+        fee: u64,
+        nonce: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        consume_sponsored_transfer(
+            ctx.accounts,
+            ctx.bumps.transfer_delegate,
+            amount,
+            fee,
+            nonce,
+            expires_at,
+        )?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let bump = [ctx.bumps.transfer_delegate];
+        let delegate_seeds = transfer_delegate_seeds(&stablecoin_key, &bump);
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination_account.to_account_info(),
+            ctx.accounts.transfer_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&delegate_seeds],
+        )?;
+        if fee > 0 {
+            transfer_with_hook(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.source_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.relayer_token_account.to_account_info(),
+                ctx.accounts.transfer_delegate.to_account_info(),
+                ctx.remaining_accounts,
+                fee,
+                ctx.accounts.mint.decimals,
+                &[&delegate_seeds],
+            )?;
+        }
+        
+        Ok(())
+    }
+    
+    // === TRANSFER FROM ===
+    // Spends a permit-granted allowance. The owner must have approved the
+    // transfer delegate PDA on `source_account`. Remaining accounts: the
+    // hook's extra accounts for the transfer.
+    pub fn transfer_from<'a>(
+        ctx: Context<'_, '_, 'a, 'a, TransferFrom<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        spend_allowance(ctx.accounts, ctx.bumps.transfer_delegate, amount)?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let bump = [ctx.bumps.transfer_delegate];
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.source_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination_account.to_account_info(),
+            ctx.accounts.transfer_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&transfer_delegate_seeds(&stablecoin_key, &bump)],
+        )?;
+        
+        Ok(())
+    }
+
+    // === ESCHEAT ===
+    // Moves a dormant account's balance to the escheatment treasury once the
+    // hook agrees its notice period has passed. Remaining accounts: the
+    // hook's extra accounts for the transfer.
+    pub fn escheat_dormant_account<'a>(
+        ctx: Context<'_, '_, 'a, 'a, EscheatDormantAccount<'a>>,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.seizer_role, ROLE_SEIZER)?,
+            StablecoinError::Unauthorized
+        );
+        let amount = ctx.accounts.token_account.amount;
+        consume_escheatment(ctx.accounts, ctx.bumps.permanent_delegate)?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.escheatment_treasury.to_account_info(),
+            ctx.accounts.permanent_delegate.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[ctx.bumps.permanent_delegate]]],
+        )?;
+        
+        Ok(())
+    }
+
+    // === FREEZE ===
+    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(!stablecoin.is_paused(), StablecoinError::ContractPaused);
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        
+        // Check pauser role
+        require!(
+            has_role(&ctx.accounts.pauser_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+
+        // CPI to freeze account
+        token_2022::freeze_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::FreezeAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                &[&[b"freeze_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.freeze_authority]]],
+            ),
+        )?;
+
+        emit!(AccountFrozen {
+            pauser: ctx.accounts.pauser.key(),
+            account: ctx.accounts.token_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === THAW ===
+    pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        
+        // Check pauser role
+        require!(
+            has_role(&ctx.accounts.pauser_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+
+        // CPI to thaw account
+        token_2022::thaw_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::ThawAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                &[&[b"freeze_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.freeze_authority]]],
+            ),
+        )?;
+
+        emit!(AccountThawed {
+            pauser: ctx.accounts.pauser.key(),
+            account: ctx.accounts.token_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === FREEZE/THAW ALL FOR OWNER ===
+    // Freezes every token account of `owner` passed as remaining_accounts,
+    // e.g. right after blacklisting them
+    pub fn freeze_accounts_for_owner<'a>(
+        ctx: Context<'_, '_, 'a, 'a, FreezeOwnerAccounts<'a>>,
+        owner: Pubkey,
+    ) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        require!(!stablecoin.is_paused(), StablecoinError::ContractPaused);
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        require!(
+            has_role(&ctx.accounts.pauser_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let accounts = set_owner_accounts_frozen(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &owner,
+            ctx.bumps.freeze_authority,
+            true,
+        )?;
+        
+        emit!(OwnerAccountsFrozen {
+            pauser: ctx.accounts.pauser.key(),
+            owner,
+            accounts,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    pub fn thaw_accounts_for_owner<'a>(
+        ctx: Context<'_, '_, 'a, 'a, FreezeOwnerAccounts<'a>>,
+        owner: Pubkey,
+    ) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        require!(
+            has_role(&ctx.accounts.pauser_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let accounts = set_owner_accounts_frozen(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            &owner,
+            ctx.bumps.freeze_authority,
+            false,
+        )?;
+        
+        emit!(OwnerAccountsThawed {
+            pauser: ctx.accounts.pauser.key(),
+            owner,
+            accounts,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    // === ONBOARD (permissionless thaw for cleared owners) ===
+    // With default-frozen accounts, anyone may thaw an account whose owner has
+    // an active hook whitelist entry or unexpired KYC record.
+    pub fn onboard_account(ctx: Context<ManageOnboarding>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        require!(stablecoin.features & 8 != 0, StablecoinError::DefaultStateNotEnabled);
+        
+        let owner = ctx.accounts.token_account.owner;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            is_onboarded(&ctx.accounts.whitelist_entry, &ctx.accounts.kyc_record, &owner, now)?,
+            StablecoinError::NotOnboarded
+        );
+        
+        token_2022::thaw_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::ThawAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                &[&[b"freeze_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.freeze_authority]]],
+            ),
+        )?;
+        
+        emit!(AccountOnboarded {
+            caller: ctx.accounts.caller.key(),
+            owner,
+            account: ctx.accounts.token_account.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === OFFBOARD (permissionless refreeze once clearance lapses) ===
+    pub fn offboard_account(ctx: Context<ManageOnboarding>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(!stablecoin.is_paused(), StablecoinError::ContractPaused);
+        require!(stablecoin.renounced & RENOUNCED_FREEZE == 0, StablecoinError::AuthorityRenounced);
+        require!(stablecoin.features & 8 != 0, StablecoinError::DefaultStateNotEnabled);
+        
+        let owner = ctx.accounts.token_account.owner;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !is_onboarded(&ctx.accounts.whitelist_entry, &ctx.accounts.kyc_record, &owner, now)?,
+            StablecoinError::StillOnboarded
+        );
+        
+        token_2022::freeze_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::FreezeAccount {
+                    account: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.freeze_authority.to_account_info(),
+                },
+                &[&[b"freeze_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.freeze_authority]]],
+            ),
+        )?;
+        
+        emit!(AccountOffboarded {
+            caller: ctx.accounts.caller.key(),
+            owner,
+            account: ctx.accounts.token_account.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    // === PAUSE/UNPAUSE ===
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        
+        // Check pauser role
+        require!(
+            has_role(&ctx.accounts.pauser_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+
+        stablecoin.is_paused = u8::from(paused);
+
+        if paused {
+            emit!(StablecoinPaused {
+                pauser: ctx.accounts.pauser.key(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        } else {
+            emit!(StablecoinUnpaused {
+                pauser: ctx.accounts.pauser.key(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    // === ROLE MANAGEMENT ===
+    pub fn update_roles(
+        ctx: Context<UpdateRoles>,
+        new_roles: u8,
+    ) -> Result<()> {
+        // Check master role
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+
+        apply_roles(&mut *ctx.accounts.stablecoin_state.load_mut()?, &mut ctx.accounts.target_role, new_roles)?;
+
+        let role_account = &mut ctx.accounts.target_role;
+        role_account.owner = ctx.accounts.target.key();
+        role_account.stablecoin = ctx.accounts.stablecoin_state.key();
+        role_account.bump = ctx.bumps.target_role;
+        
+        update_holder_index(ctx.accounts.role_index.as_mut(), ctx.accounts.target.key(), new_roles != 0)?;
+
+        emit!(RolesUpdated {
+            authority: ctx.accounts.authority.key(),
+            target: ctx.accounts.target.key(),
+            new_roles,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === SESSION KEYS ===
+    // Grants a temporary key a subset of non-master roles until `expiry`, with
+    // an optional 24h mint/burn limit. Session minters still need a minter
+    // quota. close_role revokes a session early.
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        target_key: Pubkey,
+        allowed_ops: u8,
+        expiry: i64,
+        per_op_limit: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            allowed_ops != 0
+                && allowed_ops & ROLE_MASTER == 0
+                && expiry > now,
+            StablecoinError::InvalidSessionKey
+        );
+        if ctx.accounts.stablecoin_state.load()?.locks & LOCK_SEIZER_ROLE != 0 {
+            require!(allowed_ops & ROLE_SEIZER == 0, StablecoinError::ParameterLocked);
+        }
+        
+        let role_account = &mut ctx.accounts.session_role;
+        role_account.owner = target_key;
+        role_account.roles = allowed_ops;
+        role_account.stablecoin = ctx.accounts.stablecoin_state.key();
+        role_account.bump = ctx.bumps.session_role;
+        role_account.expires_at = expiry;
+        role_account.per_op_limit = per_op_limit;
+        role_account.window_start = now;
+        role_account.window_used = 0;
+        
+        emit!(SessionKeyCreated {
+            authority: ctx.accounts.authority.key(),
+            session_key: target_key,
+            roles: allowed_ops,
+            expires_at: expiry,
+            per_op_limit,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    // === KEY REVOCATION ===
+    // Kill switch for a compromised key: strips its roles (including session
+    // roles), swaps it out of the multisig signer set for `new_key` (or drops
+    // it), and disables its voucher signer, all in one transaction. Pass the
+    // optional accounts that exist for the key. The KeyRotation record links
+    // old to new; new_key still needs its roles granted via update_roles.
+    pub fn revoke_key(
+        ctx: Context<RevokeKey>,
+        old_key: Pubkey,
+        new_key: Pubkey,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(old_key != new_key, StablecoinError::InvalidKeyRotation);
+        
+        let mut revoked_roles = 0;
+        if let Some(role) = ctx.accounts.target_role.as_mut() {
+            if role.roles & ROLE_MASTER != 0 {
+                let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+                require!(stablecoin.master_count > 1, StablecoinError::LastMasterRole);
+                stablecoin.master_count -= 1;
+            }
+            revoked_roles = role.roles;
+            role.roles = 0;
+            role.per_op_limit = 0;
+        }
+        
+        let mut multisig_member = false;
+        if let Some(config) = ctx.accounts.multisig_config.as_mut() {
+            if let Some(index) = config.signers.iter().position(|signer| *signer == old_key) {
+                multisig_member = true;
+                if new_key == Pubkey::default() || config.signers.contains(&new_key) {
+                    config.signers.remove(index);
+                } else {
+                    config.signers[index] = new_key;
+                }
+                require!(
+                    config.threshold as usize <= config.signers.len() + config.secp_signers.len(),
+                    StablecoinError::InvalidKeyRotation
+                );
+            }
+        }
+        
+        let mut voucher_signer = false;
+        if let Some(voucher) = ctx.accounts.voucher_signer.as_mut() {
+            voucher_signer = voucher.enabled;
+            voucher.enabled = false;
+        }
+        
+        let now = Clock::get()?.unix_timestamp;
+        let rotation = &mut ctx.accounts.key_rotation;
+        rotation.stablecoin = ctx.accounts.stablecoin_state.key();
+        rotation.old_key = old_key;
+        rotation.new_key = new_key;
+        rotation.revoked_roles = revoked_roles;
+        rotation.multisig_member = multisig_member;
+        rotation.voucher_signer = voucher_signer;
+        rotation.revoked_by = ctx.accounts.authority.key();
+        rotation.revoked_at = now;
+        rotation.bump = ctx.bumps.key_rotation;
+        
+        emit!(KeyRevoked {
+            authority: ctx.accounts.authority.key(),
+            old_key,
+            new_key,
+            revoked_roles,
+            multisig_member,
+            voucher_signer,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+
+    // === GOVERNANCE: CONFIGURE ===
+    // Lets a Realms DAO govern the stablecoin. Pass Pubkey::default() as
+    // `governance` to disable execute_from_governance.
+    pub fn set_governance(
+        ctx: Context<SetGovernance>,
+        governance_program: Pubkey,
+        governance: Pubkey,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let config = &mut ctx.accounts.governance_config;
+        config.stablecoin = ctx.accounts.stablecoin_state.key();
+        config.governance_program = governance_program;
+        config.governance = governance;
+        config.bump = ctx.bumps.governance_config;
+        
+        emit!(GovernanceUpdated {
+            authority: ctx.accounts.authority.key(),
+            governance_program,
+            governance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === GOVERNANCE: EXECUTE ===
+    // Entry point for an approved Realms proposal transaction. The Governance
+    // account is a PDA of the governance program, so it can only sign here
+    // when spl-governance executes a passed proposal.
+    pub fn execute_from_governance(
+        ctx: Context<ExecuteFromGovernance>,
+        action: GovernanceAction,
+    ) -> Result<()> {
+        let governance = &ctx.accounts.governance;
+        let config = &ctx.accounts.governance_config;
+        require!(
+            config.governance != Pubkey::default()
+                && governance.key() == config.governance
+                && *governance.owner == config.governance_program,
+            StablecoinError::InvalidGovernance
+        );
+        
+        match &action {
+            GovernanceAction::SetSupplyCap { new_cap } => {
+                let old_cap = apply_supply_cap(&mut *ctx.accounts.stablecoin_state.load_mut()?, *new_cap)?;
+                emit!(SupplyCapUpdated {
+                    authority: governance.key(),
+                    old_cap,
+                    new_cap: *new_cap,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+            GovernanceAction::SetFeeDistribution { recipients } => {
+                validate_fee_recipients(recipients)?;
+                let distribution = ctx.accounts.fee_distribution.as_mut()
+                    .ok_or(StablecoinError::InvalidFeeDistribution)?;
+                distribution.recipients = recipients.clone();
+                emit!(FeeDistributionUpdated {
+                    authority: governance.key(),
+                    recipients: recipients.len() as u8,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+            GovernanceAction::SetRoles { target, roles } => {
+                let role = ctx.accounts.target_role.as_mut()
+                    .ok_or(StablecoinError::Unauthorized)?;
+                require_keys_eq!(role.owner, *target, StablecoinError::Unauthorized);
+                require_keys_eq!(
+                    role.stablecoin,
+                    ctx.accounts.stablecoin_state.key(),
+                    StablecoinError::Unauthorized
+                );
+                apply_roles(&mut *ctx.accounts.stablecoin_state.load_mut()?, role, *roles)?;
+                emit!(RolesUpdated {
+                    authority: governance.key(),
+                    target: *target,
+                    new_roles: *roles,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+        }
+        
+        emit!(GovernanceActionExecuted {
+            governance: governance.key(),
+            action,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    // === HOLDER INDEX ===
+    pub fn init_holder_index(ctx: Context<InitHolderIndex>, kind: u8, page: u8) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            kind == HOLDER_INDEX_ROLES || kind == HOLDER_INDEX_MINTERS,
+            StablecoinError::InvalidHolderIndex
+        );
+        
+        let index = &mut ctx.accounts.holder_index;
+        index.stablecoin = ctx.accounts.stablecoin_state.key();
+        index.kind = kind;
+        index.page = page;
+        index.bump = ctx.bumps.holder_index;
+        Ok(())
+    }
+
+    // === MINTER STATS ===
+    // Permissionless: anyone may pay for a minter's stats account. mint and
+    // batch_mint update it whenever it is passed.
+    pub fn init_minter_stats(ctx: Context<InitMinterStats>) -> Result<()> {
+        let mut stats = ctx.accounts.minter_stats.load_init()?;
+        stats.minter = ctx.accounts.minter.key();
+        stats.stablecoin = ctx.accounts.stablecoin_state.key();
+        stats.bump = ctx.bumps.minter_stats;
+        Ok(())
+    }
+
+    // === MINTER QUOTA ===
+    pub fn update_minter_quota(
+        ctx: Context<UpdateMinterQuota>,
+        new_quota: u64,
+    ) -> Result<()> {
+        // Check master role
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+
+        let minter_info = &mut ctx.accounts.minter_info;
+        minter_info.minter = ctx.accounts.minter.key();
+        minter_info.quota = new_quota;
+        minter_info.stablecoin = ctx.accounts.stablecoin_state.key();
+        minter_info.bump = ctx.bumps.minter_info;
+        
+        update_holder_index(ctx.accounts.minter_index.as_mut(), ctx.accounts.minter.key(), true)?;
+
+        emit!(MinterQuotaUpdated {
+            authority: ctx.accounts.authority.key(),
+            minter: ctx.accounts.minter.key(),
+            new_quota,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === TRANSFER AUTHORITY ===
+    pub fn transfer_authority(ctx: Context<TransferAuthority>) -> Result<()> {
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        
+        // Only current authority can transfer
+        require!(
+            ctx.accounts.authority.key() == stablecoin.authority,
+            StablecoinError::InvalidAuthority
+        );
+
+        let pending = ctx.accounts.new_authority.key();
+        stablecoin.pending_authority = pending;
+
+        emit!(AuthorityTransferStarted {
+            previous_authority: stablecoin.authority,
+            pending_authority: pending,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // === ACCEPT AUTHORITY ===
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        
+        let pending = stablecoin.pending_authority()
+            .ok_or(StablecoinError::InvalidAuthority)?;
+            
+        require!(
+            ctx.accounts.pending_authority.key() == pending,
+            StablecoinError::InvalidAuthority
+        );
+
+        let previous_authority = stablecoin.authority;
+        stablecoin.authority = ctx.accounts.pending_authority.key();
+        stablecoin.pending_authority = Pubkey::default();
+
+        emit!(AuthorityTransferred {
+            previous_authority,
+            new_authority: ctx.accounts.pending_authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+    
+    // === UPDATE SUPPLY CAP ===
+    pub fn update_supply_cap(
+        ctx: Context<UpdateFeatures>,
+        new_cap: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let old_cap = apply_supply_cap(&mut *ctx.accounts.stablecoin_state.load_mut()?, new_cap)?;
+        
+        emit!(SupplyCapUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_cap,
+            new_cap,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === UPDATE EPOCH QUOTA ===
+    pub fn update_epoch_quota(
+        ctx: Context<UpdateFeatures>,
+        new_quota: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        let old_quota = stablecoin.epoch_quota;
+        if stablecoin.locks & LOCK_EPOCH_QUOTA != 0 {
+            require!(new_quota > 0 && new_quota <= old_quota, StablecoinError::ParameterLocked);
+        }
+        stablecoin.epoch_quota = new_quota;
+        
+        emit!(EpochQuotaUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_quota,
+            new_quota,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === UPDATE EPOCH MODE ===
+    // Switches the clock the epoch quota runs on. The current window restarts
+    // at now in the new unit but keeps what it has minted, so switching never
+    // frees up quota.
+    pub fn update_epoch_mode(
+        ctx: Context<UpdateFeatures>,
+        epoch_mode: EpochMode,
+        epoch_length: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        require!(stablecoin.locks & LOCK_EPOCH_QUOTA == 0, StablecoinError::ParameterLocked);
+        require!(
+            epoch_length > 0 || epoch_mode == EpochMode::UnixSeconds,
+            StablecoinError::InvalidAmount
+        );
+        let clock = Clock::get()?;
+        stablecoin.epoch_mode = epoch_mode as u8;
+        stablecoin.epoch_length = epoch_length;
+        stablecoin.current_epoch_start = epoch_clock(epoch_mode, &clock);
+        
+        emit!(EpochModeUpdated {
+            authority: ctx.accounts.authority.key(),
+            epoch_mode,
+            epoch_length,
+            epoch_id: stablecoin.current_epoch_start,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === EPOCH: SET CRANK BOUNTY ===
+    pub fn set_crank_bounty(ctx: Context<SetCrankBounty>, bounty_lamports: u64) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let fund = &mut ctx.accounts.crank_fund;
+        fund.stablecoin = ctx.accounts.stablecoin_state.key();
+        fund.bounty_lamports = bounty_lamports;
+        fund.bump = ctx.bumps.crank_fund;
+        
+        emit!(CrankBountyUpdated {
+            authority: ctx.accounts.authority.key(),
+            bounty_lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === EPOCH: ROLL (permissionless crank) ===
+    // Resets the epoch counters as soon as the epoch is due rather than on the
+    // next mint. Pays the crank fund's bounty while it stays rent-exempt.
+    pub fn roll_epoch(ctx: Context<RollEpoch>) -> Result<()> {
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        let previous_start = stablecoin.current_epoch_start;
+        let previous_epoch_minted = stablecoin.current_epoch_minted;
+        let epoch_id = roll_mint_epoch(&mut stablecoin)?;
+        require!(epoch_id != previous_start, StablecoinError::EpochNotDue);
+        
+        let mut bounty_lamports = 0;
+        if let Some(fund) = ctx.accounts.crank_fund.as_mut() {
+            let fund_info = fund.to_account_info();
+            let rent_floor = Rent::get()?.minimum_balance(fund_info.data_len());
+            let available = fund_info.lamports().saturating_sub(rent_floor);
+            if fund.bounty_lamports > 0 && available >= fund.bounty_lamports {
+                bounty_lamports = fund.bounty_lamports;
+                fund.sub_lamports(bounty_lamports)?;
+                ctx.accounts.cranker.add_lamports(bounty_lamports)?;
+                fund.total_paid = fund.total_paid.saturating_add(bounty_lamports);
+            }
+        }
+        
+        emit!(EpochRolled {
+            cranker: ctx.accounts.cranker.key(),
+            epoch_id,
+            previous_epoch_minted,
+            bounty_lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === ENABLE MINT CLOSE AUTHORITY ===
+    pub fn enable_mint_close_authority(ctx: Context<UpdateFeatures>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        require!(stablecoin.locks & LOCK_FEATURES == 0, StablecoinError::ParameterLocked);
+        let old_features = stablecoin.features;
+        stablecoin.features |= 4; // Bit 2 = MintCloseAuthority
+        let new_features = stablecoin.features;
+        let timestamp = Clock::get()?.unix_timestamp;
+        
+        emit!(MintCloseAuthorityEnabled {
+            authority: ctx.accounts.authority.key(),
+            old_features,
+            new_features,
+            timestamp,
+        });
+        emit!(FeatureFlagChanged {
+            authority: ctx.accounts.authority.key(),
+            flag: 4,
+            old_features,
+            new_features,
+            enabled: true,
+            timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === ENABLE DEFAULT ACCOUNT STATE ===
+    pub fn enable_default_account_state(ctx: Context<UpdateFeatures>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        require!(stablecoin.locks & LOCK_FEATURES == 0, StablecoinError::ParameterLocked);
+        let old_features = stablecoin.features;
+        stablecoin.features |= 8; // Bit 3 = DefaultAccountState
+        let new_features = stablecoin.features;
+        let timestamp = Clock::get()?.unix_timestamp;
+        
+        emit!(DefaultAccountStateEnabled {
+            authority: ctx.accounts.authority.key(),
+            old_features,
+            new_features,
+            timestamp,
+        });
+        emit!(FeatureFlagChanged {
+            authority: ctx.accounts.authority.key(),
+            flag: 8,
+            old_features,
+            new_features,
+            enabled: true,
+            timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === LOOKUP TABLES: CREATE ===
+    // Creates an address lookup table owned by the registry PDA so batch
+    // tooling can pack more accounts into a v0 transaction.
+    pub fn create_lookup_table(ctx: Context<CreateLookupTable>, recent_slot: u64) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let registry = &mut ctx.accounts.lookup_registry;
+        require!(registry.tables.len() < MAX_LOOKUP_TABLES, StablecoinError::InvalidLookupTable);
+        registry.stablecoin = ctx.accounts.stablecoin_state.key();
+        registry.bump = ctx.bumps.lookup_registry;
+        
+        let (ix, table) = address_lookup_table::instruction::create_lookup_table(
+            registry.key(),
+            ctx.accounts.authority.key(),
+            recent_slot,
+        );
+        require_keys_eq!(table, ctx.accounts.lookup_table.key(), StablecoinError::InvalidLookupTable);
+        
+        let stablecoin_key = registry.stablecoin;
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.lookup_table.to_account_info(),
+                registry.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[b"lookup_tables", stablecoin_key.as_ref(), &[registry.bump]]],
+        )?;
+        registry.tables.push(table);
+        
+        emit!(LookupTableUpdated {
+            stablecoin: stablecoin_key,
+            table,
+            added: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === LOOKUP TABLES: EXTEND ===
+    pub fn extend_lookup_table(ctx: Context<ExtendLookupTable>, addresses: Vec<Pubkey>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            !addresses.is_empty() && addresses.len() <= MAX_LOOKUP_TABLE_EXTEND,
+            StablecoinError::InvalidLookupTable
+        );
+        
+        let registry = &ctx.accounts.lookup_registry;
+        let table = ctx.accounts.lookup_table.key();
+        require!(registry.tables.contains(&table), StablecoinError::InvalidLookupTable);
+        
+        let added = addresses.len() as u32;
+        let ix = address_lookup_table::instruction::extend_lookup_table(
+            table,
+            registry.key(),
+            Some(ctx.accounts.authority.key()),
+            addresses,
+        );
+        anchor_lang::solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.lookup_table.to_account_info(),
+                registry.to_account_info(),
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[b"lookup_tables", registry.stablecoin.as_ref(), &[registry.bump]]],
+        )?;
+        
+        emit!(LookupTableUpdated {
+            stablecoin: registry.stablecoin,
+            table,
+            added,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === BATCH MINT ===
+    // Recipients' token accounts are passed as remaining_accounts (in order matching amounts)
+    pub fn batch_mint<'a>(
+        ctx: Context<'_, '_, 'a, 'a, BatchMint<'a>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        let n = amounts.len();
+        require!(n > 0 && n <= 10, StablecoinError::InvalidAmount);
+        require!(ctx.remaining_accounts.len() == n, StablecoinError::InvalidAmount);
+        
+        // Read values before any mutable borrow
+        let state = *ctx.accounts.stablecoin_state.load()?;
+        let supply_cap = state.supply_cap;
+        let epoch_quota = state.epoch_quota;
+        let total_supply = state.total_supply;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let role_bits = ctx.accounts.minter_role.roles;
+        
+        require!(!state.is_paused(), StablecoinError::ContractPaused);
+        require!(!state.is_sunset(), StablecoinError::StablecoinSunset);
+        
+        // Check minter role
+        require!(
+            has_role(&ctx.accounts.minter_role, ROLE_MINTER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut total_amount: u64 = 0;
+        for amount in amounts.iter() {
+            require!(*amount > 0, StablecoinError::InvalidAmount);
+            total_amount = total_amount.checked_add(*amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        if !check_circuit_breaker(
+            &ctx.accounts.stablecoin_state,
+            ctx.accounts.circuit_breaker.as_mut(),
+            ctx.accounts.minter.key(),
+            total_amount,
+            Clock::get()?.unix_timestamp,
+        )? {
+            return Ok(());
+        }
+        charge_session_limit(&mut ctx.accounts.minter_role, total_amount, Clock::get()?.unix_timestamp)?;
+        
+        // Check quota if not master
+        if role_bits & ROLE_MASTER == 0 {
+            let minter_info = &ctx.accounts.minter_info;
+            let new_minted = minter_info.minted.checked_add(total_amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                new_minted <= minter_info.quota,
+                StablecoinError::QuotaExceeded
+            );
+        }
+        
+        // Check supply cap
+        let new_supply = total_supply.checked_add(total_amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        if supply_cap > 0 {
+            require!(new_supply <= supply_cap, StablecoinError::SupplyCapExceeded);
+        }
+        
         // Check epoch quota
+        let mut stablecoin_mut = ctx.accounts.stablecoin_state.load_mut()?;
+        let epoch_id = roll_mint_epoch(&mut stablecoin_mut)?;
         if epoch_quota > 0 {
-            let current_time = Clock::get()?.unix_timestamp;
-            let epoch_elapsed = current_time - epoch_start;
+            let epoch_new_total = stablecoin_mut.current_epoch_minted
+                .checked_add(total_amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                epoch_new_total <= epoch_quota,
+                StablecoinError::EpochQuotaExceeded
+            );
+        }
+        
+        let mint_authority_bump = ctx.bumps.mint_authority;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"mint_authority",
+            stablecoin_key.as_ref(),
+            &[mint_authority_bump],
+        ]];
+        
+        // CPI mint_to for each recipient token account (passed as remaining_accounts)
+        for (i, amount) in amounts.iter().enumerate() {
+            let recipient_account = &ctx.remaining_accounts[i];
+            token_2022::mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::MintTo {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: recipient_account.to_account_info(),
+                        authority: ctx.accounts.mint_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                *amount,
+            )?;
+        }
+        
+        // Update state
+        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_add(total_amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        stablecoin_mut.current_epoch_minted = stablecoin_mut.current_epoch_minted
+            .checked_add(total_amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        // Update minter quota if applicable
+        if role_bits & ROLE_MASTER == 0 {
+            let minter_info = &mut ctx.accounts.minter_info;
+            minter_info.minted = minter_info.minted.checked_add(total_amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        
+        if let Some(stats) = &ctx.accounts.minter_stats {
+            record_minter_stats(&mut *stats.load_mut()?, n as u32, total_amount, Clock::get()?.unix_timestamp)?;
+        }
+        
+        emit!(BatchMinted {
+            minter: ctx.accounts.minter.key(),
+            recipients: n as u16,
+            total_amount,
+            epoch_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: INITIALIZE CONFIG ===
+    pub fn initialize_multisig(
+        ctx: Context<InitializeMultisig>,
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(threshold > 0 && threshold <= signers.len() as u8, StablecoinError::InvalidAmount);
+        require!(signers.len() <= 10, StablecoinError::InvalidAmount);
+        
+        let config = &mut ctx.accounts.multisig_config;
+        config.stablecoin = ctx.accounts.stablecoin_state.key();
+        config.threshold = threshold;
+        config.signers = signers;
+        config.bump = ctx.bumps.multisig_config;
+        config.secp_signers = vec![];
+        config.max_payload_len = 0;
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: SET PAYLOAD LIMIT ===
+    pub fn set_max_proposal_payload(ctx: Context<SetMaxProposalPayload>, max_payload_len: u16) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            max_payload_len as usize <= MAX_PROPOSAL_PAYLOAD,
+            StablecoinError::ProposalTooLarge
+        );
+        
+        ctx.accounts.multisig_config.max_payload_len = max_payload_len;
+        Ok(())
+    }
+    
+    // === MULTISIG: SET SECP256K1 SIGNERS ===
+    // Registers MPC/hardware signers that approve via approve_proposal_with_sig.
+    // They count toward the threshold alongside the ed25519 signers.
+    pub fn set_secp_signers(
+        ctx: Context<SetSecpSigners>,
+        secp_signers: Vec<[u8; 20]>,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(secp_signers.len() <= 10, StablecoinError::InvalidAmount);
+        
+        let config = &mut ctx.accounts.multisig_config;
+        require!(
+            config.threshold as usize <= config.signers.len() + secp_signers.len(),
+            StablecoinError::InvalidAmount
+        );
+        config.secp_signers = secp_signers;
+        
+        emit!(SecpSignersUpdated {
+            authority: ctx.accounts.authority.key(),
+            secp_signers: config.secp_signers.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: CREATE PROPOSAL ===
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        instruction_data: Vec<u8>,
+        expires_in: i64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.multisig_config.signers.contains(&ctx.accounts.proposer.key()),
+            StablecoinError::Unauthorized
+        );
+        require!(
+            instruction_data.len() <= ctx.accounts.multisig_config.max_payload(),
+            StablecoinError::ProposalTooLarge
+        );
+        
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.config = ctx.accounts.multisig_config.key();
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.instruction_data = instruction_data;
+        proposal.approvals = vec![];
+        proposal.secp_approvals = vec![];
+        proposal.executed = false;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+        proposal.expires_at = proposal.created_at + expires_in;
+        proposal.bump = ctx.bumps.proposal;
+        
+        emit!(MultisigProposalCreated {
+            proposal: proposal.key(),
+            proposer: ctx.accounts.proposer.key(),
+            timestamp: proposal.created_at,
+        });
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: APPROVE PROPOSAL ===
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let config = &ctx.accounts.multisig_config;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(
+            Clock::get()?.unix_timestamp < proposal.expires_at,
+            StablecoinError::ProposalExpired
+        );
+        require!(!proposal.executed, StablecoinError::AlreadyExecuted);
+        require!(
+            config.signers.contains(&ctx.accounts.signer.key()),
+            StablecoinError::Unauthorized
+        );
+        require!(
+            !proposal.approvals.contains(&ctx.accounts.signer.key()),
+            StablecoinError::AlreadyApproved
+        );
+        
+        proposal.approvals.push(ctx.accounts.signer.key());
+        
+        emit!(MultisigProposalApproved {
+            proposal: proposal.key(),
+            approver: ctx.accounts.signer.key(),
+            approvals: proposal.approvals.len() as u8,
+            threshold: config.threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: APPROVE PROPOSAL (secp256k1) ===
+    // Must directly follow a secp256k1 program instruction in which a
+    // registered secp signer signs secp_approval_message(proposal).
+    pub fn approve_proposal_with_sig(ctx: Context<ApproveProposalWithSig>) -> Result<()> {
+        let config = &ctx.accounts.multisig_config;
+        let proposal_key = ctx.accounts.proposal.key();
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(proposal.config == config.key(), StablecoinError::ProposalMismatch);
+        require!(
+            Clock::get()?.unix_timestamp < proposal.expires_at,
+            StablecoinError::ProposalExpired
+        );
+        require!(!proposal.executed, StablecoinError::AlreadyExecuted);
+        
+        let eth_address = verify_secp256k1_instruction(
+            &ctx.accounts.instructions,
+            &secp_approval_message(&proposal_key),
+        )?;
+        require!(
+            config.secp_signers.contains(&eth_address),
+            StablecoinError::Unauthorized
+        );
+        require!(
+            !proposal.secp_approvals.contains(&eth_address),
+            StablecoinError::AlreadyApproved
+        );
+        
+        proposal.secp_approvals.push(eth_address);
+        
+        emit!(SecpProposalApproved {
+            proposal: proposal_key,
+            eth_address,
+            approvals: live_approvals(config, proposal),
+            threshold: config.threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === MULTISIG: EXECUTE PROPOSAL ===
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let config = &ctx.accounts.multisig_config;
+        let proposal = &mut ctx.accounts.proposal;
+        
+        require!(
+            Clock::get()?.unix_timestamp < proposal.expires_at,
+            StablecoinError::ProposalExpired
+        );
+        require!(
+            live_approvals(config, proposal) >= config.threshold,
+            StablecoinError::ThresholdNotMet
+        );
+        require!(!proposal.executed, StablecoinError::AlreadyExecuted);
+        
+        proposal.executed = true;
+        
+        emit!(MultisigProposalExecuted {
+            proposal: proposal.key(),
+            executor: ctx.accounts.executor.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === TREASURY: INITIALIZE ===
+    // Creates the Treasury state and its ATA owned by the treasury_authority PDA
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(timelock_seconds >= 0, StablecoinError::InvalidAmount);
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.stablecoin = ctx.accounts.stablecoin_state.key();
+        treasury.token_account = ctx.accounts.treasury_token_account.key();
+        treasury.timelock_seconds = timelock_seconds;
+        treasury.total_withdrawn = 0;
+        treasury.withdrawal_count = 0;
+        treasury.bump = ctx.bumps.treasury;
+        treasury.fees_pending = 0;
+        treasury.total_fees_distributed = 0;
+        treasury.total_fees_harvested = 0;
+        
+        emit!(TreasuryInitialized {
+            authority: ctx.accounts.authority.key(),
+            treasury: treasury.key(),
+            token_account: treasury.token_account,
+            timelock_seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === TREASURY: WITHDRAW ===
+    // Requires an approved multisig proposal carrying ProposalAction::TreasuryWithdraw
+    // whose timelock has elapsed. Anyone may execute once those conditions hold.
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn withdraw_treasury<'a>(
+        ctx: Context<'_, '_, 'a, 'a, WithdrawTreasury<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        // Fees awaiting distribution are not withdrawable
+        let available = ctx.accounts.treasury_token_account.amount
+            .saturating_sub(ctx.accounts.treasury.fees_pending);
+        require!(available >= amount, StablecoinError::InsufficientBalance);
+        
+        let action = ProposalAction::TreasuryWithdraw {
+            destination: ctx.accounts.destination.key(),
+            amount,
+        };
+        consume_proposal(
+            &ctx.accounts.multisig_config,
+            ctx.accounts.multisig_config.key(),
+            &mut ctx.accounts.proposal,
+            &action,
+            ctx.accounts.treasury.timelock_seconds,
+        )?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.treasury_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination.to_account_info(),
+            ctx.accounts.treasury_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&[b"treasury_authority", stablecoin_key.as_ref(), &[ctx.bumps.treasury_authority]]],
+        )?;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_withdrawn = treasury.total_withdrawn.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        treasury.withdrawal_count = treasury.withdrawal_count.checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(TreasuryWithdrawn {
+            proposal: ctx.accounts.proposal.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            total_withdrawn: treasury.total_withdrawn,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: HARVEST (permissionless crank) ===
+    // Sweeps Token-2022 TransferFee withheld amounts into the treasury ATA.
+    // Token accounts holding withheld fees are passed as remaining_accounts;
+    // the treasury_authority PDA must be the mint's withdraw-withheld authority.
+    // total_fees_harvested plus amounts still withheld reconciles with the
+    // hook's total_fees_collected.
+    pub fn harvest_fees<'a>(
+        ctx: Context<'_, '_, 'a, 'a, HarvestFees<'a>>,
+    ) -> Result<()> {
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let harvested = harvest_withheld_to_treasury(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &mut ctx.accounts.treasury_token_account,
+            &ctx.accounts.treasury_authority,
+            &[&[b"treasury_authority", stablecoin_key.as_ref(), &[ctx.bumps.treasury_authority]]],
+            ctx.remaining_accounts,
+        )?;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.fees_pending = treasury.fees_pending.checked_add(harvested)
+            .ok_or(StablecoinError::MathOverflow)?;
+        treasury.total_fees_harvested = treasury.total_fees_harvested.checked_add(harvested)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(FeesHarvested {
+            cranker: ctx.accounts.cranker.key(),
+            amount: harvested,
+            total_fees_harvested: treasury.total_fees_harvested,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: SWEEP ALLOWLIST ===
+    // Allow sweep_to_treasury to consolidate a token account owned by one of
+    // the program's PDAs. The insurance fund and savings pool balances, and
+    // the treasury ATA itself, can't be added.
+    pub fn add_sweep_source(ctx: Context<AddSweepSource>, source: RescueSource) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let token_account = &ctx.accounts.token_account;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let (owner, _) = Pubkey::find_program_address(&[source.seed(), stablecoin_key.as_ref()], &crate::ID);
+        require!(
+            token_account.owner == owner
+                && token_account.mint == ctx.accounts.stablecoin_state.load()?.mint
+                && token_account.key() != ctx.accounts.treasury.token_account
+                && (!source.manages_stablecoin() || source == RescueSource::TreasuryAuthority),
+            StablecoinError::InvalidSweepSource
+        );
+        
+        let allowlist = &mut ctx.accounts.sweep_allowlist;
+        allowlist.stablecoin = stablecoin_key;
+        allowlist.bump = ctx.bumps.sweep_allowlist;
+        if !allowlist.sources.iter().any(|s| s.token_account == token_account.key()) {
+            require!(allowlist.sources.len() < MAX_SWEEP_SOURCES, StablecoinError::InvalidSweepSource);
+            allowlist.sources.push(SweepSource { token_account: token_account.key(), source });
+        }
+        
+        emit!(SweepSourceUpdated {
+            authority: ctx.accounts.authority.key(),
+            token_account: token_account.key(),
+            source: Some(source),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    pub fn remove_sweep_source(ctx: Context<RemoveSweepSource>, token_account: Pubkey) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let allowlist = &mut ctx.accounts.sweep_allowlist;
+        let index = allowlist.sources.iter()
+            .position(|s| s.token_account == token_account)
+            .ok_or(StablecoinError::InvalidSweepSource)?;
+        allowlist.sources.remove(index);
+        
+        emit!(SweepSourceUpdated {
+            authority: ctx.accounts.authority.key(),
+            token_account,
+            source: None,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: SWEEP TO TREASURY (permissionless crank) ===
+    // Harvests withheld fees like harvest_fees, then moves the full balance of
+    // an optional allowlisted source_token_account into the treasury ATA.
+    // remaining_accounts holds `harvest_count` token accounts to harvest,
+    // followed by the hook's extra accounts for the consolidating transfer.
+    // Harvested fees join fees_pending; consolidated balances do not, as they
+    // may be seizures or past withdrawals rather than fees.
+    pub fn sweep_to_treasury<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SweepToTreasury<'a>>,
+        harvest_count: u8,
+    ) -> Result<()> {
+        let harvest_count = harvest_count as usize;
+        require!(
+            harvest_count <= ctx.remaining_accounts.len(),
+            StablecoinError::InvalidSweepSource
+        );
+        let (withheld_accounts, hook_accounts) = ctx.remaining_accounts.split_at(harvest_count);
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let harvested = harvest_withheld_to_treasury(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &mut ctx.accounts.treasury_token_account,
+            &ctx.accounts.treasury_authority,
+            &[&[b"treasury_authority", stablecoin_key.as_ref(), &[ctx.bumps.treasury_authority]]],
+            withheld_accounts,
+        )?;
+        
+        let mut swept: u64 = 0;
+        if let Some(source_token_account) = &ctx.accounts.source_token_account {
+            let entry = ctx.accounts.sweep_allowlist.sources.iter()
+                .find(|s| s.token_account == source_token_account.key())
+                .copied()
+                .ok_or(StablecoinError::InvalidSweepSource)?;
+            let source_authority = ctx.accounts.source_authority.as_ref()
+                .ok_or(StablecoinError::InvalidSweepSource)?;
+            let (owner, bump) =
+                Pubkey::find_program_address(&[entry.source.seed(), stablecoin_key.as_ref()], &crate::ID);
+            require_keys_eq!(source_authority.key(), owner, StablecoinError::InvalidSweepSource);
+            
+            swept = source_token_account.amount;
+            if swept > 0 {
+                transfer_with_hook(
+                    &ctx.accounts.token_program.to_account_info(),
+                    source_token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    ctx.accounts.treasury_token_account.to_account_info(),
+                    source_authority.to_account_info(),
+                    hook_accounts,
+                    swept,
+                    ctx.accounts.mint.decimals,
+                    &[&[entry.source.seed(), stablecoin_key.as_ref(), &[bump]]],
+                )?;
+            }
+        }
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.fees_pending = treasury.fees_pending.checked_add(harvested)
+            .ok_or(StablecoinError::MathOverflow)?;
+        treasury.total_fees_harvested = treasury.total_fees_harvested.checked_add(harvested)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let allowlist = &mut ctx.accounts.sweep_allowlist;
+        allowlist.total_swept = allowlist.total_swept.checked_add(swept)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(TreasurySwept {
+            cranker: ctx.accounts.cranker.key(),
+            harvested,
+            source_token_account: ctx.accounts.source_token_account.as_ref().map(|a| a.key()),
+            swept,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: SET DISTRIBUTION ===
+    pub fn set_fee_distribution(
+        ctx: Context<SetFeeDistribution>,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        validate_fee_recipients(&recipients)?;
+        
+        let distribution = &mut ctx.accounts.fee_distribution;
+        distribution.stablecoin = ctx.accounts.stablecoin_state.key();
+        distribution.recipients = recipients;
+        distribution.bump = ctx.bumps.fee_distribution;
+        
+        emit!(FeeDistributionUpdated {
+            authority: ctx.accounts.authority.key(),
+            recipients: distribution.recipients.len() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: DISTRIBUTE (permissionless crank) ===
+    // Recipient token accounts lead remaining_accounts in config order; any
+    // accounts after them are transfer hook extras, if the mint has one.
+    // When an insurance fund exists, its allocation_bps share of fees_pending is
+    // routed to it first and recipients split the remainder.
+    // Rounding dust stays in fees_pending for the next run.
+    pub fn distribute_fees<'a>(
+        ctx: Context<'_, '_, 'a, 'a, DistributeFees<'a>>,
+    ) -> Result<()> {
+        let pending = ctx.accounts.treasury.fees_pending;
+        require!(pending > 0, StablecoinError::NothingToDistribute);
+        
+        let recipients = ctx.accounts.fee_distribution.recipients.clone();
+        require!(
+            ctx.remaining_accounts.len() >= recipients.len(),
+            StablecoinError::InvalidFeeDistribution
+        );
+        let (recipient_accounts, hook_accounts) = ctx.remaining_accounts.split_at(recipients.len());
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"treasury_authority",
+            stablecoin_key.as_ref(),
+            &[ctx.bumps.treasury_authority],
+        ]];
+        
+        // The fund PDA is always passed so a cranker cannot skip the allocation
+        let fund_info = ctx.accounts.insurance_fund.to_account_info();
+        let mut insurance_amount: u64 = 0;
+        if fund_info.owner == &crate::ID {
+            let mut fund = InsuranceFund::try_deserialize(&mut &fund_info.try_borrow_data()?[..])?;
+            let insurance_token_account = ctx.accounts.insurance_token_account.as_ref()
+                .ok_or(StablecoinError::InvalidInsuranceFund)?;
+            require_keys_eq!(
+                insurance_token_account.key(),
+                fund.token_account,
+                StablecoinError::InvalidInsuranceFund
+            );
+            
+            insurance_amount = ((pending as u128)
+                .checked_mul(fund.allocation_bps as u128)
+                .ok_or(StablecoinError::MathOverflow)?
+                / 10_000) as u64;
+            if insurance_amount > 0 {
+                transfer_with_hook(
+                    &ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.treasury_token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    insurance_token_account.to_account_info(),
+                    ctx.accounts.treasury_authority.to_account_info(),
+                    hook_accounts,
+                    insurance_amount,
+                    ctx.accounts.mint.decimals,
+                    signer_seeds,
+                )?;
+                fund.total_allocated = fund.total_allocated.checked_add(insurance_amount)
+                    .ok_or(StablecoinError::MathOverflow)?;
+                fund.try_serialize(&mut &mut fund_info.try_borrow_mut_data()?[..])?;
+            }
+        }
+        let distributable = pending.checked_sub(insurance_amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let mut distributed: u64 = 0;
+        for (recipient, account) in recipients.iter().zip(recipient_accounts.iter()) {
+            require!(
+                account.key() == recipient.token_account,
+                StablecoinError::InvalidFeeDistribution
+            );
+            let share = (distributable as u128)
+                .checked_mul(recipient.share_bps as u128)
+                .ok_or(StablecoinError::MathOverflow)?
+                / 10_000;
+            let share = share as u64;
+            if share == 0 {
+                continue;
+            }
             
-            if epoch_elapsed >= 86400 {
-                let stablecoin_mut = &mut ctx.accounts.stablecoin_state;
-                stablecoin_mut.current_epoch_minted = 0;
-                stablecoin_mut.current_epoch_start = current_time;
+            transfer_with_hook(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.treasury_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                account.to_account_info(),
+                ctx.accounts.treasury_authority.to_account_info(),
+                hook_accounts,
+                share,
+                ctx.accounts.mint.decimals,
+                signer_seeds,
+            )?;
+            distributed = distributed.checked_add(share)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.fees_pending = treasury.fees_pending
+            .checked_sub(distributed)
+            .and_then(|v| v.checked_sub(insurance_amount))
+            .ok_or(StablecoinError::MathOverflow)?;
+        treasury.total_fees_distributed = treasury.total_fees_distributed.checked_add(distributed)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let distribution = &mut ctx.accounts.fee_distribution;
+        distribution.total_distributed = distribution.total_distributed.checked_add(distributed)
+            .ok_or(StablecoinError::MathOverflow)?;
+        distribution.last_distributed_at = now;
+        
+        emit!(FeesDistributed {
+            cranker: ctx.accounts.cranker.key(),
+            total_amount: distributed,
+            recipients: recipients.len() as u8,
+            insurance_amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: CLAIM MARKET MAKER REBATE ===
+    // Pays a market maker the rebates the hook has settled for closed monthly
+    // periods, out of the treasury's pending fees. If fees_pending can't cover
+    // the full amount the rest stays claimable. remaining_accounts carry
+    // transfer hook extras, if the mint has one.
+    pub fn claim_rebate<'a>(ctx: Context<'_, '_, 'a, 'a, ClaimRebate<'a>>) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        let rebate = load_hook_account::<sss_transfer_hook_program::MarketMakerRebate>(
+            &ctx.accounts.rebate,
+            "MarketMakerRebate",
+        )?
+        .ok_or(StablecoinError::NoRebateDue)?;
+        
+        // The current period counts once it has closed, even before the hook
+        // has rolled it into `settled`
+        let now = Clock::get()?.unix_timestamp;
+        let mut settled = rebate.settled;
+        if now >= rebate.period_start + sss_transfer_hook_program::REBATE_PERIOD {
+            settled = settled.checked_add(rebate.period_accrued)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        let accounts = &mut *ctx.accounts;
+        let amount = pay_claim_from_treasury(
+            &accounts.token_program.to_account_info(),
+            accounts.treasury_token_account.to_account_info(),
+            &accounts.mint,
+            accounts.destination.to_account_info(),
+            accounts.treasury_authority.to_account_info(),
+            ctx.bumps.treasury_authority,
+            ctx.remaining_accounts,
+            accounts.stablecoin_state.key(),
+            &mut accounts.treasury,
+            &mut *accounts.rebate_claim,
+            ctx.bumps.rebate_claim,
+            accounts.owner.key(),
+            settled,
+            StablecoinError::NoRebateDue,
+        )?;
+        
+        emit!(RebateClaimed {
+            owner: accounts.owner.key(),
+            amount,
+            total_claimed: accounts.rebate_claim.claimed,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: CLAIM REFERRAL FEES ===
+    // Pays a partner the referral fees the hook has settled to it, out of the
+    // treasury's pending fees. Anything fees_pending can't cover stays claimable.
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn claim_referral_fees<'a>(
+        ctx: Context<'_, '_, 'a, 'a, ClaimReferralFees<'a>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        let referrer = load_hook_account::<sss_transfer_hook_program::Referrer>(
+            &ctx.accounts.referrer_account,
+            "Referrer",
+        )?
+        .ok_or(StablecoinError::NoReferralFeesDue)?;
+        
+        let accounts = &mut *ctx.accounts;
+        let amount = pay_claim_from_treasury(
+            &accounts.token_program.to_account_info(),
+            accounts.treasury_token_account.to_account_info(),
+            &accounts.mint,
+            accounts.destination.to_account_info(),
+            accounts.treasury_authority.to_account_info(),
+            ctx.bumps.treasury_authority,
+            ctx.remaining_accounts,
+            accounts.stablecoin_state.key(),
+            &mut accounts.treasury,
+            &mut *accounts.referral_claim,
+            ctx.bumps.referral_claim,
+            accounts.referrer.key(),
+            referrer.earned,
+            StablecoinError::NoReferralFeesDue,
+        )?;
+        
+        emit!(ReferralFeesClaimed {
+            referrer: accounts.referrer.key(),
+            amount,
+            total_claimed: accounts.referral_claim.claimed,
+            timestamp: accounts.referral_claim.last_claimed_at,
+        });
+        
+        Ok(())
+    }
+    
+    // === FEES: CLAIM FEE CREDIT ===
+    // Pays an owner the withheld fees the hook credited back because its tier
+    // prices transfers below the mint's TransferFee, out of the treasury's
+    // pending fees. Anything fees_pending can't cover stays claimable.
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn claim_fee_credit<'a>(
+        ctx: Context<'_, '_, 'a, 'a, ClaimFeeCredit<'a>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        let fee_credit = load_hook_account::<sss_transfer_hook_program::FeeCredit>(
+            &ctx.accounts.fee_credit,
+            "FeeCredit",
+        )?
+        .ok_or(StablecoinError::NoFeeCreditDue)?;
+        
+        let accounts = &mut *ctx.accounts;
+        let amount = pay_claim_from_treasury(
+            &accounts.token_program.to_account_info(),
+            accounts.treasury_token_account.to_account_info(),
+            &accounts.mint,
+            accounts.destination.to_account_info(),
+            accounts.treasury_authority.to_account_info(),
+            ctx.bumps.treasury_authority,
+            ctx.remaining_accounts,
+            accounts.stablecoin_state.key(),
+            &mut accounts.treasury,
+            &mut *accounts.fee_credit_claim,
+            ctx.bumps.fee_credit_claim,
+            accounts.owner.key(),
+            fee_credit.credited,
+            StablecoinError::NoFeeCreditDue,
+        )?;
+        
+        emit!(FeeCreditClaimed {
+            owner: accounts.owner.key(),
+            amount,
+            total_claimed: accounts.fee_credit_claim.claimed,
+            timestamp: accounts.fee_credit_claim.last_claimed_at,
+        });
+        
+        Ok(())
+    }
+    
+    // === TREASURY: SET BURN POLICY ===
+    pub fn set_burn_policy(
+        ctx: Context<SetBurnPolicy>,
+        enabled: bool,
+        max_per_epoch: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let policy = &mut ctx.accounts.burn_policy;
+        policy.stablecoin = ctx.accounts.stablecoin_state.key();
+        policy.enabled = enabled;
+        policy.max_per_epoch = max_per_epoch;
+        policy.bump = ctx.bumps.burn_policy;
+        
+        emit!(BurnPolicyUpdated {
+            authority: ctx.accounts.authority.key(),
+            enabled,
+            max_per_epoch,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === TREASURY: BURN (permissionless crank) ===
+    // Burns treasury balance within the BurnPolicy epoch limit. Pending fees are
+    // burned first, so the fee pool shrinks before any withdrawable balance.
+    pub fn burn_from_treasury(ctx: Context<BurnFromTreasury>, amount: u64) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        require!(ctx.accounts.burn_policy.enabled, StablecoinError::BurnPolicyDisabled);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            ctx.accounts.treasury_token_account.amount >= amount,
+            StablecoinError::InsufficientBalance
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let policy = &mut ctx.accounts.burn_policy;
+        if now - policy.epoch_start >= 86400 {
+            policy.epoch_burned = 0;
+            policy.epoch_start = now;
+        }
+        let epoch_burned = policy.epoch_burned.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        if policy.max_per_epoch > 0 {
+            require!(epoch_burned <= policy.max_per_epoch, StablecoinError::BurnLimitExceeded);
+        }
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        token_2022::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.treasury_authority.to_account_info(),
+                },
+                &[&[b"treasury_authority", stablecoin_key.as_ref(), &[ctx.bumps.treasury_authority]]],
+            ),
+            amount,
+        )?;
+        
+        let policy = &mut ctx.accounts.burn_policy;
+        policy.epoch_burned = epoch_burned;
+        policy.total_burned = policy.total_burned.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        let fees_burned = amount.min(treasury.fees_pending);
+        treasury.fees_pending -= fees_burned;
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin.total_supply = stablecoin.total_supply.checked_sub(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(TreasuryBurned {
+            cranker: ctx.accounts.cranker.key(),
+            amount,
+            fees_burned,
+            epoch_burned,
+            total_burned: ctx.accounts.burn_policy.total_burned,
+            total_supply: stablecoin.total_supply,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === INSURANCE: INITIALIZE ===
+    // Creates the InsuranceFund state and its ATA owned by the insurance_authority PDA
+    pub fn initialize_insurance_fund(
+        ctx: Context<InitializeInsuranceFund>,
+        allocation_bps: u16,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            allocation_bps <= MAX_INSURANCE_ALLOCATION_BPS,
+            StablecoinError::InvalidInsuranceAllocation
+        );
+        require!(timelock_seconds >= 0, StablecoinError::InvalidAmount);
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        fund.stablecoin = ctx.accounts.stablecoin_state.key();
+        fund.token_account = ctx.accounts.insurance_token_account.key();
+        fund.allocation_bps = allocation_bps;
+        fund.timelock_seconds = timelock_seconds;
+        fund.total_allocated = 0;
+        fund.total_drawn = 0;
+        fund.draw_count = 0;
+        fund.bump = ctx.bumps.insurance_fund;
+        
+        emit!(InsuranceFundInitialized {
+            authority: ctx.accounts.authority.key(),
+            insurance_fund: fund.key(),
+            token_account: fund.token_account,
+            allocation_bps,
+            timelock_seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === INSURANCE: SET ALLOCATION ===
+    pub fn set_insurance_allocation(
+        ctx: Context<SetInsuranceAllocation>,
+        allocation_bps: u16,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            allocation_bps <= MAX_INSURANCE_ALLOCATION_BPS,
+            StablecoinError::InvalidInsuranceAllocation
+        );
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        let old_allocation_bps = fund.allocation_bps;
+        fund.allocation_bps = allocation_bps;
+        
+        emit!(InsuranceAllocationUpdated {
+            authority: ctx.accounts.authority.key(),
+            old_allocation_bps,
+            new_allocation_bps: allocation_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === INSURANCE: DRAW ===
+    // Covers operational losses. Requires an approved multisig proposal carrying
+    // ProposalAction::InsuranceDraw whose fund timelock has elapsed.
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn draw_insurance<'a>(
+        ctx: Context<'_, '_, 'a, 'a, DrawInsurance<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            ctx.accounts.insurance_token_account.amount >= amount,
+            StablecoinError::InsufficientBalance
+        );
+        
+        let action = ProposalAction::InsuranceDraw {
+            destination: ctx.accounts.destination.key(),
+            amount,
+        };
+        consume_proposal(
+            &ctx.accounts.multisig_config,
+            ctx.accounts.multisig_config.key(),
+            &mut ctx.accounts.proposal,
+            &action,
+            ctx.accounts.insurance_fund.timelock_seconds,
+        )?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.insurance_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.destination.to_account_info(),
+            ctx.accounts.insurance_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&[b"insurance_authority", stablecoin_key.as_ref(), &[ctx.bumps.insurance_authority]]],
+        )?;
+        
+        let fund = &mut ctx.accounts.insurance_fund;
+        fund.total_drawn = fund.total_drawn.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        fund.draw_count = fund.draw_count.checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(InsuranceDrawn {
+            proposal: ctx.accounts.proposal.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            total_drawn: fund.total_drawn,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FX: CONFIGURE PAIR ===
+    // The caller must be master of both stablecoins.
+    pub fn configure_fx_pair(
+        ctx: Context<ConfigureFxPair>,
+        oracle: Pubkey,
+        spread_bps: u16,
+        daily_limit: u64,
+        max_rate_age: i64,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.source_role, ROLE_MASTER)?
+                && has_role(&ctx.accounts.destination_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            ctx.accounts.source_state.key() != ctx.accounts.destination_state.key()
+            && spread_bps <= MAX_FX_SPREAD_BPS
+            && max_rate_age > 0
+            && oracle != Pubkey::default(),
+            StablecoinError::InvalidFxPair
+        );
+        
+        let pair = &mut ctx.accounts.fx_pair;
+        if pair.oracle != oracle {
+            // A new oracle must post its own rate before swaps resume
+            pair.rate = 0;
+            pair.rate_updated_at = 0;
+        }
+        pair.source_stablecoin = ctx.accounts.source_state.key();
+        pair.destination_stablecoin = ctx.accounts.destination_state.key();
+        pair.oracle = oracle;
+        pair.spread_bps = spread_bps;
+        pair.daily_limit = daily_limit;
+        pair.max_rate_age = max_rate_age;
+        pair.enabled = enabled;
+        pair.bump = ctx.bumps.fx_pair;
+        
+        emit!(FxPairConfigured {
+            authority: ctx.accounts.authority.key(),
+            pair: pair.key(),
+            oracle,
+            spread_bps,
+            daily_limit,
+            max_rate_age,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === FX: UPDATE RATE ===
+    pub fn update_fx_rate(ctx: Context<UpdateFxRate>, rate: u64) -> Result<()> {
+        require!(rate > 0, StablecoinError::InvalidAmount);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let pair = &mut ctx.accounts.fx_pair;
+        pair.rate = rate;
+        pair.rate_updated_at = now;
+        
+        emit!(FxRateUpdated {
+            pair: pair.key(),
+            oracle: ctx.accounts.oracle.key(),
+            rate,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === FX: SWAP ===
+    // Burns `amount_in` of the source stablecoin from the user and mints the
+    // converted amount of the destination stablecoin, less the spread, which
+    // is minted to the destination treasury as pending fees.
+    pub fn swap_between_stablecoins(
+        ctx: Context<SwapBetweenStablecoins>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        require!(amount_in > 0, StablecoinError::InvalidAmount);
+        require!(!ctx.accounts.source_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        require!(!ctx.accounts.destination_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        require!(!ctx.accounts.destination_state.load()?.is_sunset(), StablecoinError::StablecoinSunset);
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        // Burning and minting skip both hooks, so screen the parties here the
+        // way a transfer out of the source and into the destination would be
+        let accounts = &ctx.accounts;
+        require!(
+            !accounts.source_token_account.is_frozen() && !accounts.destination_token_account.is_frozen(),
+            StablecoinError::SwapRestricted
+        );
+        let screened = [
+            (&accounts.source_blacklist, accounts.user.key()),
+            (&accounts.source_account_blacklist, accounts.source_token_account.key()),
+            (&accounts.destination_blacklist, accounts.destination_token_account.owner),
+            (&accounts.destination_account_blacklist, accounts.destination_token_account.key()),
+        ];
+        for (entry, address) in screened {
+            require!(!is_hook_blacklisted(entry, &address, now)?, StablecoinError::SwapRestricted);
+        }
+        require!(
+            load_hook_account::<sss_transfer_hook_program::LegalHold>(&accounts.source_legal_hold, "LegalHold")?
+                .is_none(),
+            StablecoinError::SwapRestricted
+        );
+        if let Some(hold) =
+            load_hook_account::<sss_transfer_hook_program::ClawbackHold>(&accounts.source_clawback_hold, "ClawbackHold")?
+        {
+            let remaining = accounts.source_token_account.amount.saturating_sub(amount_in);
+            require!(!hold.freeze_account && remaining >= hold.amount, StablecoinError::SwapRestricted);
+        }
+        
+        let pair = &mut ctx.accounts.fx_pair;
+        require!(pair.enabled, StablecoinError::FxPairDisabled);
+        let rate_age = now.checked_sub(pair.rate_updated_at).ok_or(StablecoinError::MathOverflow)?;
+        require!(pair.rate > 0 && rate_age <= pair.max_rate_age, StablecoinError::StaleFxRate);
+        
+        // Per-pair daily limit on the source amount
+        let today = now.div_euclid(86400);
+        if pair.current_day != today {
+            pair.current_day = today;
+            pair.day_volume = 0;
+        }
+        let day_volume = pair.day_volume.checked_add(amount_in).ok_or(StablecoinError::MathOverflow)?;
+        if pair.daily_limit > 0 {
+            require!(day_volume <= pair.daily_limit, StablecoinError::FxDailyLimitExceeded);
+        }
+        pair.day_volume = day_volume;
+        
+        let gross = fx_convert(
+            amount_in,
+            pair.rate,
+            ctx.accounts.source_mint.decimals,
+            ctx.accounts.destination_mint.decimals,
+        )?;
+        let spread = (gross as u128)
+            .checked_mul(pair.spread_bps as u128)
+            .ok_or(StablecoinError::MathOverflow)?
+            .checked_div(10_000)
+            .ok_or(StablecoinError::MathOverflow)? as u64;
+        let amount_out = gross.checked_sub(spread).ok_or(StablecoinError::MathOverflow)?;
+        require!(amount_out > 0, StablecoinError::InvalidAmount);
+        require!(amount_out >= min_amount_out, StablecoinError::SlippageExceeded);
+        let rate = pair.rate;
+        let pair_key = pair.key();
+        
+        // Burn the source side
+        token_2022::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.source_mint.to_account_info(),
+                    from: ctx.accounts.source_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+        let mut source = ctx.accounts.source_state.load_mut()?;
+        source.total_supply = source.total_supply.checked_sub(amount_in)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        // Mint the destination side, spread included in the cap and quota
+        record_mint(&mut *ctx.accounts.destination_state.load_mut()?, gross)?;
+        let destination_key = ctx.accounts.destination_state.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"mint_authority", destination_key.as_ref(), &[ctx.bumps.destination_mint_authority]]];
+        let mints = [
+            (ctx.accounts.destination_token_account.to_account_info(), amount_out),
+            (ctx.accounts.treasury_token_account.to_account_info(), spread),
+        ];
+        for (to, amount) in mints {
+            if amount == 0 {
+                continue;
+            }
+            token_2022::mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::MintTo {
+                        mint: ctx.accounts.destination_mint.to_account_info(),
+                        to,
+                        authority: ctx.accounts.destination_mint_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.fees_pending = treasury.fees_pending.checked_add(spread)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(FxSwapExecuted {
+            user: ctx.accounts.user.key(),
+            pair: pair_key,
+            amount_in,
+            amount_out,
+            spread,
+            rate,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === SAVINGS: CONFIGURE POOL ===
+    // Creates the pool and its ATA on first call. The funding cap bounds all
+    // interest the pool can ever mint; the rate itself belongs to rate_admin.
+    pub fn configure_savings_pool(
+        ctx: Context<ConfigureSavingsPool>,
+        rate_admin: Pubkey,
+        funding_cap: u64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.savings_pool;
+        if pool.stablecoin == Pubkey::default() {
+            pool.stablecoin = ctx.accounts.stablecoin_state.key();
+            pool.token_account = ctx.accounts.savings_token_account.key();
+            pool.index = SAVINGS_INDEX_SCALE;
+            pool.last_accrued_at = now;
+            pool.bump = ctx.bumps.savings_pool;
+        }
+        pool.rate_admin = rate_admin;
+        pool.funding_cap = funding_cap;
+        
+        emit!(SavingsPoolConfigured {
+            authority: ctx.accounts.authority.key(),
+            pool: pool.key(),
+            rate_admin,
+            funding_cap,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === SAVINGS: SET RATE ===
+    // Interest up to now is settled at the old rate before the change
+    pub fn set_savings_rate(ctx: Context<SetSavingsRate>, rate_bps: u16) -> Result<()> {
+        require!(rate_bps <= MAX_SAVINGS_RATE_BPS, StablecoinError::InvalidSavingsRate);
+        
+        let accounts = ctx.accounts;
+        settle_savings(
+            &mut accounts.savings_pool,
+            &mut accounts.stablecoin_state,
+            accounts.mint.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            accounts.token_program.to_account_info(),
+        )?;
+        
+        let pool = &mut accounts.savings_pool;
+        let old_rate_bps = pool.rate_bps;
+        pool.rate_bps = rate_bps;
+        
+        emit!(SavingsRateUpdated {
+            rate_admin: accounts.rate_admin.key(),
+            old_rate_bps,
+            new_rate_bps: rate_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === SAVINGS: ACCRUE (permissionless crank) ===
+    pub fn accrue_savings_interest(ctx: Context<SavingsCrank>) -> Result<()> {
+        let accounts = ctx.accounts;
+        settle_savings(
+            &mut accounts.savings_pool,
+            &mut accounts.stablecoin_state,
+            accounts.mint.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            accounts.token_program.to_account_info(),
+        )
+    }
+    
+    // === SAVINGS: DEPOSIT ===
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn deposit_savings<'a>(
+        ctx: Context<'_, '_, 'a, 'a, DepositSavings<'a>>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        
+        let accounts = ctx.accounts;
+        settle_savings(
+            &mut accounts.savings_pool,
+            &mut accounts.stablecoin_state,
+            accounts.mint.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            accounts.token_program.to_account_info(),
+        )?;
+        
+        let index = accounts.savings_pool.index;
+        let shares = u64::try_from((amount as u128) * SAVINGS_INDEX_SCALE / index)
+            .map_err(|_| error!(StablecoinError::MathOverflow))?;
+        require!(shares > 0, StablecoinError::InvalidAmount);
+        
+        transfer_with_hook(
+            &accounts.token_program.to_account_info(),
+            accounts.owner_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.owner.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            accounts.mint.decimals,
+            &[],
+        )?;
+        
+        let pool = &mut accounts.savings_pool;
+        pool.total_shares = pool.total_shares.checked_add(shares)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let position = &mut accounts.savings_position;
+        position.pool = pool.key();
+        position.owner = accounts.owner.key();
+        position.shares = position.shares.checked_add(shares)
+            .ok_or(StablecoinError::MathOverflow)?;
+        position.bump = ctx.bumps.savings_position;
+        
+        emit!(SavingsDeposited {
+            owner: position.owner,
+            amount,
+            shares,
+            index,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === SAVINGS: WITHDRAW ===
+    // Redeems `shares` at the freshly accrued index. Not gated on pause.
+    // remaining_accounts carry transfer hook extras, if the mint has one.
+    pub fn withdraw_savings<'a>(
+        ctx: Context<'_, '_, 'a, 'a, WithdrawSavings<'a>>,
+        shares: u64,
+    ) -> Result<()> {
+        require!(shares > 0, StablecoinError::InvalidAmount);
+        require!(
+            ctx.accounts.savings_position.shares >= shares,
+            StablecoinError::InsufficientBalance
+        );
+        
+        let accounts = ctx.accounts;
+        settle_savings(
+            &mut accounts.savings_pool,
+            &mut accounts.stablecoin_state,
+            accounts.mint.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.mint_authority.to_account_info(),
+            ctx.bumps.mint_authority,
+            accounts.token_program.to_account_info(),
+        )?;
+        
+        let index = accounts.savings_pool.index;
+        let amount = u64::try_from((shares as u128) * index / SAVINGS_INDEX_SCALE)
+            .map_err(|_| error!(StablecoinError::MathOverflow))?;
+        
+        let stablecoin_key = accounts.stablecoin_state.key();
+        transfer_with_hook(
+            &accounts.token_program.to_account_info(),
+            accounts.savings_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.owner_token_account.to_account_info(),
+            accounts.savings_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            accounts.mint.decimals,
+            &[&[b"savings_authority", stablecoin_key.as_ref(), &[ctx.bumps.savings_authority]]],
+        )?;
+        
+        let pool = &mut accounts.savings_pool;
+        pool.total_shares = pool.total_shares.checked_sub(shares)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let position = &mut accounts.savings_position;
+        position.shares = position.shares.checked_sub(shares)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(SavingsWithdrawn {
+            owner: position.owner,
+            amount,
+            shares,
+            index,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === REWARDS: CREATE CAMPAIGN ===
+    // Funds the campaign vault from the authority's token account in one step
+    pub fn create_reward_campaign(
+        ctx: Context<CreateRewardCampaign>,
+        campaign_id: u64,
+        root: [u8; 32],
+        snapshot_slot: u64,
+        total_balance: u64,
+        reward_amount: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            total_balance > 0 && reward_amount > 0 && expires_at > now,
+            StablecoinError::InvalidRewardCampaign
+        );
+        
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.reward_token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.funding_account.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            reward_amount,
+            ctx.accounts.reward_mint.decimals,
+        )?;
+        
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.stablecoin = ctx.accounts.stablecoin_state.key();
+        campaign.campaign_id = campaign_id;
+        campaign.root = root;
+        campaign.snapshot_slot = snapshot_slot;
+        campaign.total_balance = total_balance;
+        campaign.reward_mint = ctx.accounts.reward_mint.key();
+        campaign.vault = ctx.accounts.vault.key();
+        campaign.reward_amount = reward_amount;
+        campaign.claimed_amount = 0;
+        campaign.claim_count = 0;
+        campaign.expires_at = expires_at;
+        campaign.swept = false;
+        campaign.bump = ctx.bumps.campaign;
+        
+        emit!(RewardCampaignCreated {
+            authority: ctx.accounts.authority.key(),
+            campaign: campaign.key(),
+            campaign_id,
+            root,
+            snapshot_slot,
+            reward_mint: campaign.reward_mint,
+            reward_amount,
+            expires_at,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === REWARDS: CLAIM ===
+    // Pays reward_amount * balance / total_balance. The RewardClaim PDA is
+    // created here, so a second claim for the same owner fails.
+    pub fn claim_reward(
+        ctx: Context<ClaimReward>,
+        balance: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &ctx.accounts.campaign;
+        require!(
+            !campaign.swept && now < campaign.expires_at,
+            StablecoinError::RewardCampaignClosed
+        );
+        require!(
+            verify_reward_proof(&campaign.root, &ctx.accounts.owner.key(), balance, &proof),
+            StablecoinError::InvalidRewardProof
+        );
+        
+        let amount = u64::try_from(
+            (campaign.reward_amount as u128)
+                .checked_mul(balance as u128)
+                .ok_or(StablecoinError::MathOverflow)?
+                / campaign.total_balance as u128,
+        ).map_err(|_| error!(StablecoinError::MathOverflow))?;
+        let remaining = campaign.reward_amount.checked_sub(campaign.claimed_amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(amount > 0 && amount <= remaining, StablecoinError::InvalidRewardProof);
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let id_bytes = campaign.campaign_id.to_le_bytes();
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.reward_token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.campaign.to_account_info(),
+                },
+                &[&[b"reward_campaign", stablecoin_key.as_ref(), &id_bytes, &[campaign.bump]]],
+            ),
+            amount,
+            ctx.accounts.reward_mint.decimals,
+        )?;
+        
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.claimed_amount = campaign.claimed_amount.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        campaign.claim_count = campaign.claim_count.checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let claim = &mut ctx.accounts.claim;
+        claim.campaign = campaign.key();
+        claim.owner = ctx.accounts.owner.key();
+        claim.amount = amount;
+        claim.claimed_at = now;
+        claim.bump = ctx.bumps.claim;
+        
+        emit!(RewardClaimed {
+            campaign: campaign.key(),
+            owner: claim.owner,
+            balance,
+            amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === REWARDS: SWEEP ===
+    // Returns whatever is left in the vault once the campaign has expired
+    pub fn sweep_reward_campaign(ctx: Context<SweepRewardCampaign>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let campaign = &ctx.accounts.campaign;
+        require!(!campaign.swept, StablecoinError::RewardCampaignClosed);
+        require!(now >= campaign.expires_at, StablecoinError::RewardCampaignActive);
+        
+        let amount = ctx.accounts.vault.amount;
+        if amount > 0 {
+            let stablecoin_key = ctx.accounts.stablecoin_state.key();
+            let id_bytes = campaign.campaign_id.to_le_bytes();
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.reward_token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.vault.to_account_info(),
+                        mint: ctx.accounts.reward_mint.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                        authority: ctx.accounts.campaign.to_account_info(),
+                    },
+                    &[&[b"reward_campaign", stablecoin_key.as_ref(), &id_bytes, &[campaign.bump]]],
+                ),
+                amount,
+                ctx.accounts.reward_mint.decimals,
+            )?;
+        }
+        ctx.accounts.campaign.swept = true;
+        
+        emit!(RewardCampaignSwept {
+            authority: ctx.accounts.authority.key(),
+            campaign: ctx.accounts.campaign.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === STREAMS: CREATE ===
+    // Escrows `amount` from the payer. Hook extra accounts for the payer ->
+    // escrow transfer are passed as remaining_accounts.
+    pub fn create_stream<'a>(
+        ctx: Context<'_, '_, 'a, 'a, CreateStream<'a>>,
+        stream_id: u64,
+        recipient: Pubkey,
+        amount: u64,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_state.load()?.is_paused(), StablecoinError::ContractPaused);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            end_time > start_time && end_time > now && recipient != Pubkey::default(),
+            StablecoinError::InvalidStream
+        );
+        
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.payer_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        
+        let stream = &mut ctx.accounts.stream;
+        stream.stablecoin = ctx.accounts.stablecoin_state.key();
+        stream.payer = ctx.accounts.payer.key();
+        stream.recipient = recipient;
+        stream.escrow = ctx.accounts.escrow.key();
+        stream.stream_id = stream_id;
+        stream.amount = amount;
+        stream.withdrawn = 0;
+        stream.refunded = 0;
+        stream.start_time = start_time;
+        stream.end_time = end_time;
+        stream.canceled_at = 0;
+        stream.bump = ctx.bumps.stream;
+        
+        emit!(StreamCreated {
+            stream: stream.key(),
+            payer: stream.payer,
+            recipient,
+            amount,
+            start_time,
+            end_time,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === STREAMS: WITHDRAW ===
+    // Pays the recipient everything vested and not yet withdrawn
+    pub fn withdraw_from_stream<'a>(
+        ctx: Context<'_, '_, 'a, 'a, WithdrawFromStream<'a>>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let stream = &ctx.accounts.stream;
+        let amount = stream_vested(stream, now)?
+            .checked_sub(stream.withdrawn)
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(amount > 0, StablecoinError::NothingVested);
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let payer_key = stream.payer;
+        let id_bytes = stream.stream_id.to_le_bytes();
+        transfer_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.recipient_token_account.to_account_info(),
+            ctx.accounts.stream.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[&[b"stream", stablecoin_key.as_ref(), payer_key.as_ref(), &id_bytes, &[stream.bump]]],
+        )?;
+        
+        let stream = &mut ctx.accounts.stream;
+        stream.withdrawn = stream.withdrawn.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        emit!(StreamWithdrawn {
+            stream: stream.key(),
+            recipient: stream.recipient,
+            amount,
+            total_withdrawn: stream.withdrawn,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === STREAMS: CANCEL ===
+    // Either party may cancel: the recipient is paid what has vested and the
+    // payer is refunded the rest. remaining_accounts carry the hook extra
+    // accounts for both transfers.
+    pub fn cancel_stream<'a>(
+        ctx: Context<'_, '_, 'a, 'a, CancelStream<'a>>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let stream = &ctx.accounts.stream;
+        let signer = ctx.accounts.signer.key();
+        require!(
+            signer == stream.payer || signer == stream.recipient,
+            StablecoinError::Unauthorized
+        );
+        require!(stream.canceled_at == 0, StablecoinError::StreamCanceled);
+        
+        let vested = stream_vested(stream, now)?;
+        let to_recipient = vested.checked_sub(stream.withdrawn)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let to_payer = stream.amount.checked_sub(vested)
+            .ok_or(StablecoinError::MathOverflow)?;
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let payer_key = stream.payer;
+        let id_bytes = stream.stream_id.to_le_bytes();
+        let bump = [stream.bump];
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"stream", stablecoin_key.as_ref(), payer_key.as_ref(), &id_bytes, &bump]];
+        let payouts = [
+            (ctx.accounts.recipient_token_account.to_account_info(), to_recipient),
+            (ctx.accounts.payer_token_account.to_account_info(), to_payer),
+        ];
+        for (to, amount) in payouts {
+            if amount == 0 {
+                continue;
             }
-            
-            let epoch_new_total = ctx.accounts.stablecoin_state.current_epoch_minted
-                .checked_add(total_amount)
-                .ok_or(StablecoinError::MathOverflow)?;
-            require!(
-                epoch_new_total <= epoch_quota,
-                StablecoinError::EpochQuotaExceeded
+            transfer_with_hook(
+                &ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.escrow.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                to,
+                ctx.accounts.stream.to_account_info(),
+                ctx.remaining_accounts,
+                amount,
+                ctx.accounts.mint.decimals,
+                signer_seeds,
+            )?;
+        }
+        
+        let stream = &mut ctx.accounts.stream;
+        stream.canceled_at = now.max(stream.start_time).min(stream.end_time);
+        stream.withdrawn = vested;
+        stream.refunded = to_payer;
+        
+        emit!(StreamCanceled {
+            stream: stream.key(),
+            canceled_by: signer,
+            paid_to_recipient: to_recipient,
+            refunded_to_payer: to_payer,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === VOUCHERS: SET SIGNER ===
+    // Registers or updates an offline voucher key. Re-registering keeps `minted`.
+    pub fn set_voucher_signer(
+        ctx: Context<SetVoucherSigner>,
+        signer: Pubkey,
+        quota: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let voucher_signer = &mut ctx.accounts.voucher_signer;
+        voucher_signer.stablecoin = ctx.accounts.stablecoin_state.key();
+        voucher_signer.signer = signer;
+        voucher_signer.quota = quota;
+        voucher_signer.enabled = enabled;
+        voucher_signer.bump = ctx.bumps.voucher_signer;
+        
+        emit!(VoucherSignerUpdated {
+            authority: ctx.accounts.authority.key(),
+            signer,
+            quota,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === VOUCHERS: MINT ===
+    // Must directly follow an ed25519 program instruction verifying the voucher
+    // signer's signature over `voucher_message`. Anyone may submit a voucher.
+    pub fn mint_with_voucher(
+        ctx: Context<MintWithVoucher>,
+        amount: u64,
+        expiry: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        require!(!stablecoin.is_paused(), StablecoinError::ContractPaused);
+        require!(!stablecoin.is_sunset(), StablecoinError::StablecoinSunset);
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(ctx.accounts.voucher_signer.enabled, StablecoinError::VoucherSignerDisabled);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < expiry, StablecoinError::VoucherExpired);
+        
+        let recipient = ctx.accounts.recipient_account.key();
+        let message = voucher_message(&ctx.accounts.stablecoin_state.key(), &recipient, amount, expiry, nonce);
+        verify_ed25519_instruction(
+            &ctx.accounts.instructions,
+            &ctx.accounts.voucher_signer.signer,
+            &message,
+        )?;
+        if !check_circuit_breaker(
+            &ctx.accounts.stablecoin_state,
+            ctx.accounts.circuit_breaker.as_mut(),
+            ctx.accounts.voucher_signer.signer,
+            amount,
+            now,
+        )? {
+            return Ok(());
+        }
+        
+        let mut bitmap = load_nonce_bitmap(
+            &ctx.accounts.nonce_bitmap,
+            &ctx.accounts.voucher_signer.signer,
+            nonce,
+            ctx.bumps.nonce_bitmap,
+        )?;
+        require!(bitmap.test_and_set(nonce), StablecoinError::VoucherNonceUsed);
+        drop(bitmap);
+        
+        let voucher_signer = &mut ctx.accounts.voucher_signer;
+        let minted = voucher_signer.minted.checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(minted <= voucher_signer.quota, StablecoinError::QuotaExceeded);
+        voucher_signer.minted = minted;
+        
+        let epoch_id = record_mint(&mut *ctx.accounts.stablecoin_state.load_mut()?, amount)?;
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.recipient_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &[&[b"mint_authority", stablecoin_key.as_ref(), &[ctx.bumps.mint_authority]]],
+            ),
+            amount,
+        )?;
+        
+        emit!(VoucherMinted {
+            signer: ctx.accounts.voucher_signer.signer,
+            recipient,
+            amount,
+            nonce,
+            submitted_by: ctx.accounts.payer.key(),
+            epoch_id,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === ANOMALIES: SET MONITOR ===
+    pub fn set_monitor(
+        ctx: Context<SetMonitor>,
+        monitor: Pubkey,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let entry = &mut ctx.accounts.monitor_account;
+        entry.stablecoin = ctx.accounts.stablecoin_state.key();
+        entry.monitor = monitor;
+        entry.enabled = enabled;
+        entry.bump = ctx.bumps.monitor_account;
+        
+        emit!(MonitorUpdated {
+            authority: ctx.accounts.authority.key(),
+            monitor,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === ANOMALIES: RAISE ===
+    // Raising a flag again re-opens it even if it was acknowledged
+    pub fn raise_anomaly(ctx: Context<RaiseAnomaly>, flag: u8, value: u64) -> Result<()> {
+        require!(ctx.accounts.monitor.enabled, StablecoinError::Unauthorized);
+        require!(
+            flag.count_ones() == 1 && flag & !ANOMALY_ALL == 0,
+            StablecoinError::InvalidAnomalyFlag
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let anomaly = &mut ctx.accounts.anomaly_state;
+        anomaly.stablecoin = ctx.accounts.stablecoin_state.key();
+        anomaly.flags |= flag;
+        anomaly.acknowledged &= !flag;
+        anomaly.last_flag = flag;
+        anomaly.last_value = value;
+        anomaly.last_raised_by = ctx.accounts.signer.key();
+        anomaly.last_raised_at = now;
+        anomaly.raise_count = anomaly.raise_count.saturating_add(1);
+        anomaly.bump = ctx.bumps.anomaly_state;
+        
+        emit!(AnomalyRaised {
+            monitor: ctx.accounts.signer.key(),
+            flag,
+            value,
+            flags: anomaly.flags,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === ANOMALIES: ACKNOWLEDGE ===
+    // Marks raised flags as seen by an operator (pauser or master) without
+    // clearing them
+    pub fn acknowledge_anomaly(ctx: Context<ResolveAnomaly>, flag: u8) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_PAUSER | ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let anomaly = &mut ctx.accounts.anomaly_state;
+        require!(
+            flag != 0 && flag & !anomaly.flags == 0,
+            StablecoinError::InvalidAnomalyFlag
+        );
+        anomaly.acknowledged |= flag;
+        
+        emit!(AnomalyAcknowledged {
+            authority: ctx.accounts.authority.key(),
+            flag,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === ANOMALIES: CLEAR ===
+    pub fn clear_anomaly(ctx: Context<ResolveAnomaly>, flag: u8) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let anomaly = &mut ctx.accounts.anomaly_state;
+        require!(
+            flag != 0 && flag & !anomaly.flags == 0,
+            StablecoinError::InvalidAnomalyFlag
+        );
+        anomaly.flags &= !flag;
+        anomaly.acknowledged &= !flag;
+        
+        emit!(AnomalyCleared {
+            authority: ctx.accounts.authority.key(),
+            flag,
+            flags: anomaly.flags,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CIRCUIT BREAKER: CONFIGURE ===
+    // Arms the breaker once any limit is set; mints must then pass the
+    // CircuitBreaker account. Zero limits disarm it.
+    pub fn configure_circuit_breaker(
+        ctx: Context<ConfigureCircuitBreaker>,
+        guardian: Pubkey,
+        max_mint_per_hour: u64,
+        max_single_mint_bps: u16,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(max_single_mint_bps <= 10_000, StablecoinError::InvalidAmount);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let breaker = &mut ctx.accounts.circuit_breaker;
+        breaker.stablecoin = ctx.accounts.stablecoin_state.key();
+        breaker.guardian = guardian;
+        breaker.max_mint_per_hour = max_mint_per_hour;
+        breaker.max_single_mint_bps = max_single_mint_bps;
+        if breaker.hour_start == 0 {
+            breaker.hour_start = now;
+        }
+        breaker.bump = ctx.bumps.circuit_breaker;
+        ctx.accounts.stablecoin_state.load_mut()?.circuit_breaker_armed =
+            u8::from(max_mint_per_hour > 0 || max_single_mint_bps > 0);
+        
+        emit!(CircuitBreakerConfigured {
+            authority: ctx.accounts.authority.key(),
+            guardian,
+            max_mint_per_hour,
+            max_single_mint_bps,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === CIRCUIT BREAKER: RESET ===
+    // Guardian or master re-enables minting and starts a fresh hourly window
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let is_master = match &ctx.accounts.authority_role {
+            Some(role) => has_role(role, ROLE_MASTER)?,
+            None => false,
+        };
+        require!(
+            is_master || authority == ctx.accounts.circuit_breaker.guardian,
+            StablecoinError::Unauthorized
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.stablecoin_state.load_mut()?.circuit_tripped = 0;
+        let breaker = &mut ctx.accounts.circuit_breaker;
+        breaker.hour_minted = 0;
+        breaker.hour_start = now;
+        
+        emit!(CircuitBreakerReset {
+            authority,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === UPGRADE AUTHORITY: RECORD ===
+    // Records who may upgrade this program (Pubkey::default() = immutable),
+    // checked against the live ProgramData so a wrong key can't be recorded.
+    // With `require_multisig` the authority must be the multisig PDA.
+    pub fn record_upgrade_authority(
+        ctx: Context<RecordUpgradeAuthority>,
+        upgrade_authority: Pubkey,
+        require_multisig: bool,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        if require_multisig {
+            let (multisig, _) = Pubkey::find_program_address(
+                &[b"multisig", stablecoin_key.as_ref()],
+                &crate::ID,
             );
+            require_keys_eq!(upgrade_authority, multisig, StablecoinError::UpgradeAuthorityMismatch);
         }
+        check_upgrade_authority(&ctx.accounts.program_data, &upgrade_authority)?;
         
-        let mint_authority_bump = ctx.bumps.mint_authority;
-        let signer_seeds: &[&[&[u8]]] = &[&[
-            b"mint_authority",
-            stablecoin_key.as_ref(),
-            &[mint_authority_bump],
-        ]];
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin.upgrade_authority = upgrade_authority;
+        stablecoin.upgrade_policy = if require_multisig {
+            UPGRADE_POLICY_MULTISIG
+        } else {
+            UPGRADE_POLICY_RECORDED
+        };
         
-        // CPI mint_to for each recipient token account (passed as remaining_accounts)
-        for (i, amount) in amounts.iter().enumerate() {
-            let recipient_account = &ctx.remaining_accounts[i];
-            token_2022::mint_to(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    token_2022::MintTo {
-                        mint: ctx.accounts.mint.to_account_info(),
-                        to: recipient_account.to_account_info(),
-                        authority: ctx.accounts.mint_authority.to_account_info(),
+        emit!(UpgradeAuthorityRecorded {
+            authority: ctx.accounts.authority.key(),
+            upgrade_authority,
+            policy: stablecoin.upgrade_policy,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === UPGRADE AUTHORITY: VERIFY ===
+    // Permissionless proof that the live upgrade authority still matches the
+    // recorded one; fails otherwise. Simulate it to check off-chain.
+    pub fn verify_upgrade_authority(ctx: Context<VerifyUpgradeAuthority>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        require!(
+            stablecoin.upgrade_policy != UPGRADE_POLICY_NONE,
+            StablecoinError::UpgradeAuthorityMismatch
+        );
+        check_upgrade_authority(&ctx.accounts.program_data, &stablecoin.upgrade_authority)?;
+        
+        emit!(UpgradeAuthorityVerified {
+            upgrade_authority: stablecoin.upgrade_authority,
+            policy: stablecoin.upgrade_policy,
+            slot: ctx.accounts.program_data.slot,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === LOCK PARAMETER ===
+    // Locks are one-way: there is deliberately no unlock instruction.
+    pub fn lock_parameter(
+        ctx: Context<UpdateFeatures>,
+        flag: u8,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            flag != 0 && flag & !LOCK_ALL == 0,
+            StablecoinError::InvalidLockFlag
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        // A cap/quota lock only means something if there is a limit to lock in
+        if flag & LOCK_SUPPLY_CAP != 0 {
+            require!(stablecoin.supply_cap > 0, StablecoinError::InvalidLockFlag);
+        }
+        if flag & LOCK_EPOCH_QUOTA != 0 {
+            require!(stablecoin.epoch_quota > 0, StablecoinError::InvalidLockFlag);
+        }
+        stablecoin.locks |= flag;
+        
+        emit!(ParameterLockApplied {
+            authority: ctx.accounts.authority.key(),
+            flag,
+            locks: stablecoin.locks,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === RENOUNCE FREEZE AUTHORITY ===
+    // Sets the mint's freeze authority to None, making it provable on-chain
+    // that no account can ever be frozen again.
+    pub fn renounce_freeze_authority(ctx: Context<RenounceFreezeAuthority>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        require!(
+            ctx.accounts.stablecoin_state.load()?.renounced & RENOUNCED_FREEZE == 0,
+            StablecoinError::AuthorityRenounced
+        );
+        
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::SetAuthority {
+                    current_authority: ctx.accounts.freeze_authority.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                &[&[b"freeze_authority", stablecoin_key.as_ref(), &[ctx.bumps.freeze_authority]]],
+            ),
+            AuthorityType::FreezeAccount,
+            None,
+        )?;
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin.renounced |= RENOUNCED_FREEZE;
+        
+        emit!(AuthorityRenounced {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            renounced: RENOUNCED_FREEZE,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === RENOUNCE PERMANENT DELEGATE ===
+    // Signed by the permanent delegate PDA; afterwards seizure is impossible.
+    pub fn renounce_permanent_delegate(ctx: Context<RenouncePermanentDelegate>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(
+            ctx.accounts.stablecoin_state.load()?.renounced & RENOUNCED_PERMANENT_DELEGATE == 0,
+            StablecoinError::AuthorityRenounced
+        );
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::SetAuthority {
+                    current_authority: ctx.accounts.permanent_delegate.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                &[&[b"permanent_delegate", stablecoin_key.as_ref(), &[ctx.bumps.permanent_delegate]]],
+            ),
+            AuthorityType::PermanentDelegate,
+            None,
+        )?;
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        stablecoin.renounced |= RENOUNCED_PERMANENT_DELEGATE;
+        
+        emit!(AuthorityRenounced {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            renounced: RENOUNCED_PERMANENT_DELEGATE,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === SUNSET ===
+    // Irreversibly disables minting. Burns/redemptions keep working so supply can
+    // wind down to zero, after which close_mint and the close_* instructions apply.
+    pub fn begin_sunset(
+        ctx: Context<UpdateFeatures>,
+        redemption_only: bool,
+    ) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+        require!(!stablecoin.is_sunset(), StablecoinError::StablecoinSunset);
+        
+        let now = Clock::get()?.unix_timestamp;
+        stablecoin.is_sunset = 1;
+        stablecoin.redemption_only = u8::from(redemption_only);
+        stablecoin.sunset_at = now;
+        
+        emit!(SunsetStarted {
+            authority: ctx.accounts.authority.key(),
+            redemption_only,
+            total_supply: stablecoin.total_supply,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: MINT ===
+    // Requires the mint's MintCloseAuthority to be the close_authority PDA
+    pub fn close_mint(ctx: Context<CloseMint>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(stablecoin.is_sunset(), StablecoinError::NotSunset);
+        require!(
+            stablecoin.total_supply == 0 && ctx.accounts.mint.supply == 0,
+            StablecoinError::SupplyNotZero
+        );
+        
+        token_2022::close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::CloseAccount {
+                    account: ctx.accounts.mint.to_account_info(),
+                    destination: ctx.accounts.recipient.to_account_info(),
+                    authority: ctx.accounts.close_authority.to_account_info(),
+                },
+                &[&[b"close_authority", ctx.accounts.stablecoin_state.key().as_ref(), &[ctx.bumps.close_authority]]],
+            ),
+        )?;
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.mint.key(),
+            recipient: ctx.accounts.recipient.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: ROLE ACCOUNT ===
+    pub fn close_role(ctx: Context<CloseRole>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        if ctx.accounts.target_role.roles & ROLE_MASTER != 0 {
+            let mut stablecoin = ctx.accounts.stablecoin_state.load_mut()?;
+            require!(stablecoin.master_count > 1, StablecoinError::LastMasterRole);
+            stablecoin.master_count -= 1;
+        }
+        
+        update_holder_index(ctx.accounts.role_index.as_mut(), ctx.accounts.target.key(), false)?;
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.target_role.key(),
+            recipient: ctx.accounts.recipient.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: MINTER INFO ===
+    pub fn close_minter_info(ctx: Context<CloseMinterInfo>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        update_holder_index(ctx.accounts.minter_index.as_mut(), ctx.accounts.minter.key(), false)?;
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.minter_info.key(),
+            recipient: ctx.accounts.recipient.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: MULTISIG PROPOSAL ===
+    // Proposer reclaims rent once the proposal is executed or expired
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(
+            proposal.executed || Clock::get()?.unix_timestamp >= proposal.expires_at,
+            StablecoinError::ProposalStillPending
+        );
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.proposer.key(),
+            account: proposal.key(),
+            recipient: ctx.accounts.proposer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: MULTISIG CONFIG ===
+    pub fn close_multisig(ctx: Context<CloseMultisig>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.multisig_config.key(),
+            recipient: ctx.accounts.recipient.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === CLOSE: STABLECOIN STATE ===
+    // Final step of a sunset: closes the state and the caller's master role.
+    // Every other role and minter account still open is passed in
+    // remaining_accounts and closed along with it; all master roles must be.
+    pub fn close_stablecoin<'a>(ctx: Context<'_, '_, 'a, 'a, CloseStablecoin<'a>>) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        require!(ctx.accounts.stablecoin_state.load()?.is_sunset(), StablecoinError::NotSunset);
+        require!(
+            ctx.accounts.stablecoin_state.load()?.total_supply == 0 && ctx.accounts.mint.supply == 0,
+            StablecoinError::SupplyNotZero
+        );
+        
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let recipient = ctx.accounts.recipient.to_account_info();
+        let now = Clock::get()?.unix_timestamp;
+        let mut masters_closed: u16 = 0;
+        for info in ctx.remaining_accounts.iter() {
+            require_keys_neq!(info.key(), ctx.accounts.authority_role.key(), StablecoinError::InvalidAuthority);
+            if let Ok(role) = Account::<RoleAccount>::try_from(info) {
+                require_keys_eq!(role.stablecoin, stablecoin_key, StablecoinError::InvalidAuthority);
+                if role.roles & ROLE_MASTER != 0 {
+                    masters_closed += 1;
+                }
+                role.close(recipient.clone())?;
+            } else {
+                let minter = Account::<MinterInfo>::try_from(info)?;
+                require_keys_eq!(minter.stablecoin, stablecoin_key, StablecoinError::InvalidAuthority);
+                minter.close(recipient.clone())?;
+            }
+            emit!(StateAccountClosed {
+                authority: ctx.accounts.authority.key(),
+                account: info.key(),
+                recipient: recipient.key(),
+                timestamp: now,
+            });
+        }
+        require!(
+            ctx.accounts.stablecoin_state.load()?.master_count == masters_closed + 1,
+            StablecoinError::RolesOutstanding
+        );
+        
+        emit!(StateAccountClosed {
+            authority: ctx.accounts.authority.key(),
+            account: ctx.accounts.stablecoin_state.key(),
+            recipient: ctx.accounts.recipient.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // === MIGRATE STATE LAYOUT ===
+    // Permissionless; grows a Borsh-layout state account to the zero-copy
+    // layout. The payer tops up rent for the extra bytes.
+    pub fn migrate_state_layout(ctx: Context<MigrateStateLayout>) -> Result<()> {
+        let state_info = ctx.accounts.stablecoin_state.to_account_info();
+        let v1 = load_stablecoin_state_v1(&state_info)?
+            .ok_or(StablecoinError::NotLegacyState)?;
+        require_keys_eq!(v1.mint, ctx.accounts.mint.key(), StablecoinError::InvalidMint);
+        
+        let shortfall = Rent::get()?
+            .minimum_balance(StablecoinState::SPACE)
+            .saturating_sub(state_info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: state_info.clone(),
                     },
-                    signer_seeds,
                 ),
-                *amount,
+                shortfall,
             )?;
         }
         
-        // Update state
-        let stablecoin_mut = &mut ctx.accounts.stablecoin_state;
-        stablecoin_mut.total_supply = stablecoin_mut.total_supply.checked_add(total_amount)
-            .ok_or(StablecoinError::MathOverflow)?;
-        
-        stablecoin_mut.current_epoch_minted = stablecoin_mut.current_epoch_minted
-            .checked_add(total_amount)
-            .ok_or(StablecoinError::MathOverflow)?;
+        state_info.realloc(StablecoinState::SPACE, true)?;
+        let state = StablecoinState::from(v1);
+        let mut data = state_info.try_borrow_mut_data()?;
+        data[8..].copy_from_slice(bytemuck::bytes_of(&state));
+        Ok(())
+    }
+    
+    // === ASSERT INVARIANTS ===
+    // Permissionless; monitoring bots simulate this every slot and alert on failure.
+    pub fn assert_invariants(ctx: Context<AssertInvariants>) -> Result<()> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
         
-        // Update minter quota if applicable
-        if role_bits & ROLE_MASTER == 0 {
-            let minter_info = &mut ctx.accounts.minter_info;
-            minter_info.minted = minter_info.minted.checked_add(total_amount)
-                .ok_or(StablecoinError::MathOverflow)?;
+        require!(
+            stablecoin.total_supply == ctx.accounts.mint.supply,
+            StablecoinError::SupplyMismatch
+        );
+        if stablecoin.epoch_quota > 0 {
+            require!(
+                stablecoin.current_epoch_minted <= stablecoin.epoch_quota,
+                StablecoinError::EpochMintedExceedsQuota
+            );
+        }
+        require!(stablecoin.master_count >= 1, StablecoinError::NoMasterAuthority);
+        if stablecoin.supply_cap > 0 {
+            require!(
+                stablecoin.total_supply <= stablecoin.supply_cap,
+                StablecoinError::SupplyAboveCap
+            );
         }
         
-        emit!(BatchMinted {
-            minter: ctx.accounts.minter.key(),
-            recipients: n as u16,
-            total_amount,
-            timestamp: Clock::get()?.unix_timestamp,
+        Ok(())
+    }
+    
+    // === RESERVES: SET ATTESTOR ===
+    pub fn set_reserve_attestor(ctx: Context<ManageReserveAttestation>, attestor: Pubkey) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        attestation.stablecoin = ctx.accounts.stablecoin_state.key();
+        attestation.attestor = attestor;
+        attestation.bump = ctx.bumps.reserve_attestation;
+        
+        Ok(())
+    }
+    
+    // === RESERVES: SET AUDITOR ===
+    // Registers the audit firm's secp256k1 address for submit_signed_attestation
+    pub fn set_reserve_auditor(ctx: Context<ManageReserveAttestation>, auditor: [u8; 20]) -> Result<()> {
+        require!(
+            has_role(&ctx.accounts.authority_role, ROLE_MASTER)?,
+            StablecoinError::Unauthorized
+        );
+        
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        attestation.stablecoin = ctx.accounts.stablecoin_state.key();
+        attestation.auditor = auditor;
+        attestation.bump = ctx.bumps.reserve_attestation;
+        
+        Ok(())
+    }
+    
+    // === RESERVES: SUBMIT SIGNED ATTESTATION ===
+    // Anyone may submit an auditor's report. Must directly follow a secp256k1
+    // program instruction in which the registered auditor signed
+    // reserve_attestation_message. Periods must increase, so an old report
+    // can't be replayed over a newer one.
+    pub fn submit_signed_attestation(
+        ctx: Context<SubmitSignedAttestation>,
+        reserve_amount: u64,
+        period: u64,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        let stablecoin_key = ctx.accounts.stablecoin_state.key();
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        require!(
+            attestation.auditor != [0u8; 20] && period > attestation.audited_period,
+            StablecoinError::StaleAttestation
+        );
+        
+        let eth_address = verify_secp256k1_instruction(
+            &ctx.accounts.instructions,
+            &reserve_attestation_message(&stablecoin_key, reserve_amount, period, &report_hash),
+        )?;
+        require!(eth_address == attestation.auditor, StablecoinError::InvalidSecpSignature);
+        
+        let now = Clock::get()?.unix_timestamp;
+        attestation.audited_reserves = reserve_amount;
+        attestation.audited_period = period;
+        attestation.audited_report_hash = report_hash;
+        attestation.audited_at = now;
+        
+        emit!(SignedAttestationSubmitted {
+            auditor: eth_address,
+            reserve_amount,
+            period,
+            report_hash,
+            coverage_bps: coverage_bps(reserve_amount, ctx.accounts.stablecoin_state.load()?.total_supply),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // === RESERVES: ATTEST ===
+    // Replaces the reserve buckets with a new report and snapshots the supply
+    // it covers
+    pub fn attest_reserves(
+        ctx: Context<AttestReserves>,
+        buckets: Vec<ReserveBucket>,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            buckets.len() <= MAX_RESERVE_BUCKETS && buckets.iter().all(|b| b.attested_at <= now),
+            StablecoinError::InvalidReserveAttestation
+        );
+        
+        let attestation = &mut ctx.accounts.reserve_attestation;
+        attestation.buckets = buckets;
+        attestation.report_hash = report_hash;
+        attestation.attested_supply = ctx.accounts.stablecoin_state.load()?.total_supply;
+        attestation.updated_at = now;
+        let total_reserves = attestation.total_reserves()?;
+        
+        emit!(ReservesAttested {
+            attestor: attestation.attestor,
+            buckets: attestation.buckets.clone(),
+            total_reserves,
+            attested_supply: attestation.attested_supply,
+            coverage_bps: coverage_bps(total_reserves, attestation.attested_supply),
+            report_hash,
+            timestamp: now,
         });
         
         Ok(())
     }
     
-    // === MULTISIG: INITIALIZE CONFIG ===
-    pub fn initialize_multisig(
-        ctx: Context<InitializeMultisig>,
-        threshold: u8,
-        signers: Vec<Pubkey>,
-    ) -> Result<()> {
-        require!(
-            ctx.accounts.authority_role.roles & ROLE_MASTER != 0,
-            StablecoinError::Unauthorized
-        );
-        require!(threshold > 0 && threshold <= signers.len() as u8, StablecoinError::InvalidAmount);
-        require!(signers.len() <= 10, StablecoinError::InvalidAmount);
-        
-        let config = &mut ctx.accounts.multisig_config;
-        config.stablecoin = ctx.accounts.stablecoin_state.key();
-        config.threshold = threshold;
-        config.signers = signers;
-        config.bump = ctx.bumps.multisig_config;
-        
-        Ok(())
-    }
+    // === VIEW: SUPPLY INFO ===
+    // Read-only getters return Borsh-encoded snapshots via return data so that
+    // integrators can CPI or simulate without depending on account layouts.
+    pub fn get_supply_info(ctx: Context<GetSupplyInfo>) -> Result<SupplyInfo> {
+        let stablecoin = *ctx.accounts.stablecoin_state.load()?;
+        
+        Ok(SupplyInfo {
+            mint: stablecoin.mint,
+            decimals: stablecoin.decimals,
+            total_supply: stablecoin.total_supply,
+            supply_cap: stablecoin.supply_cap,
+            epoch_quota: stablecoin.epoch_quota,
+            current_epoch_minted: stablecoin.current_epoch_minted,
+            current_epoch_start: stablecoin.current_epoch_start,
+            is_paused: stablecoin.is_paused(),
+            features: stablecoin.features,
+            epoch_mode: stablecoin.epoch_mode(),
+        })
+    }
+    
+    // === VIEW: ROLE ===
+    pub fn get_role(ctx: Context<GetRole>) -> Result<RoleInfo> {
+        let role_account = &ctx.accounts.role_account;
+        
+        Ok(RoleInfo {
+            owner: role_account.owner,
+            stablecoin: role_account.stablecoin,
+            roles: role_account.roles,
+        })
+    }
+    
+    // === VIEW: MINTER QUOTA ===
+    pub fn get_minter_quota(ctx: Context<GetMinterQuota>) -> Result<MinterQuotaInfo> {
+        let minter_info = &ctx.accounts.minter_info;
+        
+        Ok(MinterQuotaInfo {
+            minter: minter_info.minter,
+            stablecoin: minter_info.stablecoin,
+            quota: minter_info.quota,
+            minted: minter_info.minted,
+            remaining: minter_info.quota.saturating_sub(minter_info.minted),
+        })
+    }
+
+    // === VIEW: MINTER STATS ===
+    pub fn get_minter_stats(ctx: Context<GetMinterStats>) -> Result<MinterStatsInfo> {
+        let stats = ctx.accounts.minter_stats.load()?;
+        let today = Clock::get()?.unix_timestamp.div_euclid(86400);
+        
+        let mut days: Vec<DayStats> = stats.days.iter()
+            .filter(|d| d.mint_count > 0 && today - d.day < MINTER_STATS_DAYS as i64)
+            .copied()
+            .collect();
+        days.sort_by_key(|d| std::cmp::Reverse(d.day));
+        
+        Ok(MinterStatsInfo {
+            minter: stats.minter,
+            stablecoin: stats.stablecoin,
+            total_mints: stats.total_mints,
+            total_volume: stats.total_volume,
+            today: days.iter().find(|d| d.day == today).copied()
+                .unwrap_or(DayStats { day: today, ..DayStats::default() }),
+            days,
+        })
+    }
+    
+    // === VIEW: RESERVE COVERAGE ===
+    pub fn get_reserve_info(ctx: Context<GetReserveInfo>) -> Result<ReserveInfo> {
+        let attestation = &ctx.accounts.reserve_attestation;
+        let total_reserves = attestation.total_reserves()?;
+        let total_supply = ctx.accounts.stablecoin_state.load()?.total_supply;
+        
+        Ok(ReserveInfo {
+            stablecoin: attestation.stablecoin,
+            buckets: attestation.buckets.clone(),
+            total_reserves,
+            total_supply,
+            coverage_bps: coverage_bps(total_reserves, total_supply),
+            attested_supply: attestation.attested_supply,
+            attested_coverage_bps: coverage_bps(total_reserves, attestation.attested_supply),
+            report_hash: attestation.report_hash,
+            updated_at: attestation.updated_at,
+            audited_reserves: attestation.audited_reserves,
+            audited_coverage_bps: coverage_bps(attestation.audited_reserves, total_supply),
+            audited_period: attestation.audited_period,
+            audited_report_hash: attestation.audited_report_hash,
+            audited_at: attestation.audited_at,
+        })
+    }
+}
+
+// === ACCOUNT STRUCTURES FOR INSTRUCTIONS ===
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = StablecoinState::SPACE,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump
+    )]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"role", authority.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub master_role: Account<'info, RoleAccount>,
+    
+    // Accept pre-initialized mint (initialized by SDK with any desired Token2022 extensions)
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 64,
+        seeds = [b"issuer_registry", authority.key().as_ref()],
+        bump
+    )]
+    pub issuer_registry: Account<'info, IssuerRegistry>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 128,
+        seeds = [b"issuer_registry_entry", issuer_registry.key().as_ref(), &issuer_registry.count.to_le_bytes()],
+        bump
+    )]
+    pub registry_entry: Account<'info, IssuerRegistryEntry>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub rent: Sysvar<'info, Rent>,
+    
+    // initialize_v2 with a metadata URI: signs the metadata initialization
+    /// CHECK: PDA mint authority
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct MintTokens<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"role", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_role.bump,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(mut)]
+    pub recipient_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as mint authority
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", stablecoin_state.key().as_ref()],
+        bump = circuit_breaker.bump,
+    )]
+    pub circuit_breaker: Option<Account<'info, CircuitBreaker>>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter_stats", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_stats.load()?.bump,
+    )]
+    pub minter_stats: Option<AccountLoader<'info, MinterDailyStats>>,
+}
+
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"role", burner.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = burner_role.bump,
+    )]
+    pub burner_role: Account<'info, RoleAccount>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as burn authority (for burner role)
+    #[account(
+        seeds = [b"burn_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub burn_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    pub pauser: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Account<'info, RoleAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as freeze authority
+    #[account(
+        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRescue<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    pub source_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        constraint = destination.mint == source_token_account.mint @ StablecoinError::InvalidMint,
+    )]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"rescue", stablecoin_state.key().as_ref(), source_token_account.key().as_ref()],
+        bump
+    )]
+    pub rescue_request: Account<'info, RescueRequest>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"rescue", stablecoin_state.key().as_ref(), source_token_account.key().as_ref()],
+        bump = rescue_request.bump,
+    )]
+    pub rescue_request: Account<'info, RescueRequest>,
+    
+    /// CHECK: The request's source PDA, verified against the token account owner
+    #[account(address = source_token_account.owner)]
+    pub source_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub source_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRescue<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        close = authority,
+        constraint = rescue_request.stablecoin == stablecoin_state.key() @ StablecoinError::InvalidRescue,
+    )]
+    pub rescue_request: Account<'info, RescueRequest>,
+}
+
+#[derive(Accounts)]
+pub struct BurnFrozen<'info> {
+    #[account(mut)]
+    pub seizer: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", seizer.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = seizer_role.bump,
+    )]
+    pub seizer_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(
+        init,
+        payer = seizer,
+        space = 8 + 200,
+        seeds = [b"destruction_case", stablecoin_state.key().as_ref(), proposal.key().as_ref()],
+        bump
+    )]
+    pub destruction_case: Account<'info, DestructionCase>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as freeze authority
+    #[account(
+        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    /// CHECK: PDA used as burn authority
+    #[account(
+        seeds = [b"burn_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub burn_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SeizeTokens<'info> {
+    #[account(mut)]
+    pub seizer: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", seizer.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = seizer_role.bump,
+    )]
+    pub seizer_role: Account<'info, RoleAccount>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = source_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// Destination for non-destroy cases: the treasury token account, a
+    /// registered restitution account or the case's exceptional destination.
+    /// Unused by destroy cases and splits.
+    #[account(
+        mut,
+        constraint = destination.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// CHECK: Hook RestitutionAccount PDA for `destination`, read by load_hook_account
+    pub restitution_entry: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: PDA installed as the mint's permanent delegate
+    #[account(
+        seeds = [b"permanent_delegate", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub permanent_delegate: AccountInfo<'info>,
+    
+    /// CHECK: Transfer hook config PDA, checked by the hook
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Approved hook SeizureCase for `source_account`, closed by the hook
+    #[account(mut)]
+    pub seizure_case: UncheckedAccount<'info>,
+    
+    /// CHECK: Clawback hold of a disputed case, checked and released by the hook
+    #[account(mut)]
+    pub clawback_hold: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Receives the clawback hold's rent, checked by the hook
+    #[account(mut)]
+    pub clawback_initiator: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: The transfer hook program, invoked to settle the case
+    #[account(address = sss_transfer_hook_program::ID)]
+    pub hook_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSponsoredTransfer<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = source_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = relayer_token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+        constraint = relayer_token_account.owner == relayer.key() @ StablecoinError::InvalidAuthority,
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA the holder approved as delegate of `source_account`
+    #[account(
+        seeds = [b"transfer_delegate", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub transfer_delegate: AccountInfo<'info>,
+    
+    /// CHECK: Transfer hook config PDA, checked by the hook
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Hook Relayer entry for `relayer`, checked by the hook
+    #[account(mut)]
+    pub relayer_entry: UncheckedAccount<'info>,
+    
+    /// CHECK: Hook SponsorNonce of the source owner, created by the hook
+    #[account(mut)]
+    pub sponsor_nonce: UncheckedAccount<'info>,
+    
+    /// CHECK: Instructions sysvar, read by the hook for the ed25519 intent
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    
+    /// CHECK: The transfer hook program, invoked to authorize the transfer
+    #[account(address = sss_transfer_hook_program::ID)]
+    pub hook_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferFrom<'info> {
+    pub spender: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = source_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA the owner approved as delegate of `source_account`
+    #[account(
+        seeds = [b"transfer_delegate", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub transfer_delegate: AccountInfo<'info>,
+    
+    /// CHECK: Transfer hook config PDA, checked by the hook
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Hook Allowance of the owner for `spender`, checked by the hook
+    #[account(mut)]
+    pub allowance: UncheckedAccount<'info>,
+    
+    /// CHECK: The transfer hook program, invoked to spend the allowance
+    #[account(address = sss_transfer_hook_program::ID)]
+    pub hook_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct EscheatDormantAccount<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = seizer_role.bump,
+    )]
+    pub seizer_role: Account<'info, RoleAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// Escheatment treasury token account, checked by the hook against its policy
+    #[account(
+        mut,
+        constraint = escheatment_treasury.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub escheatment_treasury: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA installed as the mint's permanent delegate
+    #[account(
+        seeds = [b"permanent_delegate", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub permanent_delegate: AccountInfo<'info>,
+    
+    /// CHECK: Transfer hook config PDA, checked by the hook
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Hook EscheatmentPolicy, checked by the hook
+    pub escheatment_policy: UncheckedAccount<'info>,
+    
+    /// CHECK: Hook ActivityRecord of `token_account`, checked and reset by the hook
+    #[account(mut)]
+    pub activity_record: UncheckedAccount<'info>,
+    
+    /// CHECK: The transfer hook program, invoked to settle the escheatment
+    #[account(address = sss_transfer_hook_program::ID)]
+    pub hook_program: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeOwnerAccounts<'info> {
+    pub pauser: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Account<'info, RoleAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    /// CHECK: PDA used as freeze authority
+    #[account(
+        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ManageOnboarding<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: Transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Owner's hook whitelist PDA, may be uninitialized; read by is_onboarded
+    #[account(
+        seeds = [b"whitelist", hook_config.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub whitelist_entry: UncheckedAccount<'info>,
+    
+    /// CHECK: Owner's hook KYC PDA, may be uninitialized; read by is_onboarded
+    #[account(
+        seeds = [b"kyc", hook_config.key().as_ref(), token_account.owner.as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub kyc_record: UncheckedAccount<'info>,
+    
+    /// CHECK: PDA used as freeze authority
+    #[account(
+        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    pub pauser: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Account<'info, RoleAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as freeze authority
+    #[account(
+        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Account<'info, RoleAccount>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    /// CHECK: Target account to update roles for
+    pub target: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"role", target.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump
+    )]
+    pub target_role: Account<'info, RoleAccount>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        mut,
+        constraint = role_index.stablecoin == stablecoin_state.key()
+            && role_index.kind == HOLDER_INDEX_ROLES @ StablecoinError::InvalidHolderIndex,
+    )]
+    pub role_index: Option<Account<'info, HolderIndex>>,
+}
+
+#[derive(Accounts)]
+#[instruction(target_key: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"role", target_key.as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump
+    )]
+    pub session_role: Account<'info, RoleAccount>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(old_key: Pubkey)]
+pub struct RevokeKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 150,
+        seeds = [b"key_rotation", stablecoin_state.key().as_ref(), old_key.as_ref()],
+        bump
+    )]
+    pub key_rotation: Account<'info, KeyRotation>,
+    
+    #[account(
+        mut,
+        seeds = [b"role", old_key.as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = target_role.bump,
+    )]
+    pub target_role: Option<Account<'info, RoleAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Option<Account<'info, MultisigConfig>>,
+    
+    #[account(
+        mut,
+        seeds = [b"voucher_signer", stablecoin_state.key().as_ref(), old_key.as_ref()],
+        bump = voucher_signer.bump,
+    )]
+    pub voucher_signer: Option<Account<'info, VoucherSigner>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"governance", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteFromGovernance<'info> {
+    /// Realms Governance account, signing via the governance program
+    pub governance: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"governance", stablecoin_state.key().as_ref()],
+        bump = governance_config.bump,
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    
+    // Required for SetFeeDistribution
+    #[account(
+        mut,
+        seeds = [b"fee_distribution", stablecoin_state.key().as_ref()],
+        bump = fee_distribution.bump,
+    )]
+    pub fee_distribution: Option<Account<'info, FeeDistribution>>,
+    
+    // Required for SetRoles
+    #[account(mut)]
+    pub target_role: Option<Account<'info, RoleAccount>>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: u8, page: u8)]
+pub struct InitHolderIndex<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 1 + 4 + 32 * HOLDER_INDEX_PAGE_SIZE + 1,
+        seeds = [b"holder_index", stablecoin_state.key().as_ref(), &[kind], &[page]],
+        bump
+    )]
+    pub holder_index: Account<'info, HolderIndex>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitMinterStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Minter whose activity is tracked
+    pub minter: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MinterDailyStats>(),
+        seeds = [b"minter_stats", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump
+    )]
+    pub minter_stats: AccountLoader<'info, MinterDailyStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMinterQuota<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    /// CHECK: Minter account
+    pub minter: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        mut,
+        constraint = minter_index.stablecoin == stablecoin_state.key()
+            && minter_index.kind == HOLDER_INDEX_MINTERS @ StablecoinError::InvalidHolderIndex,
+    )]
+    pub minter_index: Option<Account<'info, HolderIndex>>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    pub authority: Signer<'info>,
+    
+    /// CHECK: New authority address
+    pub new_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeatures<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+}
+
+#[derive(Accounts)]
+pub struct CreateLookupTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + 32 * MAX_LOOKUP_TABLES + 1,
+        seeds = [b"lookup_tables", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub lookup_registry: Account<'info, LookupTableRegistry>,
+    
+    /// CHECK: Derived from the registry and recent_slot in the handler
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+    
+    /// CHECK: Address lookup table program
+    #[account(address = address_lookup_table::program::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendLookupTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        seeds = [b"lookup_tables", stablecoin_state.key().as_ref()],
+        bump = lookup_registry.bump,
+    )]
+    pub lookup_registry: Account<'info, LookupTableRegistry>,
+    
+    /// CHECK: Must be listed in lookup_registry
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+    
+    /// CHECK: Address lookup table program
+    #[account(address = address_lookup_table::program::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BatchMint<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"role", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_role.bump,
+    )]
+    pub minter_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    /// CHECK: PDA used as mint authority
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", stablecoin_state.key().as_ref()],
+        bump = circuit_breaker.bump,
+    )]
+    pub circuit_breaker: Option<Account<'info, CircuitBreaker>>,
+    
+    #[account(
+        mut,
+        seeds = [b"minter_stats", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_stats.load()?.bump,
+    )]
+    pub minter_stats: Option<AccountLoader<'info, MinterDailyStats>>,
+}
+
+// === EPOCH ACCOUNT STRUCTS ===
+#[derive(Accounts)]
+pub struct SetCrankBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 60,
+        seeds = [b"crank_fund", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub crank_fund: Account<'info, CrankFund>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RollEpoch<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"crank_fund", stablecoin_state.key().as_ref()],
+        bump = crank_fund.bump,
+    )]
+    pub crank_fund: Option<Account<'info, CrankFund>>,
+}
+
+// === MULTISIG ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+#[instruction(threshold: u8, signers: Vec<Pubkey>)]
+pub struct InitializeMultisig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = MultisigConfig::space(signers.len(), 0),
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(instruction_data: Vec<u8>)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::space(
+            instruction_data.len(),
+            (multisig_config.threshold as usize).min(multisig_config.signers.len()),
+            0,
+        ),
+        seeds = [b"proposal", multisig_config.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        realloc = MultisigProposal::space(
+            proposal.instruction_data.len(),
+            proposal.approvals.len() + 1,
+            proposal.secp_approvals.len(),
+        ).max(proposal.to_account_info().data_len()),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxProposalPayload<'info> {
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(secp_signers: Vec<[u8; 20]>)]
+pub struct SetSecpSigners<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+        realloc = MultisigConfig::space(multisig_config.signers.len(), secp_signers.len()),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposalWithSig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        realloc = MultisigProposal::space(
+            proposal.instruction_data.len(),
+            proposal.approvals.len(),
+            proposal.secp_approvals.len() + 1,
+        ).max(proposal.to_account_info().data_len()),
+        realloc::payer = payer,
+        realloc::zero = false,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    /// CHECK: Instructions sysvar, for secp256k1 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+// === TREASURY ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = treasury_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct HarvestFees<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account (withdraw-withheld authority)
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct AddSweepSource<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SweepAllowlist::SPACE,
+        seeds = [b"sweep_allowlist", stablecoin_state.key().as_ref()],
+        bump,
+    )]
+    pub sweep_allowlist: Account<'info, SweepAllowlist>,
+    
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveSweepSource<'info> {
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"sweep_allowlist", stablecoin_state.key().as_ref()],
+        bump = sweep_allowlist.bump,
+    )]
+    pub sweep_allowlist: Account<'info, SweepAllowlist>,
+}
+
+#[derive(Accounts)]
+pub struct SweepToTreasury<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account (withdraw-withheld authority)
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"sweep_allowlist", stablecoin_state.key().as_ref()],
+        bump = sweep_allowlist.bump,
+    )]
+    pub sweep_allowlist: Account<'info, SweepAllowlist>,
+    
+    /// Allowlisted account to consolidate
+    #[account(mut)]
+    pub source_token_account: Option<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    
+    /// CHECK: Program PDA owning source_token_account, checked against its allowlist entry
+    pub source_authority: Option<AccountInfo<'info>>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeDistribution<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 250,
+        seeds = [b"fee_distribution", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub fee_distribution: Account<'info, FeeDistribution>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_distribution", stablecoin_state.key().as_ref()],
+        bump = fee_distribution.bump,
+    )]
+    pub fee_distribution: Account<'info, FeeDistribution>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: InsuranceFund PDA, deserialized in the handler when initialized
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: UncheckedAccount<'info>,
+    
+    /// CHECK: Required when insurance_fund exists, compared against its token_account
+    #[account(mut)]
+    pub insurance_token_account: Option<UncheckedAccount<'info>>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRebate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Owner's hook MarketMakerRebate PDA, read by load_hook_account
+    #[account(
+        seeds = [b"rebate", hook_config.key().as_ref(), owner.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub rebate: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + 32 + 32 + 8 + 8 + 1,
+        seeds = [b"rebate_claim", stablecoin_state.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub rebate_claim: Account<'info, RebateClaim>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.owner == owner.key() @ StablecoinError::InvalidAuthority,
+    )]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Signer's hook Referrer PDA, read by load_hook_account
+    #[account(
+        seeds = [b"referrer", hook_config.key().as_ref(), referrer.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub referrer_account: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = referrer,
+        space = 8 + 32 + 32 + 8 + 8 + 1,
+        seeds = [b"referral_claim", stablecoin_state.key().as_ref(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referral_claim: Account<'info, ReferralClaim>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.owner == referrer.key() @ StablecoinError::InvalidAuthority,
+    )]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFeeCredit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Owner's hook FeeCredit PDA, read by load_hook_account
+    #[account(
+        seeds = [b"fee_credit", hook_config.key().as_ref(), owner.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub fee_credit: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + 32 + 32 + 8 + 8 + 1,
+        seeds = [b"fee_credit_claim", stablecoin_state.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub fee_credit_claim: Account<'info, FeeCreditClaim>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.owner == owner.key() @ StablecoinError::InvalidAuthority,
+    )]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetBurnPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"burn_policy", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub burn_policy: Account<'info, BurnPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnFromTreasury<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_policy", stablecoin_state.key().as_ref()],
+        bump = burn_policy.bump,
+    )]
+    pub burn_policy: Account<'info, BurnPolicy>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", stablecoin_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: PDA that owns the treasury token account
+    #[account(
+        seeds = [b"treasury_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub treasury_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"insurance_fund", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// CHECK: PDA that owns the insurance token account
+    #[account(
+        seeds = [b"insurance_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub insurance_authority: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = insurance_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub insurance_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct SetInsuranceAllocation<'info> {
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", stablecoin_state.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+}
+
+#[derive(Accounts)]
+pub struct DrawInsurance<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+    
+    #[account(mut)]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", stablecoin_state.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    
+    /// CHECK: PDA that owns the insurance token account
+    #[account(
+        seeds = [b"insurance_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub insurance_authority: AccountInfo<'info>,
+    
+    #[account(mut, address = insurance_fund.token_account)]
+    pub insurance_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+// === FX ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+pub struct ConfigureFxPair<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub source_state: AccountLoader<'info, StablecoinState>,
+    
+    pub destination_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), source_state.load()?.mint.as_ref()],
+        bump = source_role.bump,
+    )]
+    pub source_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), destination_state.load()?.mint.as_ref()],
+        bump = destination_role.bump,
+    )]
+    pub destination_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 160,
+        seeds = [b"fx_pair", source_state.key().as_ref(), destination_state.key().as_ref()],
+        bump
+    )]
+    pub fx_pair: Account<'info, FxPair>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFxRate<'info> {
+    pub oracle: Signer<'info>,
+    
+    #[account(mut, has_one = oracle @ StablecoinError::Unauthorized)]
+    pub fx_pair: Account<'info, FxPair>,
+}
+
+#[derive(Accounts)]
+pub struct SwapBetweenStablecoins<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"fx_pair", source_state.key().as_ref(), destination_state.key().as_ref()],
+        bump = fx_pair.bump,
+    )]
+    pub fx_pair: Account<'info, FxPair>,
+    
+    #[account(
+        mut,
+        constraint = source_state.load()?.mint == source_mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub source_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(mut)]
+    pub source_mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = source_token_account.mint == source_mint.key() @ StablecoinError::InvalidMint,
+        constraint = source_token_account.owner == user.key() @ StablecoinError::Unauthorized,
+    )]
+    pub source_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination_state.load()?.mint == destination_mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(mut)]
+    pub destination_mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == destination_mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as the destination mint authority
+    #[account(
+        seeds = [b"mint_authority", destination_state.key().as_ref()],
+        bump
+    )]
+    pub destination_mint_authority: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury", destination_state.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(mut, address = treasury.token_account)]
+    pub treasury_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: Source transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", source_mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub source_hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: Destination transfer hook config PDA, only used as a seed
+    #[account(
+        seeds = [b"hook_config", destination_mint.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub destination_hook_config: UncheckedAccount<'info>,
+    
+    /// CHECK: User's hook blacklist PDA, may be uninitialized; read by is_hook_blacklisted
+    #[account(
+        seeds = [b"blacklist", source_hook_config.key().as_ref(), user.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub source_blacklist: UncheckedAccount<'info>,
+    
+    /// CHECK: Source token account's hook blacklist PDA, may be uninitialized
+    #[account(
+        seeds = [b"blacklist", source_hook_config.key().as_ref(), source_token_account.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub source_account_blacklist: UncheckedAccount<'info>,
+    
+    /// CHECK: Destination owner's hook blacklist PDA, may be uninitialized
+    #[account(
+        seeds = [b"blacklist", destination_hook_config.key().as_ref(), destination_token_account.owner.as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub destination_blacklist: UncheckedAccount<'info>,
+    
+    /// CHECK: Destination token account's hook blacklist PDA, may be uninitialized
+    #[account(
+        seeds = [b"blacklist", destination_hook_config.key().as_ref(), destination_token_account.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub destination_account_blacklist: UncheckedAccount<'info>,
+    
+    /// CHECK: User's hook legal hold PDA, may be uninitialized
+    #[account(
+        seeds = [b"legal_hold", source_hook_config.key().as_ref(), user.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub source_legal_hold: UncheckedAccount<'info>,
+    
+    /// CHECK: Source token account's hook clawback hold PDA, may be uninitialized
+    #[account(
+        seeds = [b"clawback", source_hook_config.key().as_ref(), source_token_account.key().as_ref()],
+        bump,
+        seeds::program = sss_transfer_hook_program::ID,
+    )]
+    pub source_clawback_hold: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+// === SAVINGS ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+pub struct ConfigureSavingsPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"savings_pool", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub savings_pool: Account<'info, SavingsPool>,
+    
+    /// CHECK: PDA that owns the savings token account
+    #[account(
+        seeds = [b"savings_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub savings_authority: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = savings_authority,
+        associated_token::token_program = token_program,
+    )]
+    pub savings_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct SetSavingsRate<'info> {
+    pub rate_admin: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"savings_pool", stablecoin_state.key().as_ref()],
+        bump = savings_pool.bump,
+        has_one = rate_admin @ StablecoinError::Unauthorized,
+    )]
+    pub savings_pool: Account<'info, SavingsPool>,
+    
+    #[account(mut, address = savings_pool.token_account)]
+    pub savings_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as mint authority for accrued interest
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SavingsCrank<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"savings_pool", stablecoin_state.key().as_ref()],
+        bump = savings_pool.bump,
+    )]
+    pub savings_pool: Account<'info, SavingsPool>,
+    
+    #[account(mut, address = savings_pool.token_account)]
+    pub savings_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as mint authority for accrued interest
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct DepositSavings<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"savings_pool", stablecoin_state.key().as_ref()],
+        bump = savings_pool.bump,
+    )]
+    pub savings_pool: Account<'info, SavingsPool>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + 100,
+        seeds = [b"savings_position", savings_pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub savings_position: Account<'info, SavingsPosition>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut, address = savings_pool.token_account)]
+    pub savings_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as mint authority for accrued interest
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSavings<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"savings_pool", stablecoin_state.key().as_ref()],
+        bump = savings_pool.bump,
+    )]
+    pub savings_pool: Account<'info, SavingsPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"savings_position", savings_pool.key().as_ref(), owner.key().as_ref()],
+        bump = savings_position.bump,
+    )]
+    pub savings_position: Account<'info, SavingsPosition>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut, address = savings_pool.token_account)]
+    pub savings_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA that owns the savings token account
+    #[account(
+        seeds = [b"savings_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub savings_authority: AccountInfo<'info>,
+    
+    /// CHECK: PDA used as mint authority for accrued interest
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+// === REWARD ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateRewardCampaign<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 250,
+        seeds = [b"reward_campaign", stablecoin_state.key().as_ref(), campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub campaign: Account<'info, RewardCampaign>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = reward_mint,
+        associated_token::authority = campaign,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub funding_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub reward_mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward_campaign", stablecoin_state.key().as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = vault @ StablecoinError::InvalidRewardCampaign,
+        has_one = reward_mint @ StablecoinError::InvalidRewardCampaign,
+    )]
+    pub campaign: Account<'info, RewardCampaign>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 100,
+        seeds = [b"reward_claim", campaign.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, RewardClaim>,
+    
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == reward_mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub reward_mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SweepRewardCampaign<'info> {
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward_campaign", stablecoin_state.key().as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = vault @ StablecoinError::InvalidRewardCampaign,
+        has_one = reward_mint @ StablecoinError::InvalidRewardCampaign,
+    )]
+    pub campaign: Account<'info, RewardCampaign>,
+    
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = destination.mint == reward_mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub destination: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub reward_mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+// === STREAM ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+#[instruction(stream_id: u64)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 200,
+        seeds = [b"stream", stablecoin_state.key().as_ref(), payer.key().as_ref(), stream_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = stream,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(mut)]
+    pub payer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromStream<'info> {
+    pub recipient: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"stream", stablecoin_state.key().as_ref(), stream.payer.as_ref(), stream.stream_id.to_le_bytes().as_ref()],
+        bump = stream.bump,
+        has_one = recipient @ StablecoinError::Unauthorized,
+        has_one = escrow @ StablecoinError::InvalidStream,
+    )]
+    pub stream: Account<'info, PaymentStream>,
+    
+    #[account(mut)]
+    pub escrow: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient.key() @ StablecoinError::InvalidAuthority,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    pub signer: Signer<'info>,
+    
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"stream", stablecoin_state.key().as_ref(), stream.payer.as_ref(), stream.stream_id.to_le_bytes().as_ref()],
+        bump = stream.bump,
+        has_one = escrow @ StablecoinError::InvalidStream,
+    )]
+    pub stream: Account<'info, PaymentStream>,
+    
+    #[account(mut)]
+    pub escrow: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == stream.recipient @ StablecoinError::InvalidAuthority,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = payer_token_account.owner == stream.payer @ StablecoinError::InvalidAuthority,
+    )]
+    pub payer_token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+// === VOUCHER ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+#[instruction(signer: Pubkey)]
+pub struct SetVoucherSigner<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
-    // === MULTISIG: CREATE PROPOSAL ===
-    pub fn create_proposal(
-        ctx: Context<CreateProposal>,
-        instruction_data: Vec<u8>,
-        expires_in: i64,
-    ) -> Result<()> {
-        require!(
-            ctx.accounts.multisig_config.signers.contains(&ctx.accounts.proposer.key()),
-            StablecoinError::Unauthorized
-        );
-        
-        let proposal = &mut ctx.accounts.proposal;
-        proposal.config = ctx.accounts.multisig_config.key();
-        proposal.proposer = ctx.accounts.proposer.key();
-        proposal.instruction_data = instruction_data;
-        proposal.approvals = vec![];
-        proposal.executed = false;
-        proposal.created_at = Clock::get()?.unix_timestamp;
-        proposal.expires_at = proposal.created_at + expires_in;
-        proposal.bump = ctx.bumps.proposal;
-        
-        emit!(MultisigProposalCreated {
-            proposal: proposal.key(),
-            proposer: ctx.accounts.proposer.key(),
-            timestamp: proposal.created_at,
-        });
-        
-        Ok(())
-    }
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
     
-    // === MULTISIG: APPROVE PROPOSAL ===
-    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
-        let config = &ctx.accounts.multisig_config;
-        let proposal = &mut ctx.accounts.proposal;
-        
-        require!(
-            Clock::get()?.unix_timestamp < proposal.expires_at,
-            StablecoinError::InvalidAmount
-        );
-        require!(!proposal.executed, StablecoinError::InvalidAmount);
-        require!(
-            config.signers.contains(&ctx.accounts.signer.key()),
-            StablecoinError::Unauthorized
-        );
-        require!(
-            !proposal.approvals.contains(&ctx.accounts.signer.key()),
-            StablecoinError::InvalidAmount
-        );
-        
-        proposal.approvals.push(ctx.accounts.signer.key());
-        
-        emit!(MultisigProposalApproved {
-            proposal: proposal.key(),
-            approver: ctx.accounts.signer.key(),
-            approvals: proposal.approvals.len() as u8,
-            threshold: config.threshold,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
-    }
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"voucher_signer", stablecoin_state.key().as_ref(), signer.as_ref()],
+        bump
+    )]
+    pub voucher_signer: Account<'info, VoucherSigner>,
     
-    // === MULTISIG: EXECUTE PROPOSAL ===
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
-        let config = &ctx.accounts.multisig_config;
-        let proposal = &mut ctx.accounts.proposal;
-        
-        // Check expiration
-        require!(
-            Clock::get()?.unix_timestamp < proposal.expires_at,
-            StablecoinError::InvalidAmount // Proposal expired
-        );
-        require!(
-            proposal.approvals.len() as u8 >= config.threshold,
-            StablecoinError::Unauthorized
-        );
-        require!(!proposal.executed, StablecoinError::InvalidAmount);
-        
-        proposal.executed = true;
-        
-        emit!(MultisigProposalExecuted {
-            proposal: proposal.key(),
-            executor: ctx.accounts.executor.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
-    }
+    pub system_program: Program<'info, System>,
 }
 
-// === ACCOUNT STRUCTURES FOR INSTRUCTIONS ===
+#[derive(Accounts)]
+#[instruction(amount: u64, expiry: i64, nonce: u64)]
+pub struct MintWithVoucher<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        mut,
+        seeds = [b"voucher_signer", stablecoin_state.key().as_ref(), voucher_signer.signer.as_ref()],
+        bump = voucher_signer.bump,
+    )]
+    pub voucher_signer: Account<'info, VoucherSigner>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<NonceBitmap>(),
+        seeds = [
+            b"nonce_bitmap",
+            voucher_signer.signer.as_ref(),
+            (nonce / NONCES_PER_BITMAP).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub nonce_bitmap: AccountLoader<'info, NonceBitmap>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(
+        mut,
+        constraint = recipient_account.mint == mint.key() @ StablecoinError::InvalidMint,
+    )]
+    pub recipient_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: PDA used as mint authority
+    #[account(
+        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    /// CHECK: Instructions sysvar, for ed25519 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", stablecoin_state.key().as_ref()],
+        bump = circuit_breaker.bump,
+    )]
+    pub circuit_breaker: Option<Account<'info, CircuitBreaker>>,
+}
 
+// === ANOMALY ACCOUNT STRUCTS ===
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+#[instruction(monitor: Pubkey)]
+pub struct SetMonitor<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
     #[account(
-        init,
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
         payer = authority,
-        space = 8 + 200,
-        seeds = [b"stablecoin", mint.key().as_ref()],
+        space = 8 + 80,
+        seeds = [b"monitor", stablecoin_state.key().as_ref(), monitor.as_ref()],
+        bump
+    )]
+    pub monitor_account: Account<'info, Monitor>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RaiseAnomaly<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"monitor", stablecoin_state.key().as_ref(), signer.key().as_ref()],
+        bump = monitor.bump,
+    )]
+    pub monitor: Account<'info, Monitor>,
+    
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + 100,
+        seeds = [b"anomaly_state", stablecoin_state.key().as_ref()],
+        bump
+    )]
+    pub anomaly_state: Account<'info, AnomalyState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveAnomaly<'info> {
+    pub authority: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"anomaly_state", stablecoin_state.key().as_ref()],
+        bump = anomaly_state.bump,
+    )]
+    pub anomaly_state: Account<'info, AnomalyState>,
+}
+
+// === CIRCUIT BREAKER ACCOUNT STRUCTS ===
+#[derive(Accounts)]
+pub struct ConfigureCircuitBreaker<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"circuit_breaker", stablecoin_state.key().as_ref()],
         bump
     )]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    // Omitted when the guardian resets
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Option<Account<'info, RoleAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", stablecoin_state.key().as_ref()],
+        bump = circuit_breaker.bump,
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+}
+
+// === UPGRADE AUTHORITY ACCOUNT STRUCTS ===
+#[derive(Accounts)]
+pub struct RecordUpgradeAuthority<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyUpgradeAuthority<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+// === CLOSE ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+pub struct CloseRole<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    #[account(
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
+    )]
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    /// CHECK: Holder of the role account being closed
+    pub target: AccountInfo<'info>,
     
     #[account(
-        init,
-        payer = authority,
-        space = 8 + 100,
-        seeds = [b"role", authority.key().as_ref(), mint.key().as_ref()],
-        bump
+        mut,
+        close = recipient,
+        seeds = [b"role", target.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = target_role.bump,
     )]
-    pub master_role: Account<'info, RoleAccount>,
+    pub target_role: Account<'info, RoleAccount>,
     
-    // Accept pre-initialized mint (initialized by SDK with any desired Token2022 extensions)
+    /// CHECK: Receives reclaimed rent
     #[account(mut)]
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    pub recipient: AccountInfo<'info>,
     
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token2022>,
-    pub rent: Sysvar<'info, Rent>,
+    #[account(
+        mut,
+        constraint = role_index.stablecoin == stablecoin_state.key()
+            && role_index.kind == HOLDER_INDEX_ROLES @ StablecoinError::InvalidHolderIndex,
+    )]
+    pub role_index: Option<Account<'info, HolderIndex>>,
 }
 
 #[derive(Accounts)]
-pub struct MintTokens<'info> {
-    #[account(mut)]
-    pub minter: Signer<'info>,
+pub struct CloseMinterInfo<'info> {
+    pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", minter.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = minter_role.bump,
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
     )]
-    pub minter_role: Account<'info, RoleAccount>,
+    pub authority_role: Account<'info, RoleAccount>,
+    
+    /// CHECK: Minter whose info account is being closed
+    pub minter: AccountInfo<'info>,
     
     #[account(
         mut,
-        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.mint.as_ref()],
+        close = recipient,
+        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
         bump = minter_info.bump,
     )]
     pub minter_info: Account<'info, MinterInfo>,
     
+    /// CHECK: Receives reclaimed rent
     #[account(mut)]
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
-    
-    #[account(mut)]
-    pub recipient_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub recipient: AccountInfo<'info>,
     
-    /// CHECK: PDA used as mint authority
     #[account(
-        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
-        bump
+        mut,
+        constraint = minter_index.stablecoin == stablecoin_state.key()
+            && minter_index.kind == HOLDER_INDEX_MINTERS @ StablecoinError::InvalidHolderIndex,
     )]
-    pub mint_authority: AccountInfo<'info>,
-    
-    pub token_program: Program<'info, Token2022>,
+    pub minter_index: Option<Account<'info, HolderIndex>>,
 }
 
 #[derive(Accounts)]
-pub struct BurnTokens<'info> {
+pub struct CloseProposal<'info> {
     #[account(mut)]
-    pub burner: Signer<'info>,
-    
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub proposer: Signer<'info>,
     
     #[account(
-        seeds = [b"role", burner.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = burner_role.bump,
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
     )]
-    pub burner_role: Account<'info, RoleAccount>,
-    
-    #[account(mut)]
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    pub multisig_config: Account<'info, MultisigConfig>,
     
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
-    /// CHECK: PDA used as burn authority (for burner role)
     #[account(
-        seeds = [b"burn_authority", stablecoin_state.key().as_ref()],
-        bump
+        mut,
+        close = proposer,
+        seeds = [b"proposal", multisig_config.key().as_ref(), proposer.key().as_ref()],
+        bump = proposal.bump,
     )]
-    pub burn_authority: AccountInfo<'info>,
-    
-    pub token_program: Program<'info, Token2022>,
+    pub proposal: Account<'info, MultisigProposal>,
 }
 
 #[derive(Accounts)]
-pub struct FreezeAccount<'info> {
-    pub pauser: Signer<'info>,
+pub struct CloseMultisig<'info> {
+    pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = pauser_role.bump,
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
     )]
-    pub pauser_role: Account<'info, RoleAccount>,
-    
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
-    
-    #[account(mut)]
-    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub authority_role: Account<'info, RoleAccount>,
     
-    /// CHECK: PDA used as freeze authority
     #[account(
-        seeds = [b"freeze_authority", stablecoin_state.key().as_ref()],
-        bump
+        mut,
+        close = recipient,
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump = multisig_config.bump,
     )]
-    pub freeze_authority: AccountInfo<'info>,
+    pub multisig_config: Account<'info, MultisigConfig>,
     
-    pub token_program: Program<'info, Token2022>,
+    /// CHECK: Receives reclaimed rent
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ThawAccount<'info> {
-    pub pauser: Signer<'info>,
+pub struct RenounceFreezeAuthority<'info> {
+    pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = pauser_role.bump,
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = authority_role.bump,
     )]
-    pub pauser_role: Account<'info, RoleAccount>,
-    
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    pub authority_role: Account<'info, RoleAccount>,
     
     #[account(mut)]
-    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
     
     /// CHECK: PDA used as freeze authority
     #[account(
@@ -1082,227 +10417,228 @@ pub struct ThawAccount<'info> {
 }
 
 #[derive(Accounts)]
-pub struct SetPaused<'info> {
-    pub pauser: Signer<'info>,
-    
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
-    
-    #[account(
-        seeds = [b"role", pauser.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = pauser_role.bump,
-    )]
-    pub pauser_role: Account<'info, RoleAccount>,
-}
-
-#[derive(Accounts)]
-pub struct UpdateRoles<'info> {
-    #[account(mut)]
+pub struct RenouncePermanentDelegate<'info> {
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    #[account(mut, has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", authority.key().as_ref(), stablecoin_state.mint.as_ref()],
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
         bump = authority_role.bump,
     )]
     pub authority_role: Account<'info, RoleAccount>,
     
-    /// CHECK: Target account to update roles for
-    pub target: AccountInfo<'info>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
     
+    /// CHECK: PDA installed as the mint's permanent delegate
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = 8 + 100,
-        seeds = [b"role", target.key().as_ref(), stablecoin_state.mint.as_ref()],
+        seeds = [b"permanent_delegate", stablecoin_state.key().as_ref()],
         bump
     )]
-    pub target_role: Account<'info, RoleAccount>,
+    pub permanent_delegate: AccountInfo<'info>,
     
-    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateMinterQuota<'info> {
-    #[account(mut)]
+pub struct CloseMint<'info> {
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    #[account(has_one = mint @ StablecoinError::InvalidMint)]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", authority.key().as_ref(), stablecoin_state.mint.as_ref()],
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
         bump = authority_role.bump,
     )]
     pub authority_role: Account<'info, RoleAccount>,
     
-    /// CHECK: Minter account
-    pub minter: AccountInfo<'info>,
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
     
+    /// CHECK: PDA used as mint close authority
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = 8 + 100,
-        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.mint.as_ref()],
+        seeds = [b"close_authority", stablecoin_state.key().as_ref()],
         bump
     )]
-    pub minter_info: Account<'info, MinterInfo>,
-    
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct TransferAuthority<'info> {
-    pub authority: Signer<'info>,
-    
-    /// CHECK: New authority address
-    pub new_authority: AccountInfo<'info>,
+    pub close_authority: AccountInfo<'info>,
     
+    /// CHECK: Receives the mint's rent
     #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
-}
-
-#[derive(Accounts)]
-pub struct AcceptAuthority<'info> {
-    pub pending_authority: Signer<'info>,
+    pub recipient: AccountInfo<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateFeatures<'info> {
+pub struct CloseStablecoin<'info> {
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    #[account(
+        mut,
+        close = recipient,
+        has_one = authority @ StablecoinError::InvalidAuthority,
+        has_one = mint @ StablecoinError::InvalidMint,
+    )]
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", authority.key().as_ref(), stablecoin_state.mint.as_ref()],
+        mut,
+        close = recipient,
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
         bump = authority_role.bump,
     )]
     pub authority_role: Account<'info, RoleAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    /// CHECK: Receives reclaimed rent
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct BatchMint<'info> {
-    #[account(mut)]
-    pub minter: Signer<'info>,
-    
+pub struct MigrateStateLayout<'info> {
     #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub payer: Signer<'info>,
     
-    #[account(
-        seeds = [b"role", minter.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = minter_role.bump,
-    )]
-    pub minter_role: Account<'info, RoleAccount>,
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
     
+    /// CHECK: State in the Borsh layout; owner, discriminator and size checked in handler
     #[account(
         mut,
-        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.mint.as_ref()],
-        bump = minter_info.bump,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump,
     )]
-    pub minter_info: Account<'info, MinterInfo>,
-    
-    #[account(mut)]
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    pub stablecoin_state: UncheckedAccount<'info>,
     
-    /// CHECK: PDA used as mint authority
-    #[account(
-        seeds = [b"mint_authority", stablecoin_state.key().as_ref()],
-        bump
-    )]
-    pub mint_authority: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AssertInvariants<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
-    pub token_program: Program<'info, Token2022>,
+    #[account(address = stablecoin_state.load()?.mint @ StablecoinError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
 }
 
-// === MULTISIG ACCOUNT STRUCTS ===
+// === VIEW ACCOUNT STRUCTS ===
 
 #[derive(Accounts)]
-pub struct InitializeMultisig<'info> {
+pub struct ManageReserveAttestation<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"role", authority.key().as_ref(), stablecoin_state.mint.as_ref()],
+        seeds = [b"role", authority.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
         bump = authority_role.bump,
     )]
     pub authority_role: Account<'info, RoleAccount>,
     
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
-        space = 8 + 200,
-        seeds = [b"multisig", stablecoin_state.key().as_ref()],
-        bump
+        space = ReserveAttestation::SPACE,
+        seeds = [b"reserve_attestation", stablecoin_state.key().as_ref()],
+        bump,
     )]
-    pub multisig_config: Account<'info, MultisigConfig>,
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateProposal<'info> {
-    #[account(mut)]
-    pub proposer: Signer<'info>,
+pub struct AttestReserves<'info> {
+    pub attestor: Signer<'info>,
+    
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        seeds = [b"multisig", stablecoin_state.key().as_ref()],
-        bump = multisig_config.bump,
+        mut,
+        seeds = [b"reserve_attestation", stablecoin_state.key().as_ref()],
+        bump = reserve_attestation.bump,
+        has_one = attestor @ StablecoinError::Unauthorized,
     )]
-    pub multisig_config: Account<'info, MultisigConfig>,
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitSignedAttestation<'info> {
+    pub submitter: Signer<'info>,
     
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
     #[account(
-        init,
-        payer = proposer,
-        space = 8 + 500,
-        seeds = [b"proposal", multisig_config.key().as_ref(), proposer.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"reserve_attestation", stablecoin_state.key().as_ref()],
+        bump = reserve_attestation.bump,
     )]
-    pub proposal: Account<'info, MultisigProposal>,
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
     
-    pub system_program: Program<'info, System>,
+    /// CHECK: Instructions sysvar, for secp256k1 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ApproveProposal<'info> {
-    #[account(mut)]
-    pub signer: Signer<'info>,
+pub struct GetSupplyInfo<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+}
+
+#[derive(Accounts)]
+pub struct GetRole<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Role holder being queried
+    pub holder: AccountInfo<'info>,
     
     #[account(
-        seeds = [b"multisig", stablecoin_state.key().as_ref()],
-        bump = multisig_config.bump,
+        seeds = [b"role", holder.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = role_account.bump,
     )]
-    pub multisig_config: Account<'info, MultisigConfig>,
+    pub role_account: Account<'info, RoleAccount>,
+}
+
+#[derive(Accounts)]
+pub struct GetMinterQuota<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    /// CHECK: Minter being queried
+    pub minter: AccountInfo<'info>,
     
-    #[account(mut)]
-    pub proposal: Account<'info, MultisigProposal>,
+    #[account(
+        seeds = [b"minter", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_info.bump,
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
 }
 
 #[derive(Accounts)]
-pub struct ExecuteProposal<'info> {
-    #[account(mut)]
-    pub executor: Signer<'info>,
+pub struct GetMinterStats<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
+    
+    /// CHECK: Minter being queried
+    pub minter: AccountInfo<'info>,
     
     #[account(
-        seeds = [b"multisig", stablecoin_state.key().as_ref()],
-        bump = multisig_config.bump,
+        seeds = [b"minter_stats", minter.key().as_ref(), stablecoin_state.load()?.mint.as_ref()],
+        bump = minter_stats.load()?.bump,
     )]
-    pub multisig_config: Account<'info, MultisigConfig>,
-    
-    pub stablecoin_state: Account<'info, StablecoinState>,
+    pub minter_stats: AccountLoader<'info, MinterDailyStats>,
+}
+
+#[derive(Accounts)]
+pub struct GetReserveInfo<'info> {
+    pub stablecoin_state: AccountLoader<'info, StablecoinState>,
     
-    #[account(mut)]
-    pub proposal: Account<'info, MultisigProposal>,
-}
\ No newline at end of file
+    #[account(
+        seeds = [b"reserve_attestation", stablecoin_state.key().as_ref()],
+        bump = reserve_attestation.bump,
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+}
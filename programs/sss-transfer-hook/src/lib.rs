@@ -1,45 +1,580 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::Instruction,
+    program::{get_return_data, invoke, invoke_signed},
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_lang::Discriminator;
 use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        transfer_fee::TransferFeeConfig, transfer_hook::TransferHookAccount,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as Account2022, Mint as Mint2022},
+};
 use anchor_spl::token_interface::{Mint as InterfaceMint, TokenAccount as InterfaceTokenAccount};
 use spl_tlv_account_resolution::{
     account::ExtraAccountMeta,
+    pubkey_data::PubkeyData,
     seeds::Seed,
     state::ExtraAccountMetaList,
 };
 use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use spl_transfer_hook_interface::onchain::invoke_execute;
+use spl_type_length_value::state::TlvStateBorrowed;
 
 declare_id!("By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB");
 
 // Base SSS Token Program ID
 pub mod sss_token_program {
-    use anchor_lang::prelude::declare_id;
+    use anchor_lang::prelude::*;
     declare_id!("8JpbyYEJXLeWoPJcLsHWg64bDtwFZXhPoubVJPeH11aH");
+    
+    // RoleAccount bits, mirrored from sss-token
+    pub const ROLE_MASTER: u8 = 1;
+    pub const ROLE_PAUSER: u8 = 8;
+    pub const ROLE_BLACKLISTER: u8 = 16;
+    pub const ROLE_SEIZER: u8 = 32;
+    pub const ROLE_LABELER: u8 = 128;
+    
+    // Multisig layouts, mirrored from sss-token and read with load_sss_account
+    #[derive(AnchorDeserialize)]
+    pub struct MultisigConfig {
+        pub stablecoin: Pubkey,
+        pub threshold: u8,
+        pub signers: Vec<Pubkey>,
+        pub bump: u8,
+        pub secp_signers: Vec<[u8; 20]>,
+    }
+    
+    #[derive(AnchorDeserialize)]
+    pub struct MultisigProposal {
+        pub config: Pubkey,
+        pub proposer: Pubkey,
+        pub instruction_data: Vec<u8>,
+        pub approvals: Vec<Pubkey>,
+        pub executed: bool,
+        pub created_at: i64,
+        pub expires_at: i64,
+        pub bump: u8,
+        pub secp_approvals: Vec<[u8; 20]>,
+    }
+    
+    // Variant order must match sss-token's ProposalAction
+    #[derive(AnchorSerialize)]
+    pub enum ProposalAction {
+        TreasuryWithdraw { destination: Pubkey, amount: u64 },
+        ApproveSeizure { case: Pubkey },
+        InsuranceDraw { destination: Pubkey, amount: u64 },
+        BurnFrozen { token_account: Pubkey, amount: u64 },
+    }
 }
 
-/// ============ STATE STRUCTURES ============
+// SPL Memo program, read by travel-rule introspection
+pub mod spl_memo {
+    use anchor_lang::prelude::*;
+    declare_id!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+}
 
-#[account]
+// SPL account compression program, which holds the transfer audit tree
+pub mod spl_account_compression {
+    use anchor_lang::prelude::*;
+    declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+// SPL noop program, through which account compression logs tree changelogs
+pub mod spl_noop {
+    use anchor_lang::prelude::*;
+    declare_id!("noopb9bkMVfRPU8sAbpTKg56qeFRRuWs4BhfyP1t8YV");
+}
+
+// Identity.com gateway program; gateway tokens stand in for whitelist entries
+pub mod solana_gateway {
+    use anchor_lang::prelude::*;
+    declare_id!("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs");
+    
+    // GatewayToken layout, mirrored from solana-gateway
+    #[derive(AnchorDeserialize)]
+    pub struct GatewayToken {
+        pub features: u8,
+        pub parent_gateway_token: Option<Pubkey>,
+        pub owner_wallet: Pubkey,
+        pub owner_identity: Option<Pubkey>,
+        pub gatekeeper_network: Pubkey,
+        pub issuing_gatekeeper: Pubkey,
+        pub state: GatewayTokenState,
+        pub expire_time: Option<i64>,
+    }
+    
+    #[derive(AnchorDeserialize, PartialEq, Eq)]
+    pub enum GatewayTokenState {
+        Active,
+        Frozen,
+        Revoked,
+    }
+}
+
+/// ============ STATE STRUCTURES ============
+///
+/// Per-mint hook settings, read on every transfer and so zero-copy. Flags are
+/// 0/1 bytes and optional keys and times use Pubkey::default() / 0 for none;
+/// the accessors below return them as bool and Option.
+#[account(zero_copy)]
 pub struct TransferHookConfig {
     pub stablecoin: Pubkey,              // Associated stablecoin
     pub authority: Pubkey,               // Admin authority
-    pub transfer_fee_basis_points: u16,  // Fee rate (100 = 1%)
+    pub permanent_delegate: Pubkey,      // Super admin (default = none)
+    pub redemption_treasury: Pubkey,     // Redemption-only: sole allowed destination owner (default = none)
     pub max_transfer_fee: u64,           // Maximum fee cap
     pub min_transfer_amount: u64,        // Minimum transfer
-    pub total_fees_collected: u64,       // Running total
-    pub is_paused: bool,                 // Emergency pause
-    pub blacklist_enabled: bool,         // Toggle blacklist
-    pub permanent_delegate: Option<Pubkey>, // Super admin
+    pub total_fees_collected: u64,       // Legacy; withheld fees are counted at harvest
+    pub max_balance: u64,                // Max destination balance after a transfer; 0 = no cap
+    pub paused_until: i64,               // Transfer pause lifts itself at this time (0 = never)
+    pub fees_paused_until: i64,          // Fee pause lifts itself at this time (0 = never)
+    pub blacklist_epoch: u32,            // Bumped by every blacklist write, invalidating bloom seeding
+    pub transfer_fee_basis_points: u16,  // Fee rate (100 = 1%)
+    pub fee_ceiling_bps: u16,            // Max fee once LOCK_FEE_CEILING is set
+    pub is_paused: u8,                   // Emergency pause
+    pub blacklist_enabled: u8,           // Toggle blacklist
+    pub bump: u8,
+    pub locks: u8,                       // Irreversible parameter locks (LOCK_*)
+    pub version: u8,                     // Layout version; 0 for configs predating versioning
+    pub whitelist_required: u8,          // Permissioned mode: both owners must be whitelisted
+    pub pause_reason: u8,                // PauseReason of the transfer pause
+    pub fees_paused: u8,                 // Hook fees waived while transfers continue
+    pub bloom_enabled: u8,               // Hook trusts BlacklistBloom; blacklist writes must update it
+    pub _padding: [u8; 7],
+    pub meta_groups_unused: u32,         // FEATURE_META_GROUPS never set up; 0 predates tracking (any may be in use)
+    pub meta_groups_unsynced: u32,       // FEATURE_META_GROUPS the meta list leaves out; 0 predates tracking (none)
+    pub _reserved: [u64; 6],
+}
+
+/// Account size of a TransferHookConfig
+pub const TRANSFER_HOOK_CONFIG_LEN: usize = 8 + std::mem::size_of::<TransferHookConfig>();
+
+/// Layout of configs written before TransferHookConfig went zero-copy
+/// (versions 0 to 2). Shares the `TransferHookConfig` discriminator; told
+/// apart by length.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferHookConfigV2 {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub transfer_fee_basis_points: u16,
+    pub max_transfer_fee: u64,
+    pub min_transfer_amount: u64,
+    pub total_fees_collected: u64,
+    pub is_paused: bool,
+    pub blacklist_enabled: bool,
+    pub permanent_delegate: Option<Pubkey>,
     pub bump: u8,
+    pub redemption_treasury: Option<Pubkey>,
+    pub locks: u8,
+    pub fee_ceiling_bps: u16,
+    pub version: u8,
+    pub whitelist_required: bool,
+    pub max_balance: u64,
+    pub pause_reason: PauseReason,
+    pub paused_until: Option<i64>,
+    pub fees_paused: bool,
+    pub fees_paused_until: Option<i64>,
+    pub forwarding: bool,
+    pub bloom_enabled: bool,
+    pub blacklist_epoch: u32,
+}
+
+/// Account size of a TransferHookConfigV2
+pub const TRANSFER_HOOK_CONFIG_V2_LEN: usize = 8 + 200;
+
+/// Pubkey::default() stands for "none" in zero-copy key fields
+fn optional_key(key: Pubkey) -> Option<Pubkey> {
+    (key != Pubkey::default()).then_some(key)
+}
+
+/// 0 stands for "none" in zero-copy timestamp fields
+fn optional_time(time: i64) -> Option<i64> {
+    (time != 0).then_some(time)
+}
+
+impl TransferHookConfig {
+    pub fn is_paused(&self) -> bool {
+        self.is_paused != 0
+    }
+    
+    pub fn blacklist_enabled(&self) -> bool {
+        self.blacklist_enabled != 0
+    }
+    
+    pub fn whitelist_required(&self) -> bool {
+        self.whitelist_required != 0
+    }
+    
+    pub fn fees_paused(&self) -> bool {
+        self.fees_paused != 0
+    }
+    
+    pub fn bloom_enabled(&self) -> bool {
+        self.bloom_enabled != 0
+    }
+    
+    pub fn permanent_delegate(&self) -> Option<Pubkey> {
+        optional_key(self.permanent_delegate)
+    }
+    
+    pub fn redemption_treasury(&self) -> Option<Pubkey> {
+        optional_key(self.redemption_treasury)
+    }
+    
+    pub fn paused_until(&self) -> Option<i64> {
+        optional_time(self.paused_until)
+    }
+    
+    pub fn fees_paused_until(&self) -> Option<i64> {
+        optional_time(self.fees_paused_until)
+    }
+    
+    pub fn pause_reason(&self) -> PauseReason {
+        PauseReason::from(self.pause_reason)
+    }
+    
+    /// Whether the transfer pause is in force at `now`
+    pub fn transfers_paused(&self, now: i64) -> bool {
+        self.is_paused() && self.paused_until().map_or(true, |until| now < until)
+    }
+    
+    /// Whether the fee pause is in force at `now`
+    pub fn fees_paused_at(&self, now: i64) -> bool {
+        self.fees_paused() && self.fees_paused_until().map_or(true, |until| now < until)
+    }
+    
+    /// Global fee rate (bps, max fee) in force at `now`: a queued change once
+    /// it activates, even before apply_fee_change copies it into the config
+    pub fn active_fee(&self, pending: Option<&PendingFeeChange>, now: i64) -> (u16, u64) {
+        match pending {
+            Some(p) if p.activates_at != 0 && now >= p.activates_at => (p.fee_bps, p.max_fee),
+            _ => (self.transfer_fee_basis_points, self.max_transfer_fee),
+        }
+    }
+    
+    /// Record a blacklist write, so bloom seeding in progress starts over
+    pub fn note_blacklist_write(&mut self) {
+        self.blacklist_epoch = self.blacklist_epoch.wrapping_add(1);
+    }
+    
+    /// Record that the feature behind meta `group` has been set up, so
+    /// transfers fail until the meta list resolves the group
+    pub fn note_meta_group_used(&mut self, group: u32) {
+        self.meta_groups_unused &= !group;
+    }
+    
+    /// Whether the meta list leaves out a group whose feature may be in use
+    pub fn meta_list_stale(&self) -> bool {
+        self.meta_groups_unsynced & !self.meta_groups_unused != 0
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseReason {
+    Unspecified,
+    SecurityIncident,
+    RegulatoryOrder,
+    Maintenance,
+    Migration,
+    Other,
+}
+
+impl From<u8> for PauseReason {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PauseReason::Unspecified,
+            1 => PauseReason::SecurityIncident,
+            2 => PauseReason::RegulatoryOrder,
+            3 => PauseReason::Maintenance,
+            4 => PauseReason::Migration,
+            _ => PauseReason::Other,
+        }
+    }
 }
 
+/// Current TransferHookConfig layout version (3 = zero-copy)
+pub const CONFIG_VERSION: u8 = 3;
+
+/// Fee rate can never exceed `fee_ceiling_bps`
+pub const LOCK_FEE_CEILING: u8 = 1;
+/// Seizure permanently renounced: no permanent delegate, no seized cases
+pub const LOCK_SEIZURE: u8 = 2;
+pub const LOCK_ALL: u8 = LOCK_FEE_CEILING | LOCK_SEIZURE;
+
+/// Highest hook fee rate, the 10% cap the legacy sss2_hook enforced
+pub const MAX_TRANSFER_FEE_BPS: u16 = 1_000;
+/// Highest min_transfer_amount: one token at the usual 6 decimals, far
+/// below a typical transfer
+pub const MAX_MIN_TRANSFER_AMOUNT: u64 = 1_000_000;
+
+/// Seed of the hook's legacy per-mint permanent delegate PDA, replaced by
+/// sss-token's through hand_over_permanent_delegate
+pub const PERMANENT_DELEGATE_SEED: &[u8] = b"permanent_delegate";
+
 #[account]
 pub struct BlacklistEntry {
     pub address: Pubkey,                 // Blacklisted address
-    pub reason: String,                  // Why blacklisted
+    pub reason_code: ReasonCode,         // Why blacklisted
+    pub reference: Option<[u8; 32]>,     // Hash of an external case / document
     pub blacklisted_by: Pubkey,          // Who added
     pub created_at: i64,                 // When
     pub is_active: bool,                 // Still active?
     pub bump: u8,
+    pub entry_kind: BlacklistEntryKind,  // What kind of address is listed
+    pub expires_at: Option<i64>,         // Ignored once past; None = permanent
+    pub restriction_level: RestrictionLevel, // Block or only flag transfers
+    pub listed: bool,                    // Counted in the config's ListRegistry
+}
+
+impl BlacklistEntry {
+    /// Restriction in force at `now`, if any
+    pub fn restriction(&self, now: i64) -> Option<RestrictionLevel> {
+        let expired = self.expires_at.map(|t| t <= now).unwrap_or(false);
+        (self.is_active && !expired).then_some(self.restriction_level)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReasonCode {
+    SanctionsMatch,
+    FraudInvestigation,
+    CourtOrder,
+    Chargeback,
+    Other,
+}
+
+/// Account size of a BlacklistEntry
+pub const BLACKLIST_ENTRY_LEN: usize = 8 + 128;
+
+/// Layout of blacklist entries written before reason codes, with a free-form
+/// reason. Shares the `BlacklistEntry` discriminator; told apart by length.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BlacklistEntryV1 {
+    pub address: Pubkey,
+    pub reason: String,
+    pub blacklisted_by: Pubkey,
+    pub created_at: i64,
+    pub is_active: bool,
+    pub bump: u8,
+    pub entry_kind: BlacklistEntryKind,
+    pub expires_at: Option<i64>,
+    pub restriction_level: RestrictionLevel,
+}
+
+/// Account size of a BlacklistEntryV1
+pub const BLACKLIST_ENTRY_V1_LEN: usize = 8 + 200;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RestrictionLevel {
+    Blocked,        // Transfers are rejected
+    FlagOnly,       // Transfers pass but emit FlaggedTransfer
+}
+
+/// A seizure awaiting multisig approval. Opened by a seizer, approved against
+/// an sss-token multisig proposal, and closed when sss-token's `seize_tokens`
+/// executes it.
+#[account]
+pub struct SeizureCase {
+    pub config: Pubkey,
+    pub case_id: u64,
+    pub target: Pubkey,                  // Token account to seize from
+    pub target_owner: Pubkey,
+    pub amount: u64,
+    pub reason_code: ReasonCode,
+    pub legal_reference: [u8; 32],       // Hash of the court order / case file
+    pub status: SeizureStatus,
+    pub opened_by: Pubkey,
+    pub opened_at: i64,
+    pub approved_at: i64,
+    pub bump: u8,
+    pub destroy: bool,                   // Burn instead of moving to a destination
+    pub exceptional_destination: Option<Pubkey>, // Escape-hatch token account
+    pub destination_unlocks_at: i64,     // When the exceptional destination becomes usable
+}
+
+/// Delay before an exceptional seizure destination can receive funds,
+/// leaving time to cancel the case if the seizer key is compromised
+pub const SEIZURE_ESCAPE_TIMELOCK: i64 = 72 * 3600;
+
+/// Token account approved to receive seized funds besides the treasury,
+/// e.g. a victim restitution account
+#[account]
+pub struct RestitutionAccount {
+    pub config: Pubkey,
+    pub token_account: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeizureStatus {
+    Open,           // Awaiting multisig approval
+    Approved,       // Executable by sss-token seize_tokens
+    Disputed,       // Clawback initiated; executable once the dispute window closes
+}
+
+/// Garnishment or dispute hold on an address. Unlike a blacklist entry it
+/// only blocks transfers out, and it is reported as a hold rather than a
+/// sanctions listing.
+#[account]
+pub struct LegalHold {
+    pub config: Pubkey,
+    pub address: Pubkey,                 // Wallet under hold
+    pub reference: [u8; 32],             // Hash of the garnishment order / dispute file
+    pub placed_by: Pubkey,
+    pub placed_at: i64,
+    pub bump: u8,
+}
+
+/// Machine-readable counterparty category attached to an address by a labeler
+#[account]
+pub struct AddressTag {
+    pub config: Pubkey,
+    pub address: Pubkey,                 // Tagged wallet
+    pub category: AddressCategory,
+    pub label_hash: [u8; 32],            // Hash of the free-form label kept off-chain
+    pub tagged_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddressCategory {
+    Exchange,
+    Merchant,
+    Treasury,
+    Bridge,
+}
+
+/// Dormancy rules for unclaimed-property escheatment. Accounts with no
+/// outgoing transfer for `dormancy_period` can be flagged, and once
+/// `notice_period` has passed since the flag their balance can be moved to
+/// the custodial `treasury` token account. Lamports sent to this account
+/// above its rent exemption pay for activity records the hook starts on an
+/// account's first transfer.
+#[account]
+pub struct EscheatmentPolicy {
+    pub config: Pubkey,
+    pub treasury: Pubkey,                // Custodial escheatment token account
+    pub dormancy_period: i64,            // Seconds without outgoing transfers
+    pub notice_period: i64,              // Seconds between flagging and escheatment
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+/// Shortest dormancy period an escheatment policy may set (one year)
+pub const MIN_DORMANCY_PERIOD: i64 = 365 * 86400;
+
+/// Last outgoing transfer of a token account, updated by the hook
+#[account]
+pub struct ActivityRecord {
+    pub config: Pubkey,
+    pub token_account: Pubkey,
+    pub last_activity: i64,              // Last outgoing transfer (or record creation)
+    pub flagged_at: Option<i64>,         // When the account was flagged dormant
+    pub bump: u8,
+}
+
+/// Account size of an ActivityRecord
+pub const ACTIVITY_RECORD_LEN: usize = 8 + 100;
+
+/// Relayer allowed to submit transfers that holders signed off-chain
+#[account]
+pub struct Relayer {
+    pub config: Pubkey,
+    pub relayer: Pubkey,                 // Fee payer submitting sponsored transfers
+    pub enabled: bool,
+    pub total_relayed: u64,              // Sponsored transfers submitted
+    pub bump: u8,
+}
+
+/// Highest sponsored-transfer nonce an owner has signed, for replay protection
+#[account]
+pub struct SponsorNonce {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+/// Bits in NonceBitmap::bits
+pub const NONCES_PER_BITMAP: u64 = 2048;
+
+/// Used-nonce bitmap for one page of NONCES_PER_BITMAP nonces signed by
+/// `signer`, `["nonce_bitmap", signer, page]`. Lets signed messages use nonces
+/// in any order, unlike the monotonic SponsorNonce.
+#[account(zero_copy)]
+pub struct NonceBitmap {
+    pub signer: Pubkey,
+    pub page: u64,                       // nonce / NONCES_PER_BITMAP
+    pub bits: [u8; 256],                 // Bit (nonce % NONCES_PER_BITMAP) set once used
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+impl NonceBitmap {
+    /// Mark `nonce` used; false if it already was
+    pub fn test_and_set(&mut self, nonce: u64) -> bool {
+        let bit = (nonce % NONCES_PER_BITMAP) as usize;
+        let (byte, mask) = (bit / 8, 1u8 << (bit % 8));
+        let fresh = self.bits[byte] & mask == 0;
+        self.bits[byte] |= mask;
+        fresh
+    }
+}
+
+/// Amount `spender` may move out of `owner`'s token accounts with sss-token's
+/// transfer_from, granted by a signed permit
+#[account]
+pub struct Allowance {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub spender: Pubkey,
+    pub amount: u64,                     // Remaining allowance
+    pub nonce: u64,                      // Nonce of the permit that set it
+    pub bump: u8,
+}
+
+/// Longest dispute window a clawback policy may set
+pub const MAX_DISPUTE_WINDOW: i64 = 30 * 86400;
+
+/// Who may cancel clawbacks and how long holders have to dispute them
+#[account]
+pub struct ClawbackPolicy {
+    pub config: Pubkey,
+    pub guardian: Pubkey,                // Can cancel a clawback during its window
+    pub dispute_window: i64,             // Seconds between initiation and finalization
+    pub bump: u8,
+}
+
+/// Funds held on a token account while a clawback is disputable. The hook
+/// blocks transfers out that would dip below `amount`, or all of them when
+/// `freeze_account` is set.
+#[account]
+pub struct ClawbackHold {
+    pub config: Pubkey,
+    pub case: Pubkey,
+    pub target: Pubkey,                  // Token account under hold
+    pub amount: u64,
+    pub freeze_account: bool,
+    pub initiated_by: Pubkey,
+    pub initiated_at: i64,
+    pub dispute_ends_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BlacklistEntryKind {
+    Owner,          // Wallet owning token accounts
+    TokenAccount,   // A specific token account
+    Delegate,       // A transfer authority acting as delegate
 }
 
 #[account]
@@ -49,6 +584,7 @@ pub struct WhitelistEntry {
     pub added_by: Pubkey,                // Who added
     pub created_at: i64,                 // When
     pub bump: u8,
+    pub listed: bool,                    // Counted in the config's ListRegistry
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -57,712 +593,10107 @@ pub enum WhitelistType {
     FullBypass,     // Bypass all restrictions
 }
 
-/// ============ ERROR CODES ============
+/// Number of blacklist and whitelist entries currently listed for a config,
+/// `["list_registry", config]`. Entries written before it existed are counted
+/// once they are next added again.
+#[account]
+pub struct ListRegistry {
+    pub config: Pubkey,
+    pub blacklist_count: u64,            // Active blacklist entries
+    pub whitelist_count: u64,            // Open whitelist entries
+    pub bump: u8,
+}
 
-#[error_code]
-pub enum TransferHookError {
-    #[msg("Transfer hook is paused")]
-    HookPaused = 8000,
-    #[msg("Source address is blacklisted")]
-    SourceBlacklisted,
-    #[msg("Destination address is blacklisted")]
-    DestinationBlacklisted,
-    #[msg("Transfer amount below minimum")]
-    AmountTooLow,
-    #[msg("Invalid authority")]
-    InvalidAuthority,
-    #[msg("Address already blacklisted")]
-    AlreadyBlacklisted,
-    #[msg("Blacklist entry not found")]
-    BlacklistNotFound,
-    #[msg("Already whitelisted")]
-    AlreadyWhitelisted,
-    #[msg("Compliance feature not enabled")]
-    ComplianceNotEnabled,
-    #[msg("Invalid instruction data")]
-    InvalidInstruction,
-    #[msg("Fee calculation overflow")]
-    MathOverflow,
-    #[msg("Cannot seize from self")]
-    SelfSeizure,
+/// One page of listed addresses, `["list_index", config, kind, page]`
+#[account]
+pub struct ListIndexPage {
+    pub config: Pubkey,
+    pub kind: u8,                        // LIST_BLACKLIST or LIST_WHITELIST
+    pub page: u8,
+    pub addresses: Vec<Pubkey>,          // Up to LIST_INDEX_PAGE_SIZE addresses
+    pub bump: u8,
 }
 
-/// ============ EVENTS ============
+pub const LIST_BLACKLIST: u8 = 0;
+pub const LIST_WHITELIST: u8 = 1;
+/// Addresses held by one ListIndexPage
+pub const LIST_INDEX_PAGE_SIZE: usize = 32;
 
-#[event]
-pub struct TransferExecuted {
-    pub source: Pubkey,
-    pub destination: Pubkey,
-    pub amount: u64,
-    pub fee: u64,
-    pub net_amount: u64,
-    pub is_whitelisted: bool,
-    pub is_delegate: bool,
-    pub timestamp: i64,
+/// Merkle root of an off-chain sanctions list. Addresses proven to be in the
+/// list can be materialized into blocking blacklist PDAs by anyone.
+#[account]
+pub struct SanctionsRoot {
+    pub config: Pubkey,                  // Hook config this list applies to
+    pub root: [u8; 32],                  // Current Merkle root
+    pub version: u64,                    // Bumped on every publish
+    pub updated_by: Pubkey,              // Who published
+    pub updated_at: i64,                 // When
+    pub bump: u8,
 }
 
-#[event]
-pub struct BlacklistAdded {
-    pub address: Pubkey,
-    pub reason: String,
-    pub blacklisted_by: Pubkey,
-    pub timestamp: i64,
+/// External risk program the hook consults on every transfer. Created with
+/// the extra account meta list so the program slot always resolves; an unset
+/// slot holds the default pubkey. `program` must stay at byte offset 40.
+#[account]
+pub struct RiskOracle {
+    pub config: Pubkey,
+    pub program: Pubkey,                 // Allowlisted risk program
+    pub enabled: bool,
+    pub bump: u8,
 }
 
-#[event]
-pub struct BlacklistRemoved {
-    pub address: Pubkey,
-    pub removed_by: Pubkey,
-    pub timestamp: i64,
+/// Offset of `RiskOracle::program` in account data, read by extra meta [33]
+pub const RISK_ORACLE_PROGRAM_OFFSET: u8 = 40;
+
+/// Secondary transfer-hook program run after the local checks. A PDA of its
+/// own, so mints that never forward don't carry the secondary's accounts;
+/// extra meta [35] reads `program` at byte offset 40. An unset slot holds the
+/// default pubkey. The secondary may not ask for extra accounts of its own.
+#[account]
+pub struct ForwardHook {
+    pub config: Pubkey,
+    pub program: Pubkey,                 // Secondary hook program
+    pub bump: u8,
 }
 
-#[event]
-pub struct TokensSeized {
-    pub from: Pubkey,
-    pub to: Pubkey,
-    pub amount: u64,
-    pub seized_by: Pubkey,
-    pub reason: String,
-    pub timestamp: i64,
+/// Offset of `ForwardHook::program` in account data, read by extra meta [35]
+pub const FORWARD_HOOK_PROGRAM_OFFSET: u8 = 40;
+
+/// Bits in BlacklistBloom::bits
+pub const BLOOM_BITS: usize = 8192;
+/// Bit positions set per address
+pub const BLOOM_HASHES: usize = 4;
+
+/// Bloom filter over every address blacklisted under a config,
+/// `["blacklist_bloom", config]`. While `config.bloom_enabled` is set the
+/// hook skips blacklist PDAs for addresses the filter rules out, and every
+/// instruction writing a blacklist entry must pass the filter. Bits are never
+/// cleared, so removed entries only cost false positives.
+///
+/// Existing entries are seeded in ascending address order, so each is
+/// counted once. Any blacklist write restarts the count, and the filter can
+/// only be enabled once it matches the ListRegistry's blacklist_count.
+#[account(zero_copy)]
+pub struct BlacklistBloom {
+    pub config: Pubkey,
+    pub bits: [u8; BLOOM_BITS / 8],
+    pub inserted: u64,                   // Insertions, for sizing decisions
+    pub seeded: u64,                     // Listed entries seeded since `seed_epoch`
+    pub seed_epoch: u64,                 // config.blacklist_epoch the count belongs to
+    pub last_seeded: Pubkey,             // Highest address seeded since `seed_epoch`
 }
 
-#[event]
-pub struct ConfigUpdated {
-    pub authority: Pubkey,
-    pub field: String,
-    pub value: String,
-    pub timestamp: i64,
+impl BlacklistBloom {
+    fn positions(address: &Pubkey) -> [usize; BLOOM_HASHES] {
+        let hash = keccak::hash(address.as_ref()).to_bytes();
+        let mut positions = [0; BLOOM_HASHES];
+        for (i, position) in positions.iter_mut().enumerate() {
+            let word = u32::from_le_bytes(hash[i * 4..i * 4 + 4].try_into().unwrap());
+            *position = word as usize % BLOOM_BITS;
+        }
+        positions
+    }
+    
+    pub fn insert(&mut self, address: &Pubkey) {
+        for bit in Self::positions(address) {
+            self.bits[bit / 8] |= 1 << (bit % 8);
+        }
+        self.inserted = self.inserted.saturating_add(1);
+    }
+    
+    /// False only if `address` was never inserted
+    pub fn may_contain(&self, address: &Pubkey) -> bool {
+        Self::positions(address)
+            .iter()
+            .all(|bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
 }
 
-#[event]
-pub struct BatchBlacklistAdded {
-    pub authority: Pubkey,
-    pub count: u16,
-    pub timestamp: i64,
+/// Concurrent Merkle tree the hook appends one `audit_leaf` to per transfer,
+/// `["audit_log", config]`. The PDA is the tree's authority. Created with the
+/// extra account meta list so extra meta [46] always resolves; `tree` must
+/// stay at byte offset 40. An unset tree (default pubkey) disables the log.
+#[account]
+pub struct AuditLog {
+    pub config: Pubkey,
+    pub tree: Pubkey,                    // spl-account-compression tree
+    pub leaf_count: u64,                 // Unused; the tree's sequence number indexes leaves
+    pub bump: u8,
 }
 
-/// ============ PROGRAM MODULE ============
+/// Offset of `AuditLog::tree` in account data, read by extra meta [46]
+pub const AUDIT_LOG_TREE_OFFSET: u8 = 40;
 
-#[program]
-pub mod sss_transfer_hook {
-    use super::*;
+/// Offset of a concurrent Merkle tree's sequence number in account data,
+/// after the 56-byte header. The audit tree only ever appends, so it is also
+/// the index of the next leaf.
+pub const AUDIT_TREE_SEQUENCE_OFFSET: usize = 56;
 
-    /// Initialize the transfer hook for a stablecoin
-    pub fn initialize(
-        ctx: Context<InitializeHook>,
-        transfer_fee_basis_points: u16,
-        max_transfer_fee: u64,
-        min_transfer_amount: u64,
-        blacklist_enabled: bool,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        config.stablecoin = ctx.accounts.stablecoin.key();
-        config.authority = ctx.accounts.authority.key();
-        config.transfer_fee_basis_points = transfer_fee_basis_points;
-        config.max_transfer_fee = max_transfer_fee;
-        config.min_transfer_amount = min_transfer_amount;
-        config.total_fees_collected = 0;
-        config.is_paused = false;
-        config.blacklist_enabled = blacklist_enabled;
-        config.permanent_delegate = None;
-        config.bump = ctx.bumps.config;
+/// Gatekeeper network whose gateway tokens count as whitelist entries in
+/// permissioned mode. Created with the extra account meta list so the gateway
+/// token PDAs always resolve; `network` must stay at byte offset 40.
+#[account]
+pub struct GatekeeperConfig {
+    pub config: Pubkey,
+    pub network: Pubkey,                 // Accepted gatekeeper network
+    pub enabled: bool,
+    pub bump: u8,
+}
 
-        emit!(ConfigUpdated {
-            authority: ctx.accounts.authority.key(),
-            field: "initialize".to_string(),
-            value: format!("fee_bps:{}, max_fee:{}, min:{}, blacklist:{}", 
-                transfer_fee_basis_points, max_transfer_fee, min_transfer_amount, blacklist_enabled),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+/// Offset of `GatekeeperConfig::network` in account data, read by extra metas [40] and [41]
+pub const GATEKEEPER_NETWORK_OFFSET: u8 = 40;
 
-        Ok(())
+/// Why a transfer was rejected, for statistics and monitoring
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RejectionReason {
+    Paused,
+    Blacklisted,
+    NotWhitelisted,
+    Kyc,
+    VelocityLimit,
+    TransferWindow,
+    ApprovalRequired,
+    BalanceCap,
+    AmountTooLow,
+    Hold,                                // Clawback or legal hold
+    TravelRule,
+    MemoRequired,
+    RiskOracle,
+    Other,
+}
+
+impl RejectionReason {
+    /// Classify a hook error; errors that are not transfer rejections map to Other
+    pub fn from_error(error: &Error) -> Self {
+        use TransferHookError as E;
+        let Error::AnchorError(e) = error else { return Self::Other };
+        let is = |variants: &[E]| variants.iter().any(|v| u32::from(*v) == e.error_code_number);
+        
+        if is(&[E::HookPaused, E::RedemptionOnly]) {
+            Self::Paused
+        } else if is(&[E::SourceBlacklisted, E::DestinationBlacklisted, E::DelegateBlacklisted]) {
+            Self::Blacklisted
+        } else if is(&[E::NotWhitelisted]) {
+            Self::NotWhitelisted
+        } else if is(&[E::KycTransferLimitExceeded, E::KycDailyLimitExceeded, E::TierNotAllowed]) {
+            Self::Kyc
+        } else if is(&[
+            E::DailyVolumeExceeded,
+            E::DailyTransferCountExceeded,
+            E::VelocityTrackerMissing,
+            E::TransferCooldownActive,
+        ]) {
+            Self::VelocityLimit
+        } else if is(&[E::OutsideTransferWindow]) {
+            Self::TransferWindow
+        } else if is(&[E::ApprovalRequired]) {
+            Self::ApprovalRequired
+        } else if is(&[E::BalanceCapExceeded]) {
+            Self::BalanceCap
+        } else if is(&[E::AmountTooLow]) {
+            Self::AmountTooLow
+        } else if is(&[E::ClawbackHeld, E::LegalHoldActive]) {
+            Self::Hold
+        } else if is(&[E::TravelRuleRequired]) {
+            Self::TravelRule
+        } else if is(&[E::MemoRequired]) {
+            Self::MemoRequired
+        } else if is(&[E::RiskCheckDenied]) {
+            Self::RiskOracle
+        } else {
+            Self::Other
+        }
     }
+}
 
-    /// Initialize ExtraAccountMetaList — REQUIRED by Token-2022 before hook can be installed on a mint.
-    /// This stores the PDAs the hook needs as extra accounts for every transfer.
-    pub fn initialize_extra_account_meta_list(
-        ctx: Context<InitExtraAccountMetaList>,
-    ) -> Result<()> {
-        // Define the extra accounts needed for every execute_transfer_hook call:
-        // [0] config PDA  (seeds: ["hook_config", mint])
-        // [1] source owner blacklist PDA  (seeds: ["blacklist", config, source_account.owner])
-        // [2] destination blacklist PDA   (seeds: ["blacklist", config, destination_account.owner])
-        let account_metas = vec![
-            // Config account — deterministic, seeded on mint
-            ExtraAccountMeta::new_with_seeds(
-                &[
-                    Seed::Literal { bytes: b"hook_config".to_vec() },
-                    Seed::AccountKey { index: 2 }, // mint is account index 2 in execute
-                ],
-                false, // is_signer
-                false, // is_writable — we only need to read config in execute
-            )?,
-            // Source blacklist PDA — seeded on config + source_account.owner
-            // source_account is index 0, config (extra acct 0) is index 4
-            ExtraAccountMeta::new_with_seeds(
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 4 }, // config extra account index 0 = overall index 4
-                    Seed::AccountKey { index: 3 }, // source_account.owner — wallet, passed as remaining
-                ],
-                false,
-                false,
-            )?,
-            // Destination blacklist PDA — seeded on config + destination_account.owner
-            ExtraAccountMeta::new_with_seeds(
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 4 }, // config
-                    Seed::AccountKey { index: 3 }, // destination owner
-                ],
-                false,
-                false,
-            )?,
-            // 4th extra account: The sss_token base program ID itself
-            ExtraAccountMeta::new_with_pubkey(
-                &sss_token_program::ID,
-                false,
-                false,
-            )?,
-            // 5th extra account: Master StablecoinState PDA — seeded on "stablecoin" + mint, owned by sss_token program (index 7)
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                7, // index 7: 0=src, 1=mint, 2=dest, 3=owner, 4=config, 5=src_bl, 6=dst_bl, 7=base_program
-                &[
-                    Seed::Literal { bytes: b"stablecoin".to_vec() },
-                    Seed::AccountKey { index: 1 }, // mint is account index 1 in execute instruction (Wait! In Token-2022 ExecuteInstruction: 0=source, 1=mint, 2=destination, 3=owner delegator. So mint is index 1!)
-                ],
-                false,
-                false,
-            )?,
-        ];
+/// Slots in DailyStats::rejections; at least the number of RejectionReasons
+pub const MAX_REJECTION_REASONS: usize = 16;
 
-        // Calculate required space
-        let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+/// Days of statistics kept in the HookStats ring
+pub const STATS_RING_DAYS: usize = 32;
 
-        // Initialize the ExtraAccountMetaList account
-        ExtraAccountMetaList::init::<ExecuteInstruction>(
-            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
-            &account_metas,
-        )?;
+/// Aggregates for one UTC day
+#[zero_copy]
+pub struct DailyStats {
+    pub day: i64,                        // Days since the Unix epoch
+    pub transfer_count: u64,
+    pub gross_volume: u64,
+    pub fees: u64,
+    pub rejections: [u32; MAX_REJECTION_REASONS], // Indexed by RejectionReason
+}
 
-        msg!(
-            "ExtraAccountMetaList initialized for mint {} with {} extra accounts (size: {} bytes)",
-            ctx.accounts.mint.key(),
-            account_metas.len(),
-            account_size,
-        );
+/// Per-mint statistics: a ring of the last STATS_RING_DAYS days, slot
+/// `day % STATS_RING_DAYS`. Transfers are counted in StatsShards and rolled up
+/// here. Rejected transfers revert, so rejection counters are only fed from
+/// outside the hook.
+#[account(zero_copy)]
+pub struct HookStats {
+    pub config: Pubkey,
+    pub days: [DailyStats; STATS_RING_DAYS],
+}
 
-        Ok(())
+impl HookStats {
+    /// The slot for `day`, reset if it still holds an older day
+    pub fn day_mut(&mut self, day: i64) -> &mut DailyStats {
+        let slot = &mut self.days[day.rem_euclid(STATS_RING_DAYS as i64) as usize];
+        if slot.day != day {
+            *slot = DailyStats {
+                day,
+                transfer_count: 0,
+                gross_volume: 0,
+                fees: 0,
+                rejections: [0; MAX_REJECTION_REASONS],
+            };
+        }
+        slot
+    }
+    
+    pub fn day(&self, day: i64) -> Option<&DailyStats> {
+        let slot = &self.days[day.rem_euclid(STATS_RING_DAYS as i64) as usize];
+        (slot.day == day).then_some(slot)
     }
+}
 
-    pub fn execute_transfer_hook(
-        ctx: Context<ExecuteTransferHook>,
-        amount: u64,
-    ) -> Result<()> {
-        let config = &ctx.accounts.config;
-        
-        // Check base program pause state
-        // The stablecoin_state PDA is the 4th extra account, which means it's in remaining_accounts
-        // remaining_accounts layout: [config, source_blacklist, dest_blacklist, stablecoin_state]
-        // Actually, it's [source_blacklist, dest_blacklist, stablecoin_state] because config is explicitly defined.
-        // Wait, Anchor `remaining_accounts` includes all accounts not explicitly matched, OR all extra accounts if we don't define them in struct.
-        // Let's explicitly define it in ExecuteTransferHook struct to be safe.
-        if let Some(stablecoin_state) = ctx.accounts.stablecoin_state.as_ref() {
-            let data = stablecoin_state.try_borrow_data()?;
-            // stablecoin_state layout:
-            // 8 bytes discriminator
-            // 32 bytes authority
-            // 32 bytes mint
-            // 36 bytes name (4 len + 32 chars max)
-            // 14 bytes symbol (4 len + 10 chars max)
-            // 1 byte decimals
-            // 8 bytes total_supply
-            // 1 byte is_paused
-            // Total fixed offset up to total_supply: 8 + 32 + 32 + 36 + 14 + 1 + 8 = 131
-            // 131 is the byte offset of `is_paused` flag.
-            if data.len() >= 132 {
-                let is_paused = data[131] != 0;
-                require!(!is_paused, TransferHookError::HookPaused);
-            }
-        }
-        
-        // Check hook-specific pause
-        require!(!config.is_paused, TransferHookError::HookPaused);
-        
-        // Check blacklist (if enabled)
-        if config.blacklist_enabled {
-            // Check source
-            if ctx.accounts.source_blacklist.is_some() {
-                let entry = ctx.accounts.source_blacklist.as_ref().unwrap();
-                if entry.is_active {
-                    return Err(TransferHookError::SourceBlacklisted.into());
-                }
-            }
-            
-            // Check destination
-            if ctx.accounts.destination_blacklist.is_some() {
-                let entry = ctx.accounts.destination_blacklist.as_ref().unwrap();
-                if entry.is_active {
-                    return Err(TransferHookError::DestinationBlacklisted.into());
-                }
-            }
-        }
-        
-        // Check permanent delegate (bypasses everything)
-        let is_delegate = if let Some(delegate) = config.permanent_delegate {
-            ctx.accounts.source_account.owner == delegate || 
-            ctx.accounts.destination_account.owner == delegate
-        } else {
-            false
-        };
-        
-        // Check whitelist
-        let mut is_whitelisted = false;
-        if let Some(ref _whitelist) = ctx.accounts.source_whitelist {
-            is_whitelisted = true;
-        }
-        if let Some(ref _whitelist) = ctx.accounts.destination_whitelist {
-            is_whitelisted = true;
-        }
-        
-        // Calculate fee
-        let mut fee: u64 = 0;
-        if !is_delegate && !is_whitelisted {
-            require!(amount >= config.min_transfer_amount, TransferHookError::AmountTooLow);
-            
-            fee = (amount as u128)
-                .checked_mul(config.transfer_fee_basis_points as u128)
-                .ok_or(TransferHookError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(TransferHookError::MathOverflow)? as u64;
-            
-            if fee > config.max_transfer_fee {
-                fee = config.max_transfer_fee;
-            }
+/// Days a StatsShard keeps; roll_up_stats must run at least this often
+pub const SHARD_RING_DAYS: usize = 4;
+
+/// Transfer counters for one UTC day in a StatsShard
+#[zero_copy]
+pub struct ShardDay {
+    pub day: i64,                        // Days since the Unix epoch
+    pub transfer_count: u64,
+    pub gross_volume: u64,
+    pub fees: u64,
+}
+
+/// Transfer counters for source owners whose address starts with byte
+/// `shard`, `["stats_shard", config, [shard]]`. The hook writes one of 256
+/// shards instead of HookStats, so transfers from different owners rarely
+/// contend; roll_up_stats folds the shards into HookStats.
+#[account(zero_copy)]
+pub struct StatsShard {
+    pub config: Pubkey,
+    pub days: [ShardDay; SHARD_RING_DAYS],
+    pub shard: u8,
+    pub _padding: [u8; 7],
+}
+
+impl StatsShard {
+    /// The slot for `day`, reset if it still holds an older day
+    pub fn day_mut(&mut self, day: i64) -> &mut ShardDay {
+        let slot = &mut self.days[day.rem_euclid(SHARD_RING_DAYS as i64) as usize];
+        if slot.day != day {
+            *slot = ShardDay { day, transfer_count: 0, gross_volume: 0, fees: 0 };
         }
-        
-        let net_amount = amount.checked_sub(fee).ok_or(TransferHookError::MathOverflow)?;
-        
-        // Update total fees (if fee > 0)
-        if fee > 0 {
-            let config_mut = &mut ctx.accounts.config;
-            config_mut.total_fees_collected = config_mut.total_fees_collected
-                .checked_add(fee)
-                .ok_or(TransferHookError::MathOverflow)?;
+        slot
+    }
+}
+
+/// Permanent copy of a finished day, `["daily_stats", config, day]`
+#[account(zero_copy)]
+pub struct DailyStatsRecord {
+    pub config: Pubkey,
+    pub stats: DailyStats,
+}
+
+/// Issuer-wide blacklist record shared by every hook config whose authority is
+/// `issuer`. Mirrored into a config's own blacklist by `sync_blacklist_entry`.
+#[account]
+pub struct GlobalBlacklistEntry {
+    pub issuer: Pubkey,                  // Namespace owner; matches config.authority
+    pub address: Pubkey,
+    pub reason_code: ReasonCode,
+    pub reference: Option<[u8; 32]>,
+    pub restriction_level: RestrictionLevel,
+    pub expires_at: Option<i64>,
+    pub is_active: bool,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// KYC status of an address. Outgoing volume for the current UTC day is
+/// tracked here so the hook can enforce the tier's daily limit.
+#[account]
+pub struct KycRecord {
+    pub address: Pubkey,                 // Verified address
+    pub tier: KycTier,                   // Verification level
+    pub provider: Pubkey,                // KYC provider that verified it
+    pub expires_at: Option<i64>,         // Falls back to KycTier::None once past
+    pub day: i64,                        // UTC day index of day_volume
+    pub day_volume: u64,                 // Outgoing volume on `day`
+    pub updated_at: i64,
+    pub bump: u8,
+    pub nonce: u64,                      // Last provider attestation nonce used
+}
+
+impl KycRecord {
+    /// Tier in force at `now`
+    pub fn tier_at(&self, now: i64) -> KycTier {
+        match self.expires_at {
+            Some(expiry) if expiry <= now => KycTier::None,
+            _ => self.tier,
         }
-        
-        emit!(TransferExecuted {
-            source: ctx.accounts.source_account.owner,
-            destination: ctx.accounts.destination_account.owner,
-            amount,
-            fee,
-            net_amount,
-            is_whitelisted,
-            is_delegate,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
     }
+}
 
-    /// Add address to blacklist
-    pub fn add_to_blacklist(
-        ctx: Context<ManageBlacklist>,
-        reason: String,
-    ) -> Result<()> {
-        require!(ctx.accounts.config.blacklist_enabled, TransferHookError::ComplianceNotEnabled);
-        
-        let entry = &mut ctx.accounts.blacklist_entry;
-        entry.address = ctx.accounts.target_address.key();
-        entry.reason = reason.clone();
-        entry.blacklisted_by = ctx.accounts.authority.key();
-        entry.created_at = Clock::get()?.unix_timestamp;
-        entry.is_active = true;
-        entry.bump = 0; // bump stored in PDA, not needed in data
-        
-        emit!(BlacklistAdded {
-            address: ctx.accounts.target_address.key(),
-            reason,
-            blacklisted_by: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KycTier {
+    None,
+    Basic,
+    Enhanced,
+}
+
+/// External KYC provider allowed to attest tiers with ed25519 signatures
+#[account]
+pub struct KycProvider {
+    pub config: Pubkey,                  // Hook config the provider serves
+    pub provider: Pubkey,                // Attestation signing key
+    pub allowed_tiers: u8,               // Bitmask of `1 << KycTier`
+    pub registered_by: Pubkey,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl KycProvider {
+    pub fn allows(&self, tier: KycTier) -> bool {
+        self.allowed_tiers & (1 << tier as u8) != 0
     }
+}
 
-    /// Remove from blacklist
-    pub fn remove_from_blacklist(ctx: Context<ManageBlacklist>) -> Result<()> {
-        let entry = &mut ctx.accounts.blacklist_entry;
-        entry.is_active = false;
-        
-        emit!(BlacklistRemoved {
-            address: ctx.accounts.target_address.key(),
-            removed_by: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+/// Per-tier transfer limits, indexed by KycTier. Addresses without a record
+/// use the `None` tier; daily volume is only tracked for addresses with a record.
+#[account]
+pub struct KycPolicy {
+    pub config: Pubkey,                  // Hook config this policy applies to
+    pub tier_limits: [TierLimits; 3],    // None, Basic, Enhanced
+    pub bump: u8,
+    pub tier_max_balance: [u64; 3],      // Overrides config.max_balance per tier; 0 = use config
+}
+
+impl KycPolicy {
+    pub fn limits(&self, tier: KycTier) -> TierLimits {
+        self.tier_limits[tier as usize]
+    }
+    
+    /// Balance cap for `tier`, falling back to `default` when not overridden
+    pub fn max_balance(&self, tier: KycTier, default: u64) -> u64 {
+        match self.tier_max_balance[tier as usize] {
+            0 => default,
+            cap => cap,
+        }
     }
+}
 
-    /// Seize tokens from blacklisted account
-    pub fn seize_tokens(
-        ctx: Context<SeizeTokens>,
-        amount: Option<u64>,
-        reason: String,
-    ) -> Result<()> {
-        let config = &ctx.accounts.config;
-        
-        // Only permanent delegate can seize
-        require!(
-            config.permanent_delegate == Some(ctx.accounts.authority.key()),
-            TransferHookError::InvalidAuthority
-        );
-        
-        // Cannot seize from self
-        require!(
-            ctx.accounts.source_account.owner != ctx.accounts.treasury.key(),
-            TransferHookError::SelfSeizure
-        );
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct TierLimits {
+    pub max_per_transfer: u64,           // 0 = unlimited
+    pub max_per_day: u64,                // 0 = unlimited
+}
+
+/// Seconds per UTC day, for daily limit windows
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Optional transfer policies beyond the core config. Absent = all disabled.
+#[account]
+pub struct TransferPolicy {
+    pub config: Pubkey,                  // Hook config this policy applies to
+    pub max_daily_volume: u64,           // Rolling 24h outgoing volume per owner; 0 = unlimited
+    pub max_daily_count: u32,            // Rolling 24h outgoing transfers per owner; 0 = unlimited
+    pub bump: u8,
+    pub approval_threshold: u64,         // Transfers at or above need a TransferApproval; 0 = off
+    pub cooldown_seconds: i64,           // Outgoing lock after a large inbound transfer; 0 = off
+    pub cooldown_threshold: u64,         // Inbound amount that starts a cooldown
+    pub trading_days: u8,                // Bit per UTC weekday, bit 0 = Sunday; 0 = no schedule
+    pub open_hour: u8,                   // UTC hour transfers open (inclusive)
+    pub close_hour: u8,                  // UTC hour transfers close (exclusive); wraps past midnight
+    pub maintenance_windows: [MaintenanceWindow; MAX_MAINTENANCE_WINDOWS],
+    pub travel_rule_threshold: u64,      // Transfers at or above need a travel-rule reference; 0 = off
+}
+
+impl TransferPolicy {
+    pub fn velocity_limited(&self) -> bool {
+        self.max_daily_volume > 0 || self.max_daily_count > 0
+    }
+    
+    /// Whether non-whitelisted transfers are allowed at `now`
+    pub fn in_transfer_window(&self, now: i64) -> bool {
+        if self.maintenance_windows.iter().any(|w| w.contains(now)) {
+            return false;
+        }
+        if self.trading_days == 0 {
+            return true;
+        }
         
-        // Determine amount to seize
-        let seize_amount = match amount {
-            Some(amt) => amt,
-            None => ctx.accounts.source_account.amount,
+        let days = now.div_euclid(SECONDS_PER_DAY);
+        // 1970-01-01 was a Thursday
+        let weekday = (days + 4).rem_euclid(7) as u8;
+        let hour = (now.rem_euclid(SECONDS_PER_DAY) / 3600) as u8;
+        let open_hours = match self.open_hour.cmp(&self.close_hour) {
+            std::cmp::Ordering::Less => (self.open_hour..self.close_hour).contains(&hour),
+            std::cmp::Ordering::Greater => hour >= self.open_hour || hour < self.close_hour,
+            std::cmp::Ordering::Equal => true,
         };
-        
-        require!(seize_amount > 0, TransferHookError::AmountTooLow);
-        require!(
-            seize_amount <= ctx.accounts.source_account.amount,
-            TransferHookError::AmountTooLow
-        );
-        
-        // Transfer using permanent delegate authority
-        anchor_spl::token_2022::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token_2022::TransferChecked {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    from: ctx.accounts.source_account.to_account_info(),
-                    to: ctx.accounts.treasury.to_account_info(),
-                    authority: ctx.accounts.permanent_delegate.to_account_info(),
-                },
-                &[],
-            ),
-            seize_amount,
-            ctx.accounts.mint.decimals,
-        )?;
-        
-        emit!(TokensSeized {
-            from: ctx.accounts.source_account.owner,
-            to: ctx.accounts.treasury.owner,
-            amount: seize_amount,
-            seized_by: ctx.accounts.authority.key(),
-            reason,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
+        self.trading_days & (1 << weekday) != 0 && open_hours
     }
+}
 
-    /// Add to whitelist
-    pub fn add_to_whitelist(
-        ctx: Context<ManageWhitelist>,
-        whitelist_type: WhitelistType,
-    ) -> Result<()> {
-        let entry = &mut ctx.accounts.whitelist_entry;
-        entry.address = ctx.accounts.target_address.key();
-        entry.whitelist_type = whitelist_type;
-        entry.added_by = ctx.accounts.authority.key();
-        entry.created_at = Clock::get()?.unix_timestamp;
-        entry.bump = 0; // bump stored in PDA, not needed in data
-        
-        Ok(())
+/// Ad-hoc window during which non-whitelisted transfers are rejected.
+/// An all-zero window is unused.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MaintenanceWindow {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl MaintenanceWindow {
+    pub fn contains(&self, now: i64) -> bool {
+        self.start < self.end && (self.start..self.end).contains(&now)
     }
+}
 
-    /// Remove from whitelist
-    pub fn remove_from_whitelist(_ctx: Context<ManageWhitelist>) -> Result<()> {
-        // Account will be closed by Anchor
-        Ok(())
+pub const MAX_MAINTENANCE_WINDOWS: usize = 4;
+
+/// Named fee rates that addresses can be assigned to via AddressFeeOverride
+#[account]
+pub struct FeeTiers {
+    pub config: Pubkey,
+    pub tiers: [FeeTierRate; MAX_FEE_TIERS],
+    pub bump: u8,
+    pub sol_fees: [u64; MAX_FEE_TIERS],  // Lamports paid to the SOL fee vault instead of the token fee (0 = token fee)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FeeTierRate {
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub active: bool,                    // Inactive tiers fall back to the global rate
+}
+
+pub const MAX_FEE_TIERS: usize = 8;
+/// Per-transfer ceiling for a tier's SOL fee
+pub const MAX_SOL_FEE_LAMPORTS: u64 = 100_000_000;
+
+/// Global fee increase waiting out its notice period, `["pending_fee", config]`.
+/// update_config may only lower fees; raises are queued here.
+#[account]
+pub struct PendingFeeChange {
+    pub config: Pubkey,
+    pub min_delay: i64,                  // Shortest notice a queued change may get
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub activates_at: i64,               // 0 = nothing queued
+    pub bump: u8,
+}
+
+/// Floor for PendingFeeChange::min_delay
+pub const MIN_FEE_CHANGE_DELAY: i64 = 3_600;
+
+/// Dated fee rates such as zero-fee promotions, `["fee_periods", config]`.
+/// A period can only lower the rate a transfer would otherwise pay, so it
+/// can't be used to skip the notice a fee increase needs.
+#[account]
+pub struct FeeSchedule {
+    pub config: Pubkey,
+    pub periods: Vec<FeePeriod>,         // Up to MAX_FEE_PERIODS, unordered
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FeePeriod {
+    pub start: i64,
+    pub end: i64,                        // Exclusive
+    pub fee_bps: u16,
+}
+
+impl FeeSchedule {
+    /// Lowest rate among the periods running at `now`
+    pub fn rate_at(&self, now: i64) -> Option<u16> {
+        self.periods
+            .iter()
+            .filter(|p| p.start <= now && now < p.end)
+            .map(|p| p.fee_bps)
+            .min()
     }
+}
 
-    /// Update configuration
-    pub fn update_config(
-        ctx: Context<UpdateConfig>,
-        transfer_fee_basis_points: Option<u16>,
-        max_transfer_fee: Option<u64>,
-        min_transfer_amount: Option<u64>,
-        is_paused: Option<bool>,
-        blacklist_enabled: Option<bool>,
-        permanent_delegate: Option<Option<Pubkey>>,
-    ) -> Result<()> {
-        let config = &mut ctx.accounts.config;
-        
-        if let Some(fee_bps) = transfer_fee_basis_points {
-            config.transfer_fee_basis_points = fee_bps;
+pub const MAX_FEE_PERIODS: usize = 8;
+/// How far ahead a fee period may start
+pub const MAX_FEE_PERIOD_LEAD: i64 = 90 * 86_400;
+
+/// Fee rebate for a registered market maker, `["rebate", config, owner]`.
+/// The hook accrues `rebate_bps` of every fee the owner pays, up to
+/// `monthly_cap` per period. Once a period closes its accrual is added to
+/// `settled`, which sss-token's `claim_rebate` pays out of the treasury.
+#[account]
+pub struct MarketMakerRebate {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub rebate_bps: u16,                 // Share of each fee paid back
+    pub monthly_cap: u64,                // Max accrual per period
+    pub active: bool,                    // Deregistered makers keep what they accrued
+    pub period_start: i64,               // Start of the current REBATE_PERIOD
+    pub period_accrued: u64,             // Accrued in the current period
+    pub settled: u64,                    // Running total from closed periods
+    pub bump: u8,
+}
+
+impl MarketMakerRebate {
+    pub const LEN: usize = 32 + 32 + 2 + 8 + 1 + 8 + 8 + 8 + 1;
+    
+    /// Close the current period if `now` is past it
+    pub fn roll(&mut self, now: i64) -> Result<()> {
+        let period_start = now - now.rem_euclid(REBATE_PERIOD);
+        if period_start > self.period_start {
+            self.settled = self.settled
+                .checked_add(self.period_accrued)
+                .ok_or(TransferHookError::MathOverflow)?;
+            self.period_accrued = 0;
+            self.period_start = period_start;
         }
-        if let Some(max) = max_transfer_fee {
-            config.max_transfer_fee = max;
+        Ok(())
+    }
+    
+    /// Accrue the rebate on `fee`, returning the amount added
+    pub fn accrue(&mut self, fee: u64, now: i64) -> Result<u64> {
+        self.roll(now)?;
+        if !self.active {
+            return Ok(0);
         }
-        if let Some(min) = min_transfer_amount {
-            config.min_transfer_amount = min;
+        let rebate = (fee as u128 * self.rebate_bps as u128 / 10_000) as u64;
+        let rebate = rebate.min(self.monthly_cap.saturating_sub(self.period_accrued));
+        self.period_accrued += rebate;
+        Ok(rebate)
+    }
+}
+
+/// Length of a rebate accrual period
+pub const REBATE_PERIOD: i64 = 30 * SECONDS_PER_DAY;
+pub const MAX_REBATE_BPS: u16 = 5_000;
+
+/// Distribution partner earning a share of referred addresses' fees,
+/// `["referrer", config, referrer]`. sss-token's `claim_referral_fees` pays
+/// out `earned` less what it has already paid.
+#[account]
+pub struct Referrer {
+    pub config: Pubkey,
+    pub referrer: Pubkey,
+    pub share_bps: u16,                  // Slice of referred fees, applied at settlement
+    pub earned: u64,                     // Running total settled to this referrer
+    pub bump: u8,
+}
+
+/// Links an address to its referrer, `["referral", config, address]`. The hook
+/// adds each fee the address pays to `fees_unsettled` until `settle_referral`
+/// moves the referrer's share over.
+#[account]
+pub struct ReferralLink {
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub referrer: Pubkey,
+    pub fees_unsettled: u64,
+    pub bump: u8,
+}
+
+pub const MAX_REFERRAL_BPS: u16 = 5_000;
+
+/// Withheld fees credited back to an owner, `["fee_credit", config, owner]`.
+/// Token-2022 withholds the mint's TransferFee rate from everyone; where the
+/// owner's tier, override, fee period or volume discount prices the transfer
+/// lower (or waives it), the hook credits the difference here. sss-token's
+/// `claim_fee_credit` pays out `credited` less what it has already paid.
+#[account]
+pub struct FeeCredit {
+    pub config: Pubkey,
+    pub owner: Pubkey,
+    pub credited: u64,                   // Running total credited by the hook
+    pub bump: u8,
+}
+
+/// Peg-deviation fee policy, `["peg_fee", config]`. A registered oracle key
+/// pushes the market price; while it strays past `threshold_bps`, transfers
+/// that push it further away pay a surcharge on top of the hook fee, out of
+/// what would otherwise be credited back of the withheld TransferFee.
+/// Exchange-tagged addresses mark the market side: below the peg, deposits
+/// into an exchange (sells) pay; above it, withdrawals from one (buys) pay.
+#[account]
+pub struct PegFeePolicy {
+    pub config: Pubkey,
+    pub oracle: Pubkey,                  // Only signer allowed to post prices
+    pub enabled: bool,
+    pub price: u64,                      // Market price, PEG_PRICE_SCALE = at peg
+    pub price_updated_at: i64,
+    pub max_price_age: i64,              // Older prices leave fees unscaled
+    pub threshold_bps: u16,              // Deviation tolerated without a surcharge
+    pub surcharge_pct: u16,              // Surcharge bps per 100 bps of deviation past the threshold
+    pub max_surcharge_bps: u16,
+    pub effective_at: i64,               // End of the fee notice for the current parameters
+    pub bump: u8,
+}
+
+impl PegFeePolicy {
+    /// Deviation of a fresh price from the peg in bps, negative below it
+    pub fn deviation_bps(&self, now: i64) -> Option<i64> {
+        if self.price == 0 || now - self.price_updated_at > self.max_price_age {
+            return None;
         }
-        if let Some(paused) = is_paused {
-            config.is_paused = paused;
+        let deviation = (self.price as i128 - PEG_PRICE_SCALE as i128) * 10_000 / PEG_PRICE_SCALE as i128;
+        Some(deviation.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+    
+    /// Surcharge for a transfer between addresses of these categories
+    pub fn surcharge_bps(
+        &self,
+        now: i64,
+        source: Option<AddressCategory>,
+        destination: Option<AddressCategory>,
+    ) -> u16 {
+        if !self.enabled || now < self.effective_at {
+            return 0;
         }
-        if let Some(enabled) = blacklist_enabled {
-            config.blacklist_enabled = enabled;
+        let Some(deviation) = self.deviation_bps(now) else { return 0 };
+        let wrong_side = (deviation < 0 && destination == Some(AddressCategory::Exchange))
+            || (deviation > 0 && source == Some(AddressCategory::Exchange));
+        if !wrong_side {
+            return 0;
         }
-        if let Some(delegate) = permanent_delegate {
-            config.permanent_delegate = delegate;
+        let excess = deviation.unsigned_abs().saturating_sub(self.threshold_bps as u64);
+        (excess.saturating_mul(self.surcharge_pct as u64) / 100).min(self.max_surcharge_bps as u64) as u16
+    }
+}
+
+/// Price of exactly one token at the peg
+pub const PEG_PRICE_SCALE: u64 = 1_000_000;
+pub const MAX_PEG_SURCHARGE_BPS: u16 = 500;
+/// Steepest surcharge_pct: 10 bps of surcharge per bps of deviation
+pub const MAX_PEG_SURCHARGE_PCT: u16 = 1_000;
+pub const MAX_PEG_PRICE_AGE: i64 = 86_400;
+
+/// Fee discounts by the source owner's rolling 30-day volume
+#[account]
+pub struct FeeTierSchedule {
+    pub config: Pubkey,
+    pub discounts: [VolumeDiscount; MAX_VOLUME_DISCOUNTS],
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct VolumeDiscount {
+    pub min_volume: u64,                 // 30-day volume needed to qualify
+    pub discount_bps: u16,               // Share of the fee waived; 0 = unused slot
+}
+
+pub const MAX_VOLUME_DISCOUNTS: usize = 4;
+
+/// Programs whose accounts send fee-free. Matched against the program that
+/// owns the source token account's owner, e.g. a protocol's vault PDA.
+#[account]
+pub struct FeeExemptPrograms {
+    pub config: Pubkey,
+    pub programs: Vec<Pubkey>,           // Up to MAX_FEE_EXEMPT_PROGRAMS
+    pub bump: u8,
+}
+
+pub const MAX_FEE_EXEMPT_PROGRAMS: usize = 16;
+
+impl FeeTierSchedule {
+    /// Largest discount whose volume threshold `volume` meets
+    pub fn discount_bps(&self, volume: u64) -> u16 {
+        self.discounts
+            .iter()
+            .filter(|d| d.discount_bps > 0 && volume >= d.min_volume)
+            .map(|d| d.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Assigns a source owner to a fee tier, consulted before the global rate
+#[account]
+pub struct AddressFeeOverride {
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub tier: u8,                        // Index into FeeTiers::tiers
+    pub bump: u8,
+}
+
+/// Single-use pre-approval for a large transfer between two owners
+#[account]
+pub struct TransferApproval {
+    pub config: Pubkey,
+    pub source: Pubkey,                  // Source owner
+    pub destination: Pubkey,             // Destination owner
+    pub min_amount: u64,                 // Approved amount window, inclusive
+    pub max_amount: u64,
+    pub expires_at: i64,
+    pub approved_by: Pubkey,
+    pub used: bool,                      // Consumed by the hook
+    pub bump: u8,
+}
+
+/// Travel-rule data for one transfer between two owners, attached by the
+/// originator ahead of the transfer and consumed by the hook
+#[account]
+pub struct TravelRuleRecord {
+    pub config: Pubkey,
+    pub source: Pubkey,                  // Source owner
+    pub destination: Pubkey,             // Destination owner
+    pub amount: u64,                     // Exact transfer amount covered
+    pub ivms101_hash: [u8; 32],          // Hash of the IVMS101 payload exchanged off-chain
+    pub originator_vasp: [u8; 32],       // Originating VASP identifier
+    pub beneficiary_vasp: [u8; 32],      // Beneficiary VASP identifier
+    pub created_at: i64,
+    pub used: bool,                      // Consumed by the hook
+    pub bump: u8,
+}
+
+/// Deposit account that only accepts transfers accompanied by a Memo, so
+/// exchanges can attribute deposits to customers
+#[account]
+pub struct MemoRequirement {
+    pub config: Pubkey,
+    pub token_account: Pubkey,           // Destination token account
+    pub required: bool,
+    pub set_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+/// Hours in the velocity tracker's rolling window
+pub const VELOCITY_WINDOW_HOURS: usize = 24;
+
+/// Days in the velocity tracker's fee-discount volume window
+pub const VOLUME_WINDOW_DAYS: usize = 30;
+
+/// Rolling 24h outgoing volume and transfer count for one source owner,
+/// kept in hourly buckets, plus 30-day volume in daily buckets for fee
+/// discounts. Updated by the hook on every transfer, so it is zero-copy;
+/// packed to keep the byte layout of trackers written before the change.
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct VelocityTracker {
+    pub config: Pubkey,
+    pub owner: Pubkey,                   // Tracked source owner
+    pub last_hour: i64,                  // Hour index of the newest bucket
+    pub hourly_volume: [u64; VELOCITY_WINDOW_HOURS],
+    pub hourly_count: [u32; VELOCITY_WINDOW_HOURS],
+    pub bump: u8,
+    pub last_large_inbound_at: i64,      // Start of the current transfer cooldown
+    pub last_day: i64,                   // Day index of the newest daily bucket
+    pub daily_volume: [u64; VOLUME_WINDOW_DAYS],
+}
+
+impl VelocityTracker {
+    /// Roll the window forward to `now`, add one transfer of `amount`, and
+    /// return the window's total volume and count.
+    pub fn record(&mut self, now: i64, amount: u64) -> Result<(u64, u32)> {
+        let hour = now.div_euclid(3600);
+        let window = VELOCITY_WINDOW_HOURS as i64;
+        if hour - self.last_hour >= window {
+            self.hourly_volume = [0; VELOCITY_WINDOW_HOURS];
+            self.hourly_count = [0; VELOCITY_WINDOW_HOURS];
+        } else {
+            for h in (self.last_hour + 1)..=hour {
+                let slot = h.rem_euclid(window) as usize;
+                self.hourly_volume[slot] = 0;
+                self.hourly_count[slot] = 0;
+            }
         }
+        self.last_hour = self.last_hour.max(hour);
         
-        emit!(ConfigUpdated {
-            authority: ctx.accounts.authority.key(),
-            field: "update_config".to_string(),
-            value: "multiple".to_string(),
-            timestamp: Clock::get()?.unix_timestamp,
-        });
-        
-        Ok(())
-    }
-    
-    /// ============ BATCH OPERATIONS ============
-    
-    /// Batch blacklist multiple addresses
-    pub fn batch_blacklist(
-        ctx: Context<BatchBlacklist>,
-        addresses: Vec<Pubkey>,
-        reasons: Vec<String>,
-    ) -> Result<()> {
-        require!(
-            addresses.len() == reasons.len(),
-            TransferHookError::InvalidInstruction
-        );
-        require!(
-            addresses.len() <= 10,
-            TransferHookError::InvalidInstruction
-        );
+        let slot = hour.rem_euclid(window) as usize;
+        self.hourly_volume[slot] = self.hourly_volume[slot]
+            .checked_add(amount)
+            .ok_or(TransferHookError::MathOverflow)?;
+        self.hourly_count[slot] = self.hourly_count[slot].saturating_add(1);
         
-        let config = &ctx.accounts.config;
-        require!(config.blacklist_enabled, TransferHookError::ComplianceNotEnabled);
+        let mut volume: u64 = 0;
+        for v in self.hourly_volume {
+            volume = volume.checked_add(v).ok_or(TransferHookError::MathOverflow)?;
+        }
+        let mut count: u32 = 0;
+        for c in self.hourly_count {
+            count = count.saturating_add(c);
+        }
         
-        // In real implementation, this would iterate and create multiple blacklist entries
-        // For now, we emit a batch event
+        let day = now.div_euclid(SECONDS_PER_DAY);
+        let days = VOLUME_WINDOW_DAYS as i64;
+        if day - self.last_day >= days {
+            self.daily_volume = [0; VOLUME_WINDOW_DAYS];
+        } else {
+            for d in (self.last_day + 1)..=day {
+                self.daily_volume[d.rem_euclid(days) as usize] = 0;
+            }
+        }
+        self.last_day = self.last_day.max(day);
         
-        emit!(BatchBlacklistAdded {
-            authority: ctx.accounts.authority.key(),
-            count: addresses.len() as u16,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+        let slot = day.rem_euclid(days) as usize;
+        self.daily_volume[slot] = self.daily_volume[slot]
+            .checked_add(amount)
+            .ok_or(TransferHookError::MathOverflow)?;
         
-        Ok(())
+        Ok((volume, count))
+    }
+    
+    /// Total volume in the 30-day window as of the last `record`
+    pub fn monthly_volume(&self) -> Result<u64> {
+        let mut volume: u64 = 0;
+        for v in self.daily_volume {
+            volume = volume.checked_add(v).ok_or(TransferHookError::MathOverflow)?;
+        }
+        Ok(volume)
     }
 }
 
-/// ============ ACCOUNT STRUCTURES ============
+/// One address in a `batch_blacklist` call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchBlacklistItem {
+    pub address: Pubkey,
+    pub reason_code: ReasonCode,
+    pub reference: Option<[u8; 32]>,
+    pub entry_kind: BlacklistEntryKind,
+    pub expires_at: Option<i64>,
+    pub restriction_level: RestrictionLevel,
+}
+
+/// Maximum entries per `batch_blacklist` call
+pub const MAX_BATCH_BLACKLIST: usize = 10;
+
+/// Layout of whitelist entries written by the retired sss2_hook program.
+/// Shares the `WhitelistEntry` discriminator; told apart by length.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyWhitelistEntry {
+    pub address: Pubkey,
+    pub is_whitelisted: bool,
+    pub bump: u8,
+}
+
+/// Layout of blacklist entries written by the retired sss2_hook program.
+/// Shares the `BlacklistEntry` discriminator; told apart by length.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyBlacklistEntry {
+    pub address: Pubkey,
+    pub is_blacklisted: bool,
+    pub bump: u8,
+}
+
+/// Account size of a legacy list entry: discriminator + address + flag + bump
+pub const LEGACY_ENTRY_LEN: usize = 8 + 32 + 1 + 1;
+
+/// Layout of configs created under the legacy per-authority seeds
+/// (`["config", authority]`), before fields were appended to TransferHookConfig.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyTransferHookConfig {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub transfer_fee_basis_points: u16,
+    pub max_transfer_fee: u64,
+    pub min_transfer_amount: u64,
+    pub total_fees_collected: u64,
+    pub is_paused: bool,
+    pub blacklist_enabled: bool,
+    pub permanent_delegate: Option<Pubkey>,
+    pub bump: u8,
+}
+
+/// Outcome of `precheck_transfer`, returned via set_return_data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PrecheckResult {
+    pub allowed: bool,
+    pub fee: u64,                        // Hook fee the transfer would pay
+    pub reason: Option<RejectionReason>,
+    pub error_code: Option<u32>,         // Exact hook error when rejected
+}
+
+/// Snapshot of the hook configuration, returned via set_return_data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HookConfigInfo {
+    pub stablecoin: Pubkey,
+    pub authority: Pubkey,
+    pub transfer_fee_basis_points: u16,
+    pub max_transfer_fee: u64,
+    pub min_transfer_amount: u64,
+    pub total_fees_collected: u64,
+    pub is_paused: bool,
+    pub blacklist_enabled: bool,
+    pub permanent_delegate: Option<Pubkey>,
+    pub whitelist_required: bool,
+    pub paused_until: Option<i64>,
+    pub fees_paused: bool,
+}
+
+/// ============ ERROR CODES ============
+
+#[error_code]
+pub enum TransferHookError {
+    #[msg("Transfer hook is paused")]
+    HookPaused = 8000,
+    #[msg("Source address is blacklisted")]
+    SourceBlacklisted,
+    #[msg("Destination address is blacklisted")]
+    DestinationBlacklisted,
+    #[msg("Transfer amount below minimum")]
+    AmountTooLow,
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+    #[msg("Address already blacklisted")]
+    AlreadyBlacklisted,
+    #[msg("Blacklist entry not found")]
+    BlacklistNotFound,
+    #[msg("Already whitelisted")]
+    AlreadyWhitelisted,
+    #[msg("Compliance feature not enabled")]
+    ComplianceNotEnabled,
+    #[msg("Invalid instruction data")]
+    InvalidInstruction,
+    #[msg("Fee calculation overflow")]
+    MathOverflow,
+    #[msg("Cannot seize from self")]
+    SelfSeizure,
+    #[msg("Blacklist entry is still active")]
+    BlacklistEntryActive,
+    #[msg("Redemption-only mode: transfers must go to the treasury")]
+    RedemptionOnly,
+    #[msg("Parameter is permanently locked")]
+    ParameterLocked,
+    #[msg("Invalid lock flag")]
+    InvalidLockFlag,
+    #[msg("Hook may only be invoked by Token-2022 during a transfer")]
+    NotTransferring,
+    #[msg("Config does not belong to this mint")]
+    MintMismatch,
+    #[msg("Account is not a legacy hook config")]
+    InvalidLegacyConfig,
+    #[msg("List entry does not belong to the token account owner")]
+    ListEntryMismatch,
+    #[msg("List PDA was not derived from the token account owner")]
+    InvalidListAccount,
+    #[msg("Transfer authority is blacklisted")]
+    DelegateBlacklisted,
+    #[msg("Invalid sanctions list inclusion proof")]
+    InvalidSanctionsProof,
+    #[msg("Blacklist entry has not expired")]
+    BlacklistEntryNotExpired,
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Account is not a blacklist entry in the old layout")]
+    NotLegacyEntry,
+    #[msg("Source and destination must both be whitelisted")]
+    NotWhitelisted,
+    #[msg("Transfer exceeds the KYC tier per-transfer limit")]
+    KycTransferLimitExceeded,
+    #[msg("Transfer exceeds the KYC tier daily limit")]
+    KycDailyLimitExceeded,
+    #[msg("KYC provider may not attest this tier")]
+    TierNotAllowed,
+    #[msg("Missing or invalid ed25519 attestation signature")]
+    InvalidAttestation,
+    #[msg("Attestation nonce already used")]
+    StaleNonce,
+    #[msg("Rolling 24h transfer volume limit exceeded")]
+    DailyVolumeExceeded,
+    #[msg("Rolling 24h transfer count limit exceeded")]
+    DailyTransferCountExceeded,
+    #[msg("Velocity tracker must be initialized for the source owner")]
+    VelocityTrackerMissing,
+    #[msg("Destination balance would exceed the maximum")]
+    BalanceCapExceeded,
+    #[msg("Transfer above the approval threshold needs a valid pre-approval")]
+    ApprovalRequired,
+    #[msg("Approval amount window is invalid")]
+    InvalidApproval,
+    #[msg("Outgoing transfers are locked after a large inbound transfer")]
+    TransferCooldownActive,
+    #[msg("Transfers are not allowed at this time")]
+    OutsideTransferWindow,
+    #[msg("Invalid transfer schedule")]
+    InvalidSchedule,
+    #[msg("Invalid fee tier")]
+    InvalidFeeTier,
+    #[msg("Invalid volume discount")]
+    InvalidVolumeDiscount,
+    #[msg("Program cannot be fee exempt")]
+    InvalidFeeExemptProgram,
+    #[msg("Fee exempt program list is full")]
+    FeeExemptListFull,
+    #[msg("Blacklist entry was set locally and is not mirrored")]
+    LocalEntryConflict,
+    #[msg("Seizure case is not in the required status")]
+    InvalidSeizureCase,
+    #[msg("Multisig proposal does not approve this action")]
+    InvalidProposal,
+    #[msg("Invalid seizure destination")]
+    InvalidSeizureDestination,
+    #[msg("Exceptional seizure destination is still timelocked")]
+    SeizureDestinationLocked,
+    #[msg("Permanent delegate must be sss-token's delegate PDA for the mint")]
+    InvalidPermanentDelegate,
+    #[msg("Invalid dispute window")]
+    InvalidDisputeWindow,
+    #[msg("Clawback dispute window has not ended")]
+    DisputeWindowOpen,
+    #[msg("Clawback dispute window has ended")]
+    DisputeWindowClosed,
+    #[msg("Funds are held by a pending clawback")]
+    ClawbackHeld,
+    #[msg("Source is under legal hold")]
+    LegalHoldActive,
+    #[msg("Transfer requires a travel-rule record or memo")]
+    TravelRuleRequired,
+    #[msg("Destination requires a memo")]
+    MemoRequired,
+    #[msg("Transfer denied by risk oracle")]
+    RiskCheckDenied,
+    #[msg("Invalid risk program")]
+    InvalidRiskProgram,
+    #[msg("Invalid secondary hook program")]
+    InvalidForwardHook,
+    #[msg("Secondary hook needs extra accounts, which forwarding can't supply")]
+    ForwardHookExtrasUnsupported,
+    #[msg("Statistics for that day are not available")]
+    StatsUnavailable,
+    #[msg("Invalid gatekeeper network")]
+    InvalidGatekeeperNetwork,
+    #[msg("Invalid escheatment policy")]
+    InvalidEscheatmentPolicy,
+    #[msg("Account is not dormant")]
+    AccountNotDormant,
+    #[msg("Escheatment notice period has not elapsed")]
+    EscheatmentNoticeActive,
+    #[msg("Relayer is not registered or disabled")]
+    InvalidRelayer,
+    #[msg("Signed intent has expired")]
+    IntentExpired,
+    #[msg("Transfer exceeds the spender's allowance")]
+    InsufficientAllowance,
+    #[msg("Nonce already used")]
+    NonceAlreadyUsed,
+    #[msg("Invalid accounts for freezing the blacklisted owner")]
+    InvalidFreezeAccounts,
+    #[msg("List index page is full, missing or does not match")]
+    InvalidListIndex,
+    #[msg("Audit tree account does not match the audit log")]
+    InvalidAuditTree,
+    #[msg("Blacklist bloom filter must be passed while it is enabled")]
+    BloomFilterRequired,
+    #[msg("Multisig proposal has expired")]
+    ProposalExpired,
+    #[msg("Multisig proposal has not reached its approval threshold")]
+    ThresholdNotMet,
+    #[msg("Fee increases must be queued with queue_fee_change")]
+    FeeIncreaseNotQueued,
+    #[msg("Fee change is invalid, not queued or not yet active")]
+    InvalidFeeChange,
+    #[msg("Fee exceeds the 10% cap")]
+    FeeTooHigh,
+    #[msg("Max transfer fee must be nonzero while a fee rate is set")]
+    InvalidMaxFee,
+    #[msg("Minimum transfer amount is too high")]
+    MinTransferTooHigh,
+    #[msg("Fee period is invalid, too far ahead or the schedule is full")]
+    InvalidFeePeriod,
+    #[msg("Rebate share or monthly cap is invalid")]
+    InvalidRebate,
+    #[msg("Referral share is invalid or the address is its own referrer")]
+    InvalidReferral,
+    #[msg("Transfer must be a direct Token-2022 transfer preceded by a SOL fee payment to the fee vault")]
+    SolFeeRequired,
+    #[msg("Peg fee policy parameters are out of bounds")]
+    InvalidPegFeePolicy,
+    #[msg("Redemption mode follows the stablecoin sunset and cannot be lifted")]
+    RedemptionModeLocked,
+    #[msg("Mint has no Token-2022 TransferFee for this fee setting to shape")]
+    TransferFeeRequired,
+    #[msg("Bloom filter seeding does not cover every listed blacklist entry")]
+    BloomNotSeeded,
+    #[msg("Extra account meta list predates a feature now in use; sync it")]
+    StaleExtraAccountMetas,
+    #[msg("Not a stats shard of this config")]
+    InvalidStatsShard,
+    #[msg("Stablecoin state has an unrecognised layout")]
+    UnknownStablecoinLayout,
+    #[msg("Bloom filter can't rule out a party; screen it with screen_blacklist first")]
+    BlacklistScreeningRequired,
+    #[msg("Screened restriction does not match the blacklist entry")]
+    BlacklistScreeningMismatch,
+}
+
+/// ============ EVENTS ============
+
+#[event]
+pub struct FlaggedTransfer {
+    pub flagged: Pubkey,                 // Listed address that matched
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferExecuted {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub net_amount: u64,
+    pub is_whitelisted: bool,
+    pub is_delegate: bool,
+    pub timestamp: i64,
+    pub travel_rule: Option<[u8; 32]>,   // IVMS101 hash or memo hash for large transfers
+    pub source_category: Option<AddressCategory>,
+    pub destination_category: Option<AddressCategory>,
+}
+
+/// Compute used by one execute_transfer_hook call; only emitted by builds
+/// with the `cu-trace` feature
+#[event]
+pub struct HookComputeUnits {
+    pub remaining_at_entry: u64,
+    pub remaining_at_exit: u64,
+    pub consumed: u64,
+}
+
+#[event]
+pub struct BlacklistAdded {
+    pub address: Pubkey,
+    pub entry_kind: BlacklistEntryKind,
+    pub expires_at: Option<i64>,
+    pub restriction_level: RestrictionLevel,
+    pub reason_code: ReasonCode,
+    pub reference: Option<[u8; 32]>,
+    pub blacklisted_by: Pubkey,
+    pub accounts_frozen: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlacklistRemoved {
+    pub address: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureCaseOpened {
+    pub case: Pubkey,
+    pub case_id: u64,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub reason_code: ReasonCode,
+    pub legal_reference: [u8; 32],
+    pub destroy: bool,
+    pub opened_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RestitutionAccountAdded {
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RestitutionAccountRemoved {
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExceptionalDestinationSet {
+    pub case: Pubkey,
+    pub destination: Pubkey,
+    pub unlocks_at: i64,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureCaseApproved {
+    pub case: Pubkey,
+    pub proposal: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizureCaseCancelled {
+    pub case: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegalHoldPlaced {
+    pub address: Pubkey,
+    pub reference: [u8; 32],
+    pub placed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscheatmentPolicyUpdated {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub dormancy_period: i64,
+    pub notice_period: i64,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DormantAccountFlagged {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub last_activity: i64,
+    pub escheatable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountEscheated {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub escheated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressTagged {
+    pub address: Pubkey,
+    pub category: AddressCategory,
+    pub label_hash: [u8; 32],
+    pub tagged_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressTagRemoved {
+    pub address: Pubkey,
+    pub removed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegalHoldReleased {
+    pub address: Pubkey,
+    pub released_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackPolicyUpdated {
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    pub dispute_window: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackInitiated {
+    pub case: Pubkey,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub freeze_account: bool,
+    pub dispute_ends_at: i64,
+    pub initiated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackCancelled {
+    pub case: Pubkey,
+    pub target: Pubkey,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClawbackFinalized {
+    pub case: Pubkey,
+    pub target: Pubkey,
+    pub finalized_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub field: String,
+    pub value: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransfersPaused {
+    pub authority: Pubkey,
+    pub reason: PauseReason,
+    pub paused_until: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransfersUnpaused {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesPaused {
+    pub authority: Pubkey,
+    pub reason: PauseReason,
+    pub paused_until: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesUnpaused {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchBlacklistAdded {
+    pub authority: Pubkey,
+    pub count: u16,                      // Entries created or reactivated
+    pub skipped: Vec<Pubkey>,            // Already in force, bad PDA or old layout
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterLockApplied {
+    pub authority: Pubkey,
+    pub flag: u8,
+    pub locks: u8,
+    pub fee_ceiling_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub legacy_config: Pubkey,
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EntryClosed {
+    pub address: Pubkey,
+    pub entry: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycRecordUpdated {
+    pub address: Pubkey,
+    pub tier: KycTier,
+    pub provider: Pubkey,
+    pub expires_at: Option<i64>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycProviderRegistered {
+    pub provider: Pubkey,
+    pub allowed_tiers: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycProviderRemoved {
+    pub provider: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KycPolicyUpdated {
+    pub authority: Pubkey,
+    pub tier_limits: [TierLimits; 3],
+    pub tier_max_balance: [u64; 3],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VelocityLimitsUpdated {
+    pub authority: Pubkey,
+    pub max_daily_volume: u64,
+    pub max_daily_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TravelRuleThresholdUpdated {
+    pub authority: Pubkey,
+    pub travel_rule_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TravelRuleAttached {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub ivms101_hash: [u8; 32],
+    pub originator_vasp: [u8; 32],
+    pub beneficiary_vasp: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemoRequirementUpdated {
+    pub token_account: Pubkey,
+    pub required: bool,
+    pub set_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApprovalThresholdUpdated {
+    pub authority: Pubkey,
+    pub approval_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferApproved {
+    pub approval: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub expires_at: i64,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferApprovalConsumed {
+    pub approval: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CooldownUpdated {
+    pub authority: Pubkey,
+    pub cooldown_seconds: i64,
+    pub cooldown_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferScheduleUpdated {
+    pub authority: Pubkey,
+    pub trading_days: u8,
+    pub open_hour: u8,
+    pub close_hour: u8,
+    pub maintenance_windows: [MaintenanceWindow; MAX_MAINTENANCE_WINDOWS],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeQueued {
+    pub config: Pubkey,
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub activates_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeChangeApplied {
+    pub config: Pubkey,
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeePeriodUpdated {
+    pub config: Pubkey,
+    pub start: i64,
+    pub end: i64,
+    pub fee_bps: u16,
+    pub removed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketMakerUpdated {
+    pub authority: Pubkey,
+    pub owner: Pubkey,
+    pub rebate_bps: u16,
+    pub monthly_cap: u64,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralUpdated {
+    pub authority: Pubkey,
+    pub address: Pubkey,
+    pub referrer: Option<Pubkey>,        // None when unlinked
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralSettled {
+    pub address: Pubkey,
+    pub referrer: Pubkey,
+    pub fees: u64,
+    pub share: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PegFeePolicyUpdated {
+    pub authority: Pubkey,
+    pub oracle: Pubkey,
+    pub enabled: bool,
+    pub max_price_age: i64,
+    pub threshold_bps: u16,
+    pub surcharge_pct: u16,
+    pub max_surcharge_bps: u16,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PegPriceUpdated {
+    pub oracle: Pubkey,
+    pub price: u64,
+    pub deviation_bps: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeTierUpdated {
+    pub authority: Pubkey,
+    pub tier: u8,
+    pub fee_bps: u16,
+    pub max_fee: u64,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VolumeDiscountUpdated {
+    pub authority: Pubkey,
+    pub index: u8,
+    pub min_volume: u64,
+    pub discount_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeExemptProgramAdded {
+    pub authority: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeExemptProgramRemoved {
+    pub authority: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeOverrideUpdated {
+    pub authority: Pubkey,
+    pub address: Pubkey,
+    pub tier: Option<u8>,                // None when removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GlobalBlacklistUpdated {
+    pub issuer: Pubkey,
+    pub address: Pubkey,
+    pub is_active: bool,
+    pub reason_code: ReasonCode,
+    pub reference: Option<[u8; 32]>,
+    pub restriction_level: RestrictionLevel,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferRejected {
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason: RejectionReason,
+    pub attempted_at: i64,               // When the rejected attempt happened
+    pub reported_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExtraAccountMetasSynced {
+    pub mint: Pubkey,
+    pub groups: u32,                     // META_GROUP_* now resolved
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DailyStatsArchived {
+    pub config: Pubkey,
+    pub day: i64,
+    pub transfer_count: u64,
+    pub gross_volume: u64,
+    pub fees: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuditTreeUpdated {
+    pub authority: Pubkey,
+    pub tree: Option<Pubkey>,            // None once the log is disabled
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ForwardHookUpdated {
+    pub authority: Pubkey,
+    pub forward_to_hook: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GatekeeperNetworkUpdated {
+    pub authority: Pubkey,
+    pub network: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RiskOracleUpdated {
+    pub authority: Pubkey,
+    pub program: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SanctionsRootUpdated {
+    pub authority: Pubkey,
+    pub root: [u8; 32],
+    pub version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RelayerUpdated {
+    pub authority: Pubkey,
+    pub relayer: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowanceUpdated {
+    pub owner: Pubkey,
+    pub spender: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllowanceSpent {
+    pub owner: Pubkey,
+    pub spender: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SponsoredTransferExecuted {
+    pub relayer: Pubkey,
+    pub owner: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub fee: u64,                        // Paid to the relayer out of the source
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+// ============ HELPERS ============
+
+/// Fee Token-2022 withholds for `amount` if the mint has the TransferFee extension
+pub fn withheld_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<Option<u64>> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint2022>::unpack(&data)?;
+    match mint_state.get_extension::<TransferFeeConfig>() {
+        Ok(fee_config) => {
+            let fee = fee_config
+                .calculate_epoch_fee(Clock::get()?.epoch, amount)
+                .ok_or(TransferHookError::MathOverflow)?;
+            Ok(Some(fee))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Whether the mint has the TransferFee extension, i.e. a fee to shape
+pub fn has_transfer_fee(mint: &AccountInfo) -> Result<bool> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint2022>::unpack(&data)?;
+    Ok(mint_state.get_extension::<TransferFeeConfig>().is_ok())
+}
+
+/// Token-2022 sets the TransferHookAccount `transferring` flag on both token
+/// accounts only for the duration of the hook CPI, so a direct invocation fails here.
+pub fn assert_is_transferring(token_account: &AccountInfo) -> Result<()> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<Account2022>::unpack(&data)?;
+    let extension = account
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| TransferHookError::NotTransferring)?;
+    require!(bool::from(extension.transferring), TransferHookError::NotTransferring);
+    Ok(())
+}
+
+/// Optional groups of extra account metas, each needed only while its feature
+/// is in use. Metas of a group left out of `extra_account_metas` resolve to
+/// the hook program, which every hooked transfer already carries, so they add
+/// no account to the transaction. The handler fails with
+/// StaleExtraAccountMetas if a feature comes into use before the list is synced.
+pub const META_GROUP_BLACKLIST: u32 = 1 << 0;      // [6], [7], [12]-[14]; blacklist enabled without the bloom filter
+pub const META_GROUP_BLOOM: u32 = 1 << 1;          // [49]; config.bloom_enabled
+pub const META_GROUP_KYC: u32 = 1 << 2;            // [15]-[17]; KYC policy exists
+pub const META_GROUP_POLICY: u32 = 1 << 3;         // [18]-[21], [24], [29]; transfer policy or fee schedule exists
+pub const META_GROUP_FEE_TIERS: u32 = 1 << 4;      // [22], [23]; fee tiers exist
+pub const META_GROUP_FEE_EXEMPT: u32 = 1 << 5;     // [25], [26]; fee exempt programs exist
+pub const META_GROUP_RISK: u32 = 1 << 6;           // [32], [33]; risk oracle enabled
+pub const META_GROUP_FORWARD: u32 = 1 << 7;        // [34]-[36]; secondary hook set
+pub const META_GROUP_STATS: u32 = 1 << 8;          // [37]; hook stats exist
+pub const META_GROUP_GATEWAY: u32 = 1 << 9;        // [38]-[41]; gatekeeper enabled
+pub const META_GROUP_AUDIT: u32 = 1 << 10;         // [45]-[48]; audit tree set
+pub const META_GROUP_ESCHEAT: u32 = 1 << 11;       // [44], [56], [57]; escheatment policy or activity records exist
+pub const META_GROUP_CLAWBACK: u32 = 1 << 12;      // [27]; a clawback was initiated
+pub const META_GROUP_LEGAL_HOLD: u32 = 1 << 13;    // [28]; a legal hold was placed
+pub const META_GROUP_MEMO: u32 = 1 << 14;          // [31]; a memo requirement was set
+pub const META_GROUP_ADDRESS_TAGS: u32 = 1 << 15;  // [42], [43]; an address was tagged
+pub const META_GROUP_PENDING_FEE: u32 = 1 << 16;   // [50]; pending fee change exists
+pub const META_GROUP_FEE_PERIODS: u32 = 1 << 17;   // [51]; fee periods exist
+pub const META_GROUP_REBATE: u32 = 1 << 18;        // [52]; a market maker was registered
+pub const META_GROUP_REFERRAL: u32 = 1 << 19;      // [53]; a referral was linked
+pub const META_GROUP_PEG_FEE: u32 = 1 << 20;       // [54]; peg fee policy exists
+pub const META_GROUP_FEE_CREDIT: u32 = 1 << 21;    // [55]; a fee credit account was opened
+
+/// Groups set up by an instruction rather than a config flag, tracked in
+/// `meta_groups_unused` and `meta_groups_unsynced`
+pub const FEATURE_META_GROUPS: u32 = META_GROUP_KYC
+    | META_GROUP_POLICY
+    | META_GROUP_FEE_TIERS
+    | META_GROUP_FEE_EXEMPT
+    | META_GROUP_RISK
+    | META_GROUP_FORWARD
+    | META_GROUP_STATS
+    | META_GROUP_GATEWAY
+    | META_GROUP_AUDIT
+    | META_GROUP_ESCHEAT
+    | META_GROUP_CLAWBACK
+    | META_GROUP_LEGAL_HOLD
+    | META_GROUP_MEMO
+    | META_GROUP_ADDRESS_TAGS
+    | META_GROUP_PENDING_FEE
+    | META_GROUP_FEE_PERIODS
+    | META_GROUP_REBATE
+    | META_GROUP_REFERRAL
+    | META_GROUP_PEG_FEE
+    | META_GROUP_FEE_CREDIT;
+
+/// Groups of per-owner records, which sync_extra_account_meta_list can't
+/// enumerate: once set up they stay in the list
+pub const RECORD_META_GROUPS: u32 = META_GROUP_ESCHEAT
+    | META_GROUP_CLAWBACK
+    | META_GROUP_LEGAL_HOLD
+    | META_GROUP_MEMO
+    | META_GROUP_ADDRESS_TAGS
+    | META_GROUP_REBATE
+    | META_GROUP_REFERRAL
+    | META_GROUP_FEE_CREDIT;
+
+/// Fail with StaleExtraAccountMetas if any of `infos` is a stand-in for a
+/// group the synced list left out although its feature is now in use
+pub fn require_synced(infos: &[&AccountInfo]) -> Result<()> {
+    require!(
+        infos.iter().all(|info| info.key != &crate::ID),
+        TransferHookError::StaleExtraAccountMetas
+    );
+    Ok(())
+}
+
+/// `meta` if `group` is in `groups`, otherwise the hook program as a stand-in
+fn gated_meta(groups: u32, group: u32, meta: ExtraAccountMeta) -> Result<ExtraAccountMeta> {
+    if groups & group != 0 {
+        Ok(meta)
+    } else {
+        Ok(ExtraAccountMeta::new_with_pubkey(&crate::ID, false, false)?)
+    }
+}
+
+/// Meta groups that follow from the config's own flags
+pub fn config_meta_groups(config: &TransferHookConfig) -> u32 {
+    let mut groups = 0;
+    // Behind the bloom filter, possible hits are screened by screen_blacklist
+    // instead of through blacklist PDAs on the transfer
+    if config.blacklist_enabled() && !config.bloom_enabled() {
+        groups |= META_GROUP_BLACKLIST;
+    }
+    if config.bloom_enabled() {
+        groups |= META_GROUP_BLOOM;
+    }
+    groups
+}
+
+/// Extra accounts Token-2022 resolves for every Execute call, with the
+/// optional `groups` in use. Indices refer to the Execute account list:
+/// 0 source, 1 mint, 2 destination, 3 authority, 4 extra-account-metas, then
+/// these extras starting at 5. Only the config, the whitelist entries, the
+/// base program's state and the instructions sysvar are always resolved;
+/// everything else belongs to a group, so a transfer of a mint using none of them fits a legacy
+/// transaction. Only per-owner records and the escheatment policy are
+/// writable, so transfers don't contend on any other account shared by the
+/// whole mint.
+pub fn extra_account_metas(groups: u32) -> Result<Vec<ExtraAccountMeta>> {
+    // Owner field of a token account: bytes 32..64
+    let source_owner = Seed::AccountData { account_index: 0, data_index: 32, length: 32 };
+    let destination_owner = Seed::AccountData { account_index: 2, data_index: 32, length: 32 };
+    let config = Seed::AccountKey { index: 5 };
+    
+    Ok(vec![
+        // [5] config — ["hook_config", mint]
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal { bytes: b"hook_config".to_vec() }, Seed::AccountKey { index: 1 }],
+            false,
+            false,
+        )?,
+        // [6] source blacklist — ["blacklist", config, source owner]
+        gated_meta(
+            groups,
+            META_GROUP_BLACKLIST,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist".to_vec() }, config.clone(), source_owner.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [7] destination blacklist — ["blacklist", config, destination owner]
+        gated_meta(
+            groups,
+            META_GROUP_BLACKLIST,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist".to_vec() }, config.clone(), destination_owner.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [8] source whitelist — ["whitelist", config, source owner]
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal { bytes: b"whitelist".to_vec() }, config.clone(), source_owner.clone()],
+            false,
+            false,
+        )?,
+        // [9] destination whitelist — ["whitelist", config, destination owner]
+        ExtraAccountMeta::new_with_seeds(
+            &[Seed::Literal { bytes: b"whitelist".to_vec() }, config.clone(), destination_owner.clone()],
+            false,
+            false,
+        )?,
+        // [10] sss_token base program
+        ExtraAccountMeta::new_with_pubkey(&sss_token_program::ID, false, false)?,
+        // [11] StablecoinState — ["stablecoin", mint] owned by the base program
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10,
+            &[Seed::Literal { bytes: b"stablecoin".to_vec() }, Seed::AccountKey { index: 1 }],
+            false,
+            false,
+        )?,
+        // [12] source token account blacklist — ["blacklist", config, source account]
+        gated_meta(
+            groups,
+            META_GROUP_BLACKLIST,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist".to_vec() }, config.clone(), Seed::AccountKey { index: 0 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [13] destination token account blacklist — ["blacklist", config, destination account]
+        gated_meta(
+            groups,
+            META_GROUP_BLACKLIST,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist".to_vec() }, config.clone(), Seed::AccountKey { index: 2 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [14] transfer authority blacklist — ["blacklist", config, authority]
+        gated_meta(
+            groups,
+            META_GROUP_BLACKLIST,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist".to_vec() }, config.clone(), Seed::AccountKey { index: 3 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [15] KYC policy — ["kyc_policy", config]
+        gated_meta(
+            groups,
+            META_GROUP_KYC,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"kyc_policy".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [16] source KYC record — ["kyc", config, source owner]; writable for daily volume
+        gated_meta(
+            groups,
+            META_GROUP_KYC,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"kyc".to_vec() }, config.clone(), source_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [17] destination KYC record — ["kyc", config, destination owner]
+        gated_meta(
+            groups,
+            META_GROUP_KYC,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"kyc".to_vec() }, config.clone(), destination_owner.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [18] transfer policy — ["transfer_policy", config]
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"transfer_policy".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [19] source velocity tracker — ["velocity", config, source owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"velocity".to_vec() }, config.clone(), source_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [20] transfer approval — ["approval", config, source owner, destination owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"approval".to_vec() },
+                    config.clone(),
+                    source_owner.clone(),
+                    destination_owner.clone(),
+                ],
+                false,
+                true,
+            )?,
+        )?,
+        // [21] destination velocity tracker — ["velocity", config, destination owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"velocity".to_vec() }, config.clone(), destination_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [22] fee tiers — ["fee_tiers", config]
+        gated_meta(
+            groups,
+            META_GROUP_FEE_TIERS,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_tiers".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [23] source fee override — ["fee_override", config, source owner]
+        gated_meta(
+            groups,
+            META_GROUP_FEE_TIERS,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_override".to_vec() }, config.clone(), source_owner.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [24] fee tier schedule — ["fee_schedule", config]
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_schedule".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [25] source owner — the address stored in the source token account
+        gated_meta(
+            groups,
+            META_GROUP_FEE_EXEMPT,
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData { account_index: 0, data_index: 32 },
+                false,
+                false,
+            )?,
+        )?,
+        // [26] fee exempt programs — ["fee_exempt_programs", config]
+        gated_meta(
+            groups,
+            META_GROUP_FEE_EXEMPT,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_exempt_programs".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [27] source clawback hold — ["clawback", config, source token account]
+        gated_meta(
+            groups,
+            META_GROUP_CLAWBACK,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"clawback".to_vec() }, config.clone(), Seed::AccountKey { index: 0 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [28] source legal hold — ["legal_hold", config, source owner]
+        gated_meta(
+            groups,
+            META_GROUP_LEGAL_HOLD,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"legal_hold".to_vec() }, config.clone(), source_owner.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [29] travel-rule record — ["travel_rule", config, source owner, destination owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_POLICY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"travel_rule".to_vec() }, config.clone(), source_owner.clone(), destination_owner],
+                false,
+                true,
+            )?,
+        )?,
+        // [30] instructions sysvar, for memo introspection
+        ExtraAccountMeta::new_with_pubkey(
+            &anchor_lang::solana_program::sysvar::instructions::ID,
+            false,
+            false,
+        )?,
+        // [31] destination memo requirement — ["memo_required", config, destination token account]
+        gated_meta(
+            groups,
+            META_GROUP_MEMO,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"memo_required".to_vec() }, config.clone(), Seed::AccountKey { index: 2 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [32] risk oracle — ["risk_oracle", config]
+        gated_meta(
+            groups,
+            META_GROUP_RISK,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"risk_oracle".to_vec() }, config.clone()],
+                false,
+                false,
+            )?,
+        )?,
+        // [33] risk program — the program stored in the risk oracle
+        gated_meta(
+            groups,
+            META_GROUP_RISK,
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData { account_index: 32, data_index: RISK_ORACLE_PROGRAM_OFFSET },
+                false,
+                false,
+            )?,
+        )?,
+        // [34] secondary hook slot — ["forward_hook", config]
+        gated_meta(
+            groups,
+            META_GROUP_FORWARD,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"forward_hook".to_vec() }, config],
+                false,
+                false,
+            )?,
+        )?,
+        // [35] secondary hook program — the program stored in the slot
+        gated_meta(
+            groups,
+            META_GROUP_FORWARD,
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData { account_index: 34, data_index: FORWARD_HOOK_PROGRAM_OFFSET },
+                false,
+                false,
+            )?,
+        )?,
+        // [36] secondary hook's validation account — ["extra-account-metas", mint] under [35]
+        gated_meta(
+            groups,
+            META_GROUP_FORWARD,
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                35,
+                &[Seed::Literal { bytes: b"extra-account-metas".to_vec() }, Seed::AccountKey { index: 1 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [37] stats shard — ["stats_shard", config, first byte of source owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_STATS,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"stats_shard".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 1 },
+                ],
+                false,
+                true,
+            )?,
+        )?,
+        // [38] gatekeeper config — ["gatekeeper", config]
+        gated_meta(
+            groups,
+            META_GROUP_GATEWAY,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"gatekeeper".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [39] gateway program
+        gated_meta(
+            groups,
+            META_GROUP_GATEWAY,
+            ExtraAccountMeta::new_with_pubkey(&solana_gateway::ID, false, false)?,
+        )?,
+        // [40] source gateway token — [source owner, "gateway", [0; 8], network] under [39]
+        gated_meta(
+            groups,
+            META_GROUP_GATEWAY,
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                39,
+                &[
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+                    Seed::Literal { bytes: b"gateway".to_vec() },
+                    Seed::Literal { bytes: vec![0; 8] },
+                    Seed::AccountData { account_index: 38, data_index: GATEKEEPER_NETWORK_OFFSET, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+        )?,
+        // [41] destination gateway token — [destination owner, "gateway", [0; 8], network] under [39]
+        gated_meta(
+            groups,
+            META_GROUP_GATEWAY,
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                39,
+                &[
+                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
+                    Seed::Literal { bytes: b"gateway".to_vec() },
+                    Seed::Literal { bytes: vec![0; 8] },
+                    Seed::AccountData { account_index: 38, data_index: GATEKEEPER_NETWORK_OFFSET, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+        )?,
+        // [42] source address tag — ["address_tag", config, source owner]
+        gated_meta(
+            groups,
+            META_GROUP_ADDRESS_TAGS,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"address_tag".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+        )?,
+        // [43] destination address tag — ["address_tag", config, destination owner]
+        gated_meta(
+            groups,
+            META_GROUP_ADDRESS_TAGS,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"address_tag".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
+                ],
+                false,
+                false,
+            )?,
+        )?,
+        // [44] source activity record — ["activity", config, source token account]; writable
+        gated_meta(
+            groups,
+            META_GROUP_ESCHEAT,
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"activity".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountKey { index: 0 },
+                ],
+                false,
+                true,
+            )?,
+        )?,
+        // [45] audit log — ["audit_log", config]; signs appends as tree authority
+        gated_meta(
+            groups,
+            META_GROUP_AUDIT,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"audit_log".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [46] audit tree — the tree stored in [45]; writable
+        gated_meta(
+            groups,
+            META_GROUP_AUDIT,
+            ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData { account_index: 45, data_index: AUDIT_LOG_TREE_OFFSET },
+                false,
+                true,
+            )?,
+        )?,
+        // [47] account compression program
+        gated_meta(
+            groups,
+            META_GROUP_AUDIT,
+            ExtraAccountMeta::new_with_pubkey(&spl_account_compression::ID, false, false)?,
+        )?,
+        // [48] noop program
+        gated_meta(
+            groups,
+            META_GROUP_AUDIT,
+            ExtraAccountMeta::new_with_pubkey(&spl_noop::ID, false, false)?,
+        )?,
+        // [49] blacklist bloom filter — ["blacklist_bloom", config]
+        gated_meta(
+            groups,
+            META_GROUP_BLOOM,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"blacklist_bloom".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [50] pending fee change — ["pending_fee", config]
+        gated_meta(
+            groups,
+            META_GROUP_PENDING_FEE,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"pending_fee".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [51] fee periods — ["fee_periods", config]
+        gated_meta(
+            groups,
+            META_GROUP_FEE_PERIODS,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_periods".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [52] source market maker rebate — ["rebate", config, source owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_REBATE,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"rebate".to_vec() }, Seed::AccountKey { index: 5 }, source_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [53] source referral link — ["referral", config, source owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_REFERRAL,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"referral".to_vec() }, Seed::AccountKey { index: 5 }, source_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [54] peg fee policy — ["peg_fee", config]
+        gated_meta(
+            groups,
+            META_GROUP_PEG_FEE,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"peg_fee".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                false,
+            )?,
+        )?,
+        // [55] source fee credit — ["fee_credit", config, source owner]; writable
+        gated_meta(
+            groups,
+            META_GROUP_FEE_CREDIT,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"fee_credit".to_vec() }, Seed::AccountKey { index: 5 }, source_owner.clone()],
+                false,
+                true,
+            )?,
+        )?,
+        // [56] escheatment policy — ["escheat_policy", config]; writable, to pay
+        // the rent of activity records started by a transfer
+        gated_meta(
+            groups,
+            META_GROUP_ESCHEAT,
+            ExtraAccountMeta::new_with_seeds(
+                &[Seed::Literal { bytes: b"escheat_policy".to_vec() }, Seed::AccountKey { index: 5 }],
+                false,
+                true,
+            )?,
+        )?,
+        // [57] system program, to create activity records
+        gated_meta(
+            groups,
+            META_GROUP_ESCHEAT,
+            ExtraAccountMeta::new_with_pubkey(&anchor_lang::system_program::ID, false, false)?,
+        )?,
+    ])
+}
+
+/// Create a token account's ActivityRecord on its first hooked transfer. The
+/// rent comes from lamports deposited on the escheatment policy above its own
+/// rent exemption; without an enabled, funded policy the account stays
+/// untracked (init_activity_record can still start it).
+pub fn start_activity_record<'info>(
+    activity: &AccountInfo<'info>,
+    policy_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Pubkey,
+    token_account: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    if !activity.is_writable || !activity.data_is_empty() || activity.owner != &anchor_lang::system_program::ID {
+        return Ok(());
+    }
+    let Some(policy) = load_entry::<EscheatmentPolicy>(policy_info)? else { return Ok(()) };
+    if !policy.enabled || policy.config != *config {
+        return Ok(());
+    }
+    require!(policy_info.is_writable, TransferHookError::StaleExtraAccountMetas);
+    require_keys_eq!(system_program.key(), anchor_lang::system_program::ID, ErrorCode::InvalidProgramId);
+    
+    let rent = Rent::get()?;
+    let space = ACTIVITY_RECORD_LEN;
+    let cost = rent.minimum_balance(space).saturating_sub(activity.lamports());
+    let surplus = policy_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(policy_info.data_len()));
+    if surplus < cost {
+        return Ok(());
+    }
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"activity", config.as_ref(), token_account.as_ref()],
+        &crate::ID,
+    );
+    require!(activity.key() == expected, TransferHookError::InvalidListAccount);
+    **policy_info.try_borrow_mut_lamports()? -= cost;
+    **activity.try_borrow_mut_lamports()? += cost;
+    
+    let seeds: &[&[&[u8]]] = &[&[b"activity", config.as_ref(), token_account.as_ref(), &[bump]]];
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate { account_to_allocate: activity.clone() },
+            seeds,
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign { account_to_assign: activity.clone() },
+            seeds,
+        ),
+        &crate::ID,
+    )?;
+    
+    store_entry(activity, &ActivityRecord {
+        config: *config,
+        token_account: *token_account,
+        last_activity: now,
+        flagged_at: None,
+        bump,
+    })
+}
+
+/// Whether `info` is an active, unexpired gateway token for `owner` on the
+/// accepted gatekeeper network. Anything else, including an uninitialized
+/// PDA or a disabled gatekeeper config, is simply "no pass".
+pub fn has_gateway_pass(
+    gatekeeper: Option<&GatekeeperConfig>,
+    info: &AccountInfo,
+    owner: &Pubkey,
+    now: i64,
+) -> Result<bool> {
+    let Some(gatekeeper) = gatekeeper.filter(|g| g.enabled) else { return Ok(false) };
+    if info.owner != &solana_gateway::ID || info.data_is_empty() {
+        return Ok(false);
+    }
+    let data = info.try_borrow_data()?;
+    let Ok(token) = solana_gateway::GatewayToken::deserialize(&mut &data[..]) else {
+        return Ok(false);
+    };
+    Ok(token.owner_wallet == *owner
+        && token.gatekeeper_network == gatekeeper.network
+        && token.state == solana_gateway::GatewayTokenState::Active
+        && token.expire_time.map_or(true, |expiry| now < expiry))
+}
+
+/// Category of the address tag PDA in `info`, if one exists for `address`
+pub fn address_category(info: &AccountInfo, address: &Pubkey) -> Result<Option<AddressCategory>> {
+    let Some(tag) = load_entry::<AddressTag>(info)? else { return Ok(None) };
+    require!(tag.address == *address, TransferHookError::ListEntryMismatch);
+    Ok(Some(tag.category))
+}
+
+/// Deserialize a list entry PDA, treating an uninitialized account as "no entry".
+/// Token-2022 always passes the derived PDA, whether or not it exists.
+pub fn load_entry<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+    info: &AccountInfo,
+) -> Result<Option<T>> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
+
+/// Deserialize the address and flag of a legacy sss2_hook list entry, if `info` holds one.
+fn load_legacy_entry(info: &AccountInfo, discriminator: &[u8]) -> Result<Option<(Pubkey, bool)>> {
+    if info.owner != &crate::ID || info.data_len() != LEGACY_ENTRY_LEN {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    if data[..8] != *discriminator {
+        return Ok(None);
+    }
+    // Both legacy layouts are address + flag + bump
+    let entry = LegacyBlacklistEntry::deserialize(&mut &data[8..])?;
+    Ok(Some((entry.address, entry.is_blacklisted)))
+}
+
+/// Record a newly blacklisted address in the bloom filter. Fails if the
+/// config relies on the filter but it was not passed.
+pub fn bloom_insert(
+    config: &mut TransferHookConfig,
+    bloom: Option<&AccountLoader<BlacklistBloom>>,
+    address: &Pubkey,
+) -> Result<()> {
+    config.note_blacklist_write();
+    match bloom {
+        Some(bloom) => {
+            bloom.load_mut()?.insert(address);
+            Ok(())
+        }
+        None => {
+            require!(!config.bloom_enabled(), TransferHookError::BloomFilterRequired);
+            Ok(())
+        }
+    }
+}
+
+/// Address a blacklist PDA was written for, in any of its layouts
+pub fn blacklist_entry_address(info: &AccountInfo) -> Result<Option<(Pubkey, bool)>> {
+    if let Some((address, _)) = load_legacy_entry(info, &BlacklistEntry::DISCRIMINATOR)? {
+        return Ok(Some((address, false)));
+    }
+    if let Some(v1) = load_blacklist_entry_v1(info)? {
+        return Ok(Some((v1.address, false)));
+    }
+    Ok(load_entry::<BlacklistEntry>(info)?.map(|e| (e.address, e.listed)))
+}
+
+/// Deserialize a blacklist entry still in the pre-reason-code layout, if `info` holds one.
+pub fn load_blacklist_entry_v1(info: &AccountInfo) -> Result<Option<BlacklistEntryV1>> {
+    if info.owner != &crate::ID || info.data_len() != BLACKLIST_ENTRY_V1_LEN {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    if data[..8] != BlacklistEntry::DISCRIMINATOR {
+        return Ok(None);
+    }
+    Ok(Some(BlacklistEntryV1::deserialize(&mut &data[8..])?))
+}
+
+impl From<BlacklistEntryV1> for BlacklistEntry {
+    /// Free-form reasons map to `Other`, keeping a hash of the text as reference
+    fn from(v1: BlacklistEntryV1) -> Self {
+        let reference = (!v1.reason.is_empty())
+            .then(|| keccak::hash(v1.reason.as_bytes()).to_bytes());
+        Self {
+            address: v1.address,
+            reason_code: ReasonCode::Other,
+            reference,
+            blacklisted_by: v1.blacklisted_by,
+            created_at: v1.created_at,
+            is_active: v1.is_active,
+            bump: v1.bump,
+            entry_kind: v1.entry_kind,
+            expires_at: v1.expires_at,
+            restriction_level: v1.restriction_level,
+            listed: false,
+        }
+    }
+}
+
+/// Deserialize a config still in the Borsh layout, if `info` holds one.
+pub fn load_transfer_hook_config_v2(info: &AccountInfo) -> Result<Option<TransferHookConfigV2>> {
+    if info.owner != &crate::ID || info.data_len() != TRANSFER_HOOK_CONFIG_V2_LEN {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    if data[..8] != TransferHookConfig::DISCRIMINATOR {
+        return Ok(None);
+    }
+    Ok(Some(TransferHookConfigV2::deserialize(&mut &data[8..])?))
+}
+
+impl From<TransferHookConfigV2> for TransferHookConfig {
+    fn from(v2: TransferHookConfigV2) -> Self {
+        Self {
+            stablecoin: v2.stablecoin,
+            authority: v2.authority,
+            permanent_delegate: v2.permanent_delegate.unwrap_or_default(),
+            redemption_treasury: v2.redemption_treasury.unwrap_or_default(),
+            max_transfer_fee: v2.max_transfer_fee,
+            min_transfer_amount: v2.min_transfer_amount,
+            total_fees_collected: v2.total_fees_collected,
+            max_balance: v2.max_balance,
+            paused_until: v2.paused_until.unwrap_or(0),
+            fees_paused_until: v2.fees_paused_until.unwrap_or(0),
+            blacklist_epoch: v2.blacklist_epoch,
+            transfer_fee_basis_points: v2.transfer_fee_basis_points,
+            fee_ceiling_bps: v2.fee_ceiling_bps,
+            is_paused: u8::from(v2.is_paused),
+            blacklist_enabled: u8::from(v2.blacklist_enabled),
+            bump: v2.bump,
+            locks: v2.locks,
+            version: CONFIG_VERSION,
+            whitelist_required: u8::from(v2.whitelist_required),
+            pause_reason: v2.pause_reason as u8,
+            fees_paused: u8::from(v2.fees_paused),
+            bloom_enabled: u8::from(v2.bloom_enabled),
+            _padding: [0; 7],
+            meta_groups_unused: 0,
+            meta_groups_unsynced: 0,
+            _reserved: [0; 6],
+        }
+    }
+}
+
+/// Restriction `owner` is under at `now` according to a blacklist PDA, in
+/// either the current or the legacy sss2_hook layout. Legacy entries block.
+pub fn blacklist_restriction(
+    info: &AccountInfo,
+    owner: &Pubkey,
+    now: i64,
+) -> Result<Option<RestrictionLevel>> {
+    let entry = match load_legacy_entry(info, &BlacklistEntry::DISCRIMINATOR)? {
+        Some((address, active)) => Some((address, active.then_some(RestrictionLevel::Blocked))),
+        None => match load_blacklist_entry_v1(info)? {
+            Some(v1) => Some((v1.address, BlacklistEntry::from(v1).restriction(now))),
+            None => load_entry::<BlacklistEntry>(info)?.map(|e| (e.address, e.restriction(now))),
+        },
+    };
+    let Some((address, restriction)) = entry else { return Ok(None) };
+    require!(address == *owner, TransferHookError::ListEntryMismatch);
+    Ok(restriction)
+}
+
+/// The whitelist type of `owner`'s whitelist PDA, if any, in either the
+/// current or the legacy sss2_hook layout. Legacy entries predate
+/// FullBypass and are treated as FeeExempt.
+pub fn whitelist_type(info: &AccountInfo, owner: &Pubkey) -> Result<Option<WhitelistType>> {
+    let entry = match load_legacy_entry(info, &WhitelistEntry::DISCRIMINATOR)? {
+        Some((address, listed)) => listed.then_some((address, WhitelistType::FeeExempt)),
+        None => load_entry::<WhitelistEntry>(info)?.map(|e| (e.address, e.whitelist_type)),
+    };
+    let Some((address, whitelist_type)) = entry else { return Ok(None) };
+    require!(address == *owner, TransferHookError::ListEntryMismatch);
+    Ok(Some(whitelist_type))
+}
+
+/// Bring an entry's `listed` flag in line with `active`, counting the address
+/// in the registry and recording it on an index page. Without the registry a
+/// new address simply stays uncounted; unlisting a counted one needs both
+/// accounts so the count never drifts.
+pub fn sync_list_membership(
+    listed: &mut bool,
+    active: bool,
+    kind: u8,
+    address: Pubkey,
+    registry: Option<&mut ListRegistry>,
+    page: Option<&mut ListIndexPage>,
+) -> Result<()> {
+    if *listed == active {
+        return Ok(());
+    }
+    let (registry, page) = match (registry, page) {
+        (Some(registry), Some(page)) => (registry, page),
+        _ if active => return Ok(()),
+        _ => return err!(TransferHookError::InvalidListIndex),
+    };
+    require!(page.kind == kind, TransferHookError::InvalidListIndex);
+    let count = if kind == LIST_BLACKLIST {
+        &mut registry.blacklist_count
+    } else {
+        &mut registry.whitelist_count
+    };
+    if active {
+        require!(page.addresses.len() < LIST_INDEX_PAGE_SIZE, TransferHookError::InvalidListIndex);
+        page.addresses.push(address);
+        *count = count.checked_add(1).ok_or(TransferHookError::MathOverflow)?;
+    } else {
+        let position = page.addresses.iter().position(|a| *a == address)
+            .ok_or(TransferHookError::InvalidListIndex)?;
+        page.addresses.swap_remove(position);
+        *count = count.saturating_sub(1);
+    }
+    *listed = active;
+    Ok(())
+}
+
+/// Create or reactivate one blacklist PDA for `batch_blacklist`.
+/// Returns `Ok(false)` when the entry is skipped rather than failing the batch.
+#[allow(clippy::too_many_arguments)]
+pub fn write_batch_blacklist_entry<'info>(
+    entry_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    config: &Pubkey,
+    authority: &Pubkey,
+    item: &BatchBlacklistItem,
+    now: i64,
+    registry: Option<&mut ListRegistry>,
+    page: Option<&mut ListIndexPage>,
+) -> Result<bool> {
+    let space = BLACKLIST_ENTRY_LEN;
+    
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"blacklist", config.as_ref(), item.address.as_ref()],
+        &crate::ID,
+    );
+    if entry_info.key() != expected || !entry_info.is_writable {
+        return Ok(false);
+    }
+    
+    let mut entry = BlacklistEntry {
+        address: item.address,
+        reason_code: item.reason_code,
+        reference: item.reference,
+        blacklisted_by: *authority,
+        created_at: now,
+        is_active: true,
+        bump: 0,
+        entry_kind: item.entry_kind,
+        expires_at: item.expires_at,
+        restriction_level: item.restriction_level,
+        listed: false,
+    };
+    if entry_info.owner == &crate::ID {
+        // Existing entry: rewrite it unless still in force or in an older layout
+        if entry_info.data_len() != space
+            || blacklist_restriction(entry_info, &item.address, now)?.is_some()
+        {
+            return Ok(false);
+        }
+        entry.listed = load_entry::<BlacklistEntry>(entry_info)?.map(|e| e.listed).unwrap_or(false);
+    } else if entry_info.lamports() == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: entry_info.clone(),
+                },
+                &[&[b"blacklist", config.as_ref(), item.address.as_ref(), &[bump]]],
+            ),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        return Ok(false);
+    }
+    sync_list_membership(&mut entry.listed, true, LIST_BLACKLIST, item.address, registry, page)?;
+    
+    let mut data = entry_info.try_borrow_mut_data()?;
+    entry.try_serialize(&mut &mut data[..])?;
+    Ok(true)
+}
+
+/// Copy a zero-copy account out of `info`, if it holds one
+pub fn load_zero_copy<T: anchor_lang::ZeroCopy + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &T::owner() || info.data_is_empty() {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    let end = 8 + std::mem::size_of::<T>();
+    require!(data.len() >= end, ErrorCode::AccountDidNotDeserialize);
+    require!(data[..8] == T::DISCRIMINATOR, ErrorCode::AccountDiscriminatorMismatch);
+    Ok(Some(*bytemuck::from_bytes::<T>(&data[8..end])))
+}
+
+/// `store_entry` for zero-copy accounts
+pub fn store_zero_copy<T: anchor_lang::ZeroCopy>(info: &AccountInfo, entry: &T) -> Result<()> {
+    if !info.is_writable {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    data[8..8 + std::mem::size_of::<T>()].copy_from_slice(bytemuck::bytes_of(entry));
+    Ok(())
+}
+
+/// Write an updated entry back to its account. Read-only accounts are left
+/// untouched, so `precheck_transfer` runs the hook's checks without recording
+/// anything; the hook's own accounts are `mut`, so the hook always records.
+pub fn store_entry<T: AccountSerialize>(info: &AccountInfo, entry: &T) -> Result<()> {
+    if !info.is_writable {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    entry.try_serialize(&mut &mut data[..])
+}
+
+/// Enforce KYC tier limits for a transfer and record the source's daily volume.
+/// Returns the destination's tier; no-op when the config has no KycPolicy.
+pub fn enforce_kyc_limits(
+    policy: Option<&KycPolicy>,
+    source_kyc: &AccountInfo,
+    destination_kyc: &AccountInfo,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<KycTier> {
+    let Some(policy) = policy else { return Ok(KycTier::None) };
+    
+    let mut source_record = load_entry::<KycRecord>(source_kyc)?;
+    let destination_record = load_entry::<KycRecord>(destination_kyc)?;
+    if let Some(record) = &source_record {
+        require!(record.address == *source_owner, TransferHookError::ListEntryMismatch);
+    }
+    if let Some(record) = &destination_record {
+        require!(record.address == *destination_owner, TransferHookError::ListEntryMismatch);
+    }
+    
+    let source_limits = policy.limits(
+        source_record.as_ref().map(|r| r.tier_at(now)).unwrap_or(KycTier::None),
+    );
+    let destination_tier = destination_record.as_ref().map(|r| r.tier_at(now)).unwrap_or(KycTier::None);
+    let destination_limits = policy.limits(destination_tier);
+    for limit in [source_limits.max_per_transfer, destination_limits.max_per_transfer] {
+        require!(limit == 0 || amount <= limit, TransferHookError::KycTransferLimitExceeded);
+    }
+    
+    // Daily volume lives on the source record
+    if let Some(record) = source_record.as_mut() {
+        let day = now.div_euclid(SECONDS_PER_DAY);
+        if record.day != day {
+            record.day = day;
+            record.day_volume = 0;
+        }
+        record.day_volume = record.day_volume
+            .checked_add(amount)
+            .ok_or(TransferHookError::MathOverflow)?;
+        require!(
+            source_limits.max_per_day == 0 || record.day_volume <= source_limits.max_per_day,
+            TransferHookError::KycDailyLimitExceeded
+        );
+        store_entry(source_kyc, record)?;
+    }
+    
+    Ok(destination_tier)
+}
+
+/// Enforce rolling 24h velocity limits for the source owner.
+/// No-op unless a TransferPolicy with velocity limits exists.
+pub fn enforce_velocity_limits(
+    policy: Option<&TransferPolicy>,
+    track_volume: bool,
+    tracker_info: &AccountInfo,
+    source_owner: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<Option<u64>> {
+    let limits = policy.filter(|p| p.velocity_limited());
+    if limits.is_none() && !track_volume {
+        return Ok(None);
+    }
+    // Without velocity limits a missing tracker only forfeits the discount
+    let Some(mut tracker) = load_zero_copy::<VelocityTracker>(tracker_info)? else {
+        require!(limits.is_none(), TransferHookError::VelocityTrackerMissing);
+        return Ok(None);
+    };
+    let owner = tracker.owner;
+    require!(owner == *source_owner, TransferHookError::ListEntryMismatch);
+    
+    let (volume, count) = tracker.record(now, amount)?;
+    if let Some(policy) = limits {
+        require!(
+            policy.max_daily_volume == 0 || volume <= policy.max_daily_volume,
+            TransferHookError::DailyVolumeExceeded
+        );
+        require!(
+            policy.max_daily_count == 0 || count <= policy.max_daily_count,
+            TransferHookError::DailyTransferCountExceeded
+        );
+    }
+    // Discounts are earned by prior volume, not by the transfer being priced
+    let prior_volume = tracker
+        .monthly_volume()?
+        .checked_sub(amount)
+        .ok_or(TransferHookError::MathOverflow)?;
+    
+    store_zero_copy(tracker_info, &tracker)?;
+    Ok(Some(prior_volume))
+}
+
+/// Fee rate (bps, max fee) for a source owner: its override's tier when
+/// assigned and active, otherwise the global rate in force.
+pub fn fee_rate(
+    global: (u16, u64),
+    fee_tiers: &AccountInfo,
+    fee_override: &AccountInfo,
+    source_owner: &Pubkey,
+) -> Result<(u16, u64)> {
+    Ok(assigned_fee_tier(fee_tiers, fee_override, source_owner)?
+        .map(|(rate, _)| (rate.fee_bps, rate.max_fee))
+        .unwrap_or(global))
+}
+
+/// The source's active fee tier and that tier's SOL fee, if it has one
+pub fn assigned_fee_tier(
+    fee_tiers: &AccountInfo,
+    fee_override: &AccountInfo,
+    source_owner: &Pubkey,
+) -> Result<Option<(FeeTierRate, u64)>> {
+    let Some(assigned) = load_entry::<AddressFeeOverride>(fee_override)? else { return Ok(None) };
+    require!(assigned.address == *source_owner, TransferHookError::ListEntryMismatch);
+    let Some(tiers) = load_entry::<FeeTiers>(fee_tiers)? else { return Ok(None) };
+    let tier = assigned.tier as usize;
+    match tiers.tiers.get(tier) {
+        Some(rate) if rate.active => Ok(Some((*rate, tiers.sol_fees[tier]))),
+        _ => Ok(None),
+    }
+}
+
+/// Peg-deviation surcharge on `amount` under the policy at `policy`, if any.
+/// Not subject to the hook fee's max_fee, so large sells still pay it up to
+/// the withheld TransferFee.
+pub fn peg_surcharge(
+    policy: &AccountInfo,
+    source_tag: &AccountInfo,
+    destination_tag: &AccountInfo,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    let Some(policy) = load_entry::<PegFeePolicy>(policy)? else { return Ok(0) };
+    let surcharge_bps = policy.surcharge_bps(
+        now,
+        address_category(source_tag, source_owner)?,
+        address_category(destination_tag, destination_owner)?,
+    );
+    Ok((amount as u128 * surcharge_bps as u128 / 10_000) as u64)
+}
+
+/// System-owned PDA collecting SOL fees, `["sol_fee_vault", config]`
+pub fn sol_fee_vault(config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"sol_fee_vault", config.as_ref()], &crate::ID).0
+}
+
+/// Require the transfer to be a top-level Token-2022 transfer of `mint` out of
+/// `source`, and the instruction before it, skipping memos, to be a System
+/// transfer of at least `lamports` into `vault`. Transfers made by CPI share
+/// their outer instruction's predecessor, so they can't use a payment at all,
+/// and each payment precedes exactly one transfer, so it can't cover two.
+pub fn require_sol_fee_payment(
+    instructions: &AccountInfo,
+    vault: &Pubkey,
+    lamports: u64,
+    source: &Pubkey,
+    mint: &Pubkey,
+) -> Result<()> {
+    let mut index = load_current_index_checked(instructions)? as usize;
+    let current = load_instruction_at_checked(index, instructions)?;
+    // TransferChecked (12), or TransferCheckedWithFee (26, 1)
+    let direct = current.program_id == anchor_spl::token_2022::ID
+        && (current.data.first() == Some(&12) || current.data.get(..2) == Some(&[26, 1]))
+        && current.accounts.first().map(|meta| meta.pubkey) == Some(*source)
+        && current.accounts.get(1).map(|meta| meta.pubkey) == Some(*mint);
+    require!(direct, TransferHookError::SolFeeRequired);
+    while index > 0 {
+        index -= 1;
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id == spl_memo::ID {
+            continue;
+        }
+        // SystemInstruction::Transfer: u32 discriminator 2, then u64 lamports
+        let paid = ix.program_id == anchor_lang::system_program::ID
+            && ix.data.len() == 12
+            && ix.data[..4] == 2u32.to_le_bytes()
+            && ix.accounts.get(1).map(|meta| meta.pubkey) == Some(*vault)
+            && ix.data[4..].try_into().map(u64::from_le_bytes).unwrap_or(0) >= lamports;
+        require!(paid, TransferHookError::SolFeeRequired);
+        return Ok(());
+    }
+    err!(TransferHookError::SolFeeRequired)
+}
+
+/// Credit `referrer` with its share of the link's unsettled fees
+pub fn settle_referral_fees(link: &mut ReferralLink, referrer: &mut Referrer, now: i64) -> Result<()> {
+    let fees = link.fees_unsettled;
+    let share = (fees as u128 * referrer.share_bps as u128 / 10_000) as u64;
+    referrer.earned = referrer.earned
+        .checked_add(share)
+        .ok_or(TransferHookError::MathOverflow)?;
+    link.fees_unsettled = 0;
+    
+    emit!(ReferralSettled {
+        address: link.address,
+        referrer: referrer.referrer,
+        fees,
+        share,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+/// Hook fee for `amount` at the source's rate, lowered to any running fee
+/// period's rate, less its volume discount
+#[allow(clippy::too_many_arguments)]
+pub fn hook_fee(
+    global: (u16, u64),
+    fee_tiers: &AccountInfo,
+    fee_override: &AccountInfo,
+    fee_schedule: Option<&FeeTierSchedule>,
+    period_bps: Option<u16>,
+    monthly_volume: Option<u64>,
+    source_owner: &Pubkey,
+    amount: u64,
+) -> Result<u64> {
+    let (mut fee_bps, max_fee) = fee_rate(global, fee_tiers, fee_override, source_owner)?;
+    if let Some(period_bps) = period_bps {
+        fee_bps = fee_bps.min(period_bps);
+    }
+    let mut fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(TransferHookError::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(TransferHookError::MathOverflow)? as u64;
+    
+    if fee > max_fee {
+        fee = max_fee;
+    }
+    
+    if let (Some(schedule), Some(volume)) = (fee_schedule, monthly_volume) {
+        let discount = (fee as u128)
+            .checked_mul(schedule.discount_bps(volume) as u128)
+            .ok_or(TransferHookError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(TransferHookError::MathOverflow)? as u64;
+        fee = fee.checked_sub(discount).ok_or(TransferHookError::MathOverflow)?;
+    }
+    Ok(fee)
+}
+
+/// Reject outgoing transfers during the source's cooldown, and start a
+/// cooldown on the destination after a large inbound transfer.
+pub fn enforce_cooldown(
+    policy: Option<&TransferPolicy>,
+    source_tracker: &AccountInfo,
+    destination_tracker: &AccountInfo,
+    destination_owner: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let Some(policy) = policy.filter(|p| p.cooldown_seconds > 0) else { return Ok(()) };
+    
+    if let Some(tracker) = load_zero_copy::<VelocityTracker>(source_tracker)? {
+        let unlocked_at = tracker.last_large_inbound_at.saturating_add(policy.cooldown_seconds);
+        require!(now >= unlocked_at, TransferHookError::TransferCooldownActive);
+    }
+    
+    if amount >= policy.cooldown_threshold {
+        let mut tracker = load_zero_copy::<VelocityTracker>(destination_tracker)?
+            .ok_or(TransferHookError::VelocityTrackerMissing)?;
+        let owner = tracker.owner;
+        require!(owner == *destination_owner, TransferHookError::ListEntryMismatch);
+        tracker.last_large_inbound_at = now;
+        store_zero_copy(destination_tracker, &tracker)?;
+    }
+    Ok(())
+}
+
+/// Require and consume a pre-approval for transfers at or above the policy threshold.
+pub fn enforce_transfer_approval(
+    policy: Option<&TransferPolicy>,
+    approval_info: &AccountInfo,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let Some(policy) = policy else { return Ok(()) };
+    if policy.approval_threshold == 0 || amount < policy.approval_threshold {
+        return Ok(());
+    }
+    
+    let mut approval = load_entry::<TransferApproval>(approval_info)?
+        .ok_or(TransferHookError::ApprovalRequired)?;
+    require!(
+        approval.source == *source_owner
+            && approval.destination == *destination_owner
+            && !approval.used
+            && approval.expires_at > now
+            && (approval.min_amount..=approval.max_amount).contains(&amount),
+        TransferHookError::ApprovalRequired
+    );
+    
+    if !approval_info.is_writable {
+        return Ok(());
+    }
+    approval.used = true;
+    store_entry(approval_info, &approval)?;
+    
+    emit!(TransferApprovalConsumed {
+        approval: approval_info.key(),
+        source: *source_owner,
+        destination: *destination_owner,
+        amount,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Require a travel-rule reference for transfers at or above the policy
+/// threshold: a matching TravelRuleRecord, which is consumed, or a Memo
+/// instruction directly before the transfer. Returns the reference hash.
+pub fn enforce_travel_rule(
+    policy: Option<&TransferPolicy>,
+    record_info: &AccountInfo,
+    instructions: &AccountInfo,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+) -> Result<Option<[u8; 32]>> {
+    let Some(policy) = policy else { return Ok(None) };
+    if policy.travel_rule_threshold == 0 || amount < policy.travel_rule_threshold {
+        return Ok(None);
+    }
+    
+    if let Some(mut record) = load_entry::<TravelRuleRecord>(record_info)? {
+        if record.source == *source_owner
+            && record.destination == *destination_owner
+            && record.amount == amount
+            && !record.used
+        {
+            record.used = true;
+            store_entry(record_info, &record)?;
+            return Ok(Some(record.ivms101_hash));
+        }
+    }
+    
+    // Fall back to a memo carrying the reference
+    let memo = preceding_memo(instructions)?.ok_or(TransferHookError::TravelRuleRequired)?;
+    Ok(Some(anchor_lang::solana_program::hash::hash(&memo).to_bytes()))
+}
+
+/// Check that `meta_list` is `program`'s validation account for `mint` and
+/// lists no extra accounts for Execute. A secondary hook without one is
+/// accepted; forwarding passes it nothing beyond the transfer's accounts.
+pub fn require_no_forward_extras(meta_list: &AccountInfo, program: &Pubkey, mint: &Pubkey) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(&[b"extra-account-metas", mint.as_ref()], program);
+    require_keys_eq!(meta_list.key(), expected, TransferHookError::InvalidForwardHook);
+    if meta_list.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*meta_list.owner, *program, TransferHookError::InvalidForwardHook);
+    let data = meta_list.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&data)?;
+    let extras = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+    require!(extras.data().is_empty(), TransferHookError::ForwardHookExtrasUnsupported);
+    Ok(())
+}
+
+/// Remaining compute units, logged as well with the `cu-trace` feature;
+/// always 0 without it.
+pub fn sample_compute_units() -> u64 {
+    #[cfg(feature = "cu-trace")]
+    {
+        anchor_lang::solana_program::log::sol_log_compute_units();
+        anchor_lang::solana_program::compute_units::sol_remaining_compute_units()
+    }
+    #[cfg(not(feature = "cu-trace"))]
+    {
+        0
+    }
+}
+
+/// Emit HookComputeUnits for a call that started with `remaining_at_entry`
+/// units left. No-op without the `cu-trace` feature.
+pub fn trace_compute_units(remaining_at_entry: u64) {
+    #[cfg(feature = "cu-trace")]
+    {
+        let remaining_at_exit = sample_compute_units();
+        emit!(HookComputeUnits {
+            remaining_at_entry,
+            remaining_at_exit,
+            consumed: remaining_at_entry.saturating_sub(remaining_at_exit),
+        });
+    }
+    #[cfg(not(feature = "cu-trace"))]
+    let _ = remaining_at_entry;
+}
+
+/// Add a transfer to today's slot in the source owner's StatsShard, if the
+/// shard exists. Counters saturate so statistics can never fail a transfer.
+pub fn record_transfer_stats(info: &AccountInfo, now: i64, amount: u64, fee: u64) -> Result<()> {
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    require!(
+        data[..8] == StatsShard::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let shard: &mut StatsShard =
+        bytemuck::from_bytes_mut(&mut data[8..8 + std::mem::size_of::<StatsShard>()]);
+    let today = shard.day_mut(now.div_euclid(SECONDS_PER_DAY));
+    today.transfer_count = today.transfer_count.saturating_add(1);
+    today.gross_volume = today.gross_volume.saturating_add(amount);
+    today.fees = today.fees.saturating_add(fee);
+    Ok(())
+}
+
+/// Freeze `owner`'s token accounts through sss-token's freeze_accounts_for_owner,
+/// with `authority` signing as pauser. `accounts` is
+/// `[sss-token program, stablecoin state, authority's RoleAccount, mint,
+/// freeze authority PDA, token program, token accounts...]`; sss-token
+/// validates all of them. Returns the number of token accounts passed.
+pub fn freeze_owner_accounts_via_sss<'info>(
+    authority: &AccountInfo<'info>,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+) -> Result<u8> {
+    require!(accounts.len() > 6, TransferHookError::InvalidFreezeAccounts);
+    require_keys_eq!(accounts[0].key(), sss_token_program::ID, TransferHookError::InvalidFreezeAccounts);
+    require_keys_eq!(accounts[3].key(), *mint, TransferHookError::InvalidFreezeAccounts);
+    
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(
+        &anchor_lang::solana_program::hash::hash(b"global:freeze_accounts_for_owner").to_bytes()[..8],
+    );
+    data.extend_from_slice(owner.as_ref());
+    
+    let mut metas = vec![AccountMeta::new_readonly(authority.key(), true)];
+    metas.extend(accounts[1..6].iter().map(|info| AccountMeta::new_readonly(info.key(), false)));
+    metas.extend(accounts[6..].iter().map(|info| AccountMeta::new(info.key(), false)));
+    let ix = Instruction {
+        program_id: sss_token_program::ID,
+        accounts: metas,
+        data,
+    };
+    let mut infos = vec![authority.clone()];
+    infos.extend_from_slice(accounts);
+    invoke(&ix, &infos)?;
+    
+    Ok((accounts.len() - 6) as u8)
+}
+
+/// Leaf the hook appends to the audit tree for a transfer:
+/// `keccak(config || source owner || destination owner || amount || fee ||
+/// timestamp || leaf index)`, integers little-endian. Indexers rebuild the
+/// leaves from TransferExecuted events and prove them against the tree.
+pub fn audit_leaf(
+    config: &Pubkey,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+    fee: u64,
+    timestamp: i64,
+    index: u64,
+) -> [u8; 32] {
+    keccak::hashv(&[
+        config.as_ref(),
+        source_owner.as_ref(),
+        destination_owner.as_ref(),
+        &amount.to_le_bytes(),
+        &fee.to_le_bytes(),
+        &timestamp.to_le_bytes(),
+        &index.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Sequence number of the audit tree, the index its next appended leaf gets
+pub fn audit_tree_sequence(tree: &AccountInfo) -> Result<u64> {
+    require!(tree.owner == &spl_account_compression::ID, TransferHookError::InvalidAuditTree);
+    let data = tree.try_borrow_data()?;
+    let bytes = data
+        .get(AUDIT_TREE_SEQUENCE_OFFSET..AUDIT_TREE_SEQUENCE_OFFSET + 8)
+        .ok_or(TransferHookError::InvalidAuditTree)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Call an spl-account-compression instruction on the audit tree, with the
+/// audit log PDA signing as tree authority. `args` follow the discriminator.
+pub fn invoke_audit_tree<'info>(
+    name: &str,
+    args: &[u8],
+    tree: &AccountInfo<'info>,
+    audit_log: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = Vec::with_capacity(8 + args.len());
+    data.extend_from_slice(
+        &anchor_lang::solana_program::hash::hash(format!("global:{}", name).as_bytes()).to_bytes()[..8],
+    );
+    data.extend_from_slice(args);
+    let ix = Instruction {
+        program_id: spl_account_compression::ID,
+        accounts: vec![
+            AccountMeta::new(tree.key(), false),
+            AccountMeta::new_readonly(audit_log.key(), true),
+            AccountMeta::new_readonly(noop_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(&ix, &[tree.clone(), audit_log.clone(), noop_program.clone()], &[signer_seeds])?;
+    Ok(())
+}
+
+/// First bytes of the instruction the hook sends to a risk program:
+/// `RISK_CHECK_DISCRIMINATOR || source owner || destination owner || amount (u64 LE)`,
+/// with the mint, source and destination token accounts as read-only accounts.
+/// The program answers through return data; a first byte of 0 allows the
+/// transfer, anything else is a deny code.
+pub const RISK_CHECK_DISCRIMINATOR: [u8; 8] = *b"riskchck";
+
+/// CPI into the registered risk program and fail the transfer unless it
+/// returns an allow code. Missing return data counts as a denial.
+pub fn enforce_risk_check<'info>(
+    program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    source_owner: &Pubkey,
+    destination_owner: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let mut data = Vec::with_capacity(80);
+    data.extend_from_slice(&RISK_CHECK_DISCRIMINATOR);
+    data.extend_from_slice(source_owner.as_ref());
+    data.extend_from_slice(destination_owner.as_ref());
+    data.extend_from_slice(&amount.to_le_bytes());
+    
+    let ix = Instruction {
+        program_id: program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(source.key(), false),
+            AccountMeta::new_readonly(destination.key(), false),
+        ],
+        data,
+    };
+    invoke(&ix, &[mint.clone(), source.clone(), destination.clone(), program.clone()])?;
+    
+    let code = match get_return_data() {
+        Some((returned_by, data)) if returned_by == program.key() => data.first().copied(),
+        _ => None,
+    };
+    match code {
+        Some(0) => Ok(()),
+        Some(code) => {
+            msg!("Risk oracle deny code {}", code);
+            Err(TransferHookError::RiskCheckDenied.into())
+        }
+        None => Err(TransferHookError::RiskCheckDenied.into()),
+    }
+}
+
+/// Data of a non-empty Memo instruction directly before the current
+/// top-level instruction, if there is one
+pub fn preceding_memo(instructions: &AccountInfo) -> Result<Option<Vec<u8>>> {
+    let current = load_current_index_checked(instructions)?;
+    if current == 0 {
+        return Ok(None);
+    }
+    let ix = load_instruction_at_checked(current as usize - 1, instructions)?;
+    Ok((ix.program_id == spl_memo::ID && !ix.data.is_empty()).then_some(ix.data))
+}
+
+/// Restriction a screen_blacklist instruction earlier in the transaction
+/// asserted for `address` under `config`. Fails with
+/// BlacklistScreeningRequired if no such instruction precedes the current one.
+pub fn screened_restriction(
+    instructions: &AccountInfo,
+    config: &Pubkey,
+    address: &Pubkey,
+) -> Result<Option<RestrictionLevel>> {
+    let current = load_current_index_checked(instructions)?;
+    for index in (0..current as usize).rev() {
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id != crate::ID
+            || ix.data.len() < 8
+            || ix.data[..8] != instruction::ScreenBlacklist::DISCRIMINATOR
+            || ix.accounts.first().map(|meta| meta.pubkey) != Some(*config)
+        {
+            continue;
+        }
+        let args = instruction::ScreenBlacklist::try_from_slice(&ix.data[8..])?;
+        if args.address == *address {
+            return Ok(args.restriction);
+        }
+    }
+    err!(TransferHookError::BlacklistScreeningRequired)
+}
+
+/// Message a KYC provider signs:
+/// `config || address || tier || expires_at (i64 LE) || nonce (u64 LE)`
+pub fn kyc_attestation_message(
+    config: &Pubkey,
+    address: &Pubkey,
+    tier: KycTier,
+    expires_at: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 + 32 + 1 + 8 + 8);
+    message.extend_from_slice(config.as_ref());
+    message.extend_from_slice(address.as_ref());
+    message.push(tier as u8);
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+/// Load a NonceBitmap created with init_if_needed, initializing it on first use
+pub fn load_nonce_bitmap<'a>(
+    loader: &'a AccountLoader<NonceBitmap>,
+    signer: &Pubkey,
+    nonce: u64,
+    bump: u8,
+) -> Result<std::cell::RefMut<'a, NonceBitmap>> {
+    let fresh = loader.as_ref().try_borrow_data()?[..8] == [0u8; 8];
+    if !fresh {
+        return loader.load_mut();
+    }
+    let mut bitmap = loader.load_init()?;
+    bitmap.signer = *signer;
+    bitmap.page = nonce / NONCES_PER_BITMAP;
+    bitmap.bump = bump;
+    Ok(bitmap)
+}
+
+/// Message an owner signs to grant an allowance:
+/// `"permit" || config || owner || spender || amount || deadline || nonce` (integers LE)
+pub fn permit_message(
+    config: &Pubkey,
+    owner: &Pubkey,
+    spender: &Pubkey,
+    amount: u64,
+    deadline: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(6 + 32 * 3 + 8 * 3);
+    message.extend_from_slice(b"permit");
+    message.extend_from_slice(config.as_ref());
+    message.extend_from_slice(owner.as_ref());
+    message.extend_from_slice(spender.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&deadline.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+/// Message a holder signs to authorize a sponsored transfer:
+/// `config || source || destination || amount || fee || nonce || expires_at` (integers LE)
+pub fn sponsored_transfer_message(
+    config: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+    fee: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 * 3 + 8 * 4);
+    message.extend_from_slice(config.as_ref());
+    message.extend_from_slice(source.as_ref());
+    message.extend_from_slice(destination.as_ref());
+    message.extend_from_slice(&amount.to_le_bytes());
+    message.extend_from_slice(&fee.to_le_bytes());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&expires_at.to_le_bytes());
+    message
+}
+
+/// Require that the instruction preceding this one is an ed25519 program
+/// instruction verifying exactly one signature by `signer` over `message`,
+/// with all data inline.
+pub fn verify_ed25519_instruction(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, TransferHookError::InvalidAttestation);
+    let ix = load_instruction_at_checked(current as usize - 1, instructions)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, TransferHookError::InvalidAttestation);
+    
+    // Header: count (u8), padding (u8), then one Ed25519SignatureOffsets (7 x u16)
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, TransferHookError::InvalidAttestation);
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+    // Signature, key and message must all live in the ed25519 instruction itself
+    for index_at in [4, 8, 14] {
+        require!(read_u16(index_at) == u16::MAX as usize, TransferHookError::InvalidAttestation);
+    }
+    
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(TransferHookError::InvalidAttestation)?;
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(TransferHookError::InvalidAttestation)?;
+    require!(
+        public_key == signer.as_ref() && signed == message,
+        TransferHookError::InvalidAttestation
+    );
+    Ok(())
+}
+
+/// Verify that `address` is a leaf of the sanctions Merkle tree.
+/// Leaves are `keccak(0x00 || address)`, nodes `keccak(0x01 || min(a, b) || max(a, b))`.
+pub fn verify_sanctions_proof(root: &[u8; 32], address: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let mut node = keccak::hashv(&[&[0u8], address.as_ref()]).to_bytes();
+    for sibling in proof {
+        let (a, b) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        node = keccak::hashv(&[&[1u8], &a, &b]).to_bytes();
+    }
+    node == *root
+}
+
+/// Account sizes of an sss-token StablecoinState still in the Borsh layout:
+/// as first created, and after new stablecoins were given room to grow
+const STABLECOIN_STATE_V1_LENS: [usize; 2] = [8 + 200, 8 + 256];
+/// Account size of the zero-copy StablecoinState
+const STABLECOIN_STATE_LEN: usize = 8 + 4 * 32 + 7 * 8 + 32 + 10 + 2 + 12 + 9 * 8;
+/// Offsets of `is_paused` and `is_sunset` in the zero-copy StablecoinState:
+/// four pubkeys, seven u64/i64s, name, symbol, master_count, decimals, then
+/// is_paused, features, bump and is_sunset. redemption_only follows is_sunset.
+const STABLECOIN_PAUSED_OFFSET: usize = 8 + 4 * 32 + 7 * 8 + 32 + 10 + 2 + 1;
+const STABLECOIN_SUNSET_OFFSET: usize = STABLECOIN_PAUSED_OFFSET + 3;
+
+/// Offset of `is_paused` in an sss-token StablecoinState, parsed without
+/// depending on the crate. In the Borsh layout: discriminator, authority,
+/// mint, then the name and symbol strings, decimals and total_supply. Any
+/// other account size is rejected rather than guessed at.
+fn stablecoin_paused_offset(data: &[u8]) -> Result<usize> {
+    if data.len() == STABLECOIN_STATE_LEN {
+        return Ok(STABLECOIN_PAUSED_OFFSET);
+    }
+    require!(
+        STABLECOIN_STATE_V1_LENS.contains(&data.len()),
+        TransferHookError::UnknownStablecoinLayout
+    );
+    let mut offset = 8 + 32 + 32;
+    for _ in 0..2 {
+        let len_bytes = data
+            .get(offset..offset + 4)
+            .ok_or(TransferHookError::UnknownStablecoinLayout)?;
+        let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+        offset += 4 + len;
+    }
+    Ok(offset + 1 + 8)
+}
+
+/// Read `is_paused` from an sss-token StablecoinState
+pub fn stablecoin_is_paused(data: &[u8]) -> Result<bool> {
+    let offset = stablecoin_paused_offset(data)?;
+    let flag = data.get(offset).ok_or(TransferHookError::UnknownStablecoinLayout)?;
+    Ok(*flag != 0)
+}
+
+/// Read `(is_sunset, redemption_only)` from an sss-token StablecoinState. In
+/// the Borsh layout, after is_paused come features, four u64s,
+/// pending_authority, bump and master_count.
+pub fn stablecoin_sunset(data: &[u8]) -> Result<(bool, bool)> {
+    let mut offset = stablecoin_paused_offset(data)?;
+    if data.len() == STABLECOIN_STATE_LEN {
+        offset = STABLECOIN_SUNSET_OFFSET;
+    } else {
+        offset += 1 + 1 + 8 * 4;
+        match data.get(offset) {
+            Some(1) => offset += 1 + 32,
+            Some(_) => offset += 1,
+            None => return err!(TransferHookError::UnknownStablecoinLayout),
+        }
+        offset += 1 + 2;
+    }
+    let flag = |i: usize| data.get(i).map(|b| *b != 0).ok_or(TransferHookError::UnknownStablecoinLayout);
+    Ok((flag(offset)?, flag(offset + 1)?))
+}
+
+/// Sole destination owner allowed while redemption-only mode is in force: the
+/// configured treasury, or sss-token's treasury authority once the stablecoin
+/// sunset itself turns redemption-only on.
+pub fn redemption_destination(config: &TransferHookConfig, stablecoin_data: &[u8]) -> Result<Option<Pubkey>> {
+    if let Some(treasury) = config.redemption_treasury() {
+        return Ok(Some(treasury));
+    }
+    let (_, redemption_only) = stablecoin_sunset(stablecoin_data)?;
+    Ok(redemption_only.then(|| sss_treasury_authority(&config.stablecoin)))
+}
+
+/// Whether `role_account` is `holder`'s sss-token RoleAccount for `mint` and
+/// grants `role` (or ROLE_MASTER). Parsed by hand like StablecoinState:
+/// discriminator, owner, roles, stablecoin, bump, then a session key's
+/// expires_at (0 = permanent).
+pub fn holds_sss_role(role_account: &AccountInfo, holder: &Pubkey, mint: &Pubkey, role: u8) -> bool {
+    if role_account.owner != &sss_token_program::ID {
+        return false;
+    }
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"role", holder.as_ref(), mint.as_ref()],
+        &sss_token_program::ID,
+    );
+    if role_account.key() != expected {
+        return false;
+    }
+    let Ok(data) = role_account.try_borrow_data() else { return false };
+    let discriminator = anchor_lang::solana_program::hash::hash(b"account:RoleAccount").to_bytes();
+    if data.len() < 8 + 32 + 1 || data[..8] != discriminator[..8] || data[8..40] != holder.to_bytes() {
+        return false;
+    }
+    if data[40] & (role | sss_token_program::ROLE_MASTER) == 0 {
+        return false;
+    }
+    let expires_at = data
+        .get(74..82)
+        .map_or(0, |bytes| i64::from_le_bytes(bytes.try_into().unwrap()));
+    expires_at == 0 || Clock::get().is_ok_and(|clock| clock.unix_timestamp < expires_at)
+}
+
+/// Deserialize an sss-token account after checking its owner and Anchor
+/// discriminator, using the mirrored layouts in `sss_token_program`.
+pub fn load_sss_account<T: AnchorDeserialize>(info: &AccountInfo, name: &str) -> Result<T> {
+    require_keys_eq!(*info.owner, sss_token_program::ID, TransferHookError::InvalidProposal);
+    let data = info.try_borrow_data()?;
+    let discriminator =
+        anchor_lang::solana_program::hash::hash(format!("account:{}", name).as_bytes()).to_bytes();
+    require!(
+        data.len() >= 8 && data[..8] == discriminator[..8],
+        TransferHookError::InvalidProposal
+    );
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(TransferHookError::InvalidProposal))
+}
+
+/// sss-token treasury_authority PDA, owner of the mint's treasury token account
+pub fn sss_treasury_authority(mint: &Pubkey) -> Pubkey {
+    let (stablecoin_state, _) =
+        Pubkey::find_program_address(&[b"stablecoin", mint.as_ref()], &sss_token_program::ID);
+    Pubkey::find_program_address(
+        &[b"treasury_authority", stablecoin_state.as_ref()],
+        &sss_token_program::ID,
+    )
+    .0
+}
+
+/// sss-token permanent_delegate PDA. The mint's PermanentDelegate extension
+/// must name it: sss-token executes seizures with it, outside the hook, so
+/// the transfer can run the hook like any other.
+pub fn sss_permanent_delegate(mint: &Pubkey) -> Pubkey {
+    let (stablecoin_state, _) =
+        Pubkey::find_program_address(&[b"stablecoin", mint.as_ref()], &sss_token_program::ID);
+    Pubkey::find_program_address(
+        &[b"permanent_delegate", stablecoin_state.as_ref()],
+        &sss_token_program::ID,
+    )
+    .0
+}
+
+/// sss-token transfer_delegate PDA. Holders approve it as the Token-2022
+/// delegate of their accounts to use sponsored transfers and allowances,
+/// which sss-token executes after the hook has authorized them.
+pub fn sss_transfer_delegate(mint: &Pubkey) -> Pubkey {
+    let (stablecoin_state, _) =
+        Pubkey::find_program_address(&[b"stablecoin", mint.as_ref()], &sss_token_program::ID);
+    Pubkey::find_program_address(
+        &[b"transfer_delegate", stablecoin_state.as_ref()],
+        &sss_token_program::ID,
+    )
+    .0
+}
+
+/// Reject fee settings that overcharge or silently disable the fee
+pub fn validate_fee_params(fee_bps: u16, max_fee: u64, min_transfer_amount: u64) -> Result<()> {
+    require!(fee_bps <= MAX_TRANSFER_FEE_BPS, TransferHookError::FeeTooHigh);
+    require!(fee_bps == 0 || max_fee > 0, TransferHookError::InvalidMaxFee);
+    require!(
+        min_transfer_amount <= MAX_MIN_TRANSFER_AMOUNT,
+        TransferHookError::MinTransferTooHigh
+    );
+    Ok(())
+}
+
+/// Whether `signer` may act with `role`: the hook authority, or the holder of
+/// an sss-token RoleAccount granting it for this stablecoin.
+pub fn has_hook_role(
+    config: &TransferHookConfig,
+    signer: &Pubkey,
+    role_account: Option<&UncheckedAccount>,
+    role: u8,
+) -> bool {
+    *signer == config.authority
+        || role_account.is_some_and(|info| holds_sss_role(info, signer, &config.stablecoin, role))
+}
+
+/// ============ PROGRAM MODULE ============
+
+#[program]
+pub mod sss_transfer_hook {
+    use super::*;
+
+    /// Initialize the transfer hook for a stablecoin
+    pub fn initialize(
+        ctx: Context<InitializeHook>,
+        transfer_fee_basis_points: u16,
+        max_transfer_fee: u64,
+        min_transfer_amount: u64,
+        blacklist_enabled: bool,
+    ) -> Result<()> {
+        validate_fee_params(transfer_fee_basis_points, max_transfer_fee, min_transfer_amount)?;
+        
+        let mut config = ctx.accounts.config.load_init()?;
+        config.stablecoin = ctx.accounts.stablecoin.key();
+        config.authority = ctx.accounts.authority.key();
+        config.transfer_fee_basis_points = transfer_fee_basis_points;
+        config.max_transfer_fee = max_transfer_fee;
+        config.min_transfer_amount = min_transfer_amount;
+        config.blacklist_enabled = u8::from(blacklist_enabled);
+        config.bump = ctx.bumps.config;
+        config.version = CONFIG_VERSION;
+        config.meta_groups_unused = FEATURE_META_GROUPS;
+
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "initialize".to_string(),
+            value: format!("fee_bps:{}, max_fee:{}, min:{}, blacklist:{}", 
+                transfer_fee_basis_points, max_transfer_fee, min_transfer_amount, blacklist_enabled),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Initialize ExtraAccountMetaList — REQUIRED by Token-2022 before hook can be installed on a mint.
+    /// This stores the PDAs the hook needs as extra accounts for every transfer.
+    /// Only the groups the config's flags and the features already set up
+    /// call for are resolved; features set up later need
+    /// sync_extra_account_meta_list.
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitExtraAccountMetaList>,
+    ) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        let groups = config_meta_groups(&config) | (FEATURE_META_GROUPS & !config.meta_groups_unused);
+        config.meta_groups_unsynced = FEATURE_META_GROUPS & !groups;
+        drop(config);
+        
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas(groups)?,
+        )?;
+        
+        let oracle = &mut ctx.accounts.risk_oracle;
+        oracle.config = ctx.accounts.config.key();
+        oracle.bump = ctx.bumps.risk_oracle;
+        
+        let forward_hook = &mut ctx.accounts.forward_hook;
+        forward_hook.config = ctx.accounts.config.key();
+        forward_hook.bump = ctx.bumps.forward_hook;
+        
+        let gatekeeper = &mut ctx.accounts.gatekeeper;
+        gatekeeper.config = ctx.accounts.config.key();
+        gatekeeper.bump = ctx.bumps.gatekeeper;
+        
+        let audit_log = &mut ctx.accounts.audit_log;
+        audit_log.config = ctx.accounts.config.key();
+        audit_log.bump = ctx.bumps.audit_log;
+        
+        Ok(())
+    }
+    
+    /// Rewrite the ExtraAccountMetaList after the hook's account set changes,
+    /// growing the account (and topping up rent) if needed. Every group is
+    /// resolved; sync_extra_account_meta_list narrows the list to the features
+    /// in use.
+    pub fn update_extra_account_meta_list(
+        ctx: Context<UpdateExtraAccountMetaList>,
+    ) -> Result<()> {
+        let account_metas = extra_account_metas(u32::MAX)?;
+        let meta_list = &ctx.accounts.extra_account_meta_list;
+        ctx.accounts.config.load_mut()?.meta_groups_unsynced = 0;
+        
+        let new_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+        if new_size > meta_list.data_len() {
+            let required = Rent::get()?.minimum_balance(new_size);
+            let shortfall = required.saturating_sub(meta_list.lamports());
+            if shortfall > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.authority.to_account_info(),
+                            to: meta_list.to_account_info(),
+                        },
+                    ),
+                    shortfall,
+                )?;
+            }
+            meta_list.realloc(new_size, false)?;
+        }
+        
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut meta_list.try_borrow_mut_data()?,
+            &account_metas,
+        )?;
+        
+        let oracle = &mut ctx.accounts.risk_oracle;
+        oracle.config = ctx.accounts.config.key();
+        oracle.bump = ctx.bumps.risk_oracle;
+        
+        let forward_hook = &mut ctx.accounts.forward_hook;
+        forward_hook.config = ctx.accounts.config.key();
+        forward_hook.bump = ctx.bumps.forward_hook;
+        
+        let gatekeeper = &mut ctx.accounts.gatekeeper;
+        gatekeeper.config = ctx.accounts.config.key();
+        gatekeeper.bump = ctx.bumps.gatekeeper;
+        
+        let audit_log = &mut ctx.accounts.audit_log;
+        audit_log.config = ctx.accounts.config.key();
+        audit_log.bump = ctx.bumps.audit_log;
+        
+        Ok(())
+    }
+    
+    /// Rewrite the ExtraAccountMetaList so it resolves exactly the groups the
+    /// features in use need. Permissionless: the list follows from on-chain
+    /// state alone. Run it after turning a feature on or off. Groups of
+    /// per-owner records can't be enumerated, so they stay once set up.
+    pub fn sync_extra_account_meta_list(ctx: Context<SyncExtraAccountMetaList>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let exists = |info: &AccountInfo| info.owner == &crate::ID && !info.data_is_empty();
+        
+        let mut config = accounts.config.load_mut()?;
+        let mut groups = config_meta_groups(&config) | (RECORD_META_GROUPS & !config.meta_groups_unused);
+        if exists(&accounts.kyc_policy) {
+            groups |= META_GROUP_KYC;
+        }
+        if exists(&accounts.transfer_policy) || exists(&accounts.fee_schedule) {
+            groups |= META_GROUP_POLICY;
+        }
+        if exists(&accounts.fee_tiers) {
+            groups |= META_GROUP_FEE_TIERS;
+        }
+        if exists(&accounts.fee_exempt_programs) {
+            groups |= META_GROUP_FEE_EXEMPT;
+        }
+        if load_entry::<RiskOracle>(&accounts.risk_oracle)?.is_some_and(|oracle| oracle.enabled) {
+            groups |= META_GROUP_RISK;
+        }
+        if load_entry::<ForwardHook>(&accounts.forward_hook)?.is_some_and(|slot| slot.program != Pubkey::default()) {
+            groups |= META_GROUP_FORWARD;
+        }
+        if exists(&accounts.hook_stats) {
+            groups |= META_GROUP_STATS;
+        }
+        if load_entry::<GatekeeperConfig>(&accounts.gatekeeper)?.is_some_and(|gatekeeper| gatekeeper.enabled) {
+            groups |= META_GROUP_GATEWAY;
+        }
+        if load_entry::<AuditLog>(&accounts.audit_log)?.is_some_and(|audit| audit.tree != Pubkey::default()) {
+            groups |= META_GROUP_AUDIT;
+        }
+        if exists(&accounts.escheatment_policy) {
+            groups |= META_GROUP_ESCHEAT;
+        }
+        if exists(&accounts.pending_fee) {
+            groups |= META_GROUP_PENDING_FEE;
+        }
+        if exists(&accounts.fee_periods) {
+            groups |= META_GROUP_FEE_PERIODS;
+        }
+        if exists(&accounts.peg_fee_policy) {
+            groups |= META_GROUP_PEG_FEE;
+        }
+        config.meta_groups_unused = FEATURE_META_GROUPS & !groups;
+        config.meta_groups_unsynced = FEATURE_META_GROUPS & !groups;
+        drop(config);
+        
+        ExtraAccountMetaList::update::<ExecuteInstruction>(
+            &mut accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas(groups)?,
+        )?;
+        
+        emit!(ExtraAccountMetasSynced {
+            mint: accounts.mint.key(),
+            groups,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Token-2022 invokes the hook with the spl-transfer-hook-interface Execute
+    /// discriminator rather than an Anchor one; route it to execute_transfer_hook.
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                let amount_bytes = amount.to_le_bytes();
+                __private::__global::execute_transfer_hook(program_id, accounts, &amount_bytes)
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+
+    /// Called by Token-2022 on every transfer. Both the source and the
+    /// destination owner are screened against the blacklist.
+    pub fn execute_transfer_hook<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTransferHook<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        let cu_entry = sample_compute_units();
+        assert_is_transferring(&ctx.accounts.source_account.to_account_info())?;
+        assert_is_transferring(&ctx.accounts.destination_account.to_account_info())?;
+        
+        // Owners come from the token account data, never from a caller-supplied account
+        let source_owner = ctx.accounts.source_account.owner;
+        let destination_owner = ctx.accounts.destination_account.owner;
+        let config_key = ctx.accounts.config.key();
+        let source_key = ctx.accounts.source_account.key();
+        let destination_key = ctx.accounts.destination_account.key();
+        let transfer_authority = ctx.accounts.owner.key();
+        
+        let config = *ctx.accounts.config.load()?;
+        require!(!config.meta_list_stale(), TransferHookError::StaleExtraAccountMetas);
+        
+        // Check base program pause state
+        let stablecoin_data = ctx.accounts.stablecoin_state.try_borrow_data()?;
+        if stablecoin_is_paused(&stablecoin_data)? {
+            return Err(TransferHookError::HookPaused.into());
+        }
+        let redemption_treasury = redemption_destination(&config, &stablecoin_data)?;
+        drop(stablecoin_data);
+        
+        // Check hook-specific pause
+        let now = Clock::get()?.unix_timestamp;
+        require!(!config.transfers_paused(now), TransferHookError::HookPaused);
+        
+        // A pending clawback holds funds on the source account and a legal
+        // hold stops the owner sending at all; receiving stays open. Only the
+        // permanent delegate, which executes seizures, may move held funds.
+        if config.permanent_delegate() != Some(transfer_authority) {
+            if let Some(hold) = load_entry::<ClawbackHold>(&ctx.accounts.clawback_hold)? {
+                require!(
+                    !hold.freeze_account && ctx.accounts.source_account.amount >= hold.amount,
+                    TransferHookError::ClawbackHeld
+                );
+            }
+            require!(
+                load_entry::<LegalHold>(&ctx.accounts.source_legal_hold)?.is_none(),
+                TransferHookError::LegalHoldActive
+            );
+        }
+        
+        // During a sunset, holders may only send tokens back to the treasury
+        if let Some(treasury) = redemption_treasury {
+            require!(
+                destination_owner == treasury,
+                TransferHookError::RedemptionOnly
+            );
+        }
+        
+        // Check whitelist. FeeExempt only waives fees; FullBypass skips every
+        // check for that party, and the transfer-level checks if either side has it.
+        let source_whitelist = whitelist_type(&ctx.accounts.source_whitelist, &source_owner)?;
+        let destination_whitelist =
+            whitelist_type(&ctx.accounts.destination_whitelist, &destination_owner)?;
+        let source_bypass = source_whitelist == Some(WhitelistType::FullBypass);
+        let destination_bypass = destination_whitelist == Some(WhitelistType::FullBypass);
+        let full_bypass = source_bypass || destination_bypass;
+        
+        // Transfers the permanent delegate signs are sss-token seizures, whose
+        // source is usually blacklisted. The permanent delegate bypasses
+        // everything else.
+        let seizure = config.permanent_delegate() == Some(transfer_authority);
+        let is_delegate = seizure
+            || config
+                .permanent_delegate()
+                .is_some_and(|delegate| source_owner == delegate || destination_owner == delegate);
+        
+        // Check blacklist (if enabled): owners, token accounts and the acting delegate.
+        // Blocked entries reject the transfer, FlagOnly entries are reported.
+        if config.blacklist_enabled() {
+            let mut checks = Vec::new();
+            if !source_bypass && !seizure {
+                checks.push((&ctx.accounts.source_blacklist, source_owner, TransferHookError::SourceBlacklisted));
+                checks.push((&ctx.accounts.source_account_blacklist, source_key, TransferHookError::SourceBlacklisted));
+            }
+            if !destination_bypass {
+                checks.push((&ctx.accounts.destination_blacklist, destination_owner, TransferHookError::DestinationBlacklisted));
+                checks.push((&ctx.accounts.destination_account_blacklist, destination_key, TransferHookError::DestinationBlacklisted));
+            }
+            // Check the transfer authority when it is a delegate rather than the owner
+            if transfer_authority != source_owner {
+                checks.push((&ctx.accounts.authority_blacklist, transfer_authority, TransferHookError::DelegateBlacklisted));
+            }
+            
+            // With the bloom filter enabled the transfer carries no blacklist
+            // PDAs: addresses it rules out need no check, and a possible hit
+            // must have been screened by screen_blacklist earlier in the
+            // transaction, which read the PDA there
+            let bloom_data = if config.bloom_enabled() {
+                let info = &ctx.accounts.blacklist_bloom;
+                require!(info.owner == &crate::ID, TransferHookError::BloomFilterRequired);
+                let data = info.try_borrow_data()?;
+                require!(
+                    data.len() >= 8 + std::mem::size_of::<BlacklistBloom>()
+                        && data[..8] == BlacklistBloom::DISCRIMINATOR,
+                    TransferHookError::BloomFilterRequired
+                );
+                Some(data)
+            } else {
+                None
+            };
+            let bloom = bloom_data.as_ref().map(|data| {
+                bytemuck::from_bytes::<BlacklistBloom>(&data[8..8 + std::mem::size_of::<BlacklistBloom>()])
+            });
+            
+            for (info, address, error) in checks {
+                let restriction = match bloom {
+                    Some(bloom) if !bloom.may_contain(&address) => continue,
+                    Some(_) => screened_restriction(&ctx.accounts.instructions, &config_key, &address)?,
+                    None => {
+                        require_synced(&[info])?;
+                        let (expected, _) = Pubkey::find_program_address(
+                            &[b"blacklist", config_key.as_ref(), address.as_ref()],
+                            &crate::ID,
+                        );
+                        require!(info.key() == expected, TransferHookError::InvalidListAccount);
+                        blacklist_restriction(info, &address, now)?
+                    }
+                };
+                match restriction {
+                    Some(RestrictionLevel::Blocked) => return Err(error.into()),
+                    Some(RestrictionLevel::FlagOnly) => emit!(FlaggedTransfer {
+                        flagged: address,
+                        source: source_owner,
+                        destination: destination_owner,
+                        amount,
+                        timestamp: now,
+                    }),
+                    None => {}
+                }
+            }
+        }
+        
+        let source_whitelisted = source_whitelist.is_some();
+        let destination_whitelisted = destination_whitelist.is_some();
+        let is_whitelisted = source_whitelisted || destination_whitelisted;
+        
+        // Permissioned mode: only whitelisted holders may transact. A valid
+        // gateway token stands in for a whitelist entry but does not waive fees.
+        if config.whitelist_required() && !is_delegate && !full_bypass {
+            let gatekeeper = load_entry::<GatekeeperConfig>(&ctx.accounts.gatekeeper)?;
+            if gatekeeper.as_ref().is_some_and(|gatekeeper| gatekeeper.enabled) {
+                require_synced(&[&ctx.accounts.source_gateway_token, &ctx.accounts.destination_gateway_token])?;
+            }
+            require!(
+                (source_whitelisted
+                    || has_gateway_pass(gatekeeper.as_ref(), &ctx.accounts.source_gateway_token, &source_owner, now)?)
+                    && (destination_whitelisted
+                        || has_gateway_pass(gatekeeper.as_ref(), &ctx.accounts.destination_gateway_token, &destination_owner, now)?),
+                TransferHookError::NotWhitelisted
+            );
+        }
+        
+        // KYC tier limits, velocity limits and the balance cap
+        let fee_schedule = load_entry::<FeeTierSchedule>(&ctx.accounts.fee_schedule)?;
+        let mut monthly_volume: Option<u64> = None;
+        let mut travel_rule: Option<[u8; 32]> = None;
+        if !is_delegate && !full_bypass {
+            let kyc_policy = load_entry::<KycPolicy>(&ctx.accounts.kyc_policy)?;
+            if kyc_policy.is_some() {
+                require_synced(&[&ctx.accounts.source_kyc, &ctx.accounts.destination_kyc])?;
+            }
+            let destination_tier = enforce_kyc_limits(
+                kyc_policy.as_ref(),
+                &ctx.accounts.source_kyc,
+                &ctx.accounts.destination_kyc,
+                &source_owner,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            
+            let policy = load_entry::<TransferPolicy>(&ctx.accounts.transfer_policy)?;
+            if policy.is_some() || fee_schedule.is_some() {
+                require_synced(&[
+                    &ctx.accounts.velocity_tracker,
+                    &ctx.accounts.destination_velocity_tracker,
+                    &ctx.accounts.transfer_approval,
+                    &ctx.accounts.travel_rule_record,
+                ])?;
+            }
+            if let Some(policy) = &policy {
+                require!(
+                    policy.in_transfer_window(now),
+                    TransferHookError::OutsideTransferWindow
+                );
+            }
+            enforce_cooldown(
+                policy.as_ref(),
+                &ctx.accounts.velocity_tracker,
+                &ctx.accounts.destination_velocity_tracker,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            monthly_volume = enforce_velocity_limits(
+                policy.as_ref(),
+                fee_schedule.is_some(),
+                &ctx.accounts.velocity_tracker,
+                &source_owner,
+                amount,
+                now,
+            )?;
+            enforce_transfer_approval(
+                policy.as_ref(),
+                &ctx.accounts.transfer_approval,
+                &source_owner,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            travel_rule = enforce_travel_rule(
+                policy.as_ref(),
+                &ctx.accounts.travel_rule_record,
+                &ctx.accounts.instructions,
+                &source_owner,
+                &destination_owner,
+                amount,
+            )?;
+            
+            // Token-2022 has already credited the destination when the hook runs
+            let max_balance = kyc_policy
+                .as_ref()
+                .map(|p| p.max_balance(destination_tier, config.max_balance))
+                .unwrap_or(config.max_balance);
+            require!(
+                max_balance == 0 || ctx.accounts.destination_account.amount <= max_balance,
+                TransferHookError::BalanceCapExceeded
+            );
+        }
+        
+        // Registered risk oracle gets the final say once local checks pass
+        if !is_delegate {
+            if let Some(oracle) = load_entry::<RiskOracle>(&ctx.accounts.risk_oracle)? {
+                if oracle.enabled {
+                    require!(ctx.accounts.risk_program.key() == oracle.program, TransferHookError::InvalidRiskProgram);
+                    enforce_risk_check(
+                        &ctx.accounts.risk_program,
+                        &ctx.accounts.mint.to_account_info(),
+                        &ctx.accounts.source_account.to_account_info(),
+                        &ctx.accounts.destination_account.to_account_info(),
+                        &source_owner,
+                        &destination_owner,
+                        amount,
+                    )?;
+                }
+            }
+        }
+        
+        // Deposit accounts flagged memo-required reject bare transfers
+        if !is_delegate {
+            if let Some(requirement) = load_entry::<MemoRequirement>(&ctx.accounts.memo_requirement)? {
+                require!(
+                    !requirement.required || preceding_memo(&ctx.accounts.instructions)?.is_some(),
+                    TransferHookError::MemoRequired
+                );
+            }
+        }
+        
+        // The only token fee ever collected is what Token-2022 withholds under
+        // the mint's TransferFee extension, so that is the fee recorded. The
+        // hook can't move tokens itself: without the extension there is no fee.
+        // Tiers, overrides, fee periods and volume discounts can't change that
+        // rate, so they price what the source owes and the rest is credited
+        // back to its fee credit account.
+        let withheld_fee = withheld_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+        let fee = withheld_fee.unwrap_or(0);
+        let mut owed = if full_bypass && !is_delegate { 0 } else { fee };
+        let mut fee_credit = load_entry::<FeeCredit>(&ctx.accounts.source_fee_credit)?;
+        if !is_delegate && !full_bypass {
+            require!(amount >= config.min_transfer_amount, TransferHookError::AmountTooLow);
+            
+            // A source owned by an allowlisted program (e.g. a protocol PDA)
+            // pays no hook fee
+            let program_exempt = match load_entry::<FeeExemptPrograms>(&ctx.accounts.fee_exempt_programs)? {
+                Some(list) => {
+                    let source_owner_info = &ctx.accounts.source_owner;
+                    require_synced(&[source_owner_info])?;
+                    require_keys_eq!(source_owner_info.key(), source_owner, TransferHookError::ListEntryMismatch);
+                    list.programs.contains(source_owner_info.owner)
+                }
+                None => false,
+            };
+            if ctx.accounts.fee_tiers.owner == &crate::ID && !ctx.accounts.fee_tiers.data_is_empty() {
+                require_synced(&[&ctx.accounts.fee_override])?;
+            }
+            
+            // A tier with a SOL fee pays lamports to the vault instead of the
+            // token fee. Where Token-2022 withholds one anyway it is credited
+            // back in full, so without a fee credit account the token fee stands.
+            let fee_exempt = is_whitelisted || program_exempt || config.fees_paused_at(now);
+            let sol_fee = assigned_fee_tier(&ctx.accounts.fee_tiers, &ctx.accounts.fee_override, &source_owner)?
+                .map(|(_, lamports)| lamports)
+                .filter(|lamports| *lamports > 0 && (withheld_fee.is_none() || fee_credit.is_some()));
+            if fee_exempt {
+                owed = 0;
+            } else if let Some(lamports) = sol_fee {
+                require_sol_fee_payment(
+                    &ctx.accounts.instructions,
+                    &sol_fee_vault(&config_key),
+                    lamports,
+                    &ctx.accounts.source_account.key(),
+                    &ctx.accounts.mint.key(),
+                )?;
+                owed = 0;
+            } else if withheld_fee.is_some() {
+                let pending_fee = load_entry::<PendingFeeChange>(&ctx.accounts.pending_fee)?;
+                let fee_periods = load_entry::<FeeSchedule>(&ctx.accounts.fee_periods)?;
+                owed = hook_fee(
+                    config.active_fee(pending_fee.as_ref(), now),
+                    &ctx.accounts.fee_tiers,
+                    &ctx.accounts.fee_override,
+                    fee_schedule.as_ref(),
+                    fee_periods.and_then(|periods| periods.rate_at(now)),
+                    monthly_volume,
+                    &source_owner,
+                    amount,
+                )?
+                .checked_add(peg_surcharge(
+                    &ctx.accounts.peg_fee_policy,
+                    &ctx.accounts.source_address_tag,
+                    &ctx.accounts.destination_address_tag,
+                    &source_owner,
+                    &destination_owner,
+                    amount,
+                    now,
+                )?)
+                .ok_or(TransferHookError::MathOverflow)?
+                .min(fee);
+            }
+        }
+        // Without a fee credit account the source simply pays the full fee
+        let credit = fee - owed;
+        if credit > 0 {
+            if let Some(fee_credit) = fee_credit.as_mut() {
+                fee_credit.credited = fee_credit.credited
+                    .checked_add(credit)
+                    .ok_or(TransferHookError::MathOverflow)?;
+                store_entry(&ctx.accounts.source_fee_credit, fee_credit)?;
+            }
+        }
+        let net_amount = amount.checked_sub(fee).ok_or(TransferHookError::MathOverflow)?;
+        
+        record_transfer_stats(&ctx.accounts.stats_shard, now, amount, fee)?;
+        
+        // Registered market makers accrue a share of the fee they owed
+        if owed > 0 {
+            if let Some(mut rebate) = load_entry::<MarketMakerRebate>(&ctx.accounts.source_rebate)? {
+                rebate.accrue(owed, now)?;
+                store_entry(&ctx.accounts.source_rebate, &rebate)?;
+            }
+            if let Some(mut link) = load_entry::<ReferralLink>(&ctx.accounts.source_referral)? {
+                link.fees_unsettled = link.fees_unsettled
+                    .checked_add(owed)
+                    .ok_or(TransferHookError::MathOverflow)?;
+                store_entry(&ctx.accounts.source_referral, &link)?;
+            }
+        }
+        
+        // An outgoing transfer resets dormancy and clears any escheatment
+        // flag; the first one starts tracking the account
+        if let Some(mut activity) = load_entry::<ActivityRecord>(&ctx.accounts.source_activity)? {
+            activity.last_activity = now;
+            activity.flagged_at = None;
+            store_entry(&ctx.accounts.source_activity, &activity)?;
+        } else {
+            start_activity_record(
+                &ctx.accounts.source_activity,
+                &ctx.accounts.escheatment_policy,
+                &ctx.accounts.system_program.to_account_info(),
+                &config_key,
+                &ctx.accounts.source_account.key(),
+                now,
+            )?;
+        }
+        
+        // Chain to the integrator's hook once every local check has passed,
+        // one level deep: the secondary gets the transfer's accounts and its
+        // own validation account, which must list no extras, so it holds no
+        // program account to invoke a third program with. The runtime
+        // rejects re-entry, so it can't call back into this one either.
+        let forward_to_hook = load_entry::<ForwardHook>(&ctx.accounts.forward_hook)?
+            .map(|slot| slot.program)
+            .filter(|program| *program != Pubkey::default());
+        if let Some(forward) = forward_to_hook {
+            require!(ctx.accounts.forward_hook_program.key() == forward, TransferHookError::InvalidForwardHook);
+            require_no_forward_extras(&ctx.accounts.forward_hook_meta_list, &forward, &ctx.accounts.mint.key())?;
+            
+            invoke_execute(
+                &forward,
+                ctx.accounts.source_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.destination_account.to_account_info(),
+                ctx.accounts.owner.clone(),
+                &[
+                    ctx.accounts.forward_hook_program.clone(),
+                    ctx.accounts.forward_hook_meta_list.clone(),
+                ],
+                amount,
+            )?;
+        }
+        
+        // Append the transfer to the compressed audit log, if one is set up
+        if let Some(audit) = load_entry::<AuditLog>(&ctx.accounts.audit_log)? {
+            if audit.tree != Pubkey::default() {
+                require!(ctx.accounts.audit_tree.key() == audit.tree, TransferHookError::InvalidAuditTree);
+                let leaf = audit_leaf(
+                    &config_key,
+                    &source_owner,
+                    &destination_owner,
+                    amount,
+                    fee,
+                    now,
+                    audit_tree_sequence(&ctx.accounts.audit_tree)?,
+                );
+                invoke_audit_tree(
+                    "append",
+                    &leaf,
+                    &ctx.accounts.audit_tree,
+                    &ctx.accounts.audit_log,
+                    &ctx.accounts.noop_program,
+                    &[b"audit_log", config_key.as_ref(), &[audit.bump]],
+                )?;
+            }
+        }
+        
+        emit!(TransferExecuted {
+            source: source_owner,
+            destination: destination_owner,
+            amount,
+            fee,
+            net_amount,
+            is_whitelisted,
+            is_delegate,
+            timestamp: now,
+            travel_rule,
+            source_category: address_category(&ctx.accounts.source_address_tag, &source_owner)?,
+            destination_category: address_category(&ctx.accounts.destination_address_tag, &destination_owner)?,
+        });
+        
+        trace_compute_units(cu_entry);
+        Ok(())
+    }
+
+    /// Add address to blacklist. Optionally freezes the target's token accounts in
+    /// the same instruction: pass the accounts freeze_owner_accounts_via_sss
+    /// expects as remaining_accounts (the authority also needs ROLE_PAUSER).
+    pub fn add_to_blacklist<'a>(
+        ctx: Context<'_, '_, 'a, 'a, ManageBlacklist<'a>>,
+        reason_code: ReasonCode,
+        reference: Option<[u8; 32]>,
+        entry_kind: BlacklistEntryKind,
+        expires_at: Option<i64>,
+        restriction_level: RestrictionLevel,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.load()?.blacklist_enabled(), TransferHookError::ComplianceNotEnabled);
+        if let Some(expiry) = expires_at {
+            require!(expiry > Clock::get()?.unix_timestamp, TransferHookError::InvalidExpiry);
+        }
+        
+        let entry = &mut ctx.accounts.blacklist_entry;
+        entry.address = ctx.accounts.target_address.key();
+        entry.reason_code = reason_code;
+        entry.reference = reference;
+        entry.blacklisted_by = ctx.accounts.authority.key();
+        entry.created_at = Clock::get()?.unix_timestamp;
+        entry.is_active = true;
+        entry.bump = 0; // bump stored in PDA, not needed in data
+        entry.entry_kind = entry_kind;
+        entry.expires_at = expires_at;
+        entry.restriction_level = restriction_level;
+        sync_list_membership(
+            &mut entry.listed,
+            true,
+            LIST_BLACKLIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        bloom_insert(
+            &mut *ctx.accounts.config.load_mut()?,
+            ctx.accounts.blacklist_bloom.as_ref(),
+            &ctx.accounts.target_address.key(),
+        )?;
+        
+        let stablecoin = ctx.accounts.config.load()?.stablecoin;
+        let accounts_frozen = if ctx.remaining_accounts.is_empty() {
+            0
+        } else {
+            freeze_owner_accounts_via_sss(
+                &ctx.accounts.authority.to_account_info(),
+                &stablecoin,
+                &ctx.accounts.target_address.key(),
+                ctx.remaining_accounts,
+            )?
+        };
+        
+        emit!(BlacklistAdded {
+            address: ctx.accounts.target_address.key(),
+            entry_kind,
+            expires_at,
+            restriction_level,
+            reason_code,
+            reference,
+            blacklisted_by: ctx.accounts.authority.key(),
+            accounts_frozen,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Remove from blacklist
+    pub fn remove_from_blacklist(ctx: Context<ManageBlacklist>) -> Result<()> {
+        ctx.accounts.config.load_mut()?.note_blacklist_write();
+        let entry = &mut ctx.accounts.blacklist_entry;
+        entry.is_active = false;
+        sync_list_membership(
+            &mut entry.listed,
+            false,
+            LIST_BLACKLIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        
+        emit!(BlacklistRemoved {
+            address: ctx.accounts.target_address.key(),
+            removed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Mark an approved seizure case executed and close it. Only sss-token
+    /// can call this, signing with its permanent delegate PDA from
+    /// seize_tokens, which moves or burns the funds itself. A disputed case
+    /// settles once its clawback's dispute window has passed, releasing the hold.
+    pub fn consume_seizure_case(ctx: Context<ConsumeSeizureCase>) -> Result<()> {
+        let config = ctx.accounts.config.load()?;
+        require!(config.locks & LOCK_SEIZURE == 0, TransferHookError::ParameterLocked);
+        require!(
+            config.permanent_delegate() == Some(ctx.accounts.permanent_delegate.key()),
+            TransferHookError::InvalidAuthority
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let case = &ctx.accounts.seizure_case;
+        match case.status {
+            SeizureStatus::Approved => {}
+            SeizureStatus::Disputed => {
+                let hold = ctx
+                    .accounts
+                    .clawback_hold
+                    .as_ref()
+                    .ok_or(TransferHookError::InvalidSeizureCase)?;
+                require!(hold.case == case.key(), TransferHookError::InvalidSeizureCase);
+                require!(now >= hold.dispute_ends_at, TransferHookError::DisputeWindowOpen);
+                let initiator = ctx
+                    .accounts
+                    .clawback_initiator
+                    .as_ref()
+                    .ok_or(TransferHookError::InvalidSeizureCase)?;
+                require_keys_eq!(initiator.key(), hold.initiated_by, TransferHookError::InvalidSeizureCase);
+                
+                emit!(ClawbackFinalized {
+                    case: case.key(),
+                    target: hold.target,
+                    finalized_by: ctx.accounts.seizer.key(),
+                    timestamp: now,
+                });
+                hold.close(initiator.to_account_info())?;
+            }
+            SeizureStatus::Open => return err!(TransferHookError::InvalidSeizureCase),
+        }
+        
+        Ok(())
+    }
+    
+    /// Move the mint's permanent delegate from the hook's legacy PDA to
+    /// sss-token's, which seize_tokens signs with. For mints created before
+    /// seizure moved into sss-token.
+    pub fn hand_over_permanent_delegate(ctx: Context<HandOverPermanentDelegate>) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        require!(config.locks & LOCK_SEIZURE == 0, TransferHookError::ParameterLocked);
+        
+        let delegate = sss_permanent_delegate(&config.stablecoin);
+        let mint_key = ctx.accounts.mint.key();
+        anchor_spl::token_2022::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token_2022::SetAuthority {
+                    current_authority: ctx.accounts.legacy_delegate.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                &[&[PERMANENT_DELEGATE_SEED, mint_key.as_ref(), &[ctx.bumps.legacy_delegate]]],
+            ),
+            anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType::PermanentDelegate,
+            Some(delegate),
+        )?;
+        config.permanent_delegate = delegate;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "permanent_delegate".to_string(),
+            value: delegate.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Add to whitelist
+    pub fn add_to_whitelist(
+        ctx: Context<ManageWhitelist>,
+        whitelist_type: WhitelistType,
+    ) -> Result<()> {
+        let entry = &mut ctx.accounts.whitelist_entry;
+        entry.address = ctx.accounts.target_address.key();
+        entry.whitelist_type = whitelist_type;
+        entry.added_by = ctx.accounts.authority.key();
+        entry.created_at = Clock::get()?.unix_timestamp;
+        entry.bump = 0; // bump stored in PDA, not needed in data
+        sync_list_membership(
+            &mut entry.listed,
+            true,
+            LIST_WHITELIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        
+        Ok(())
+    }
+
+    /// Remove from whitelist
+    pub fn remove_from_whitelist(_ctx: Context<ManageWhitelist>) -> Result<()> {
+        // Account will be closed by Anchor
+        Ok(())
+    }
+
+    /// Update configuration
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        transfer_fee_basis_points: Option<u16>,
+        max_transfer_fee: Option<u64>,
+        min_transfer_amount: Option<u64>,
+        is_paused: Option<bool>,
+        blacklist_enabled: Option<bool>,
+        permanent_delegate: Option<Option<Pubkey>>,
+    ) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        
+        // Fees apply at once only when they fall; raises go through queue_fee_change
+        if let Some(fee_bps) = transfer_fee_basis_points {
+            require!(
+                fee_bps <= config.transfer_fee_basis_points,
+                TransferHookError::FeeIncreaseNotQueued
+            );
+            config.transfer_fee_basis_points = fee_bps;
+        }
+        if let Some(max) = max_transfer_fee {
+            require!(max <= config.max_transfer_fee, TransferHookError::FeeIncreaseNotQueued);
+            config.max_transfer_fee = max;
+        }
+        if let Some(min) = min_transfer_amount {
+            config.min_transfer_amount = min;
+        }
+        if transfer_fee_basis_points.is_some() || max_transfer_fee.is_some() || min_transfer_amount.is_some() {
+            validate_fee_params(
+                config.transfer_fee_basis_points,
+                config.max_transfer_fee,
+                config.min_transfer_amount,
+            )?;
+        }
+        if let Some(paused) = is_paused {
+            config.is_paused = u8::from(paused);
+            config.pause_reason = PauseReason::Unspecified as u8;
+            config.paused_until = 0;
+        }
+        if let Some(enabled) = blacklist_enabled {
+            config.blacklist_enabled = u8::from(enabled);
+        }
+        if let Some(delegate) = permanent_delegate {
+            if config.locks & LOCK_SEIZURE != 0 {
+                require!(delegate.is_none(), TransferHookError::ParameterLocked);
+            }
+            // Only sss-token's PDA can sign seizures
+            if let Some(d) = delegate {
+                require!(
+                    d == sss_permanent_delegate(&config.stablecoin),
+                    TransferHookError::InvalidPermanentDelegate
+                );
+            }
+            config.permanent_delegate = delegate.unwrap_or_default();
+        }
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "update_config".to_string(),
+            value: "multiple".to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Chain a secondary transfer-hook program after the local checks, or
+    /// stop chaining.
+    pub fn set_forward_hook(
+        ctx: Context<SetForwardHook>,
+        forward_to_hook: Option<Pubkey>,
+    ) -> Result<()> {
+        if let Some(program) = forward_to_hook {
+            require!(
+                program != crate::ID && program != anchor_spl::token_2022::ID,
+                TransferHookError::InvalidForwardHook
+            );
+            let meta_list = ctx.accounts.forward_hook_meta_list.as_ref()
+                .ok_or(TransferHookError::InvalidForwardHook)?;
+            require_no_forward_extras(meta_list, &program, &ctx.accounts.config.load()?.stablecoin)?;
+        }
+        
+        let slot = &mut ctx.accounts.forward_hook;
+        slot.config = ctx.accounts.config.key();
+        slot.program = forward_to_hook.unwrap_or_default();
+        slot.bump = ctx.bumps.forward_hook;
+        if forward_to_hook.is_some() {
+            ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_FORWARD);
+        }
+        
+        emit!(ForwardHookUpdated {
+            authority: ctx.accounts.authority.key(),
+            forward_to_hook,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Start a compressed audit log on a new concurrent Merkle tree. The tree
+    /// account must already be allocated for `max_depth`/`max_buffer_size` and
+    /// owned by account compression; the audit log PDA becomes its authority.
+    /// Replaces any previous tree.
+    pub fn init_audit_tree(
+        ctx: Context<InitAuditTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let config_key = ctx.accounts.config.key();
+        let bump = ctx.accounts.audit_log.bump;
+        let mut args = Vec::with_capacity(8);
+        args.extend_from_slice(&max_depth.to_le_bytes());
+        args.extend_from_slice(&max_buffer_size.to_le_bytes());
+        invoke_audit_tree(
+            "init_empty_merkle_tree",
+            &args,
+            &ctx.accounts.audit_tree,
+            &ctx.accounts.audit_log.to_account_info(),
+            &ctx.accounts.noop_program,
+            &[b"audit_log", config_key.as_ref(), &[bump]],
+        )?;
+        
+        let audit_log = &mut ctx.accounts.audit_log;
+        audit_log.tree = ctx.accounts.audit_tree.key();
+        audit_log.leaf_count = 0;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_AUDIT);
+        
+        emit!(AuditTreeUpdated {
+            authority: ctx.accounts.authority.key(),
+            tree: Some(audit_log.tree),
+            max_depth,
+            max_buffer_size,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Stop appending transfers to the audit tree. The tree and its history stay.
+    pub fn disable_audit_log(ctx: Context<DisableAuditLog>) -> Result<()> {
+        ctx.accounts.audit_log.tree = Pubkey::default();
+        
+        emit!(AuditTreeUpdated {
+            authority: ctx.accounts.authority.key(),
+            tree: None,
+            max_depth: 0,
+            max_buffer_size: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ SEIZURE CASES ============
+    
+    /// Open a seizure case against a token account. Executable only after an
+    /// sss-token multisig proposal carrying ProposalAction::ApproveSeizure passes.
+    pub fn open_seizure_case(
+        ctx: Context<OpenSeizureCase>,
+        case_id: u64,
+        amount: u64,
+        reason_code: ReasonCode,
+        legal_reference: [u8; 32],
+        destroy: bool,
+    ) -> Result<()> {
+        require!(amount > 0, TransferHookError::AmountTooLow);
+        require!(
+            ctx.accounts.config.load()?.locks & LOCK_SEIZURE == 0,
+            TransferHookError::ParameterLocked
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let case = &mut ctx.accounts.seizure_case;
+        case.config = ctx.accounts.config.key();
+        case.case_id = case_id;
+        case.target = ctx.accounts.target_account.key();
+        case.target_owner = ctx.accounts.target_account.owner;
+        case.amount = amount;
+        case.reason_code = reason_code;
+        case.legal_reference = legal_reference;
+        case.status = SeizureStatus::Open;
+        case.opened_by = ctx.accounts.authority.key();
+        case.opened_at = now;
+        case.approved_at = 0;
+        case.bump = ctx.bumps.seizure_case;
+        case.destroy = destroy;
+        case.exceptional_destination = None;
+        case.destination_unlocks_at = 0;
+        
+        emit!(SeizureCaseOpened {
+            case: case.key(),
+            case_id,
+            target: case.target,
+            amount,
+            reason_code,
+            legal_reference,
+            destroy,
+            opened_by: case.opened_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Mark a case approved once the stablecoin's multisig has passed a
+    /// proposal for it. Permissionless; the proposal is the authorization.
+    pub fn approve_seizure_case(ctx: Context<ApproveSeizureCase>) -> Result<()> {
+        let multisig: sss_token_program::MultisigConfig =
+            load_sss_account(&ctx.accounts.multisig_config, "MultisigConfig")?;
+        let proposal: sss_token_program::MultisigProposal =
+            load_sss_account(&ctx.accounts.proposal, "MultisigProposal")?;
+        let now = Clock::get()?.unix_timestamp;
+        
+        let case = &mut ctx.accounts.seizure_case;
+        require!(case.status == SeizureStatus::Open, TransferHookError::InvalidSeizureCase);
+        require_keys_eq!(
+            proposal.config,
+            ctx.accounts.multisig_config.key(),
+            TransferHookError::InvalidProposal
+        );
+        require!(now < proposal.expires_at, TransferHookError::ProposalExpired);
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|a| multisig.signers.contains(a))
+            .count()
+            + proposal
+                .secp_approvals
+                .iter()
+                .filter(|a| multisig.secp_signers.contains(a))
+                .count();
+        require!(
+            approvals >= multisig.threshold as usize,
+            TransferHookError::ThresholdNotMet
+        );
+        let action = sss_token_program::ProposalAction::ApproveSeizure { case: case.key() };
+        require!(
+            proposal.instruction_data == action.try_to_vec()?,
+            TransferHookError::InvalidProposal
+        );
+        
+        case.status = SeizureStatus::Approved;
+        case.approved_at = now;
+        
+        emit!(SeizureCaseApproved {
+            case: case.key(),
+            proposal: ctx.accounts.proposal.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Point a case at a destination outside the treasury and restitution
+    /// registry. Usable only after SEIZURE_ESCAPE_TIMELOCK.
+    pub fn set_exceptional_destination(
+        ctx: Context<SetExceptionalDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let unlocks_at = now
+            .checked_add(SEIZURE_ESCAPE_TIMELOCK)
+            .ok_or(TransferHookError::MathOverflow)?;
+        
+        let case = &mut ctx.accounts.seizure_case;
+        case.exceptional_destination = Some(destination);
+        case.destination_unlocks_at = unlocks_at;
+        
+        emit!(ExceptionalDestinationSet {
+            case: case.key(),
+            destination,
+            unlocks_at,
+            set_by: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Register a token account that seizures may pay out to
+    pub fn add_restitution_account(ctx: Context<AddRestitutionAccount>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let entry = &mut ctx.accounts.restitution_entry;
+        entry.config = ctx.accounts.config.key();
+        entry.token_account = ctx.accounts.token_account.key();
+        entry.added_by = ctx.accounts.authority.key();
+        entry.added_at = now;
+        entry.bump = ctx.bumps.restitution_entry;
+        
+        emit!(RestitutionAccountAdded {
+            authority: entry.added_by,
+            token_account: entry.token_account,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove a token account from the restitution registry
+    pub fn remove_restitution_account(ctx: Context<RemoveRestitutionAccount>) -> Result<()> {
+        emit!(RestitutionAccountRemoved {
+            authority: ctx.accounts.authority.key(),
+            token_account: ctx.accounts.restitution_entry.token_account,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Withdraw a seizure case before it is executed
+    pub fn cancel_seizure_case(ctx: Context<CancelSeizureCase>) -> Result<()> {
+        // A disputed clawback is released through cancel_clawback with its hold
+        require!(
+            ctx.accounts.seizure_case.status != SeizureStatus::Disputed,
+            TransferHookError::InvalidSeizureCase
+        );
+        
+        emit!(SeizureCaseCancelled {
+            case: ctx.accounts.seizure_case.key(),
+            cancelled_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set the guardian and dispute window used by clawbacks
+    pub fn set_clawback_policy(
+        ctx: Context<SetClawbackPolicy>,
+        guardian: Pubkey,
+        dispute_window: i64,
+    ) -> Result<()> {
+        require!(
+            dispute_window > 0 && dispute_window <= MAX_DISPUTE_WINDOW,
+            TransferHookError::InvalidDisputeWindow
+        );
+        
+        let policy = &mut ctx.accounts.clawback_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.guardian = guardian;
+        policy.dispute_window = dispute_window;
+        policy.bump = ctx.bumps.clawback_policy;
+        
+        emit!(ClawbackPolicyUpdated {
+            authority: ctx.accounts.authority.key(),
+            guardian,
+            dispute_window,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Start the two-phase execution of an approved case: hold the case
+    /// amount (or the whole account) on the target and open the dispute
+    /// window, during which the guardian can cancel.
+    pub fn initiate_clawback(ctx: Context<InitiateClawback>, freeze_account: bool) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let dispute_ends_at = now
+            .checked_add(ctx.accounts.clawback_policy.dispute_window)
+            .ok_or(TransferHookError::MathOverflow)?;
+        
+        let case = &mut ctx.accounts.seizure_case;
+        require!(case.status == SeizureStatus::Approved, TransferHookError::InvalidSeizureCase);
+        case.status = SeizureStatus::Disputed;
+        
+        let hold = &mut ctx.accounts.clawback_hold;
+        hold.config = ctx.accounts.config.key();
+        hold.case = case.key();
+        hold.target = case.target;
+        hold.amount = case.amount;
+        hold.freeze_account = freeze_account;
+        hold.initiated_by = ctx.accounts.authority.key();
+        hold.initiated_at = now;
+        hold.dispute_ends_at = dispute_ends_at;
+        hold.bump = ctx.bumps.clawback_hold;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_CLAWBACK);
+        
+        emit!(ClawbackInitiated {
+            case: hold.case,
+            target: hold.target,
+            amount: hold.amount,
+            freeze_account,
+            dispute_ends_at,
+            initiated_by: hold.initiated_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Guardian upholds a dispute: release the hold and drop the case
+    pub fn cancel_clawback(ctx: Context<CancelClawback>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < ctx.accounts.clawback_hold.dispute_ends_at,
+            TransferHookError::DisputeWindowClosed
+        );
+        
+        emit!(ClawbackCancelled {
+            case: ctx.accounts.seizure_case.key(),
+            target: ctx.accounts.clawback_hold.target,
+            guardian: ctx.accounts.guardian.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ PAUSE ============
+    
+    /// Halt all transfers. Callable by the authority or an sss-token pauser;
+    /// `paused_until` makes the pause lift itself.
+    pub fn pause_transfers(
+        ctx: Context<PauseTransfers>,
+        reason: PauseReason,
+        paused_until: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(until) = paused_until {
+            require!(until > now, TransferHookError::InvalidExpiry);
+        }
+        
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.is_paused = 1;
+        config.pause_reason = reason as u8;
+        config.paused_until = paused_until.unwrap_or(0);
+        
+        emit!(TransfersPaused {
+            authority: ctx.accounts.authority.key(),
+            reason,
+            paused_until,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Lift the transfer pause
+    pub fn unpause_transfers(ctx: Context<PauseTransfers>) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.is_paused = 0;
+        config.pause_reason = PauseReason::Unspecified as u8;
+        config.paused_until = 0;
+        
+        emit!(TransfersUnpaused {
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Stop charging hook fees while transfers continue. Fee admin only.
+    pub fn pause_fees(
+        ctx: Context<UpdateConfig>,
+        reason: PauseReason,
+        paused_until: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(until) = paused_until {
+            require!(until > now, TransferHookError::InvalidExpiry);
+        }
+        
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.fees_paused = 1;
+        config.fees_paused_until = paused_until.unwrap_or(0);
+        
+        emit!(FeesPaused {
+            authority: ctx.accounts.authority.key(),
+            reason,
+            paused_until,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Resume charging hook fees
+    pub fn unpause_fees(ctx: Context<UpdateConfig>) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.fees_paused = 0;
+        config.fees_paused_until = 0;
+        
+        emit!(FeesUnpaused {
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ BATCH OPERATIONS ============
+    
+    /// Batch blacklist multiple addresses
+    pub fn batch_blacklist<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchBlacklist<'info>>,
+        entries: Vec<BatchBlacklistItem>,
+    ) -> Result<()> {
+        require!(
+            entries.len() <= MAX_BATCH_BLACKLIST,
+            TransferHookError::InvalidInstruction
+        );
+        // remaining_accounts[i] is the blacklist PDA for entries[i]
+        require!(
+            entries.len() == ctx.remaining_accounts.len(),
+            TransferHookError::InvalidInstruction
+        );
+        
+        require!(ctx.accounts.config.load()?.blacklist_enabled(), TransferHookError::ComplianceNotEnabled);
+        
+        let config_key = ctx.accounts.config.key();
+        let authority = ctx.accounts.authority.key();
+        let payer = ctx.accounts.authority.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let now = Clock::get()?.unix_timestamp;
+        
+        // Entries that cannot be written are skipped and reported; the rest still land
+        let mut count: u16 = 0;
+        let mut skipped = Vec::new();
+        for (item, entry_info) in entries.iter().zip(ctx.remaining_accounts.iter()) {
+            if write_batch_blacklist_entry(
+                entry_info, &payer, &system_program, &config_key, &authority, item, now,
+                ctx.accounts.list_registry.as_deref_mut(),
+                ctx.accounts.list_index.as_deref_mut(),
+            )? {
+                bloom_insert(&mut *ctx.accounts.config.load_mut()?, ctx.accounts.blacklist_bloom.as_ref(), &item.address)?;
+                count += 1;
+                emit!(BlacklistAdded {
+                    address: item.address,
+                    entry_kind: item.entry_kind,
+                    expires_at: item.expires_at,
+                    restriction_level: item.restriction_level,
+                    reason_code: item.reason_code,
+                    reference: item.reference,
+                    blacklisted_by: authority,
+                    accounts_frozen: 0,
+                    timestamp: now,
+                });
+            } else {
+                skipped.push(item.address);
+            }
+        }
+        
+        emit!(BatchBlacklistAdded {
+            authority,
+            count,
+            skipped,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ KYC ============
+    
+    /// Create or update the KYC record for an address
+    pub fn set_kyc_record(
+        ctx: Context<SetKycRecord>,
+        tier: KycTier,
+        provider: Pubkey,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(expiry) = expires_at {
+            require!(expiry > now, TransferHookError::InvalidExpiry);
+        }
+        
+        let record = &mut ctx.accounts.kyc_record;
+        record.address = ctx.accounts.target_address.key();
+        record.tier = tier;
+        record.provider = provider;
+        record.expires_at = expires_at;
+        record.updated_at = now;
+        record.bump = ctx.bumps.kyc_record;
+        
+        emit!(KycRecordUpdated {
+            address: record.address,
+            tier,
+            provider,
+            expires_at,
+            updated_by: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set per-tier transfer limits and balance caps, indexed None, Basic, Enhanced
+    pub fn set_kyc_policy(
+        ctx: Context<SetKycPolicy>,
+        tier_limits: [TierLimits; 3],
+        tier_max_balance: [u64; 3],
+    ) -> Result<()> {
+        let policy = &mut ctx.accounts.kyc_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.tier_limits = tier_limits;
+        policy.bump = ctx.bumps.kyc_policy;
+        policy.tier_max_balance = tier_max_balance;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_KYC);
+        
+        emit!(KycPolicyUpdated {
+            authority: ctx.accounts.authority.key(),
+            tier_limits,
+            tier_max_balance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Register (or update) a KYC provider key and the tiers it may attest
+    pub fn register_kyc_provider(
+        ctx: Context<RegisterKycProvider>,
+        provider: Pubkey,
+        allowed_tiers: u8,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let entry = &mut ctx.accounts.kyc_provider;
+        entry.config = ctx.accounts.config.key();
+        entry.provider = provider;
+        entry.allowed_tiers = allowed_tiers;
+        entry.registered_by = ctx.accounts.authority.key();
+        entry.registered_at = now;
+        entry.bump = ctx.bumps.kyc_provider;
+        
+        emit!(KycProviderRegistered {
+            provider,
+            allowed_tiers,
+            authority: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove a KYC provider. Records it already attested stay in place.
+    pub fn remove_kyc_provider(ctx: Context<RemoveKycProvider>) -> Result<()> {
+        emit!(KycProviderRemoved {
+            provider: ctx.accounts.kyc_provider.provider,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Create or update a KYC record from a provider's signed attestation.
+    /// Must directly follow an ed25519 program instruction verifying the
+    /// provider's signature over `kyc_attestation_message`.
+    pub fn submit_kyc_attestation(
+        ctx: Context<SubmitKycAttestation>,
+        tier: KycTier,
+        expires_at: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let provider = &ctx.accounts.kyc_provider;
+        require!(provider.allows(tier), TransferHookError::TierNotAllowed);
+        require!(expires_at > now, TransferHookError::InvalidExpiry);
+        
+        let address = ctx.accounts.target_address.key();
+        let message = kyc_attestation_message(
+            &ctx.accounts.config.key(),
+            &address,
+            tier,
+            expires_at,
+            nonce,
+        );
+        verify_ed25519_instruction(&ctx.accounts.instructions, &provider.provider, &message)?;
+        
+        let record = &mut ctx.accounts.kyc_record;
+        require!(nonce > record.nonce, TransferHookError::StaleNonce);
+        record.address = address;
+        record.tier = tier;
+        record.provider = provider.provider;
+        record.expires_at = Some(expires_at);
+        record.updated_at = now;
+        record.bump = ctx.bumps.kyc_record;
+        record.nonce = nonce;
+        
+        emit!(KycRecordUpdated {
+            address,
+            tier,
+            provider: provider.provider,
+            expires_at: Some(expires_at),
+            updated_by: provider.provider,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ VELOCITY LIMITS ============
+    
+    /// Set rolling 24h limits per source owner. Zero disables a limit.
+    pub fn set_velocity_limits(
+        ctx: Context<SetTransferPolicy>,
+        max_daily_volume: u64,
+        max_daily_count: u32,
+    ) -> Result<()> {
+        let policy = &mut ctx.accounts.transfer_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.max_daily_volume = max_daily_volume;
+        policy.max_daily_count = max_daily_count;
+        policy.bump = ctx.bumps.transfer_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(VelocityLimitsUpdated {
+            authority: ctx.accounts.authority.key(),
+            max_daily_volume,
+            max_daily_count,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Create the velocity tracker for an owner. Permissionless; required
+    /// before the owner can send once velocity limits are set.
+    pub fn init_velocity_tracker(ctx: Context<InitVelocityTracker>) -> Result<()> {
+        let mut tracker = ctx.accounts.velocity_tracker.load_init()?;
+        tracker.config = ctx.accounts.config.key();
+        tracker.owner = ctx.accounts.owner.key();
+        let now = Clock::get()?.unix_timestamp;
+        tracker.last_hour = now.div_euclid(3600);
+        tracker.last_day = now.div_euclid(SECONDS_PER_DAY);
+        tracker.bump = ctx.bumps.velocity_tracker;
+        
+        Ok(())
+    }
+    
+    /// Lock outgoing transfers for `cooldown_seconds` after an inbound transfer
+    /// of at least `cooldown_threshold`. Zero seconds disables.
+    pub fn set_cooldown(
+        ctx: Context<SetTransferPolicy>,
+        cooldown_seconds: i64,
+        cooldown_threshold: u64,
+    ) -> Result<()> {
+        require!(cooldown_seconds >= 0, TransferHookError::InvalidInstruction);
+        
+        let policy = &mut ctx.accounts.transfer_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.cooldown_seconds = cooldown_seconds;
+        policy.cooldown_threshold = cooldown_threshold;
+        policy.bump = ctx.bumps.transfer_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(CooldownUpdated {
+            authority: ctx.accounts.authority.key(),
+            cooldown_seconds,
+            cooldown_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ FEE CHANGES ============
+    
+    /// Create the pending fee change account with the minimum notice a fee
+    /// increase must be given
+    pub fn init_pending_fee(ctx: Context<InitPendingFee>, min_delay: i64) -> Result<()> {
+        require!(min_delay >= MIN_FEE_CHANGE_DELAY, TransferHookError::InvalidFeeChange);
+        
+        let pending = &mut ctx.accounts.pending_fee;
+        pending.config = ctx.accounts.config.key();
+        pending.min_delay = min_delay;
+        pending.bump = ctx.bumps.pending_fee;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_PENDING_FEE);
+        Ok(())
+    }
+    
+    /// Raise the minimum notice. It can't be lowered, or a shorter delay
+    /// could be set right before queueing an increase.
+    pub fn set_fee_change_delay(ctx: Context<ManagePendingFee>, min_delay: i64) -> Result<()> {
+        let pending = &mut ctx.accounts.pending_fee;
+        require!(min_delay >= pending.min_delay, TransferHookError::InvalidFeeChange);
+        pending.min_delay = min_delay;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "fee_change_delay".to_string(),
+            value: min_delay.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Queue new global fee parameters to take effect after `delay` seconds.
+    /// Replaces any change still waiting.
+    pub fn queue_fee_change(
+        ctx: Context<ManagePendingFee>,
+        fee_bps: u16,
+        max_fee: u64,
+        delay: i64,
+    ) -> Result<()> {
+        let config = ctx.accounts.config.load()?;
+        validate_fee_params(fee_bps, max_fee, config.min_transfer_amount)?;
+        if config.locks & LOCK_FEE_CEILING != 0 {
+            require!(fee_bps <= config.fee_ceiling_bps, TransferHookError::ParameterLocked);
+        }
+        
+        let pending = &mut ctx.accounts.pending_fee;
+        require!(delay >= pending.min_delay, TransferHookError::InvalidFeeChange);
+        let now = Clock::get()?.unix_timestamp;
+        pending.fee_bps = fee_bps;
+        pending.max_fee = max_fee;
+        pending.activates_at = now.checked_add(delay).ok_or(TransferHookError::MathOverflow)?;
+        
+        emit!(FeeChangeQueued {
+            config: ctx.accounts.config.key(),
+            fee_bps,
+            max_fee,
+            activates_at: pending.activates_at,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Drop the queued fee change
+    pub fn cancel_fee_change(ctx: Context<ManagePendingFee>) -> Result<()> {
+        let pending = &mut ctx.accounts.pending_fee;
+        require!(pending.activates_at != 0, TransferHookError::InvalidFeeChange);
+        pending.activates_at = 0;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "pending_fee".to_string(),
+            value: "cancelled".to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Copy an active fee change into the config. Permissionless; the hook
+    /// already charges the new rate from activation.
+    pub fn apply_fee_change(ctx: Context<ApplyFeeChange>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pending = &mut ctx.accounts.pending_fee;
+        require!(
+            pending.activates_at != 0 && now >= pending.activates_at,
+            TransferHookError::InvalidFeeChange
+        );
+        
+        let mut config = ctx.accounts.config.load_mut()?;
+        if config.locks & LOCK_FEE_CEILING != 0 {
+            require!(pending.fee_bps <= config.fee_ceiling_bps, TransferHookError::ParameterLocked);
+        }
+        config.transfer_fee_basis_points = pending.fee_bps;
+        config.max_transfer_fee = pending.max_fee;
+        pending.activates_at = 0;
+        
+        emit!(FeeChangeApplied {
+            config: ctx.accounts.config.key(),
+            fee_bps: pending.fee_bps,
+            max_fee: pending.max_fee,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ FEE PERIODS ============
+    
+    /// Schedule a dated fee rate, e.g. `fee_bps = 0` for a fee holiday.
+    /// Periods that have ended are pruned to make room. The rate can only
+    /// lower the withheld TransferFee, so the mint must have one.
+    pub fn add_fee_period(
+        ctx: Context<ManageFeePeriods>,
+        start: i64,
+        end: i64,
+        fee_bps: u16,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            start < end && end > now && start <= now + MAX_FEE_PERIOD_LEAD,
+            TransferHookError::InvalidFeePeriod
+        );
+        require!(fee_bps <= MAX_TRANSFER_FEE_BPS, TransferHookError::FeeTooHigh);
+        require!(
+            has_transfer_fee(&ctx.accounts.mint.to_account_info())?,
+            TransferHookError::TransferFeeRequired
+        );
+        
+        let schedule = &mut ctx.accounts.fee_periods;
+        schedule.config = ctx.accounts.config.key();
+        schedule.bump = ctx.bumps.fee_periods;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_FEE_PERIODS);
+        schedule.periods.retain(|p| p.end > now);
+        require!(schedule.periods.len() < MAX_FEE_PERIODS, TransferHookError::InvalidFeePeriod);
+        schedule.periods.push(FeePeriod { start, end, fee_bps });
+        
+        emit!(FeePeriodUpdated {
+            config: schedule.config,
+            start,
+            end,
+            fee_bps,
+            removed: false,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove the period starting at `start`, ending it early if it is running
+    pub fn remove_fee_period(ctx: Context<ManageFeePeriods>, start: i64) -> Result<()> {
+        let schedule = &mut ctx.accounts.fee_periods;
+        let index = schedule
+            .periods
+            .iter()
+            .position(|p| p.start == start)
+            .ok_or(TransferHookError::InvalidFeePeriod)?;
+        let period = schedule.periods.remove(index);
+        
+        emit!(FeePeriodUpdated {
+            config: schedule.config,
+            start: period.start,
+            end: period.end,
+            fee_bps: period.fee_bps,
+            removed: true,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ MARKET MAKER REBATES ============
+    
+    /// Register or update a market maker's rebate share and monthly cap
+    pub fn register_market_maker(
+        ctx: Context<RegisterMarketMaker>,
+        rebate_bps: u16,
+        monthly_cap: u64,
+    ) -> Result<()> {
+        require!(
+            rebate_bps > 0 && rebate_bps <= MAX_REBATE_BPS && monthly_cap > 0,
+            TransferHookError::InvalidRebate
+        );
+        let now = Clock::get()?.unix_timestamp;
+        
+        let rebate = &mut ctx.accounts.rebate;
+        rebate.roll(now)?;
+        rebate.config = ctx.accounts.config.key();
+        rebate.owner = ctx.accounts.market_maker.key();
+        rebate.rebate_bps = rebate_bps;
+        rebate.monthly_cap = monthly_cap;
+        rebate.active = true;
+        rebate.bump = ctx.bumps.rebate;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_REBATE);
+        
+        emit!(MarketMakerUpdated {
+            authority: ctx.accounts.authority.key(),
+            owner: rebate.owner,
+            rebate_bps,
+            monthly_cap,
+            active: true,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Stop accruing rebates for a market maker. The account stays open so
+    /// rebates already accrued remain claimable.
+    pub fn deregister_market_maker(ctx: Context<DeregisterMarketMaker>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let rebate = &mut ctx.accounts.rebate;
+        rebate.roll(now)?;
+        rebate.active = false;
+        
+        emit!(MarketMakerUpdated {
+            authority: ctx.accounts.authority.key(),
+            owner: rebate.owner,
+            rebate_bps: rebate.rebate_bps,
+            monthly_cap: rebate.monthly_cap,
+            active: false,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ REFERRALS ============
+    
+    /// Register a referrer, or change the share applied at its next settlements
+    pub fn set_referrer(ctx: Context<SetReferrer>, share_bps: u16) -> Result<()> {
+        require!(share_bps <= MAX_REFERRAL_BPS, TransferHookError::InvalidReferral);
+        
+        let referrer = &mut ctx.accounts.referrer_account;
+        referrer.config = ctx.accounts.config.key();
+        referrer.referrer = ctx.accounts.referrer.key();
+        referrer.share_bps = share_bps;
+        referrer.bump = ctx.bumps.referrer_account;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: format!("referrer_share:{}", referrer.referrer),
+            value: share_bps.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Link an address to a registered referrer
+    pub fn link_referral(ctx: Context<LinkReferral>) -> Result<()> {
+        let address = ctx.accounts.address.key();
+        let referrer = ctx.accounts.referrer_account.referrer;
+        require!(address != referrer, TransferHookError::InvalidReferral);
+        
+        let link = &mut ctx.accounts.referral_link;
+        link.config = ctx.accounts.config.key();
+        link.address = address;
+        link.referrer = referrer;
+        link.fees_unsettled = 0;
+        link.bump = ctx.bumps.referral_link;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_REFERRAL);
+        
+        emit!(ReferralUpdated {
+            authority: ctx.accounts.authority.key(),
+            address,
+            referrer: Some(referrer),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Settle and close an address's referral link
+    pub fn unlink_referral(ctx: Context<UnlinkReferral>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        settle_referral_fees(&mut ctx.accounts.referral_link, &mut ctx.accounts.referrer_account, now)?;
+        
+        emit!(ReferralUpdated {
+            authority: ctx.accounts.authority.key(),
+            address: ctx.accounts.referral_link.address,
+            referrer: None,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Move the referrer's share of an address's unsettled fees to the
+    /// referrer (permissionless crank)
+    pub fn settle_referral(ctx: Context<SettleReferral>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        settle_referral_fees(&mut ctx.accounts.referral_link, &mut ctx.accounts.referrer_account, now)
+    }
+    
+    // ============ PEG FEE POLICY ============
+    
+    /// Configure the peg-deviation surcharge. Switching oracles clears the
+    /// stored price, so surcharges stop until the new oracle posts one.
+    /// The surcharge is taken out of the withheld TransferFee, so the mint
+    /// must have one; new parameters give the same notice as a queued fee
+    /// change, with surcharges off meanwhile, and stay under a locked ceiling.
+    pub fn set_peg_fee_policy(
+        ctx: Context<SetPegFeePolicy>,
+        oracle: Pubkey,
+        enabled: bool,
+        max_price_age: i64,
+        threshold_bps: u16,
+        surcharge_pct: u16,
+        max_surcharge_bps: u16,
+    ) -> Result<()> {
+        require!(
+            oracle != Pubkey::default()
+                && max_price_age > 0
+                && max_price_age <= MAX_PEG_PRICE_AGE
+                && threshold_bps <= 10_000
+                && surcharge_pct <= MAX_PEG_SURCHARGE_PCT
+                && max_surcharge_bps <= MAX_PEG_SURCHARGE_BPS,
+            TransferHookError::InvalidPegFeePolicy
+        );
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.note_meta_group_used(META_GROUP_PEG_FEE);
+        if enabled {
+            require!(
+                has_transfer_fee(&ctx.accounts.mint.to_account_info())?,
+                TransferHookError::TransferFeeRequired
+            );
+            if config.locks & LOCK_FEE_CEILING != 0 {
+                require!(
+                    config.transfer_fee_basis_points as u32 + max_surcharge_bps as u32 <= config.fee_ceiling_bps as u32,
+                    TransferHookError::ParameterLocked
+                );
+            }
+        }
+        let now = Clock::get()?.unix_timestamp;
+        let notice = load_entry::<PendingFeeChange>(&ctx.accounts.pending_fee)?
+            .map(|pending| pending.min_delay)
+            .unwrap_or(MIN_FEE_CHANGE_DELAY)
+            .max(MIN_FEE_CHANGE_DELAY);
+        
+        let policy = &mut ctx.accounts.peg_fee_policy;
+        if policy.oracle != oracle {
+            policy.price = 0;
+            policy.price_updated_at = 0;
+        }
+        policy.config = ctx.accounts.config.key();
+        policy.oracle = oracle;
+        policy.enabled = enabled;
+        policy.max_price_age = max_price_age;
+        policy.threshold_bps = threshold_bps;
+        policy.surcharge_pct = surcharge_pct;
+        policy.max_surcharge_bps = max_surcharge_bps;
+        policy.effective_at = now.checked_add(notice).ok_or(TransferHookError::MathOverflow)?;
+        policy.bump = ctx.bumps.peg_fee_policy;
+        
+        emit!(PegFeePolicyUpdated {
+            authority: ctx.accounts.authority.key(),
+            oracle,
+            enabled,
+            max_price_age,
+            threshold_bps,
+            surcharge_pct,
+            max_surcharge_bps,
+            effective_at: policy.effective_at,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Post the market price, scaled by PEG_PRICE_SCALE (oracle only)
+    pub fn update_peg_price(ctx: Context<UpdatePegPrice>, price: u64) -> Result<()> {
+        require!(price > 0, TransferHookError::InvalidPegFeePolicy);
+        let now = Clock::get()?.unix_timestamp;
+        
+        let policy = &mut ctx.accounts.peg_fee_policy;
+        policy.price = price;
+        policy.price_updated_at = now;
+        
+        emit!(PegPriceUpdated {
+            oracle: policy.oracle,
+            price,
+            deviation_bps: policy.deviation_bps(now).unwrap_or(0),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ FEE TIERS ============
+    
+    /// Create or update a fee tier
+    pub fn set_fee_tier(
+        ctx: Context<SetFeeTier>,
+        tier: u8,
+        fee_bps: u16,
+        max_fee: u64,
+        active: bool,
+    ) -> Result<()> {
+        require!((tier as usize) < MAX_FEE_TIERS, TransferHookError::InvalidFeeTier);
+        require!(fee_bps <= MAX_TRANSFER_FEE_BPS, TransferHookError::FeeTooHigh);
+        let mut config = ctx.accounts.config.load_mut()?;
+        if config.locks & LOCK_FEE_CEILING != 0 {
+            require!(fee_bps <= config.fee_ceiling_bps, TransferHookError::ParameterLocked);
+        }
+        config.note_meta_group_used(META_GROUP_FEE_TIERS);
+        
+        let fee_tiers = &mut ctx.accounts.fee_tiers;
+        fee_tiers.config = ctx.accounts.config.key();
+        fee_tiers.tiers[tier as usize] = FeeTierRate { fee_bps, max_fee, active };
+        fee_tiers.bump = ctx.bumps.fee_tiers;
+        
+        emit!(FeeTierUpdated {
+            authority: ctx.accounts.authority.key(),
+            tier,
+            fee_bps,
+            max_fee,
+            active,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Make a tier pay a flat `lamports` per transfer to the SOL fee vault in
+    /// place of its token fee, or return it to the token fee with 0. On a
+    /// TransferFee mint the withheld fee is credited back, so only sources
+    /// with a fee credit account pay in SOL. Funds the vault up to rent
+    /// exemption on first use.
+    pub fn set_tier_sol_fee(ctx: Context<SetTierSolFee>, tier: u8, lamports: u64) -> Result<()> {
+        require!((tier as usize) < MAX_FEE_TIERS, TransferHookError::InvalidFeeTier);
+        require!(lamports <= MAX_SOL_FEE_LAMPORTS, TransferHookError::FeeTooHigh);
+        
+        let shortfall = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(ctx.accounts.sol_fee_vault.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.sol_fee_vault.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        
+        ctx.accounts.fee_tiers.sol_fees[tier as usize] = lamports;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: format!("tier_sol_fee:{}", tier),
+            value: lamports.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Withdraw collected SOL fees, leaving the vault rent exempt
+    pub fn withdraw_sol_fees(ctx: Context<WithdrawSolFees>, amount: u64) -> Result<()> {
+        let available = ctx.accounts.sol_fee_vault
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        require!(amount > 0 && amount <= available, TransferHookError::AmountTooLow);
+        
+        let config_key = ctx.accounts.config.key();
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.sol_fee_vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                },
+                &[&[b"sol_fee_vault", config_key.as_ref(), &[ctx.bumps.sol_fee_vault]]],
+            ),
+            amount,
+        )?;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "sol_fees_withdrawn".to_string(),
+            value: amount.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set one volume discount slot: sources whose prior 30-day volume is at
+    /// least `min_volume` get `discount_bps` of the fee credited back. Zero
+    /// clears it. Only a mint withholding a TransferFee has a fee to discount.
+    pub fn set_volume_discount(
+        ctx: Context<SetFeeTierSchedule>,
+        index: u8,
+        min_volume: u64,
+        discount_bps: u16,
+    ) -> Result<()> {
+        require!((index as usize) < MAX_VOLUME_DISCOUNTS, TransferHookError::InvalidVolumeDiscount);
+        require!(discount_bps <= 10_000, TransferHookError::InvalidVolumeDiscount);
+        require!(
+            discount_bps == 0 || has_transfer_fee(&ctx.accounts.mint.to_account_info())?,
+            TransferHookError::TransferFeeRequired
+        );
+        
+        let schedule = &mut ctx.accounts.fee_schedule;
+        schedule.config = ctx.accounts.config.key();
+        schedule.discounts[index as usize] = VolumeDiscount { min_volume, discount_bps };
+        schedule.bump = ctx.bumps.fee_schedule;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(VolumeDiscountUpdated {
+            authority: ctx.accounts.authority.key(),
+            index,
+            min_volume,
+            discount_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Assign an address to a fee tier
+    pub fn set_address_fee_override(
+        ctx: Context<SetAddressFeeOverride>,
+        tier: u8,
+    ) -> Result<()> {
+        require!((tier as usize) < MAX_FEE_TIERS, TransferHookError::InvalidFeeTier);
+        
+        let fee_override = &mut ctx.accounts.fee_override;
+        fee_override.config = ctx.accounts.config.key();
+        fee_override.address = ctx.accounts.target_address.key();
+        fee_override.tier = tier;
+        fee_override.bump = ctx.bumps.fee_override;
+        
+        emit!(FeeOverrideUpdated {
+            authority: ctx.accounts.authority.key(),
+            address: fee_override.address,
+            tier: Some(tier),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove an address's fee tier, returning it to the global rate
+    pub fn remove_address_fee_override(ctx: Context<RemoveAddressFeeOverride>) -> Result<()> {
+        emit!(FeeOverrideUpdated {
+            authority: ctx.accounts.authority.key(),
+            address: ctx.accounts.fee_override.address,
+            tier: None,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Open an owner's fee credit account so the hook can credit back the
+    /// part of the withheld fee its tier doesn't charge (permissionless)
+    pub fn init_fee_credit(ctx: Context<InitFeeCredit>) -> Result<()> {
+        let fee_credit = &mut ctx.accounts.fee_credit;
+        fee_credit.config = ctx.accounts.config.key();
+        fee_credit.owner = ctx.accounts.owner.key();
+        fee_credit.credited = 0;
+        fee_credit.bump = ctx.bumps.fee_credit;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_FEE_CREDIT);
+        
+        Ok(())
+    }
+    
+    /// Exempt transfers from accounts owned by `program_id` from hook fees
+    pub fn add_fee_exempt_program(
+        ctx: Context<AddFeeExemptProgram>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        // Every wallet is system-owned, so exempting it would waive all fees
+        require!(
+            program_id != anchor_lang::system_program::ID && program_id != Pubkey::default(),
+            TransferHookError::InvalidFeeExemptProgram
+        );
+        
+        let list = &mut ctx.accounts.fee_exempt_programs;
+        list.config = ctx.accounts.config.key();
+        list.bump = ctx.bumps.fee_exempt_programs;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_FEE_EXEMPT);
+        if !list.programs.contains(&program_id) {
+            require!(
+                list.programs.len() < MAX_FEE_EXEMPT_PROGRAMS,
+                TransferHookError::FeeExemptListFull
+            );
+            list.programs.push(program_id);
+        }
+        
+        emit!(FeeExemptProgramAdded {
+            authority: ctx.accounts.authority.key(),
+            program_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove a program from the fee exempt list
+    pub fn remove_fee_exempt_program(
+        ctx: Context<RemoveFeeExemptProgram>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        let list = &mut ctx.accounts.fee_exempt_programs;
+        let index = list
+            .programs
+            .iter()
+            .position(|p| *p == program_id)
+            .ok_or(TransferHookError::InvalidFeeExemptProgram)?;
+        list.programs.swap_remove(index);
+        
+        emit!(FeeExemptProgramRemoved {
+            authority: ctx.accounts.authority.key(),
+            program_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ TRANSFER SCHEDULE ============
+    
+    /// Restrict non-whitelisted transfers to UTC trading days and hours.
+    /// `trading_days` = 0 lifts the schedule; maintenance windows always apply.
+    pub fn set_transfer_schedule(
+        ctx: Context<SetTransferPolicy>,
+        trading_days: u8,
+        open_hour: u8,
+        close_hour: u8,
+        maintenance_windows: [MaintenanceWindow; MAX_MAINTENANCE_WINDOWS],
+    ) -> Result<()> {
+        require!(
+            trading_days < 1 << 7 && open_hour < 24 && close_hour < 24,
+            TransferHookError::InvalidSchedule
+        );
+        for window in &maintenance_windows {
+            require!(
+                *window == MaintenanceWindow::default() || window.start < window.end,
+                TransferHookError::InvalidSchedule
+            );
+        }
+        
+        let policy = &mut ctx.accounts.transfer_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.trading_days = trading_days;
+        policy.open_hour = open_hour;
+        policy.close_hour = close_hour;
+        policy.maintenance_windows = maintenance_windows;
+        policy.bump = ctx.bumps.transfer_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(TransferScheduleUpdated {
+            authority: ctx.accounts.authority.key(),
+            trading_days,
+            open_hour,
+            close_hour,
+            maintenance_windows,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ LARGE TRANSFER APPROVALS ============
+    
+    /// Set the amount at or above which transfers need a pre-approval. Zero disables.
+    pub fn set_approval_threshold(
+        ctx: Context<SetTransferPolicy>,
+        approval_threshold: u64,
+    ) -> Result<()> {
+        let policy = &mut ctx.accounts.transfer_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.approval_threshold = approval_threshold;
+        policy.bump = ctx.bumps.transfer_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(ApprovalThresholdUpdated {
+            authority: ctx.accounts.authority.key(),
+            approval_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set the amount above which transfers need a travel-rule reference
+    pub fn set_travel_rule_threshold(
+        ctx: Context<SetTransferPolicy>,
+        travel_rule_threshold: u64,
+    ) -> Result<()> {
+        let policy = &mut ctx.accounts.transfer_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.travel_rule_threshold = travel_rule_threshold;
+        policy.bump = ctx.bumps.transfer_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_POLICY);
+        
+        emit!(TravelRuleThresholdUpdated {
+            authority: ctx.accounts.authority.key(),
+            travel_rule_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Attach travel-rule data to the sender's next transfer of exactly
+    /// `amount` to `destination_owner`. Replaces any previous record.
+    pub fn attach_travel_rule(
+        ctx: Context<AttachTravelRule>,
+        amount: u64,
+        ivms101_hash: [u8; 32],
+        originator_vasp: [u8; 32],
+        beneficiary_vasp: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, TransferHookError::AmountTooLow);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let record = &mut ctx.accounts.travel_rule_record;
+        record.config = ctx.accounts.config.key();
+        record.source = ctx.accounts.source_owner.key();
+        record.destination = ctx.accounts.destination_owner.key();
+        record.amount = amount;
+        record.ivms101_hash = ivms101_hash;
+        record.originator_vasp = originator_vasp;
+        record.beneficiary_vasp = beneficiary_vasp;
+        record.created_at = now;
+        record.used = false;
+        record.bump = ctx.bumps.travel_rule_record;
+        
+        emit!(TravelRuleAttached {
+            source: record.source,
+            destination: record.destination,
+            amount,
+            ivms101_hash,
+            originator_vasp,
+            beneficiary_vasp,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Pre-approve one large transfer between two owners. Replaces any
+    /// previous approval for the same pair.
+    pub fn approve_transfer(
+        ctx: Context<ApproveTransfer>,
+        min_amount: u64,
+        max_amount: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(min_amount <= max_amount, TransferHookError::InvalidApproval);
+        require!(expires_at > now, TransferHookError::InvalidExpiry);
+        
+        let approval = &mut ctx.accounts.transfer_approval;
+        approval.config = ctx.accounts.config.key();
+        approval.source = ctx.accounts.source_owner.key();
+        approval.destination = ctx.accounts.destination_owner.key();
+        approval.min_amount = min_amount;
+        approval.max_amount = max_amount;
+        approval.expires_at = expires_at;
+        approval.approved_by = ctx.accounts.authority.key();
+        approval.used = false;
+        approval.bump = ctx.bumps.transfer_approval;
+        
+        emit!(TransferApproved {
+            approval: approval.key(),
+            source: approval.source,
+            destination: approval.destination,
+            min_amount,
+            max_amount,
+            expires_at,
+            approved_by: approval.approved_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Close a transfer approval and return its rent
+    pub fn close_transfer_approval(ctx: Context<CloseTransferApproval>) -> Result<()> {
+        emit!(EntryClosed {
+            address: ctx.accounts.transfer_approval.source,
+            entry: ctx.accounts.transfer_approval.key(),
+            closed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ MEMO REQUIREMENTS ============
+    
+    /// Require (or stop requiring) a memo on transfers into a token account.
+    /// Callable by the account's owner or compliance.
+    pub fn set_memo_required(ctx: Context<SetMemoRequired>, required: bool) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let requirement = &mut ctx.accounts.memo_requirement;
+        requirement.config = ctx.accounts.config.key();
+        requirement.token_account = ctx.accounts.token_account.key();
+        requirement.required = required;
+        requirement.set_by = ctx.accounts.authority.key();
+        requirement.updated_at = now;
+        requirement.bump = ctx.bumps.memo_requirement;
+        if required {
+            ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_MEMO);
+        }
+        
+        emit!(MemoRequirementUpdated {
+            token_account: requirement.token_account,
+            required,
+            set_by: requirement.set_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ SANCTIONS LIST ============
+    
+    /// Publish a new sanctions list Merkle root
+    pub fn set_sanctions_root(
+        ctx: Context<SetSanctionsRoot>,
+        root: [u8; 32],
+    ) -> Result<()> {
+        require!(ctx.accounts.config.load()?.blacklist_enabled(), TransferHookError::ComplianceNotEnabled);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let sanctions = &mut ctx.accounts.sanctions_root;
+        sanctions.config = ctx.accounts.config.key();
+        sanctions.root = root;
+        sanctions.version = sanctions.version
+            .checked_add(1)
+            .ok_or(TransferHookError::MathOverflow)?;
+        sanctions.updated_by = ctx.accounts.authority.key();
+        sanctions.updated_at = now;
+        sanctions.bump = ctx.bumps.sanctions_root;
+        
+        emit!(SanctionsRootUpdated {
+            authority: ctx.accounts.authority.key(),
+            root,
+            version: sanctions.version,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Instantiate a blocking blacklist entry for an address proven to be in the
+    /// published sanctions list. Permissionless; the caller pays rent.
+    pub fn materialize_blacklist_entry(
+        ctx: Context<MaterializeBlacklistEntry>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(ctx.accounts.config.load()?.blacklist_enabled(), TransferHookError::ComplianceNotEnabled);
+        
+        let address = ctx.accounts.target_address.key();
+        require!(
+            verify_sanctions_proof(&ctx.accounts.sanctions_root.root, &address, &proof),
+            TransferHookError::InvalidSanctionsProof
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        // Reference the root the address was proven against
+        let reference = Some(ctx.accounts.sanctions_root.root);
+        let entry = &mut ctx.accounts.blacklist_entry;
+        entry.address = address;
+        entry.reason_code = ReasonCode::SanctionsMatch;
+        entry.reference = reference;
+        entry.blacklisted_by = ctx.accounts.sanctions_root.key();
+        entry.created_at = now;
+        entry.is_active = true;
+        entry.bump = ctx.bumps.blacklist_entry;
+        entry.entry_kind = BlacklistEntryKind::Owner;
+        entry.expires_at = None;
+        entry.restriction_level = RestrictionLevel::Blocked;
+        sync_list_membership(
+            &mut entry.listed,
+            true,
+            LIST_BLACKLIST,
+            address,
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        bloom_insert(&mut *ctx.accounts.config.load_mut()?, ctx.accounts.blacklist_bloom.as_ref(), &address)?;
+        
+        emit!(BlacklistAdded {
+            address,
+            entry_kind: BlacklistEntryKind::Owner,
+            expires_at: None,
+            restriction_level: RestrictionLevel::Blocked,
+            reason_code: ReasonCode::SanctionsMatch,
+            reference,
+            blacklisted_by: ctx.accounts.sanctions_root.key(),
+            accounts_frozen: 0,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ LEGAL HOLDS ============
+    
+    /// Place a legal hold on an address: it can still receive but not send
+    pub fn place_legal_hold(ctx: Context<PlaceLegalHold>, reference: [u8; 32]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let hold = &mut ctx.accounts.legal_hold;
+        hold.config = ctx.accounts.config.key();
+        hold.address = ctx.accounts.target_address.key();
+        hold.reference = reference;
+        hold.placed_by = ctx.accounts.authority.key();
+        hold.placed_at = now;
+        hold.bump = ctx.bumps.legal_hold;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_LEGAL_HOLD);
+        
+        emit!(LegalHoldPlaced {
+            address: hold.address,
+            reference,
+            placed_by: hold.placed_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Lift a legal hold
+    pub fn release_legal_hold(ctx: Context<ReleaseLegalHold>) -> Result<()> {
+        emit!(LegalHoldReleased {
+            address: ctx.accounts.legal_hold.address,
+            released_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ ADDRESS TAGS ============
+    
+    /// Tag an address with a counterparty category, or update its tag
+    pub fn set_address_tag(
+        ctx: Context<SetAddressTag>,
+        category: AddressCategory,
+        label_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let tag = &mut ctx.accounts.address_tag;
+        tag.config = ctx.accounts.config.key();
+        tag.address = ctx.accounts.target_address.key();
+        tag.category = category;
+        tag.label_hash = label_hash;
+        tag.tagged_by = ctx.accounts.authority.key();
+        tag.updated_at = now;
+        tag.bump = ctx.bumps.address_tag;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_ADDRESS_TAGS);
+        
+        emit!(AddressTagged {
+            address: tag.address,
+            category,
+            label_hash,
+            tagged_by: tag.tagged_by,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Remove an address tag
+    pub fn remove_address_tag(ctx: Context<RemoveAddressTag>) -> Result<()> {
+        emit!(AddressTagRemoved {
+            address: ctx.accounts.address_tag.address,
+            removed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ ESCHEATMENT ============
+    
+    /// Configure dormancy tracking and the escheatment treasury
+    pub fn set_escheatment_policy(
+        ctx: Context<SetEscheatmentPolicy>,
+        dormancy_period: i64,
+        notice_period: i64,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            dormancy_period >= MIN_DORMANCY_PERIOD && notice_period > 0,
+            TransferHookError::InvalidEscheatmentPolicy
+        );
+        
+        let policy = &mut ctx.accounts.escheatment_policy;
+        policy.config = ctx.accounts.config.key();
+        policy.treasury = ctx.accounts.treasury.key();
+        policy.dormancy_period = dormancy_period;
+        policy.notice_period = notice_period;
+        policy.enabled = enabled;
+        policy.bump = ctx.bumps.escheatment_policy;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_ESCHEAT);
+        
+        emit!(EscheatmentPolicyUpdated {
+            authority: ctx.accounts.authority.key(),
+            treasury: policy.treasury,
+            dormancy_period,
+            notice_period,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Start tracking a token account's outgoing activity (permissionless)
+    pub fn init_activity_record(ctx: Context<InitActivityRecord>) -> Result<()> {
+        let record = &mut ctx.accounts.activity_record;
+        record.config = ctx.accounts.config.key();
+        record.token_account = ctx.accounts.token_account.key();
+        record.last_activity = Clock::get()?.unix_timestamp;
+        record.flagged_at = None;
+        record.bump = ctx.bumps.activity_record;
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_ESCHEAT);
+        
+        Ok(())
+    }
+    
+    /// Flag an account with no outgoing transfers for the dormancy period,
+    /// starting the notice period (permissionless crank)
+    pub fn flag_dormant_account(ctx: Context<FlagDormantAccount>) -> Result<()> {
+        let policy = &ctx.accounts.escheatment_policy;
+        require!(policy.enabled, TransferHookError::InvalidEscheatmentPolicy);
+        
+        let now = Clock::get()?.unix_timestamp;
+        let record = &mut ctx.accounts.activity_record;
+        let dormant_at = record.last_activity
+            .checked_add(policy.dormancy_period)
+            .ok_or(TransferHookError::MathOverflow)?;
+        require!(record.flagged_at.is_none() && now >= dormant_at, TransferHookError::AccountNotDormant);
+        record.flagged_at = Some(now);
+        
+        emit!(DormantAccountFlagged {
+            token_account: record.token_account,
+            owner: ctx.accounts.token_account.owner,
+            last_activity: record.last_activity,
+            escheatable_at: now.checked_add(policy.notice_period).ok_or(TransferHookError::MathOverflow)?,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Settle the escheatment of a flagged account once the notice period
+    /// has passed without activity. Only sss-token calls this, signing with
+    /// its permanent delegate from its own escheat_dormant_account, which then
+    /// moves the balance to the escheatment treasury.
+    pub fn consume_escheatment(ctx: Context<ConsumeEscheatment>) -> Result<()> {
+        let policy = &ctx.accounts.escheatment_policy;
+        require!(policy.enabled, TransferHookError::InvalidEscheatmentPolicy);
+        require!(
+            ctx.accounts.config.load()?.permanent_delegate() == Some(ctx.accounts.permanent_delegate.key()),
+            TransferHookError::InvalidAuthority
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        let flagged_at = ctx.accounts.activity_record.flagged_at.ok_or(TransferHookError::AccountNotDormant)?;
+        let escheatable_at = flagged_at
+            .checked_add(policy.notice_period)
+            .ok_or(TransferHookError::MathOverflow)?;
+        require!(now >= escheatable_at, TransferHookError::EscheatmentNoticeActive);
+        
+        let amount = ctx.accounts.token_account.amount;
+        require!(amount > 0, TransferHookError::AmountTooLow);
+        
+        let record = &mut ctx.accounts.activity_record;
+        record.flagged_at = None;
+        record.last_activity = now;
+        
+        emit!(AccountEscheated {
+            token_account: record.token_account,
+            owner: ctx.accounts.token_account.owner,
+            treasury: policy.treasury,
+            amount,
+            escheated_by: ctx.accounts.authority.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ SPONSORED TRANSFERS AND PERMITS ============
+    
+    /// Register or disable a relayer that may submit sponsored transfers
+    pub fn set_relayer(ctx: Context<SetRelayer>, enabled: bool) -> Result<()> {
+        let entry = &mut ctx.accounts.relayer_entry;
+        entry.config = ctx.accounts.config.key();
+        entry.relayer = ctx.accounts.relayer.key();
+        entry.enabled = enabled;
+        entry.bump = ctx.bumps.relayer_entry;
+        
+        emit!(RelayerUpdated {
+            authority: ctx.accounts.authority.key(),
+            relayer: entry.relayer,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Authorize a sponsored transfer a holder signed off-chain. Must directly
+    /// follow an ed25519 program instruction verifying the source owner's
+    /// signature over `sponsored_transfer_message`. Only sss-token calls
+    /// this, signing with its transfer delegate PDA from its own
+    /// execute_sponsored_transfer, which then moves `amount` to the
+    /// destination and `fee` to the relayer as the holder's approved delegate.
+    pub fn consume_sponsored_transfer(
+        ctx: Context<ConsumeSponsoredTransfer>,
+        amount: u64,
+        fee: u64,
+        nonce: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        let config = ctx.accounts.config.load()?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(!config.transfers_paused(now), TransferHookError::HookPaused);
+        require!(now < expires_at, TransferHookError::IntentExpired);
+        require!(amount > 0, TransferHookError::AmountTooLow);
+        
+        let owner = ctx.accounts.source_account.owner;
+        let message = sponsored_transfer_message(
+            &ctx.accounts.config.key(),
+            &ctx.accounts.source_account.key(),
+            &ctx.accounts.destination_account.key(),
+            amount,
+            fee,
+            nonce,
+            expires_at,
+        );
+        verify_ed25519_instruction(&ctx.accounts.instructions, &owner, &message)?;
+        
+        let sponsor_nonce = &mut ctx.accounts.sponsor_nonce;
+        require!(nonce > sponsor_nonce.nonce, TransferHookError::StaleNonce);
+        sponsor_nonce.config = ctx.accounts.config.key();
+        sponsor_nonce.owner = owner;
+        sponsor_nonce.nonce = nonce;
+        sponsor_nonce.bump = ctx.bumps.sponsor_nonce;
+        
+        let entry = &mut ctx.accounts.relayer_entry;
+        entry.total_relayed = entry.total_relayed.checked_add(1)
+            .ok_or(TransferHookError::MathOverflow)?;
+        
+        emit!(SponsoredTransferExecuted {
+            relayer: entry.relayer,
+            owner,
+            source: ctx.accounts.source_account.key(),
+            destination: ctx.accounts.destination_account.key(),
+            amount,
+            fee,
+            nonce,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set `spender`'s allowance over `owner`'s tokens from an off-chain
+    /// signature. Must directly follow an ed25519 program instruction verifying
+    /// the owner's signature over `permit_message`. Anyone may submit it.
+    pub fn permit(
+        ctx: Context<Permit>,
+        owner: Pubkey,
+        spender: Pubkey,
+        amount: u64,
+        deadline: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < deadline, TransferHookError::IntentExpired);
+        
+        let config_key = ctx.accounts.config.key();
+        let message = permit_message(&config_key, &owner, &spender, amount, deadline, nonce);
+        verify_ed25519_instruction(&ctx.accounts.instructions, &owner, &message)?;
+        
+        let mut bitmap = load_nonce_bitmap(&ctx.accounts.nonce_bitmap, &owner, nonce, ctx.bumps.nonce_bitmap)?;
+        require!(bitmap.test_and_set(nonce), TransferHookError::NonceAlreadyUsed);
+        drop(bitmap);
+        
+        let allowance = &mut ctx.accounts.allowance;
+        allowance.config = config_key;
+        allowance.owner = owner;
+        allowance.spender = spender;
+        allowance.amount = amount;
+        allowance.nonce = nonce;
+        allowance.bump = ctx.bumps.allowance;
+        
+        emit!(AllowanceUpdated {
+            owner,
+            spender,
+            amount,
+            nonce,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Spend `amount` of an allowance. Only sss-token calls this, signing
+    /// with its transfer delegate PDA from its own transfer_from, which then
+    /// moves the tokens as the owner's approved delegate.
+    pub fn spend_allowance(
+        ctx: Context<SpendAllowance>,
+        destination: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(!ctx.accounts.config.load()?.transfers_paused(now), TransferHookError::HookPaused);
+        require!(amount > 0, TransferHookError::AmountTooLow);
+        
+        let allowance = &mut ctx.accounts.allowance;
+        allowance.amount = allowance.amount
+            .checked_sub(amount)
+            .ok_or(TransferHookError::InsufficientAllowance)?;
+        
+        emit!(AllowanceSpent {
+            owner: allowance.owner,
+            spender: allowance.spender,
+            source: ctx.accounts.source_account.key(),
+            destination,
+            amount,
+            remaining: allowance.amount,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ RISK ORACLE ============
+    
+    /// Register the external risk program consulted on every transfer, or
+    /// switch the check off
+    pub fn set_risk_oracle(ctx: Context<SetRiskOracle>, enabled: bool) -> Result<()> {
+        let program = ctx.accounts.risk_program.key();
+        require!(
+            program != crate::ID && program != anchor_spl::token_2022::ID,
+            TransferHookError::InvalidRiskProgram
+        );
+        
+        let oracle = &mut ctx.accounts.risk_oracle;
+        oracle.config = ctx.accounts.config.key();
+        oracle.program = program;
+        oracle.enabled = enabled;
+        oracle.bump = ctx.bumps.risk_oracle;
+        if enabled {
+            ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_RISK);
+        }
+        
+        emit!(RiskOracleUpdated {
+            authority: ctx.accounts.authority.key(),
+            program,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ GATEWAY TOKENS ============
+    
+    /// Accept gateway tokens from a gatekeeper network as whitelist entries in
+    /// permissioned mode, or stop accepting them
+    pub fn set_gatekeeper_network(
+        ctx: Context<SetGatekeeperNetwork>,
+        network: Pubkey,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            !enabled || network != Pubkey::default(),
+            TransferHookError::InvalidGatekeeperNetwork
+        );
+        
+        let gatekeeper = &mut ctx.accounts.gatekeeper;
+        gatekeeper.config = ctx.accounts.config.key();
+        gatekeeper.network = network;
+        gatekeeper.enabled = enabled;
+        gatekeeper.bump = ctx.bumps.gatekeeper;
+        if enabled {
+            ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_GATEWAY);
+        }
+        
+        emit!(GatekeeperNetworkUpdated {
+            authority: ctx.accounts.authority.key(),
+            network,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ GLOBAL BLACKLIST ============
+    
+    /// Create or update an issuer-wide blacklist record. Each of the issuer's
+    /// mints picks it up through `sync_blacklist_entry`.
+    pub fn set_global_blacklist_entry(
+        ctx: Context<SetGlobalBlacklistEntry>,
+        reason_code: ReasonCode,
+        reference: Option<[u8; 32]>,
+        restriction_level: RestrictionLevel,
+        expires_at: Option<i64>,
+        is_active: bool,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(expiry) = expires_at {
+            require!(expiry > now, TransferHookError::InvalidExpiry);
+        }
+        
+        let entry = &mut ctx.accounts.global_entry;
+        entry.issuer = ctx.accounts.issuer.key();
+        entry.address = ctx.accounts.target_address.key();
+        entry.reason_code = reason_code;
+        entry.reference = reference;
+        entry.restriction_level = restriction_level;
+        entry.expires_at = expires_at;
+        entry.is_active = is_active;
+        entry.updated_at = now;
+        entry.bump = ctx.bumps.global_entry;
+        
+        emit!(GlobalBlacklistUpdated {
+            issuer: entry.issuer,
+            address: entry.address,
+            is_active,
+            reason_code,
+            reference,
+            restriction_level,
+            expires_at,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Mirror the config authority's global record for an address into this
+    /// config's blacklist. Permissionless; the caller pays rent. Only new
+    /// entries or ones previously mirrored from the issuer are overwritten.
+    pub fn sync_blacklist_entry(ctx: Context<SyncBlacklistEntry>) -> Result<()> {
+        require!(ctx.accounts.config.load()?.blacklist_enabled(), TransferHookError::ComplianceNotEnabled);
+        
+        let global = &ctx.accounts.global_entry;
+        let entry = &mut ctx.accounts.blacklist_entry;
+        require!(
+            entry.address == Pubkey::default() || entry.blacklisted_by == global.issuer,
+            TransferHookError::LocalEntryConflict
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        entry.address = global.address;
+        entry.reason_code = global.reason_code;
+        entry.reference = global.reference;
+        entry.blacklisted_by = global.issuer;
+        entry.created_at = now;
+        entry.is_active = global.is_active;
+        entry.bump = ctx.bumps.blacklist_entry;
+        entry.entry_kind = BlacklistEntryKind::Owner;
+        entry.expires_at = global.expires_at;
+        entry.restriction_level = global.restriction_level;
+        sync_list_membership(
+            &mut entry.listed,
+            global.is_active,
+            LIST_BLACKLIST,
+            global.address,
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        if global.is_active {
+            bloom_insert(&mut *ctx.accounts.config.load_mut()?, ctx.accounts.blacklist_bloom.as_ref(), &global.address)?;
+        } else {
+            ctx.accounts.config.load_mut()?.note_blacklist_write();
+        }
+        
+        if global.is_active {
+            emit!(BlacklistAdded {
+                address: global.address,
+                entry_kind: BlacklistEntryKind::Owner,
+                expires_at: global.expires_at,
+                restriction_level: global.restriction_level,
+                reason_code: global.reason_code,
+                reference: global.reference,
+                blacklisted_by: global.issuer,
+                accounts_frozen: 0,
+                timestamp: now,
+            });
+        } else {
+            emit!(BlacklistRemoved {
+                address: global.address,
+                removed_by: global.issuer,
+                timestamp: now,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Irreversibly lock a parameter. `fee_ceiling_bps` is only used with LOCK_FEE_CEILING.
+    pub fn lock_parameter(
+        ctx: Context<UpdateConfig>,
+        flag: u8,
+        fee_ceiling_bps: u16,
+    ) -> Result<()> {
+        require!(
+            flag != 0 && flag & !LOCK_ALL == 0,
+            TransferHookError::InvalidLockFlag
+        );
+        
+        let mut config = ctx.accounts.config.load_mut()?;
+        if flag & LOCK_FEE_CEILING != 0 {
+            // The ceiling itself is immutable once set
+            require!(config.locks & LOCK_FEE_CEILING == 0, TransferHookError::ParameterLocked);
+            require!(
+                fee_ceiling_bps <= 10_000 && config.transfer_fee_basis_points <= fee_ceiling_bps,
+                TransferHookError::InvalidLockFlag
+            );
+            config.fee_ceiling_bps = fee_ceiling_bps;
+        }
+        if flag & LOCK_SEIZURE != 0 {
+            config.permanent_delegate = Pubkey::default();
+        }
+        config.locks |= flag;
+        
+        emit!(ParameterLockApplied {
+            authority: ctx.accounts.authority.key(),
+            flag,
+            locks: config.locks,
+            fee_ceiling_bps: config.fee_ceiling_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Enable (Some) or disable (None) redemption-only mode for a sunset. Only
+    /// possible once the stablecoin is sunset, and never disabled while the
+    /// sunset is redemption-only.
+    pub fn set_redemption_mode(
+        ctx: Context<SetRedemptionMode>,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        let (is_sunset, redemption_only) =
+            stablecoin_sunset(&ctx.accounts.stablecoin_state.try_borrow_data()?)?;
+        require!(
+            is_sunset && (treasury.is_some() || !redemption_only),
+            TransferHookError::RedemptionModeLocked
+        );
+        
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.redemption_treasury = treasury.unwrap_or_default();
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "redemption_treasury".to_string(),
+            value: treasury.map(|t| t.to_string()).unwrap_or_default(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Set the default maximum balance a transfer may leave the destination with
+    pub fn set_max_balance(
+        ctx: Context<UpdateConfig>,
+        max_balance: u64,
+    ) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.max_balance = max_balance;
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "max_balance".to_string(),
+            value: max_balance.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Enable or disable permissioned (whitelist-only) transfers
+    pub fn set_whitelist_required(
+        ctx: Context<UpdateConfig>,
+        required: bool,
+    ) -> Result<()> {
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.whitelist_required = u8::from(required);
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "whitelist_required".to_string(),
+            value: required.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ LIST INDEX ============
+    
+    /// Create a blacklist or whitelist index page, and the config's
+    /// ListRegistry on first use. List-managing instructions keep both in
+    /// step when passed them.
+    pub fn init_list_index(ctx: Context<InitListIndex>, kind: u8, page: u8) -> Result<()> {
+        require!(
+            kind == LIST_BLACKLIST || kind == LIST_WHITELIST,
+            TransferHookError::InvalidListIndex
+        );
+        
+        let registry = &mut ctx.accounts.list_registry;
+        registry.config = ctx.accounts.config.key();
+        registry.bump = ctx.bumps.list_registry;
+        
+        let index = &mut ctx.accounts.list_index;
+        index.config = ctx.accounts.config.key();
+        index.kind = kind;
+        index.page = page;
+        index.bump = ctx.bumps.list_index;
+        Ok(())
+    }
+    
+    // ============ BLACKLIST BLOOM FILTER ============
+    
+    /// Create the blacklist bloom filter. It stays unused until seeded with
+    /// the existing entries and enabled.
+    pub fn init_blacklist_bloom(ctx: Context<InitBlacklistBloom>) -> Result<()> {
+        let mut bloom = ctx.accounts.blacklist_bloom.load_init()?;
+        bloom.config = ctx.accounts.config.key();
+        Ok(())
+    }
+    
+    /// Add existing blacklist entries, passed as remaining_accounts in
+    /// ascending address order, to the bloom filter. A blacklist write since
+    /// the last call restarts the count, so the pass starts over from the
+    /// lowest address. Every entry must be seeded before the filter is enabled.
+    pub fn seed_blacklist_bloom<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SeedBlacklistBloom<'info>>,
+    ) -> Result<()> {
+        let config_key = ctx.accounts.config.key();
+        let epoch = ctx.accounts.config.load()?.blacklist_epoch as u64;
+        let mut bloom = ctx.accounts.blacklist_bloom.load_mut()?;
+        if bloom.seed_epoch != epoch {
+            bloom.seed_epoch = epoch;
+            bloom.seeded = 0;
+            bloom.last_seeded = Pubkey::default();
+        }
+        for info in ctx.remaining_accounts {
+            let (address, listed) = blacklist_entry_address(info)?
+                .ok_or(TransferHookError::InvalidListAccount)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"blacklist", config_key.as_ref(), address.as_ref()],
+                &crate::ID,
+            );
+            require!(info.key() == expected, TransferHookError::InvalidListAccount);
+            require!(address > bloom.last_seeded, TransferHookError::InvalidListAccount);
+            bloom.insert(&address);
+            bloom.last_seeded = address;
+            if listed {
+                bloom.seeded += 1;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Let the hook skip blacklist PDAs the bloom filter rules out. From then
+    /// on every blacklist write must pass the filter. Enabling requires a
+    /// seeding pass, uninterrupted by blacklist writes, that covered every
+    /// entry the ListRegistry counts; entries written without the registry
+    /// are not counted and must be re-added first.
+    pub fn set_bloom_enabled(ctx: Context<SetBloomEnabled>, enabled: bool) -> Result<()> {
+        if enabled {
+            let bloom = ctx.accounts.blacklist_bloom.load()?;
+            require!(
+                bloom.seed_epoch == ctx.accounts.config.load()?.blacklist_epoch as u64
+                    && bloom.seeded == ctx.accounts.list_registry.blacklist_count,
+                TransferHookError::BloomNotSeeded
+            );
+        }
+        let mut config = ctx.accounts.config.load_mut()?;
+        config.bloom_enabled = u8::from(enabled);
+        
+        emit!(ConfigUpdated {
+            authority: ctx.accounts.authority.key(),
+            field: "bloom_enabled".to_string(),
+            value: enabled.to_string(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Screen `address` against the blacklist for a transfer later in the
+    /// same transaction, asserting its current `restriction`. With the bloom
+    /// filter enabled, transfers carry no blacklist PDAs, so each party the
+    /// filter can't rule out must be screened first.
+    pub fn screen_blacklist(
+        ctx: Context<ScreenBlacklist>,
+        address: Pubkey,
+        restriction: Option<RestrictionLevel>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            blacklist_restriction(&ctx.accounts.blacklist_entry, &address, now)? == restriction,
+            TransferHookError::BlacklistScreeningMismatch
+        );
+        Ok(())
+    }
+    
+    // ============ STATISTICS ============
+    
+    /// Create the stats account shards roll up into. Permissionless.
+    pub fn init_hook_stats(ctx: Context<InitHookStats>) -> Result<()> {
+        let mut stats = ctx.accounts.hook_stats.load_init()?;
+        stats.config = ctx.accounts.config.key();
+        ctx.accounts.config.load_mut()?.note_meta_group_used(META_GROUP_STATS);
+        Ok(())
+    }
+    
+    /// Create the shard transfers from owners starting with byte `shard` are
+    /// counted in. Transfers whose shard doesn't exist go uncounted. Permissionless.
+    pub fn init_stats_shard(ctx: Context<InitStatsShard>, shard: u8) -> Result<()> {
+        let mut stats_shard = ctx.accounts.stats_shard.load_init()?;
+        stats_shard.config = ctx.accounts.config.key();
+        stats_shard.shard = shard;
+        Ok(())
+    }
+    
+    /// Fold `day`'s counters from the shards passed as remaining accounts into
+    /// HookStats, zeroing them so a later roll-up only adds what came after.
+    /// Permissionless; must run within SHARD_RING_DAYS of `day`.
+    pub fn roll_up_stats(ctx: Context<RollUpStats>, day: i64) -> Result<()> {
+        let now_day = Clock::get()?.unix_timestamp.div_euclid(SECONDS_PER_DAY);
+        require!(
+            day <= now_day && now_day - day < SHARD_RING_DAYS as i64,
+            TransferHookError::StatsUnavailable
+        );
+        
+        let config_key = ctx.accounts.config.key();
+        let mut stats = ctx.accounts.hook_stats.load_mut()?;
+        let total = stats.day_mut(day);
+        for info in ctx.remaining_accounts {
+            require!(info.owner == &crate::ID, TransferHookError::InvalidStatsShard);
+            let mut data = info.try_borrow_mut_data()?;
+            require!(
+                data.len() >= 8 + std::mem::size_of::<StatsShard>() && data[..8] == StatsShard::DISCRIMINATOR,
+                TransferHookError::InvalidStatsShard
+            );
+            let shard: &mut StatsShard =
+                bytemuck::from_bytes_mut(&mut data[8..8 + std::mem::size_of::<StatsShard>()]);
+            require!(shard.config == config_key, TransferHookError::InvalidStatsShard);
+            
+            let slot = shard.day_mut(day);
+            total.transfer_count = total.transfer_count.saturating_add(slot.transfer_count);
+            total.gross_volume = total.gross_volume.saturating_add(slot.gross_volume);
+            total.fees = total.fees.saturating_add(slot.fees);
+            *slot = ShardDay { day, transfer_count: 0, gross_volume: 0, fees: 0 };
+        }
+        
+        Ok(())
+    }
+    
+    /// Copy a finished day out of the ring into its own PDA before the slot
+    /// is reused. Permissionless.
+    pub fn archive_daily_stats(ctx: Context<ArchiveDailyStats>, day: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(day < now.div_euclid(SECONDS_PER_DAY), TransferHookError::StatsUnavailable);
+        
+        let stats = *ctx
+            .accounts
+            .hook_stats
+            .load()?
+            .day(day)
+            .ok_or(TransferHookError::StatsUnavailable)?;
+        let mut record = ctx.accounts.daily_stats.load_init()?;
+        record.config = ctx.accounts.config.key();
+        record.stats = stats;
+        
+        emit!(DailyStatsArchived {
+            config: record.config,
+            day,
+            transfer_count: stats.transfer_count,
+            gross_volume: stats.gross_volume,
+            fees: stats.fees,
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Record a transfer attempt the hook rejected. A rejected transfer
+    /// reverts and leaves no trace, so compliance monitoring replays failed or
+    /// simulated transfers and reports them here with a structured reason.
+    pub fn record_rejection(
+        ctx: Context<RecordRejection>,
+        source_owner: Pubkey,
+        destination_owner: Pubkey,
+        amount: u64,
+        reason: RejectionReason,
+        attempted_at: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(attempted_at <= now, TransferHookError::InvalidInstruction);
+        
+        {
+            let mut stats = ctx.accounts.hook_stats.load_mut()?;
+            // Attempts older than the ring are still reported, just not counted
+            let day = attempted_at.div_euclid(SECONDS_PER_DAY);
+            if now.div_euclid(SECONDS_PER_DAY) - day < STATS_RING_DAYS as i64 {
+                let slot = &mut stats.day_mut(day).rejections[reason as usize];
+                *slot = slot.saturating_add(1);
+            }
+        }
+        
+        emit!(TransferRejected {
+            source: source_owner,
+            destination: destination_owner,
+            amount,
+            reason,
+            attempted_at,
+            reported_by: ctx.accounts.reporter.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ RENT RECLAIM ============
+    
+    /// Close an inactive or expired blacklist entry and return its rent
+    pub fn close_blacklist_entry(ctx: Context<CloseBlacklistEntry>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.blacklist_entry.restriction(now).is_none(),
+            TransferHookError::BlacklistEntryActive
+        );
+        ctx.accounts.config.load_mut()?.note_blacklist_write();
+        sync_list_membership(
+            &mut ctx.accounts.blacklist_entry.listed,
+            false,
+            LIST_BLACKLIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        
+        emit!(EntryClosed {
+            address: ctx.accounts.target_address.key(),
+            entry: ctx.accounts.blacklist_entry.key(),
+            closed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Crank: close an expired blacklist entry. Permissionless; rent goes
+    /// back to the config authority.
+    pub fn close_expired_blacklist_entry(ctx: Context<CloseExpiredBlacklistEntry>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.blacklist_entry.expires_at.map(|t| t <= now).unwrap_or(false),
+            TransferHookError::BlacklistEntryNotExpired
+        );
+        ctx.accounts.config.load_mut()?.note_blacklist_write();
+        sync_list_membership(
+            &mut ctx.accounts.blacklist_entry.listed,
+            false,
+            LIST_BLACKLIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        
+        emit!(EntryClosed {
+            address: ctx.accounts.target_address.key(),
+            entry: ctx.accounts.blacklist_entry.key(),
+            closed_by: ctx.accounts.cranker.key(),
+            timestamp: now,
+        });
+        
+        Ok(())
+    }
+    
+    /// Close a whitelist entry and return its rent
+    pub fn close_whitelist_entry(ctx: Context<CloseWhitelistEntry>) -> Result<()> {
+        sync_list_membership(
+            &mut ctx.accounts.whitelist_entry.listed,
+            false,
+            LIST_WHITELIST,
+            ctx.accounts.target_address.key(),
+            ctx.accounts.list_registry.as_deref_mut(),
+            ctx.accounts.list_index.as_deref_mut(),
+        )?;
+        
+        emit!(EntryClosed {
+            address: ctx.accounts.target_address.key(),
+            entry: ctx.accounts.whitelist_entry.key(),
+            closed_by: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    // ============ MIGRATION ============
+    
+    /// Move a legacy `["config", authority]` config to the per-mint
+    /// `["hook_config", mint]` PDA and close the legacy account.
+    /// List entries are seeded by config address and must be re-created.
+    pub fn migrate_legacy_config(ctx: Context<MigrateLegacyConfig>) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_config.to_account_info();
+        require!(legacy_info.owner == &crate::ID, TransferHookError::InvalidLegacyConfig);
+        
+        let legacy = {
+            let data = legacy_info.try_borrow_data()?;
+            require!(
+                data.len() > 8 && data[..8] == TransferHookConfig::DISCRIMINATOR,
+                TransferHookError::InvalidLegacyConfig
+            );
+            LegacyTransferHookConfig::deserialize(&mut &data[8..])?
+        };
+        require!(
+            legacy.authority == ctx.accounts.authority.key(),
+            TransferHookError::InvalidAuthority
+        );
+        
+        let mut config = ctx.accounts.config.load_init()?;
+        config.stablecoin = ctx.accounts.mint.key();
+        config.authority = legacy.authority;
+        config.transfer_fee_basis_points = legacy.transfer_fee_basis_points;
+        config.max_transfer_fee = legacy.max_transfer_fee;
+        config.min_transfer_amount = legacy.min_transfer_amount;
+        config.total_fees_collected = legacy.total_fees_collected;
+        config.is_paused = u8::from(legacy.is_paused);
+        config.blacklist_enabled = u8::from(legacy.blacklist_enabled);
+        config.permanent_delegate = legacy.permanent_delegate.unwrap_or_default();
+        config.bump = ctx.bumps.config;
+        config.version = CONFIG_VERSION;
+        config.meta_groups_unused = FEATURE_META_GROUPS;
+        
+        // Close the legacy account
+        let authority_info = ctx.accounts.authority.to_account_info();
+        let lamports = legacy_info.lamports();
+        **authority_info.try_borrow_mut_lamports()? += lamports;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.assign(&System::id());
+        legacy_info.realloc(0, false)?;
+        
+        emit!(ConfigMigrated {
+            legacy_config: legacy_info.key(),
+            config: ctx.accounts.config.key(),
+            mint: ctx.accounts.mint.key(),
+            authority: legacy.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+    
+    /// Rewrite a config from the Borsh layout to the zero-copy one, growing
+    /// the account. Permissionless; the payer tops up the rent. Every other
+    /// instruction fails on the config until this has run.
+    pub fn migrate_config_layout(ctx: Context<MigrateConfigLayout>) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        let v2 = load_transfer_hook_config_v2(&config_info)?
+            .ok_or(TransferHookError::InvalidLegacyConfig)?;
+        require_keys_eq!(v2.stablecoin, ctx.accounts.mint.key(), TransferHookError::MintMismatch);
+        
+        let shortfall = Rent::get()?
+            .minimum_balance(TRANSFER_HOOK_CONFIG_LEN)
+            .saturating_sub(config_info.lamports());
+        if shortfall > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: config_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        
+        config_info.realloc(TRANSFER_HOOK_CONFIG_LEN, true)?;
+        store_zero_copy(&config_info, &TransferHookConfig::from(v2))
+    }
+    
+    /// Rewrite a blacklist entry from the free-form reason layout to reason
+    /// codes, shrinking the account. Permissionless; excess rent goes back to
+    /// the config authority.
+    pub fn migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+        let entry_info = ctx.accounts.blacklist_entry.to_account_info();
+        let v1 = load_blacklist_entry_v1(&entry_info)?
+            .ok_or(TransferHookError::NotLegacyEntry)?;
+        require_keys_eq!(
+            v1.address,
+            ctx.accounts.target_address.key(),
+            TransferHookError::ListEntryMismatch
+        );
+        
+        let entry = BlacklistEntry::from(v1);
+        entry_info.realloc(BLACKLIST_ENTRY_LEN, false)?;
+        {
+            let mut data = entry_info.try_borrow_mut_data()?;
+            entry.try_serialize(&mut &mut data[..])?;
+        }
+        
+        // Refund rent freed by the smaller account
+        let excess = entry_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(BLACKLIST_ENTRY_LEN));
+        if excess > 0 {
+            **entry_info.try_borrow_mut_lamports()? -= excess;
+            **ctx.accounts.authority.try_borrow_mut_lamports()? += excess;
+        }
+        
+        Ok(())
+    }
+    
+    // ============ VIEWS ============
+    
+    /// Dry-run the hook's owner-level checks for a transfer: pauses,
+    /// redemption mode, legal holds, blacklist, whitelist, KYC, velocity,
+    /// cooldown, approval and fees. Nothing is recorded. Token-account
+    /// entries, clawback holds, balance caps, travel-rule, memo and risk-oracle
+    /// checks depend on the actual transfer and are not evaluated.
+    pub fn precheck_transfer(
+        ctx: Context<PrecheckTransfer>,
+        source_owner: Pubkey,
+        destination_owner: Pubkey,
+        amount: u64,
+    ) -> Result<PrecheckResult> {
+        let accounts = &ctx.accounts;
+        let config = *accounts.config.load()?;
+        let now = Clock::get()?.unix_timestamp;
+        
+        let evaluate = || -> Result<u64> {
+            // Token-2022 withholds the TransferFee on every transfer, exempt
+            // parties included, so that is the fee the destination goes short
+            let fee = withheld_transfer_fee(&accounts.mint.to_account_info(), amount)?.unwrap_or(0);
+            let stablecoin_data = accounts.stablecoin_state.try_borrow_data()?;
+            let paused = stablecoin_is_paused(&stablecoin_data)?;
+            require!(!paused && !config.transfers_paused(now), TransferHookError::HookPaused);
+            if let Some(treasury) = redemption_destination(&config, &stablecoin_data)? {
+                require!(destination_owner == treasury, TransferHookError::RedemptionOnly);
+            }
+            require!(
+                load_entry::<LegalHold>(&accounts.source_legal_hold)?.is_none(),
+                TransferHookError::LegalHoldActive
+            );
+            
+            let source_whitelist = whitelist_type(&accounts.source_whitelist, &source_owner)?;
+            let destination_whitelist =
+                whitelist_type(&accounts.destination_whitelist, &destination_owner)?;
+            let source_bypass = source_whitelist == Some(WhitelistType::FullBypass);
+            let destination_bypass = destination_whitelist == Some(WhitelistType::FullBypass);
+            
+            if config.blacklist_enabled() {
+                let mut checks = Vec::new();
+                if !source_bypass {
+                    checks.push((&accounts.source_blacklist, source_owner, TransferHookError::SourceBlacklisted));
+                }
+                if !destination_bypass {
+                    checks.push((&accounts.destination_blacklist, destination_owner, TransferHookError::DestinationBlacklisted));
+                }
+                for (info, address, error) in checks {
+                    if blacklist_restriction(info, &address, now)? == Some(RestrictionLevel::Blocked) {
+                        return Err(error.into());
+                    }
+                }
+            }
+            
+            let is_delegate = config
+                .permanent_delegate()
+                .is_some_and(|d| source_owner == d || destination_owner == d);
+            if is_delegate || source_bypass || destination_bypass {
+                return Ok(fee);
+            }
+            if config.whitelist_required() {
+                let gatekeeper = load_entry::<GatekeeperConfig>(&accounts.gatekeeper)?;
+                require!(
+                    (source_whitelist.is_some()
+                        || has_gateway_pass(gatekeeper.as_ref(), &accounts.source_gateway_token, &source_owner, now)?)
+                        && (destination_whitelist.is_some()
+                            || has_gateway_pass(gatekeeper.as_ref(), &accounts.destination_gateway_token, &destination_owner, now)?),
+                    TransferHookError::NotWhitelisted
+                );
+            }
+            
+            let kyc_policy = load_entry::<KycPolicy>(&accounts.kyc_policy)?;
+            enforce_kyc_limits(
+                kyc_policy.as_ref(),
+                &accounts.source_kyc,
+                &accounts.destination_kyc,
+                &source_owner,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            let policy = load_entry::<TransferPolicy>(&accounts.transfer_policy)?;
+            if let Some(policy) = &policy {
+                require!(policy.in_transfer_window(now), TransferHookError::OutsideTransferWindow);
+            }
+            enforce_cooldown(
+                policy.as_ref(),
+                &accounts.velocity_tracker,
+                &accounts.destination_velocity_tracker,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            let fee_schedule = load_entry::<FeeTierSchedule>(&accounts.fee_schedule)?;
+            enforce_velocity_limits(
+                policy.as_ref(),
+                fee_schedule.is_some(),
+                &accounts.velocity_tracker,
+                &source_owner,
+                amount,
+                now,
+            )?;
+            enforce_transfer_approval(
+                policy.as_ref(),
+                &accounts.transfer_approval,
+                &source_owner,
+                &destination_owner,
+                amount,
+                now,
+            )?;
+            
+            require!(amount >= config.min_transfer_amount, TransferHookError::AmountTooLow);
+            Ok(fee)
+        };
+        
+        Ok(match evaluate() {
+            Ok(fee) => PrecheckResult { allowed: true, fee, reason: None, error_code: None },
+            Err(error) => PrecheckResult {
+                allowed: false,
+                fee: 0,
+                reason: Some(RejectionReason::from_error(&error)),
+                error_code: match &error {
+                    Error::AnchorError(e) => Some(e.error_code_number),
+                    Error::ProgramError(_) => None,
+                },
+            },
+        })
+    }
+    
+    /// Return the current hook configuration for CPI callers and simulations
+    pub fn get_hook_config(ctx: Context<GetHookConfig>) -> Result<HookConfigInfo> {
+        let config = ctx.accounts.config.load()?;
+        
+        Ok(HookConfigInfo {
+            stablecoin: config.stablecoin,
+            authority: config.authority,
+            transfer_fee_basis_points: config.transfer_fee_basis_points,
+            max_transfer_fee: config.max_transfer_fee,
+            min_transfer_amount: config.min_transfer_amount,
+            total_fees_collected: config.total_fees_collected,
+            is_paused: config.is_paused(),
+            blacklist_enabled: config.blacklist_enabled(),
+            permanent_delegate: config.permanent_delegate(),
+            whitelist_required: config.whitelist_required(),
+            paused_until: config.paused_until(),
+            fees_paused: config.fees_paused(),
+        })
+    }
+}
+
+/// ============ ACCOUNT STRUCTURES ============
+
+#[derive(Accounts)]
+pub struct InitializeHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: The stablecoin mint this hook is for
+    pub stablecoin: AccountInfo<'info>,
+    
+    /// CHECK: Stablecoin state PDA
+    pub stablecoin_state: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = TRANSFER_HOOK_CONFIG_LEN,
+        seeds = [b"hook_config", stablecoin.key().as_ref()],
+        bump
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for initialize_extra_account_meta_list.
+/// Token-2022 requires this account to be created with the extra PDAs
+/// the hook will need during execute_transfer_hook invocations.
+#[derive(Accounts)]
+pub struct InitExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Transfer hook config (already initialized)
+    #[account(
+        mut,
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump = config.load()?.bump,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+
+    /// The Token-2022 mint this hook is registered on
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// The ExtraAccountMetaList account — seeded on "extra-account-metas" + mint
+    /// This is the canonical seed required by the spl-transfer-hook-interface.
+    /// CHECK: initialized inside the instruction via ExtraAccountMetaList::init
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas(0)?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// Risk oracle slot; must exist for extra meta [33] to resolve
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 80,
+        seeds = [b"risk_oracle", config.key().as_ref()],
+        bump,
+    )]
+    pub risk_oracle: Account<'info, RiskOracle>,
+
+    /// Secondary hook slot; must exist for extra meta [35] to resolve
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 72,
+        seeds = [b"forward_hook", config.key().as_ref()],
+        bump,
+    )]
+    pub forward_hook: Account<'info, ForwardHook>,
+
+    /// Gatekeeper config; must exist for extra metas [40] and [41] to resolve
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 80,
+        seeds = [b"gatekeeper", config.key().as_ref()],
+        bump,
+    )]
+    pub gatekeeper: Account<'info, GatekeeperConfig>,
+
+    /// Audit log; must exist for extra meta [46] to resolve
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 80,
+        seeds = [b"audit_log", config.key().as_ref()],
+        bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump = config.load()?.bump,
+        has_one = authority @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// CHECK: rewritten inside the instruction via ExtraAccountMetaList::update
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// Risk oracle slot; must exist for extra meta [33] to resolve
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"risk_oracle", config.key().as_ref()],
+        bump,
+    )]
+    pub risk_oracle: Account<'info, RiskOracle>,
+
+    /// Secondary hook slot; must exist for extra meta [35] to resolve
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 72,
+        seeds = [b"forward_hook", config.key().as_ref()],
+        bump,
+    )]
+    pub forward_hook: Account<'info, ForwardHook>,
+
+    /// Gatekeeper config; must exist for extra metas [40] and [41] to resolve
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"gatekeeper", config.key().as_ref()],
+        bump,
+    )]
+    pub gatekeeper: Account<'info, GatekeeperConfig>,
+
+    /// Audit log; must exist for extra meta [46] to resolve
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"audit_log", config.key().as_ref()],
+        bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncExtraAccountMetaList<'info> {
+    #[account(
+        mut,
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump = config.load()?.bump,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+
+    /// CHECK: rewritten inside the instruction via ExtraAccountMetaList::update
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    /// CHECK: KYC policy PDA, may be uninitialized
+    #[account(seeds = [b"kyc_policy", config.key().as_ref()], bump)]
+    pub kyc_policy: AccountInfo<'info>,
+
+    /// CHECK: Transfer policy PDA, may be uninitialized
+    #[account(seeds = [b"transfer_policy", config.key().as_ref()], bump)]
+    pub transfer_policy: AccountInfo<'info>,
+
+    /// CHECK: Fee tier schedule PDA, may be uninitialized
+    #[account(seeds = [b"fee_schedule", config.key().as_ref()], bump)]
+    pub fee_schedule: AccountInfo<'info>,
+
+    /// CHECK: Fee tiers PDA, may be uninitialized
+    #[account(seeds = [b"fee_tiers", config.key().as_ref()], bump)]
+    pub fee_tiers: AccountInfo<'info>,
+
+    /// CHECK: Fee exempt programs PDA, may be uninitialized
+    #[account(seeds = [b"fee_exempt_programs", config.key().as_ref()], bump)]
+    pub fee_exempt_programs: AccountInfo<'info>,
+
+    /// CHECK: Risk oracle PDA
+    #[account(seeds = [b"risk_oracle", config.key().as_ref()], bump)]
+    pub risk_oracle: AccountInfo<'info>,
+
+    /// CHECK: Secondary hook slot PDA
+    #[account(seeds = [b"forward_hook", config.key().as_ref()], bump)]
+    pub forward_hook: AccountInfo<'info>,
+
+    /// CHECK: Hook stats PDA, may be uninitialized
+    #[account(seeds = [b"hook_stats", config.key().as_ref()], bump)]
+    pub hook_stats: AccountInfo<'info>,
+
+    /// CHECK: Gatekeeper config PDA
+    #[account(seeds = [b"gatekeeper", config.key().as_ref()], bump)]
+    pub gatekeeper: AccountInfo<'info>,
+
+    /// CHECK: Audit log PDA
+    #[account(seeds = [b"audit_log", config.key().as_ref()], bump)]
+    pub audit_log: AccountInfo<'info>,
+
+    /// CHECK: Escheatment policy PDA, may be uninitialized
+    #[account(seeds = [b"escheat_policy", config.key().as_ref()], bump)]
+    pub escheatment_policy: AccountInfo<'info>,
+    
+    /// CHECK: Pending fee change PDA, may be uninitialized
+    #[account(seeds = [b"pending_fee", config.key().as_ref()], bump)]
+    pub pending_fee: AccountInfo<'info>,
+    
+    /// CHECK: Fee periods PDA, may be uninitialized
+    #[account(seeds = [b"fee_periods", config.key().as_ref()], bump)]
+    pub fee_periods: AccountInfo<'info>,
+    
+    /// CHECK: Peg fee policy PDA, may be uninitialized
+    #[account(seeds = [b"peg_fee", config.key().as_ref()], bump)]
+    pub peg_fee_policy: AccountInfo<'info>,
+}
+
+/// Account order follows the spl-transfer-hook-interface Execute instruction,
+/// followed by the extra accounts declared in `extra_account_metas`. Token-2022
+/// resolves those extras by address from `extra_account_meta_list`, and the
+/// handler only runs inside its transfer (assert_is_transferring), so their
+/// PDAs are not re-derived here beyond the blacklist entries read without
+/// the bloom filter.
+#[derive(Accounts)]
+pub struct ExecuteTransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    #[account(token::mint = mint)]
+    pub destination_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    /// CHECK: Transfer authority (source owner or delegate); list PDAs are
+    /// derived from the token accounts' owners, not from this account
+    pub owner: AccountInfo<'info>,
+    
+    /// CHECK: Validated by seeds
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump = config.load()?.bump,
+        constraint = config.load()?.stablecoin == mint.key() @ TransferHookError::MintMismatch,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Source blacklist PDA, may be uninitialized; the hook
+    /// program while the bloom filter is enabled
+    pub source_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Destination blacklist PDA, may be uninitialized; the hook
+    /// program while the bloom filter is enabled
+    pub destination_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Source whitelist PDA, may be uninitialized
+    pub source_whitelist: AccountInfo<'info>,
+    
+    /// CHECK: Destination whitelist PDA, may be uninitialized
+    pub destination_whitelist: AccountInfo<'info>,
+    
+    /// CHECK: Base Program ID
+    #[account(address = sss_token_program::ID)]
+    pub base_program_id_account: AccountInfo<'info>,
+
+    /// CHECK: Master Stablecoin State from Base Program
+    pub stablecoin_state: AccountInfo<'info>,
+    
+    /// CHECK: Source token account blacklist PDA, may be uninitialized; the hook
+    /// program while the bloom filter is enabled
+    pub source_account_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Destination token account blacklist PDA, may be uninitialized; the hook
+    /// program while the bloom filter is enabled
+    pub destination_account_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Transfer authority blacklist PDA, may be uninitialized; the hook
+    /// program while the bloom filter is enabled
+    pub authority_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: KYC policy PDA, may be uninitialized
+    pub kyc_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source owner KYC record PDA, may be uninitialized; writable
+    /// while META_GROUP_KYC is synced
+    pub source_kyc: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner KYC record PDA, may be uninitialized
+    pub destination_kyc: AccountInfo<'info>,
+    
+    /// CHECK: Transfer policy PDA, may be uninitialized
+    pub transfer_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source owner velocity tracker PDA, may be uninitialized; writable
+    /// while META_GROUP_POLICY is synced
+    pub velocity_tracker: AccountInfo<'info>,
+    
+    /// CHECK: Large-transfer approval PDA, may be uninitialized; writable
+    /// while META_GROUP_POLICY is synced
+    pub transfer_approval: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner velocity tracker PDA, may be uninitialized;
+    /// writable while META_GROUP_POLICY is synced
+    pub destination_velocity_tracker: AccountInfo<'info>,
+    
+    /// CHECK: Fee tiers PDA, may be uninitialized
+    pub fee_tiers: AccountInfo<'info>,
+    
+    /// CHECK: Source owner fee override PDA, may be uninitialized
+    pub fee_override: AccountInfo<'info>,
+    
+    /// CHECK: Fee tier schedule PDA, may be uninitialized
+    pub fee_schedule: AccountInfo<'info>,
+    
+    /// CHECK: Owner of the source token account; only its owning program is
+    /// read, after checking the address in the handler
+    pub source_owner: AccountInfo<'info>,
+    
+    /// CHECK: Fee exempt programs PDA, may be uninitialized
+    pub fee_exempt_programs: AccountInfo<'info>,
+    
+    /// CHECK: Source token account clawback hold PDA, may be uninitialized
+    pub clawback_hold: AccountInfo<'info>,
+    
+    /// CHECK: Source owner legal hold PDA, may be uninitialized
+    pub source_legal_hold: AccountInfo<'info>,
+    
+    /// CHECK: Travel-rule record PDA, may be uninitialized; writable while
+    /// META_GROUP_POLICY is synced
+    pub travel_rule_record: AccountInfo<'info>,
+    
+    /// CHECK: Instructions sysvar, for memo introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    /// CHECK: Destination memo requirement PDA, may be uninitialized
+    pub memo_requirement: AccountInfo<'info>,
+    
+    /// CHECK: Risk oracle PDA
+    pub risk_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Risk program named by the oracle, checked in the handler
+    pub risk_program: AccountInfo<'info>,
+    
+    /// CHECK: Secondary hook slot PDA
+    pub forward_hook: AccountInfo<'info>,
+    
+    /// CHECK: Secondary hook program named by the slot, checked in the handler
+    pub forward_hook_program: AccountInfo<'info>,
+    
+    /// CHECK: Secondary hook's ExtraAccountMetaList, checked in the handler
+    pub forward_hook_meta_list: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's stats shard PDA, may be uninitialized; writable
+    /// while META_GROUP_STATS is synced
+    pub stats_shard: AccountInfo<'info>,
+    
+    /// CHECK: Gatekeeper config PDA
+    pub gatekeeper: AccountInfo<'info>,
+    
+    /// CHECK: Gateway program, only used to resolve the gateway token PDAs
+    pub gateway_program: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's gateway token, validated by has_gateway_pass
+    pub source_gateway_token: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner's gateway token, validated by has_gateway_pass
+    pub destination_gateway_token: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's address tag PDA, may be uninitialized
+    pub source_address_tag: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner's address tag PDA, may be uninitialized
+    pub destination_address_tag: AccountInfo<'info>,
+    
+    /// CHECK: Source activity record PDA, may be uninitialized; writable
+    /// while META_GROUP_ESCHEAT is synced
+    pub source_activity: AccountInfo<'info>,
+    
+    /// CHECK: Audit log PDA
+    pub audit_log: AccountInfo<'info>,
+    
+    /// CHECK: Audit tree named by the audit log, checked in the handler
+    pub audit_tree: AccountInfo<'info>,
+    
+    /// CHECK: Account compression program, the audit append's CPI target
+    pub compression_program: AccountInfo<'info>,
+    
+    /// CHECK: Noop program, checked by account compression
+    pub noop_program: AccountInfo<'info>,
+    
+    /// CHECK: Blacklist bloom filter PDA, may be uninitialized
+    pub blacklist_bloom: AccountInfo<'info>,
+    
+    /// CHECK: Pending fee change PDA, may be uninitialized
+    pub pending_fee: AccountInfo<'info>,
+    
+    /// CHECK: Fee periods PDA, may be uninitialized
+    pub fee_periods: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's market maker rebate PDA, may be uninitialized;
+    /// writable while META_GROUP_REBATE is synced
+    pub source_rebate: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's referral link PDA, may be uninitialized;
+    /// writable while META_GROUP_REFERRAL is synced
+    pub source_referral: AccountInfo<'info>,
+    
+    /// CHECK: Peg fee policy PDA, may be uninitialized
+    pub peg_fee_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's fee credit PDA, may be uninitialized; writable
+    /// while META_GROUP_FEE_CREDIT is synced
+    pub source_fee_credit: AccountInfo<'info>,
+    
+    /// CHECK: Escheatment policy PDA, may be uninitialized; writable while
+    /// META_GROUP_ESCHEAT is synced, checked by start_activity_record against
+    /// its stored config
+    pub escheatment_policy: AccountInfo<'info>,
+    
+    /// CHECK: System program while META_GROUP_ESCHEAT is synced, checked by
+    /// start_activity_record
+    pub system_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageBlacklist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Target address
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BLACKLIST_ENTRY_LEN,
+        seeds = [b"blacklist", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    pub system_program: Program<'info, System>,    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+    
+    #[account(
+        mut,
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: Option<AccountLoader<'info, BlacklistBloom>>,
+}
+
+#[derive(Accounts)]
+pub struct ManageWhitelist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Target address
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 100,
+        seeds = [b"whitelist", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+    
+    pub system_program: Program<'info, System>,    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeSeizureCase<'info> {
+    /// sss-token's permanent delegate PDA, signing for sss-token seize_tokens
+    pub permanent_delegate: Signer<'info>,
+    
+    /// CHECK: The sss-token seizer executing the case; receives its rent
+    #[account(mut)]
+    pub seizer: UncheckedAccount<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = seizer,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    /// Hold of a disputed case, released once it settles
+    #[account(
+        mut,
+        seeds = [b"clawback", config.key().as_ref(), seizure_case.target.as_ref()],
+        bump = clawback_hold.bump,
+    )]
+    pub clawback_hold: Option<Account<'info, ClawbackHold>>,
+    
+    /// CHECK: Receives the hold's rent, checked against `initiated_by`
+    #[account(mut)]
+    pub clawback_initiator: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct HandOverPermanentDelegate<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(mut, address = config.load()?.stablecoin)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    /// CHECK: The hook's legacy permanent delegate PDA, signs via seeds
+    #[account(seeds = [PERMANENT_DELEGATE_SEED, mint.key().as_ref()], bump)]
+    pub legacy_delegate: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(case_id: u64)]
+pub struct OpenSeizureCase<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_SEIZER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub target_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 260,
+        seeds = [b"seizure_case", config.key().as_ref(), &case_id.to_le_bytes()],
+        bump,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveSeizureCase<'info> {
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+        seeds = [b"seizure_case", config.key().as_ref(), &seizure_case.case_id.to_le_bytes()],
+        bump = seizure_case.bump,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    /// CHECK: sss-token StablecoinState for this mint
+    #[account(
+        seeds = [b"stablecoin", config.load()?.stablecoin.as_ref()],
+        bump,
+        seeds::program = sss_token_program::ID,
+    )]
+    pub stablecoin_state: AccountInfo<'info>,
+    
+    /// CHECK: sss-token MultisigConfig, parsed with load_sss_account
+    #[account(
+        seeds = [b"multisig", stablecoin_state.key().as_ref()],
+        bump,
+        seeds::program = sss_token_program::ID,
+    )]
+    pub multisig_config: AccountInfo<'info>,
+    
+    /// CHECK: sss-token MultisigProposal, parsed with load_sss_account
+    pub proposal: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetExceptionalDestination<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_SEIZER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct AddRestitutionAccount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"restitution", config.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub restitution_entry: Account<'info, RestitutionAccount>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveRestitutionAccount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"restitution", config.key().as_ref(), restitution_entry.token_account.as_ref()],
+        bump = restitution_entry.bump,
+    )]
+    pub restitution_entry: Account<'info, RestitutionAccount>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSeizureCase<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_SEIZER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SetClawbackPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 90,
+        seeds = [b"clawback_policy", config.key().as_ref()],
+        bump,
+    )]
+    pub clawback_policy: Account<'info, ClawbackPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitiateClawback<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_SEIZER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"clawback_policy", config.key().as_ref()],
+        bump = clawback_policy.bump,
+    )]
+    pub clawback_policy: Account<'info, ClawbackPolicy>,
+    
+    #[account(
+        mut,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 170,
+        seeds = [b"clawback", config.key().as_ref(), seizure_case.target.as_ref()],
+        bump,
+    )]
+    pub clawback_hold: Account<'info, ClawbackHold>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelClawback<'info> {
+    pub guardian: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"clawback_policy", config.key().as_ref()],
+        bump = clawback_policy.bump,
+        has_one = guardian @ TransferHookError::InvalidAuthority,
+    )]
+    pub clawback_policy: Account<'info, ClawbackPolicy>,
+    
+    #[account(
+        mut,
+        close = case_opener,
+        has_one = config @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub seizure_case: Account<'info, SeizureCase>,
+    
+    #[account(
+        mut,
+        close = initiator,
+        seeds = [b"clawback", config.key().as_ref(), seizure_case.target.as_ref()],
+        bump = clawback_hold.bump,
+        constraint = clawback_hold.case == seizure_case.key() @ TransferHookError::InvalidSeizureCase,
+    )]
+    pub clawback_hold: Account<'info, ClawbackHold>,
+    
+    /// CHECK: Receives the case's rent
+    #[account(mut, address = seizure_case.opened_by)]
+    pub case_opener: UncheckedAccount<'info>,
+    
+    /// CHECK: Receives the hold's rent
+    #[account(mut, address = clawback_hold.initiated_by)]
+    pub initiator: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetForwardHook<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 72,
+        seeds = [b"forward_hook", config.key().as_ref()],
+        bump,
+    )]
+    pub forward_hook: Account<'info, ForwardHook>,
+    
+    /// CHECK: New secondary hook's ExtraAccountMetaList, checked in the handler;
+    /// required when setting one
+    pub forward_hook_meta_list: Option<UncheckedAccount<'info>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitAuditTree<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log", config.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+    
+    /// CHECK: Pre-allocated tree, initialized by account compression
+    #[account(mut, owner = spl_account_compression::ID @ TransferHookError::InvalidAuditTree)]
+    pub audit_tree: AccountInfo<'info>,
+    
+    /// CHECK: Account compression program
+    #[account(address = spl_account_compression::ID)]
+    pub compression_program: AccountInfo<'info>,
+    
+    /// CHECK: Noop program
+    #[account(address = spl_noop::ID)]
+    pub noop_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DisableAuditLog<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"audit_log", config.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+}
+
+#[derive(Accounts)]
+pub struct InitBlacklistBloom<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<BlacklistBloom>(),
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: AccountLoader<'info, BlacklistBloom>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SeedBlacklistBloom<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: AccountLoader<'info, BlacklistBloom>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct ScreenBlacklist<'info> {
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Blacklist PDA of `address`, may be uninitialized
+    #[account(
+        seeds = [b"blacklist", config.key().as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetBloomEnabled<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// Must exist before the hook can rely on it
+    #[account(
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: AccountLoader<'info, BlacklistBloom>,
+    
+    #[account(
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Account<'info, ListRegistry>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionMode<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: sss-token StablecoinState for this mint, parsed with stablecoin_sunset
+    #[account(
+        seeds = [b"stablecoin", config.load()?.stablecoin.as_ref()],
+        bump,
+        seeds::program = sss_token_program::ID,
+    )]
+    pub stablecoin_state: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BatchBlacklist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+    
+    #[account(
+        mut,
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: Option<AccountLoader<'info, BlacklistBloom>>,
+}
+#[derive(Accounts)]
+pub struct SetKycRecord<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Address being verified
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 110,
+        seeds = [b"kyc", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub kyc_record: Account<'info, KycRecord>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(provider: Pubkey)]
+pub struct RegisterKycProvider<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"kyc_provider", config.key().as_ref(), provider.as_ref()],
+        bump,
+    )]
+    pub kyc_provider: Account<'info, KycProvider>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveKycProvider<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"kyc_provider", config.key().as_ref(), kyc_provider.provider.as_ref()],
+        bump = kyc_provider.bump,
+    )]
+    pub kyc_provider: Account<'info, KycProvider>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitKycAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"kyc_provider", config.key().as_ref(), kyc_provider.provider.as_ref()],
+        bump = kyc_provider.bump,
+    )]
+    pub kyc_provider: Account<'info, KycProvider>,
+    
+    /// CHECK: Address being attested
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 110,
+        seeds = [b"kyc", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub kyc_record: Account<'info, KycRecord>,
+    
+    /// CHECK: Instructions sysvar, for ed25519 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetKycPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 160,
+        seeds = [b"kyc_policy", config.key().as_ref()],
+        bump,
+    )]
+    pub kyc_policy: Account<'info, KycPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"transfer_policy", config.key().as_ref()],
+        bump,
+    )]
+    pub transfer_policy: Account<'info, TransferPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitPendingFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 8 + 2 + 8 + 8 + 1,
+        seeds = [b"pending_fee", config.key().as_ref()],
+        bump,
+    )]
+    pub pending_fee: Account<'info, PendingFeeChange>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManagePendingFee<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_fee", config.key().as_ref()],
+        bump = pending_fee.bump,
+    )]
+    pub pending_fee: Account<'info, PendingFeeChange>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyFeeChange<'info> {
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_fee", config.key().as_ref()],
+        bump = pending_fee.bump,
+    )]
+    pub pending_fee: Account<'info, PendingFeeChange>,
+}
+
+#[derive(Accounts)]
+pub struct ManageFeePeriods<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 4 + 18 * MAX_FEE_PERIODS + 1,
+        seeds = [b"fee_periods", config.key().as_ref()],
+        bump,
+    )]
+    pub fee_periods: Account<'info, FeeSchedule>,
+    
+    #[account(address = config.load()?.stablecoin)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterMarketMaker<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Wallet that owns the market maker's token accounts
+    pub market_maker: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MarketMakerRebate::LEN,
+        seeds = [b"rebate", config.key().as_ref(), market_maker.key().as_ref()],
+        bump,
+    )]
+    pub rebate: Account<'info, MarketMakerRebate>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterMarketMaker<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"rebate", config.key().as_ref(), rebate.owner.as_ref()],
+        bump = rebate.bump,
+    )]
+    pub rebate: Account<'info, MarketMakerRebate>,
+}
+
+#[derive(Accounts)]
+pub struct SetReferrer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Partner wallet credited with referral fees
+    pub referrer: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 32 + 2 + 8 + 1,
+        seeds = [b"referrer", config.key().as_ref(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LinkReferral<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Address whose fees are shared
+    pub address: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"referrer", config.key().as_ref(), referrer_account.referrer.as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 32 + 32 + 8 + 1,
+        seeds = [b"referral", config.key().as_ref(), address.key().as_ref()],
+        bump,
+    )]
+    pub referral_link: Account<'info, ReferralLink>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlinkReferral<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"referral", config.key().as_ref(), referral_link.address.as_ref()],
+        bump = referral_link.bump,
+    )]
+    pub referral_link: Account<'info, ReferralLink>,
+    
+    #[account(
+        mut,
+        seeds = [b"referrer", config.key().as_ref(), referral_link.referrer.as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+}
+
+#[derive(Accounts)]
+pub struct SettleReferral<'info> {
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"referral", config.key().as_ref(), referral_link.address.as_ref()],
+        bump = referral_link.bump,
+    )]
+    pub referral_link: Account<'info, ReferralLink>,
+    
+    #[account(
+        mut,
+        seeds = [b"referrer", config.key().as_ref(), referral_link.referrer.as_ref()],
+        bump = referrer_account.bump,
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+}
+
+#[derive(Accounts)]
+pub struct SetPegFeePolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 32 + 1 + 8 + 8 + 8 + 2 + 2 + 2 + 8 + 1,
+        seeds = [b"peg_fee", config.key().as_ref()],
+        bump,
+    )]
+    pub peg_fee_policy: Account<'info, PegFeePolicy>,
+    
+    /// CHECK: Pending fee change PDA holding the notice period, may be uninitialized
+    #[account(seeds = [b"pending_fee", config.key().as_ref()], bump)]
+    pub pending_fee: AccountInfo<'info>,
+    
+    #[account(address = config.load()?.stablecoin)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePegPrice<'info> {
+    pub oracle: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"peg_fee", peg_fee_policy.config.as_ref()],
+        bump = peg_fee_policy.bump,
+        has_one = oracle @ TransferHookError::InvalidAuthority,
+    )]
+    pub peg_fee_policy: Account<'info, PegFeePolicy>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeTier<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"fee_tiers", config.key().as_ref()],
+        bump,
+    )]
+    pub fee_tiers: Account<'info, FeeTiers>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTierSolFee<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_tiers", config.key().as_ref()],
+        bump = fee_tiers.bump,
+    )]
+    pub fee_tiers: Account<'info, FeeTiers>,
+    
+    #[account(
+        mut,
+        seeds = [b"sol_fee_vault", config.key().as_ref()],
+        bump,
+    )]
+    pub sol_fee_vault: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSolFees<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"sol_fee_vault", config.key().as_ref()],
+        bump,
+    )]
+    pub sol_fee_vault: SystemAccount<'info>,
+    
+    /// CHECK: Any account receiving the lamports
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeeTierSchedule<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"fee_schedule", config.key().as_ref()],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeTierSchedule>,
+    
+    #[account(address = config.load()?.stablecoin)]
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAddressFeeOverride<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Address assigned to the tier
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"fee_override", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub fee_override: Account<'info, AddressFeeOverride>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAddressFeeOverride<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"fee_override", config.key().as_ref(), fee_override.address.as_ref()],
+        bump = fee_override.bump,
+    )]
+    pub fee_override: Account<'info, AddressFeeOverride>,
+}
+
+#[derive(Accounts)]
+pub struct InitFeeCredit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Owner the credit accrues to
+    pub owner: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 32 + 32 + 8 + 1,
+        seeds = [b"fee_credit", config.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub fee_credit: Account<'info, FeeCredit>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddFeeExemptProgram<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 560,
+        seeds = [b"fee_exempt_programs", config.key().as_ref()],
+        bump,
+    )]
+    pub fee_exempt_programs: Account<'info, FeeExemptPrograms>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemptProgram<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"fee_exempt_programs", config.key().as_ref()],
+        bump = fee_exempt_programs.bump,
+    )]
+    pub fee_exempt_programs: Account<'info, FeeExemptPrograms>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTransfer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Source owner the approval is for
+    pub source_owner: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner the approval is for
+    pub destination_owner: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 180,
+        seeds = [
+            b"approval",
+            config.key().as_ref(),
+            source_owner.key().as_ref(),
+            destination_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct AttachTravelRule<'info> {
+    #[account(mut)]
+    pub source_owner: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Destination owner the record is for
+    pub destination_owner: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = source_owner,
+        space = 8 + 220,
+        seeds = [
+            b"travel_rule",
+            config.key().as_ref(),
+            source_owner.key().as_ref(),
+            destination_owner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub travel_rule_record: Account<'info, TravelRuleRecord>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemoRequired<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        token::mint = config.load()?.stablecoin,
+        constraint = authority.key() == token_account.owner
+            || has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"memo_required", config.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub memo_requirement: Account<'info, MemoRequirement>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTransferApproval<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"approval",
+            config.key().as_ref(),
+            transfer_approval.source.as_ref(),
+            transfer_approval.destination.as_ref(),
+        ],
+        bump = transfer_approval.bump,
+    )]
+    pub transfer_approval: Account<'info, TransferApproval>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct InitVelocityTracker<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Owner whose outgoing transfers are tracked
+    pub owner: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 620,
+        seeds = [b"velocity", config.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub velocity_tracker: AccountLoader<'info, VelocityTracker>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSanctionsRoot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"sanctions_root", config.key().as_ref()],
+        bump,
+    )]
+    pub sanctions_root: Account<'info, SanctionsRoot>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct MaterializeBlacklistEntry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"sanctions_root", config.key().as_ref()],
+        bump = sanctions_root.bump,
+    )]
+    pub sanctions_root: Account<'info, SanctionsRoot>,
+    
+    /// CHECK: Address proven to be in the sanctions list
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BLACKLIST_ENTRY_LEN,
+        seeds = [b"blacklist", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+    
+    #[account(
+        mut,
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: Option<AccountLoader<'info, BlacklistBloom>>,
+}
+
+#[derive(Accounts)]
+pub struct SetGlobalBlacklistEntry<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+    
+    /// CHECK: Address being listed
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = 8 + 140,
+        seeds = [b"global_blacklist", issuer.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub global_entry: Account<'info, GlobalBlacklistEntry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncBlacklistEntry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"global_blacklist", config.load()?.authority.as_ref(), global_entry.address.as_ref()],
+        bump = global_entry.bump,
+    )]
+    pub global_entry: Account<'info, GlobalBlacklistEntry>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BLACKLIST_ENTRY_LEN,
+        seeds = [b"blacklist", config.key().as_ref(), global_entry.address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    pub system_program: Program<'info, System>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+    
+    #[account(
+        mut,
+        seeds = [b"blacklist_bloom", config.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_bloom: Option<AccountLoader<'info, BlacklistBloom>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBlacklistEntry<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Address the entry was created for
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"blacklist", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    
+    /// CHECK: Receives reclaimed rent
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceLegalHold<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Address being placed under hold
+    pub target_address: AccountInfo<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 150,
+        seeds = [b"legal_hold", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
+    )]
+    pub legal_hold: Account<'info, LegalHold>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseLegalHold<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"legal_hold", config.key().as_ref(), legal_hold.address.as_ref()],
+        bump = legal_hold.bump,
+    )]
+    pub legal_hold: Account<'info, LegalHold>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct SetEscheatmentPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub treasury: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 120,
+        seeds = [b"escheat_policy", config.key().as_ref()],
+        bump,
+    )]
+    pub escheatment_policy: Account<'info, EscheatmentPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitActivityRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = ACTIVITY_RECORD_LEN,
+        seeds = [b"activity", config.key().as_ref(), token_account.key().as_ref()],
+        bump,
+    )]
+    pub activity_record: Account<'info, ActivityRecord>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FlagDormantAccount<'info> {
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"escheat_policy", config.key().as_ref()],
+        bump = escheatment_policy.bump,
+    )]
+    pub escheatment_policy: Account<'info, EscheatmentPolicy>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"activity", config.key().as_ref(), token_account.key().as_ref()],
+        bump = activity_record.bump,
+    )]
+    pub activity_record: Account<'info, ActivityRecord>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeEscheatment<'info> {
+    /// sss-token's permanent delegate PDA, signing for its escheat_dormant_account
+    pub permanent_delegate: Signer<'info>,
+    
+    /// CHECK: The sss-token seizer running the escheatment, checked by sss-token
+    pub authority: UncheckedAccount<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        seeds = [b"escheat_policy", config.key().as_ref()],
+        bump = escheatment_policy.bump,
+    )]
+    pub escheatment_policy: Account<'info, EscheatmentPolicy>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub token_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"activity", config.key().as_ref(), token_account.key().as_ref()],
+        bump = activity_record.bump,
+    )]
+    pub activity_record: Account<'info, ActivityRecord>,
+    
+    /// CHECK: Token account sss-token moves the balance to
+    #[account(address = escheatment_policy.treasury @ TransferHookError::InvalidEscheatmentPolicy)]
+    pub escheatment_treasury: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRelayer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Relayer being registered
+    pub relayer: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 90,
+        seeds = [b"relayer", config.key().as_ref(), relayer.key().as_ref()],
+        bump,
+    )]
+    pub relayer_entry: Account<'info, Relayer>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConsumeSponsoredTransfer<'info> {
+    /// sss-token's transfer delegate PDA, signing for its execute_sponsored_transfer
+    #[account(address = sss_transfer_delegate(&config.load()?.stablecoin) @ TransferHookError::InvalidAuthority)]
+    pub transfer_delegate: Signer<'info>,
+    
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"relayer", config.key().as_ref(), relayer.key().as_ref()],
+        bump = relayer_entry.bump,
+        constraint = relayer_entry.enabled @ TransferHookError::InvalidRelayer,
+    )]
+    pub relayer_entry: Account<'info, Relayer>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub destination_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + 80,
+        seeds = [b"sponsor_nonce", config.key().as_ref(), source_account.owner.as_ref()],
+        bump,
+    )]
+    pub sponsor_nonce: Account<'info, SponsorNonce>,
+    
+    /// CHECK: Instructions sysvar, for ed25519 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey, spender: Pubkey, amount: u64, deadline: i64, nonce: u64)]
+pub struct Permit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 120,
+        seeds = [b"allowance", config.key().as_ref(), owner.as_ref(), spender.as_ref()],
+        bump,
+    )]
+    pub allowance: Account<'info, Allowance>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<NonceBitmap>(),
+        seeds = [b"nonce_bitmap", owner.as_ref(), (nonce / NONCES_PER_BITMAP).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub nonce_bitmap: AccountLoader<'info, NonceBitmap>,
+    
+    /// CHECK: Instructions sysvar, for ed25519 introspection
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SpendAllowance<'info> {
+    /// sss-token's transfer delegate PDA, signing for its transfer_from
+    #[account(address = sss_transfer_delegate(&config.load()?.stablecoin) @ TransferHookError::InvalidAuthority)]
+    pub transfer_delegate: Signer<'info>,
+    
+    pub spender: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(token::mint = config.load()?.stablecoin)]
+    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"allowance", config.key().as_ref(), source_account.owner.as_ref(), spender.key().as_ref()],
+        bump = allowance.bump,
+    )]
+    pub allowance: Account<'info, Allowance>,
+}
 
 #[derive(Accounts)]
-pub struct InitializeHook<'info> {
+pub struct SetAddressTag<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: The stablecoin mint this hook is for
-    pub stablecoin: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_LABELER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Stablecoin state PDA
-    pub stablecoin_state: AccountInfo<'info>,
+    /// CHECK: Address being tagged
+    pub target_address: AccountInfo<'info>,
     
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
-        space = 8 + 200,
-        seeds = [b"hook_config", stablecoin.key().as_ref()],
-        bump
+        space = 8 + 150,
+        seeds = [b"address_tag", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
     )]
-    pub config: Account<'info, TransferHookConfig>,
+    pub address_tag: Account<'info, AddressTag>,
     
     pub system_program: Program<'info, System>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
 }
 
-/// Accounts for initialize_extra_account_meta_list.
-/// Token-2022 requires this account to be created with the extra PDAs
-/// the hook will need during execute_transfer_hook invocations.
 #[derive(Accounts)]
-pub struct InitExtraAccountMetaList<'info> {
+pub struct RemoveAddressTag<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_LABELER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"address_tag", config.key().as_ref(), address_tag.address.as_ref()],
+        bump = address_tag.bump,
+    )]
+    pub address_tag: Account<'info, AddressTag>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
 
-    /// CHECK: Transfer hook config (already initialized)
+#[derive(Accounts)]
+pub struct SetGatekeeperNetwork<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
     #[account(
-        seeds = [b"hook_config", mint.key().as_ref()],
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"gatekeeper", config.key().as_ref()],
         bump,
     )]
-    pub config: Account<'info, TransferHookConfig>,
+    pub gatekeeper: Account<'info, GatekeeperConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
 
-    /// The Token-2022 mint this hook is registered on
-    /// CHECK: validated by seeds constraint
+#[derive(Accounts)]
+pub struct SetRiskOracle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    /// CHECK: Risk program being allowlisted
+    #[account(constraint = risk_program.executable @ TransferHookError::InvalidRiskProgram)]
+    pub risk_program: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 80,
+        seeds = [b"risk_oracle", config.key().as_ref()],
+        bump,
+    )]
+    pub risk_oracle: Account<'info, RiskOracle>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Read-only mirror of the hook's owner-level accounts, derived from the
+/// instruction's owners instead of token accounts
+#[derive(Accounts)]
+#[instruction(source_owner: Pubkey, destination_owner: Pubkey)]
+pub struct PrecheckTransfer<'info> {
+    #[account(constraint = config.load()?.stablecoin == mint.key() @ TransferHookError::MintMismatch)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
     pub mint: InterfaceAccount<'info, InterfaceMint>,
+    
+    /// CHECK: Master Stablecoin State from Base Program
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump,
+        seeds::program = sss_token_program::ID,
+    )]
+    pub stablecoin_state: AccountInfo<'info>,
+    
+    /// CHECK: Source blacklist PDA, may be uninitialized
+    #[account(seeds = [b"blacklist", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub source_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Destination blacklist PDA, may be uninitialized
+    #[account(seeds = [b"blacklist", config.key().as_ref(), destination_owner.as_ref()], bump)]
+    pub destination_blacklist: AccountInfo<'info>,
+    
+    /// CHECK: Source whitelist PDA, may be uninitialized
+    #[account(seeds = [b"whitelist", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub source_whitelist: AccountInfo<'info>,
+    
+    /// CHECK: Destination whitelist PDA, may be uninitialized
+    #[account(seeds = [b"whitelist", config.key().as_ref(), destination_owner.as_ref()], bump)]
+    pub destination_whitelist: AccountInfo<'info>,
+    
+    /// CHECK: Source legal hold PDA, may be uninitialized
+    #[account(seeds = [b"legal_hold", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub source_legal_hold: AccountInfo<'info>,
+    
+    /// CHECK: KYC policy PDA, may be uninitialized
+    #[account(seeds = [b"kyc_policy", config.key().as_ref()], bump)]
+    pub kyc_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source KYC record PDA, may be uninitialized
+    #[account(seeds = [b"kyc", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub source_kyc: AccountInfo<'info>,
+    
+    /// CHECK: Destination KYC record PDA, may be uninitialized
+    #[account(seeds = [b"kyc", config.key().as_ref(), destination_owner.as_ref()], bump)]
+    pub destination_kyc: AccountInfo<'info>,
+    
+    /// CHECK: Transfer policy PDA, may be uninitialized
+    #[account(seeds = [b"transfer_policy", config.key().as_ref()], bump)]
+    pub transfer_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source velocity tracker PDA, may be uninitialized
+    #[account(seeds = [b"velocity", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub velocity_tracker: AccountInfo<'info>,
+    
+    /// CHECK: Destination velocity tracker PDA, may be uninitialized
+    #[account(seeds = [b"velocity", config.key().as_ref(), destination_owner.as_ref()], bump)]
+    pub destination_velocity_tracker: AccountInfo<'info>,
+    
+    /// CHECK: Large-transfer approval PDA, may be uninitialized
+    #[account(
+        seeds = [
+            b"approval",
+            config.key().as_ref(),
+            source_owner.as_ref(),
+            destination_owner.as_ref(),
+        ],
+        bump,
+    )]
+    pub transfer_approval: AccountInfo<'info>,
+    
+    /// CHECK: Fee tiers PDA, may be uninitialized
+    #[account(seeds = [b"fee_tiers", config.key().as_ref()], bump)]
+    pub fee_tiers: AccountInfo<'info>,
+    
+    /// CHECK: Source fee override PDA, may be uninitialized
+    #[account(seeds = [b"fee_override", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub fee_override: AccountInfo<'info>,
+    
+    /// CHECK: Fee tier schedule PDA, may be uninitialized
+    #[account(seeds = [b"fee_schedule", config.key().as_ref()], bump)]
+    pub fee_schedule: AccountInfo<'info>,
+    
+    /// CHECK: Gatekeeper config PDA, may be uninitialized
+    #[account(seeds = [b"gatekeeper", config.key().as_ref()], bump)]
+    pub gatekeeper: AccountInfo<'info>,
+    
+    /// CHECK: Source owner's gateway token, validated by has_gateway_pass
+    pub source_gateway_token: AccountInfo<'info>,
+    
+    /// CHECK: Destination owner's gateway token, validated by has_gateway_pass
+    pub destination_gateway_token: AccountInfo<'info>,
+    
+    /// CHECK: Pending fee change PDA, may be uninitialized
+    #[account(seeds = [b"pending_fee", config.key().as_ref()], bump)]
+    pub pending_fee: AccountInfo<'info>,
+    
+    /// CHECK: Fee periods PDA, may be uninitialized
+    #[account(seeds = [b"fee_periods", config.key().as_ref()], bump)]
+    pub fee_periods: AccountInfo<'info>,
+    
+    /// CHECK: Peg fee policy PDA, may be uninitialized
+    #[account(seeds = [b"peg_fee", config.key().as_ref()], bump)]
+    pub peg_fee_policy: AccountInfo<'info>,
+    
+    /// CHECK: Source address tag PDA, may be uninitialized
+    #[account(seeds = [b"address_tag", config.key().as_ref(), source_owner.as_ref()], bump)]
+    pub source_address_tag: AccountInfo<'info>,
+    
+    /// CHECK: Destination address tag PDA, may be uninitialized
+    #[account(seeds = [b"address_tag", config.key().as_ref(), destination_owner.as_ref()], bump)]
+    pub destination_address_tag: AccountInfo<'info>,
+}
 
-    /// The ExtraAccountMetaList account — seeded on "extra-account-metas" + mint
-    /// This is the canonical seed required by the spl-transfer-hook-interface.
-    /// CHECK: initialized inside the instruction via ExtraAccountMetaList::init
+#[derive(Accounts)]
+#[instruction(kind: u8, page: u8)]
+pub struct InitListIndex<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 32 + 8 + 8 + 1,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump,
+    )]
+    pub list_registry: Account<'info, ListRegistry>,
+    
     #[account(
         init,
-        payer = payer,
-        space = ExtraAccountMetaList::size_of(5).unwrap_or(256), // Expanded for 5 extra accounts
-        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        payer = authority,
+        space = 8 + 32 + 1 + 1 + 4 + 32 * LIST_INDEX_PAGE_SIZE + 1,
+        seeds = [b"list_index", config.key().as_ref(), &[kind], &[page]],
         bump,
     )]
-    pub extra_account_meta_list: AccountInfo<'info>,
-
+    pub list_index: Account<'info, ListIndexPage>,
+    
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct ExecuteTransferHook<'info> {
+pub struct InitHookStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+    
     #[account(
-        seeds = [b"hook_config", mint.key().as_ref()],
-        bump = config.bump,
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<HookStats>(),
+        seeds = [b"hook_stats", config.key().as_ref()],
+        bump,
     )]
-    pub config: Account<'info, TransferHookConfig>,
+    pub hook_stats: AccountLoader<'info, HookStats>,
     
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(shard: u8)]
+pub struct InitStatsShard<'info> {
     #[account(mut)]
-    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub payer: Signer<'info>,
     
-    #[account(mut)]
-    pub destination_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    pub mint: InterfaceAccount<'info, InterfaceMint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<StatsShard>(),
+        seeds = [b"stats_shard", config.key().as_ref(), &[shard]],
+        bump,
+    )]
+    pub stats_shard: AccountLoader<'info, StatsShard>,
     
-    /// CHECK: Source owner (from token account data)
-    pub source_owner: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RollUpStats<'info> {
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Optional source blacklist
     #[account(
-        seeds = [b"blacklist", config.key().as_ref(), source_owner.key().as_ref()],
+        mut,
+        seeds = [b"hook_stats", config.key().as_ref()],
         bump,
     )]
-    pub source_blacklist: Option<Account<'info, BlacklistEntry>>,
+    pub hook_stats: AccountLoader<'info, HookStats>,
+}
+
+#[derive(Accounts)]
+pub struct RecordRejection<'info> {
+    pub reporter: Signer<'info>,
+    
+    #[account(
+        constraint = has_hook_role(&*config.load()?, &reporter.key(), role_account.as_ref(), sss_token_program::ROLE_BLACKLISTER)
+            @ TransferHookError::InvalidAuthority,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Optional destination blacklist
     #[account(
-        seeds = [b"blacklist", config.key().as_ref(), destination_account.owner.as_ref()],
+        mut,
+        seeds = [b"hook_stats", config.key().as_ref()],
         bump,
     )]
-    pub destination_blacklist: Option<Account<'info, BlacklistEntry>>,
+    pub hook_stats: AccountLoader<'info, HookStats>,
+    
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+#[instruction(day: i64)]
+pub struct ArchiveDailyStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Optional source whitelist
     #[account(
-        seeds = [b"whitelist", config.key().as_ref(), source_owner.key().as_ref()],
+        seeds = [b"hook_stats", config.key().as_ref()],
         bump,
     )]
-    pub source_whitelist: Option<Account<'info, WhitelistEntry>>,
+    pub hook_stats: AccountLoader<'info, HookStats>,
     
-    /// CHECK: Optional destination whitelist
     #[account(
-        seeds = [b"whitelist", config.key().as_ref(), destination_account.owner.as_ref()],
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<DailyStatsRecord>(),
+        seeds = [b"daily_stats", config.key().as_ref(), &day.to_le_bytes()],
         bump,
     )]
-    pub destination_whitelist: Option<Account<'info, WhitelistEntry>>,
+    pub daily_stats: AccountLoader<'info, DailyStatsRecord>,
     
-    /// CHECK: Base Program ID
-    pub base_program_id_account: Option<AccountInfo<'info>>,
-
-    /// CHECK: Master Stablecoin State from Base Program
-    pub stablecoin_state: Option<AccountInfo<'info>>,
-
-    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ManageBlacklist<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+pub struct CloseExpiredBlacklistEntry<'info> {
+    pub cranker: Signer<'info>,
     
-    #[account(mut)]
-    pub config: Account<'info, TransferHookConfig>,
+    #[account(mut, has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Target address
+    /// CHECK: Address the entry was created for
     pub target_address: AccountInfo<'info>,
     
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = 8 + 200,
+        mut,
+        close = authority,
         seeds = [b"blacklist", config.key().as_ref(), target_address.key().as_ref()],
         bump,
     )]
     pub blacklist_entry: Account<'info, BlacklistEntry>,
     
-    pub system_program: Program<'info, System>,
+    /// CHECK: Config authority, receives reclaimed rent
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
 }
 
 #[derive(Accounts)]
-pub struct ManageWhitelist<'info> {
-    #[account(mut)]
+pub struct CloseWhitelistEntry<'info> {
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub config: Account<'info, TransferHookConfig>,
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    /// CHECK: Target address
+    /// CHECK: Address the entry was created for
     pub target_address: AccountInfo<'info>,
     
     #[account(
-        init_if_needed,
-        payer = authority,
-        space = 8 + 100,
+        mut,
+        close = recipient,
         seeds = [b"whitelist", config.key().as_ref(), target_address.key().as_ref()],
         bump,
     )]
     pub whitelist_entry: Account<'info, WhitelistEntry>,
     
-    pub system_program: Program<'info, System>,
+    /// CHECK: Receives reclaimed rent
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"list_registry", config.key().as_ref()],
+        bump = list_registry.bump,
+    )]
+    pub list_registry: Option<Account<'info, ListRegistry>>,
+    
+    #[account(mut, constraint = list_index.config == config.key() @ TransferHookError::InvalidListIndex)]
+    pub list_index: Option<Account<'info, ListIndexPage>>,
 }
 
 #[derive(Accounts)]
-pub struct SeizeTokens<'info> {
+pub struct MigrateLegacyConfig<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
     
-    #[account(mut)]
-    pub config: Account<'info, TransferHookConfig>,
+    /// CHECK: Legacy config; owner, discriminator and authority checked in handler
+    #[account(
+        mut,
+        seeds = [b"config", authority.key().as_ref()],
+        bump,
+    )]
+    pub legacy_config: UncheckedAccount<'info>,
     
-    #[account(mut)]
     pub mint: InterfaceAccount<'info, InterfaceMint>,
     
-    #[account(mut)]
-    pub source_account: InterfaceAccount<'info, InterfaceTokenAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = TRANSFER_HOOK_CONFIG_LEN,
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfigLayout<'info> {
     #[account(mut)]
-    pub treasury: InterfaceAccount<'info, InterfaceTokenAccount>,
+    pub payer: Signer<'info>,
     
-    /// CHECK: Permanent delegate PDA
-    pub permanent_delegate: AccountInfo<'info>,
+    pub mint: InterfaceAccount<'info, InterfaceMint>,
     
-    pub token_program: Program<'info, Token2022>,
+    /// CHECK: Config in the Borsh layout; owner, discriminator and size checked in handler
+    #[account(
+        mut,
+        seeds = [b"hook_config", mint.key().as_ref()],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateConfig<'info> {
-    pub authority: Signer<'info>,
+pub struct MigrateBlacklistEntry<'info> {
+    #[account(has_one = authority @ TransferHookError::InvalidAuthority)]
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
+    /// CHECK: Address the entry was created for
+    pub target_address: AccountInfo<'info>,
+    
+    /// CHECK: Entry in the old layout; owner, discriminator and size checked in handler
     #[account(
         mut,
-        has_one = authority @ TransferHookError::InvalidAuthority,
+        seeds = [b"blacklist", config.key().as_ref(), target_address.key().as_ref()],
+        bump,
     )]
-    pub config: Account<'info, TransferHookConfig>,
+    pub blacklist_entry: UncheckedAccount<'info>,
+    
+    /// CHECK: Config authority, receives freed rent
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-pub struct BatchBlacklist<'info> {
+pub struct PauseTransfers<'info> {
     pub authority: Signer<'info>,
     
     #[account(
         mut,
-        has_one = authority @ TransferHookError::InvalidAuthority,
+        constraint = has_hook_role(&*config.load()?, &authority.key(), role_account.as_ref(), sss_token_program::ROLE_PAUSER)
+            @ TransferHookError::InvalidAuthority,
     )]
-    pub config: Account<'info, TransferHookConfig>,
+    pub config: AccountLoader<'info, TransferHookConfig>,
     
-    pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+    /// CHECK: Caller's sss-token RoleAccount, verified by has_hook_role
+    pub role_account: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct GetHookConfig<'info> {
+    #[account(
+        seeds = [b"hook_config", config.load()?.stablecoin.as_ref()],
+        bump = config.load()?.bump,
+    )]
+    pub config: AccountLoader<'info, TransferHookConfig>,
+}
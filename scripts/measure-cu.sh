@@ -0,0 +1,58 @@
+#!/bin/bash
+
+# Compare compute units of a mint and of a hooked transfer between two
+# revisions. Each revision is built in its own worktree (the hook with
+# cu-trace where it has the feature), loaded into a fresh local validator,
+# and measured by tests/compute-units.test.ts from this checkout.
+#
+#   bash scripts/measure-cu.sh [before-rev] [after-rev]
+#
+# Defaults compare the last revision before the zero-copy state accounts
+# with HEAD.
+
+set -euo pipefail
+
+BEFORE=${1:-f95e664}
+AFTER=${2:-HEAD}
+ROOT=$(git rev-parse --show-toplevel)
+WALLET=${ANCHOR_WALLET:-$HOME/.config/solana/id.json}
+
+measure() {
+    local rev=$1
+    local dir
+    dir=$(mktemp -d)
+    git -C "$ROOT" worktree add --detach "$dir" "$rev" > /dev/null
+
+    (
+        cd "$dir"
+        if grep -q "cu-trace" programs/sss-transfer-hook/Cargo.toml; then
+            anchor build -p sss_transfer_hook -- --features cu-trace > /dev/null
+        else
+            anchor build -p sss_transfer_hook > /dev/null
+        fi
+        anchor build -p sss_token > /dev/null
+    )
+
+    local token_id hook_id
+    token_id=$(node -p "require('$dir/target/idl/sss_token.json').address")
+    hook_id=$(node -p "require('$dir/target/idl/sss_transfer_hook.json').address")
+
+    solana-test-validator --reset --quiet --ledger "$dir/.ledger" \
+        --bpf-program "$token_id" "$dir/target/deploy/sss_token.so" \
+        --bpf-program "$hook_id" "$dir/target/deploy/sss_transfer_hook.so" &
+    local validator=$!
+    sleep 10
+
+    echo "== $rev ($(git -C "$ROOT" rev-parse --short "$rev"))"
+    ANCHOR_PROVIDER_URL=http://127.0.0.1:8899 ANCHOR_WALLET="$WALLET" CU_IDL_DIR="$dir/target/idl" \
+        npx ts-mocha -p ./tsconfig.json tests/compute-units.test.ts --timeout 120000 \
+        | grep "^CU "
+
+    kill "$validator"
+    wait "$validator" 2> /dev/null || true
+    git -C "$ROOT" worktree remove --force "$dir"
+}
+
+cd "$ROOT"
+measure "$BEFORE"
+measure "$AFTER"
@@ -1,6 +1,20 @@
 import { Connection, PublicKey, Keypair } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
-import { SSS_TRANSFER_HOOK_PROGRAM_ID, SDKResult } from "./types";
+import {
+  SSS_TOKEN_PROGRAM_ID,
+  SSS_TRANSFER_HOOK_PROGRAM_ID,
+  SDKResult,
+} from "./types";
+
+/**
+ * Reason recorded on blacklist entries and seizures
+ */
+export type ReasonCode =
+  | "sanctions_match"
+  | "fraud_investigation"
+  | "court_order"
+  | "chargeback"
+  | "other";
 
 /**
  * Compliance Module for SSS-2 transfer hooks
@@ -35,6 +49,256 @@ export class ComplianceModule {
     )[0];
   }
 
+  /**
+   * Get the audit log PDA, authority of the compressed transfer audit tree
+   */
+  getAuditLogPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_log"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the blacklist bloom filter PDA the hook pre-checks before blacklist entries
+   */
+  getBlacklistBloomPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("blacklist_bloom"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the list registry PDA holding blacklist and whitelist counts
+   */
+  getListRegistryPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("list_registry"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get a list index page PDA (kind 0 = blacklist, 1 = whitelist)
+   */
+  getListIndexPDA(config: PublicKey, kind: number, page: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("list_index"), config.toBuffer(), Buffer.from([kind]), Buffer.from([page])],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the pending fee change PDA holding a queued fee increase
+   */
+  getPendingFeePDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_fee"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the fee periods PDA holding dated fee rates (e.g. fee holidays)
+   */
+  getFeePeriodsPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_periods"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get a market maker's fee rebate PDA
+   */
+  getMarketMakerRebatePDA(config: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("rebate"), config.toBuffer(), owner.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get an owner's fee credit PDA, where the hook credits back the part of
+   * the withheld TransferFee its tier doesn't charge
+   */
+  getFeeCreditPDA(config: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_credit"), config.toBuffer(), owner.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get a referral partner's PDA
+   */
+  getReferrerPDA(config: PublicKey, referrer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), config.toBuffer(), referrer.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the PDA linking an address to its referrer
+   */
+  getReferralLinkPDA(config: PublicKey, address: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("referral"), config.toBuffer(), address.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the SOL fee vault PDA. Tiers paying fees in SOL must precede each
+   * transfer with a SystemProgram transfer of the tier's lamports to it.
+   */
+  getSolFeeVaultPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_fee_vault"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the peg fee policy PDA holding the oracle price and surcharge bounds
+   */
+  getPegFeePolicyPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("peg_fee"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get legal hold PDA
+   */
+  getLegalHoldPDA(config: PublicKey, address: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("legal_hold"), config.toBuffer(), address.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get address tag PDA
+   */
+  getAddressTagPDA(config: PublicKey, address: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("address_tag"), config.toBuffer(), address.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get gatekeeper config PDA
+   */
+  getGatekeeperPDA(config: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("gatekeeper"), config.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get a registered relayer PDA
+   */
+  getRelayerPDA(config: PublicKey, relayer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("relayer"), config.toBuffer(), relayer.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the allowance PDA granted by a permit from owner to spender
+   */
+  getAllowancePDA(config: PublicKey, owner: PublicKey, spender: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("allowance"), config.toBuffer(), owner.toBuffer(), spender.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the used-nonce bitmap PDA covering a permit nonce (2048 nonces per page)
+   */
+  getNonceBitmapPDA(signer: PublicKey, nonce: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nonce_bitmap"),
+        signer.toBuffer(),
+        nonce.divn(2048).toArrayLike(Buffer, "le", 8),
+      ],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the sponsored-transfer nonce PDA for a token owner
+   */
+  getSponsorNoncePDA(config: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("sponsor_nonce"), config.toBuffer(), owner.toBuffer()],
+      this.programId
+    )[0];
+  }
+
+  /**
+   * Get the gateway token address for a wallet on a gatekeeper network
+   */
+  getGatewayTokenPDA(wallet: PublicKey, network: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [wallet.toBuffer(), Buffer.from("gateway"), Buffer.alloc(8), network.toBuffer()],
+      new PublicKey("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs")
+    )[0];
+  }
+
+  /**
+   * Get the permanent delegate PDA the mint must be configured with. It
+   * belongs to sss-token, which executes seizures.
+   */
+  getPermanentDelegatePDA(mint: PublicKey): PublicKey {
+    const [stablecoin] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.toBuffer()],
+      SSS_TOKEN_PROGRAM_ID
+    );
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("permanent_delegate"), stablecoin.toBuffer()],
+      SSS_TOKEN_PROGRAM_ID
+    )[0];
+  }
+
+  /**
+   * Get the transfer delegate PDA a holder approves on their token account
+   * before using sponsored transfers or allowances through sss-token.
+   */
+  getTransferDelegatePDA(mint: PublicKey): PublicKey {
+    const [stablecoin] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stablecoin"), mint.toBuffer()],
+      SSS_TOKEN_PROGRAM_ID
+    );
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer_delegate"), stablecoin.toBuffer()],
+      SSS_TOKEN_PROGRAM_ID
+    )[0];
+  }
+
+  /**
+   * Get seizure case PDA
+   */
+  getSeizureCasePDA(config: PublicKey, caseId: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("seizure_case"),
+        config.toBuffer(),
+        caseId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.programId
+    )[0];
+  }
+
   /**
    * Get whitelist entry PDA
    */
@@ -73,7 +337,9 @@ export class ComplianceModule {
     config: PublicKey;
     authority: Keypair;
     target: PublicKey;
-    reason: string;
+    reasonCode: ReasonCode;
+    reference?: Uint8Array; // 32-byte hash of an external case reference
+    entryKind?: "owner" | "token_account" | "delegate";
   }): Promise<SDKResult> {
     try {
       return { success: true };
@@ -171,12 +437,11 @@ export class ComplianceModule {
    */
   async seize(params: {
     config: PublicKey;
-    authority: Keypair; // Must be permanent delegate
+    authority: Keypair; // sss-token seizer; see SolanaStablecoin.seizeTokens
     source: PublicKey;
     treasury: PublicKey;
     mint: PublicKey;
-    amount?: BN; // If not provided, seizes all
-    reason: string;
+    seizureCase: PublicKey; // Multisig-approved SeizureCase for `source`
   }): Promise<SDKResult> {
     try {
       return { success: true };
@@ -305,11 +570,11 @@ export class ComplianceModule {
     authority: Keypair,
     config: PublicKey,
     addresses: PublicKey[],
-    reasons: string[]
+    reasonCodes: ReasonCode[]
   ): Promise<SDKResult> {
     try {
-      if (addresses.length !== reasons.length) {
-        throw new Error("Addresses and reasons length mismatch");
+      if (addresses.length !== reasonCodes.length) {
+        throw new Error("Addresses and reason codes length mismatch");
       }
       if (addresses.length > 10) {
         throw new Error("Maximum 10 addresses per batch");
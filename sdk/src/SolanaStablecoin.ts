@@ -1,4 +1,13 @@
-import { Connection, PublicKey, Keypair, Transaction } from "@solana/web3.js";
+import {
+  Connection,
+  PublicKey,
+  Keypair,
+  Transaction,
+  AddressLookupTableAccount,
+  AddressLookupTableProgram,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
 import { BN, Program, AnchorProvider, web3 } from "@coral-xyz/anchor";
 import {
   SSS_TOKEN_PROGRAM_ID,
@@ -22,7 +31,11 @@ import {
   createInitializePermanentDelegateInstruction,
   createInitializeMintCloseAuthorityInstruction,
   createInitializeDefaultAccountStateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createInitializeMetadataPointerInstruction,
   TOKEN_2022_PROGRAM_ID,
+  getAccount,
+  createTransferCheckedWithTransferHookInstruction,
 } from "@solana/spl-token";
 import * as anchor from "@coral-xyz/anchor";
 
@@ -51,6 +64,7 @@ const createInitializeConfidentialTransferMintInstruction = (
 };
 
 import { TransactionInstruction } from "@solana/web3.js";
+import { decodeStablecoinState, issuerRegistryAccounts } from "./utils";
 
 /**
  * Core SDK for managing SSS-1 and SSS-2 stablecoins
@@ -137,6 +151,37 @@ export class SolanaStablecoin {
     )[0];
   }
 
+  /**
+   * Get a holder index page PDA (kind 0 = role holders, 1 = minters)
+   */
+  getHolderIndexPDA(stablecoinPDA: PublicKey, kind: number, page: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("holder_index"), stablecoinPDA.toBuffer(), Buffer.from([kind]), Buffer.from([page])],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the registry PDA listing the issuer's address lookup tables.
+   * It is also the authority of every registered table.
+   */
+  getLookupTableRegistryPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("lookup_tables"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get per-minter daily stats PDA
+   */
+  getMinterStatsPDA(minter: PublicKey, mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("minter_stats"), minter.toBuffer(), mint.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
   /**
    * Get mint authority PDA
    */
@@ -167,6 +212,325 @@ export class SolanaStablecoin {
     )[0];
   }
 
+  /**
+   * Get treasury authority PDA (owner of the treasury ATA and
+   * withdraw-withheld authority for the TransferFee extension)
+   */
+  getTreasuryAuthorityPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_authority"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get insurance fund PDA (fee-fed loss buffer)
+   */
+  getInsuranceFundPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_fund"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get insurance authority PDA (owner of the insurance fund ATA)
+   */
+  getInsuranceAuthorityPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_authority"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get savings pool PDA (deposit-to-earn pool)
+   */
+  getSavingsPoolPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("savings_pool"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get a holder's savings position PDA
+   */
+  getSavingsPositionPDA(savingsPool: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("savings_position"), savingsPool.toBuffer(), owner.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get a snapshot reward campaign PDA
+   */
+  getRewardCampaignPDA(stablecoinPDA: PublicKey, campaignId: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reward_campaign"),
+        stablecoinPDA.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the claim receipt PDA marking a holder's reward as paid
+   */
+  getRewardClaimPDA(campaign: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("reward_claim"), campaign.toBuffer(), owner.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the PDA tracking rebates already paid to a market maker
+   */
+  getRebateClaimPDA(stablecoinPDA: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("rebate_claim"), stablecoinPDA.toBuffer(), owner.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the PDA tracking fee credit already paid to an owner
+   */
+  getFeeCreditClaimPDA(stablecoinPDA: PublicKey, owner: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_credit_claim"), stablecoinPDA.toBuffer(), owner.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the PDA tracking referral fees already paid to a partner
+   */
+  getReferralClaimPDA(stablecoinPDA: PublicKey, referrer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("referral_claim"), stablecoinPDA.toBuffer(), referrer.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the allowlist of program-owned token accounts sweepToTreasury may consolidate
+   */
+  getSweepAllowlistPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("sweep_allowlist"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the reserve attestation PDA holding the latest reserve buckets
+   */
+  getReserveAttestationPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("reserve_attestation"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get a payment stream PDA
+   */
+  getStreamPDA(stablecoinPDA: PublicKey, payer: PublicKey, streamId: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stream"),
+        stablecoinPDA.toBuffer(),
+        payer.toBuffer(),
+        streamId.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get a registered voucher signer PDA
+   */
+  getVoucherSignerPDA(stablecoinPDA: PublicKey, signer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("voucher_signer"), stablecoinPDA.toBuffer(), signer.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the used-nonce bitmap PDA covering a signer's nonce (2048 nonces per page)
+   */
+  getNonceBitmapPDA(signer: PublicKey, nonce: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nonce_bitmap"),
+        signer.toBuffer(),
+        nonce.divn(2048).toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the key rotation record PDA written when `oldKey` is revoked
+   */
+  getKeyRotationPDA(stablecoinPDA: PublicKey, oldKey: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("key_rotation"), stablecoinPDA.toBuffer(), oldKey.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the governance config PDA naming the Realms governance that may
+   * call executeFromGovernance
+   */
+  getGovernanceConfigPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("governance"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the mint velocity circuit breaker PDA
+   */
+  getCircuitBreakerPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("circuit_breaker"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get a registered monitor PDA (may raise anomaly flags)
+   */
+  getMonitorPDA(stablecoinPDA: PublicKey, monitor: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("monitor"), stablecoinPDA.toBuffer(), monitor.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the anomaly flags PDA
+   */
+  getAnomalyStatePDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("anomaly_state"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the crank fund PDA paying rollEpoch bounties
+   */
+  getCrankFundPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("crank_fund"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the destruction case PDA recorded by burnFrozen for a proposal
+   */
+  getDestructionCasePDA(stablecoinPDA: PublicKey, proposal: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("destruction_case"), stablecoinPDA.toBuffer(), proposal.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the pending rescue request PDA for a token account stranded on a program PDA
+   */
+  getRescueRequestPDA(stablecoinPDA: PublicKey, sourceTokenAccount: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("rescue"), stablecoinPDA.toBuffer(), sourceTokenAccount.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get mint close authority PDA
+   */
+  getCloseAuthorityPDA(stablecoinPDA: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("close_authority"), stablecoinPDA.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the issuer registry PDA listing every stablecoin an authority created
+   */
+  getIssuerRegistryPDA(authority: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("issuer_registry"), authority.toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * Get the issuer registry entry PDA at `index`
+   */
+  getIssuerRegistryEntryPDA(registry: PublicKey, index: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("issuer_registry_entry"),
+        registry.toBuffer(),
+        index.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    )[0];
+  }
+
+  /**
+   * List the mints an issuer authority has created, oldest first
+   */
+  async listIssuerStablecoins(
+    authority: PublicKey,
+    offset = 0,
+    limit = 50
+  ): Promise<PublicKey[]> {
+    const registry = this.getIssuerRegistryPDA(authority);
+    // @ts-ignore
+    const state = await this.program.account.issuerRegistry.fetchNullable(
+      registry
+    );
+    if (!state) {
+      return [];
+    }
+    const count = (state.count as BN).toNumber();
+    const entries = [];
+    for (let i = offset; i < Math.min(count, offset + limit); i++) {
+      entries.push(this.getIssuerRegistryEntryPDA(registry, new BN(i)));
+    }
+    // @ts-ignore
+    const accounts = await this.program.account.issuerRegistryEntry.fetchMultiple(
+      entries
+    );
+    return accounts
+      .filter((entry: any) => entry !== null)
+      .map((entry: any) => entry.mint as PublicKey);
+  }
+
+  /**
+   * Get the permanent delegate PDA for a mint, which seize_tokens signs with
+   */
+  getPermanentDelegatePDA(mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("permanent_delegate"), this.getStablecoinPDA(mint).toBuffer()],
+      this.program.programId
+    )[0];
+  }
+
   /**
    * Initialize a new stablecoin (SSS-1 or SSS-2)
    */
@@ -180,6 +544,16 @@ export class SolanaStablecoin {
     enableConfidentialTransfers?: boolean;
     enableMintCloseAuthority?: boolean;
     enableDefaultAccountState?: boolean;
+    transferFee?: { basisPoints: number; maxFee: bigint };
+    // Launch policy, applied atomically through initialize_v2
+    policy?: {
+      supplyCap?: BN;
+      epochQuota?: BN;
+      epochMode?: "unixSeconds" | "slots" | "solanaEpoch";
+      epochLength?: BN;
+      startPaused?: boolean;
+      uri?: string; // Stored as Token-2022 metadata in the mint
+    };
   }): Promise<
     SDKResult<{ mint: PublicKey; stablecoin: PublicKey; signature: string }>
   > {
@@ -194,6 +568,8 @@ export class SolanaStablecoin {
         enableConfidentialTransfers = false,
         enableMintCloseAuthority = false,
         enableDefaultAccountState = false,
+        transferFee,
+        policy,
       } = params;
 
       if (name.length > 32)
@@ -219,15 +595,17 @@ export class SolanaStablecoin {
         extensions.push(ExtensionType.MintCloseAuthority);
       if (enableDefaultAccountState)
         extensions.push(ExtensionType.DefaultAccountState);
+      if (transferFee) extensions.push(ExtensionType.TransferFeeConfig);
+      if (policy?.uri) extensions.push(ExtensionType.MetadataPointer);
 
       const mintLen = getMintLen(extensions);
       const lamports = await this.connection.getMinimumBalanceForRentExemption(
         mintLen
       );
 
-      // SSS-2 Config PDA is the transfer hook program ID
+      // Hook config is keyed by mint, so one authority can run several stablecoins
       const [configPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("config"), authority.publicKey.toBuffer()],
+        [Buffer.from("hook_config"), mintKeypair.publicKey.toBuffer()],
         this.hookProgram.programId
       );
 
@@ -271,7 +649,7 @@ export class SolanaStablecoin {
         tx.add(
           createInitializePermanentDelegateInstruction(
             mintKeypair.publicKey,
-            authority.publicKey,
+            this.getPermanentDelegatePDA(mintKeypair.publicKey),
             TOKEN_2022_PROGRAM_ID
           )
         );
@@ -280,8 +658,22 @@ export class SolanaStablecoin {
       if (enableMintCloseAuthority) {
         tx.add(
           createInitializeMintCloseAuthorityInstruction(
+            mintKeypair.publicKey,
+            this.getCloseAuthorityPDA(stablecoin),
+            TOKEN_2022_PROGRAM_ID
+          )
+        );
+      }
+
+      if (transferFee) {
+        // Fees are withheld by Token-2022 and harvested into the treasury
+        tx.add(
+          createInitializeTransferFeeConfigInstruction(
             mintKeypair.publicKey,
             authority.publicKey,
+            this.getTreasuryAuthorityPDA(stablecoin),
+            transferFee.basisPoints,
+            transferFee.maxFee,
             TOKEN_2022_PROGRAM_ID
           )
         );
@@ -298,6 +690,18 @@ export class SolanaStablecoin {
         );
       }
 
+      if (policy?.uri) {
+        // Metadata lives in the mint itself; initialize_v2 writes it
+        tx.add(
+          createInitializeMetadataPointerInstruction(
+            mintKeypair.publicKey,
+            authority.publicKey,
+            mintKeypair.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          )
+        );
+      }
+
       tx.add(
         createInitializeMintInstruction(
           mintKeypair.publicKey,
@@ -308,26 +712,59 @@ export class SolanaStablecoin {
         )
       );
 
+      // The new stablecoin is appended to the issuer's registry
+      const { issuerRegistry, registryEntry } = await issuerRegistryAccounts(
+        this.program,
+        authority.publicKey
+      );
+
       // Now call the anchor program to initialize state
-      // @ts-ignore
-      const initIx = await this.program.methods
-        .initialize(
-          name,
-          symbol,
-          decimals,
-          enableTransferHook,
-          enablePermanentDelegate
-        )
-        .accounts({
-          authority: authority.publicKey,
-          stablecoinState: stablecoin,
-          masterRole: masterRole,
-          mint: mintKeypair.publicKey,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          systemProgram: web3.SystemProgram.programId,
-          rent: web3.SYSVAR_RENT_PUBKEY,
-        })
-        .instruction();
+      const initAccounts = {
+        authority: authority.publicKey,
+        stablecoinState: stablecoin,
+        masterRole: masterRole,
+        mint: mintKeypair.publicKey,
+        issuerRegistry,
+        registryEntry,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: web3.SystemProgram.programId,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+      };
+      const initIx = policy
+        ? // @ts-ignore
+          await this.program.methods
+            .initializeV2({
+              name,
+              symbol,
+              decimals,
+              enableTransferHook,
+              enablePermanentDelegate,
+              supplyCap: policy.supplyCap ?? new BN(0),
+              epochQuota: policy.epochQuota ?? new BN(0),
+              epochMode: { [policy.epochMode ?? "unixSeconds"]: {} },
+              epochLength: policy.epochLength ?? new BN(0),
+              startPaused: policy.startPaused ?? false,
+              uri: policy.uri ?? "",
+              transferHookProgram: enableTransferHook
+                ? this.hookProgram.programId
+                : null,
+            })
+            .accounts({
+              ...initAccounts,
+              mintAuthority: policy.uri ? mintAuthorityPDA : null,
+            })
+            .instruction()
+        : // @ts-ignore
+          await this.program.methods
+            .initialize(
+              name,
+              symbol,
+              decimals,
+              enableTransferHook,
+              enablePermanentDelegate
+            )
+            .accounts(initAccounts)
+            .instruction();
 
       tx.add(initIx);
 
@@ -555,9 +992,445 @@ export class SolanaStablecoin {
   }
 
   /**
-   * Pause all operations
+   * Thaw a default-frozen token account whose owner is whitelisted or
+   * KYC verified in the transfer hook (permissionless)
    */
-  async pause(params: {
+  async onboard(params: {
+    stablecoin: PublicKey;
+    caller: Keypair;
+    tokenAccount: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    return this.manageOnboarding({ ...params, offboard: false });
+  }
+
+  /**
+   * Re-freeze a token account whose owner's whitelist entry and KYC record
+   * have lapsed (permissionless)
+   */
+  async offboard(params: {
+    stablecoin: PublicKey;
+    caller: Keypair;
+    tokenAccount: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    return this.manageOnboarding({ ...params, offboard: true });
+  }
+
+  private async manageOnboarding(params: {
+    stablecoin: PublicKey;
+    caller: Keypair;
+    tokenAccount: PublicKey;
+    offboard: boolean;
+  }): Promise<SDKResult<{ signature: string }>> {
+    try {
+      const { stablecoin, caller, tokenAccount, offboard } = params;
+
+      // @ts-ignore
+      const state = await this.program.account.stablecoinState.fetch(
+        stablecoin
+      );
+      const mint = state.mint;
+      const { owner } = await getAccount(
+        this.connection,
+        tokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const hookProgramId = this.hookProgram.programId;
+      const [hookConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hook_config"), mint.toBuffer()],
+        hookProgramId
+      );
+      const [whitelistEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("whitelist"), hookConfig.toBuffer(), owner.toBuffer()],
+        hookProgramId
+      );
+      const [kycRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("kyc"), hookConfig.toBuffer(), owner.toBuffer()],
+        hookProgramId
+      );
+
+      const method = offboard
+        // @ts-ignore
+        ? this.program.methods.offboardAccount()
+        // @ts-ignore
+        : this.program.methods.onboardAccount();
+      const tx = await method
+        .accounts({
+          caller: caller.publicKey,
+          stablecoinState: stablecoin,
+          mint: mint,
+          tokenAccount: tokenAccount,
+          hookConfig: hookConfig,
+          whitelistEntry: whitelistEntry,
+          kycRecord: kycRecord,
+          freezeAuthority: this.getFreezeAuthorityPDA(stablecoin),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([caller])
+        .rpc();
+
+      return {
+        success: true,
+        signature: tx,
+      };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Resolve the transfer hook extra accounts for a transfer the program makes
+   * on the caller's behalf, to pass as remaining accounts
+   */
+  private async getTransferHookAccounts(
+    source: PublicKey,
+    mint: PublicKey,
+    destination: PublicKey,
+    authority: PublicKey,
+    decimals: number
+  ): Promise<web3.AccountMeta[]> {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      this.connection,
+      source,
+      mint,
+      destination,
+      authority,
+      BigInt(0),
+      decimals,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    // source, mint, destination, authority, then the hook's extras
+    return ix.keys.slice(4).map((key) => ({ ...key, isSigner: false }));
+  }
+
+  /**
+   * Claim a market maker's settled fee rebates from the treasury
+   */
+  async claimRebate(params: {
+    stablecoin: PublicKey;
+    owner: Keypair;
+    destination: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    try {
+      const { stablecoin, owner, destination } = params;
+
+      // @ts-ignore
+      const state = await this.program.account.stablecoinState.fetch(
+        stablecoin
+      );
+      const mint = state.mint;
+      const [treasury] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), stablecoin.toBuffer()],
+        this.program.programId
+      );
+      // @ts-ignore
+      const treasuryState = await this.program.account.treasury.fetch(treasury);
+
+      const hookProgramId = this.hookProgram.programId;
+      const [hookConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hook_config"), mint.toBuffer()],
+        hookProgramId
+      );
+      const [rebate] = PublicKey.findProgramAddressSync(
+        [Buffer.from("rebate"), hookConfig.toBuffer(), owner.publicKey.toBuffer()],
+        hookProgramId
+      );
+      const treasuryAuthority = this.getTreasuryAuthorityPDA(stablecoin);
+      const hookAccounts = await this.getTransferHookAccounts(
+        treasuryState.tokenAccount,
+        mint,
+        destination,
+        treasuryAuthority,
+        state.decimals
+      );
+
+      const tx = await this.program.methods
+        // @ts-ignore
+        .claimRebate()
+        .accounts({
+          owner: owner.publicKey,
+          stablecoinState: stablecoin,
+          hookConfig: hookConfig,
+          rebate: rebate,
+          rebateClaim: this.getRebateClaimPDA(stablecoin, owner.publicKey),
+          treasury: treasury,
+          treasuryAuthority: treasuryAuthority,
+          treasuryTokenAccount: treasuryState.tokenAccount,
+          destination: destination,
+          mint: mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(hookAccounts)
+        .signers([owner])
+        .rpc();
+
+      return {
+        success: true,
+        signature: tx,
+      };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Claim a partner's settled referral fees from the treasury
+   */
+  async claimReferralFees(params: {
+    stablecoin: PublicKey;
+    referrer: Keypair;
+    destination: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    try {
+      const { stablecoin, referrer, destination } = params;
+
+      // @ts-ignore
+      const state = await this.program.account.stablecoinState.fetch(
+        stablecoin
+      );
+      const mint = state.mint;
+      const [treasury] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), stablecoin.toBuffer()],
+        this.program.programId
+      );
+      // @ts-ignore
+      const treasuryState = await this.program.account.treasury.fetch(treasury);
+
+      const hookProgramId = this.hookProgram.programId;
+      const [hookConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hook_config"), mint.toBuffer()],
+        hookProgramId
+      );
+      const [referrerAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("referrer"), hookConfig.toBuffer(), referrer.publicKey.toBuffer()],
+        hookProgramId
+      );
+      const treasuryAuthority = this.getTreasuryAuthorityPDA(stablecoin);
+      const hookAccounts = await this.getTransferHookAccounts(
+        treasuryState.tokenAccount,
+        mint,
+        destination,
+        treasuryAuthority,
+        state.decimals
+      );
+
+      const tx = await this.program.methods
+        // @ts-ignore
+        .claimReferralFees()
+        .accounts({
+          referrer: referrer.publicKey,
+          stablecoinState: stablecoin,
+          hookConfig: hookConfig,
+          referrerAccount: referrerAccount,
+          referralClaim: this.getReferralClaimPDA(stablecoin, referrer.publicKey),
+          treasury: treasury,
+          treasuryAuthority: treasuryAuthority,
+          treasuryTokenAccount: treasuryState.tokenAccount,
+          destination: destination,
+          mint: mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(hookAccounts)
+        .signers([referrer])
+        .rpc();
+
+      return {
+        success: true,
+        signature: tx,
+      };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Claim the withheld fees the hook credited back to an owner from the treasury
+   */
+  async claimFeeCredit(params: {
+    stablecoin: PublicKey;
+    owner: Keypair;
+    destination: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    try {
+      const { stablecoin, owner, destination } = params;
+
+      // @ts-ignore
+      const state = await this.program.account.stablecoinState.fetch(
+        stablecoin
+      );
+      const mint = state.mint;
+      const [treasury] = PublicKey.findProgramAddressSync(
+        [Buffer.from("treasury"), stablecoin.toBuffer()],
+        this.program.programId
+      );
+      // @ts-ignore
+      const treasuryState = await this.program.account.treasury.fetch(treasury);
+
+      const hookProgramId = this.hookProgram.programId;
+      const [hookConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hook_config"), mint.toBuffer()],
+        hookProgramId
+      );
+      const [feeCredit] = PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_credit"), hookConfig.toBuffer(), owner.publicKey.toBuffer()],
+        hookProgramId
+      );
+      const treasuryAuthority = this.getTreasuryAuthorityPDA(stablecoin);
+      const hookAccounts = await this.getTransferHookAccounts(
+        treasuryState.tokenAccount,
+        mint,
+        destination,
+        treasuryAuthority,
+        state.decimals
+      );
+
+      const tx = await this.program.methods
+        // @ts-ignore
+        .claimFeeCredit()
+        .accounts({
+          owner: owner.publicKey,
+          stablecoinState: stablecoin,
+          hookConfig: hookConfig,
+          feeCredit: feeCredit,
+          feeCreditClaim: this.getFeeCreditClaimPDA(stablecoin, owner.publicKey),
+          treasury: treasury,
+          treasuryAuthority: treasuryAuthority,
+          treasuryTokenAccount: treasuryState.tokenAccount,
+          destination: destination,
+          mint: mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .remainingAccounts(hookAccounts)
+        .signers([owner])
+        .rpc();
+
+      return {
+        success: true,
+        signature: tx,
+      };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Execute an approved seizure case: burn it for destroy cases, otherwise
+   * move it to the treasury, a restitution account or the exceptional destination
+   */
+  async seizeTokens(params: {
+    stablecoin: PublicKey;
+    seizer: Keypair;
+    seizureCase: PublicKey;
+    destination?: PublicKey;
+    restitutionEntry?: PublicKey;
+  }): Promise<SDKResult<{ signature: string }>> {
+    try {
+      const { stablecoin, seizer, seizureCase, destination, restitutionEntry } =
+        params;
+
+      // @ts-ignore
+      const state = await this.program.account.stablecoinState.fetch(
+        stablecoin
+      );
+      const mint = state.mint;
+      // @ts-ignore
+      const caseState = await this.hookProgram.account.seizureCase.fetch(
+        seizureCase
+      );
+      const hookProgramId = this.hookProgram.programId;
+      const [hookConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("hook_config"), mint.toBuffer()],
+        hookProgramId
+      );
+      const [seizerRole] = PublicKey.findProgramAddressSync(
+        [Buffer.from("role"), seizer.publicKey.toBuffer(), mint.toBuffer()],
+        this.program.programId
+      );
+      const permanentDelegate = this.getPermanentDelegatePDA(mint);
+
+      // A disputed case settles its clawback hold too
+      let clawbackHold: PublicKey | null = null;
+      let clawbackInitiator: PublicKey | null = null;
+      if (caseState.status.disputed) {
+        [clawbackHold] = PublicKey.findProgramAddressSync(
+          [Buffer.from("clawback"), hookConfig.toBuffer(), caseState.target.toBuffer()],
+          hookProgramId
+        );
+        // @ts-ignore
+        const hold = await this.hookProgram.account.clawbackHold.fetch(
+          clawbackHold
+        );
+        clawbackInitiator = hold.initiatedBy;
+      }
+
+      const hookAccounts =
+        caseState.destroy || !destination
+          ? []
+          : await this.getTransferHookAccounts(
+              caseState.target,
+              mint,
+              destination,
+              permanentDelegate,
+              state.decimals
+            );
+
+      const tx = await this.program.methods
+        // @ts-ignore
+        .seizeTokens()
+        .accounts({
+          seizer: seizer.publicKey,
+          stablecoinState: stablecoin,
+          seizerRole: seizerRole,
+          mint: mint,
+          sourceAccount: caseState.target,
+          destination: destination ?? null,
+          restitutionEntry: restitutionEntry ?? null,
+          permanentDelegate: permanentDelegate,
+          hookConfig: hookConfig,
+          seizureCase: seizureCase,
+          clawbackHold: clawbackHold,
+          clawbackInitiator: clawbackInitiator,
+          hookProgram: hookProgramId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(hookAccounts)
+        .signers([seizer])
+        .rpc();
+
+      return {
+        success: true,
+        signature: tx,
+      };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Pause all operations
+   */
+  async pause(params: {
     stablecoin: PublicKey;
     pauser: Keypair;
   }): Promise<SDKResult<{ signature: string }>> {
@@ -692,7 +1565,7 @@ export class SolanaStablecoin {
 
       return {
         success: true,
-        data: state as unknown as StablecoinState,
+        data: decodeStablecoinState(state),
       };
     } catch (error: any) {
       return {
@@ -722,6 +1595,121 @@ export class SolanaStablecoin {
     }
   }
 
+  /**
+   * Create an address lookup table owned by the stablecoin's registry (master only)
+   */
+  async createLookupTable(params: {
+    mint: PublicKey;
+    authority: Keypair;
+  }): Promise<SDKResult<{ signature: string; table: PublicKey }>> {
+    try {
+      const stablecoin = this.getStablecoinPDA(params.mint);
+      const registry = this.getLookupTableRegistryPDA(stablecoin);
+      const recentSlot = await this.connection.getSlot("finalized");
+      const [table] = PublicKey.findProgramAddressSync(
+        [registry.toBuffer(), new BN(recentSlot).toArrayLike(Buffer, "le", 8)],
+        AddressLookupTableProgram.programId
+      );
+
+      const tx = await this.program.methods
+        .createLookupTable(new BN(recentSlot))
+        .accounts({
+          authority: params.authority.publicKey,
+          stablecoinState: stablecoin,
+          authorityRole: this.getRolePDA(params.authority.publicKey, params.mint),
+          lookupRegistry: registry,
+          lookupTable: table,
+          addressLookupTableProgram: AddressLookupTableProgram.programId,
+        })
+        .signers([params.authority])
+        .rpc();
+
+      return { success: true, signature: tx, data: { signature: tx, table } };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Add addresses to a registered lookup table, 30 per transaction (master only)
+   */
+  async extendLookupTable(params: {
+    mint: PublicKey;
+    authority: Keypair;
+    table: PublicKey;
+    addresses: PublicKey[];
+  }): Promise<SDKResult<{ signatures: string[] }>> {
+    try {
+      const stablecoin = this.getStablecoinPDA(params.mint);
+      const signatures: string[] = [];
+      for (let i = 0; i < params.addresses.length; i += 30) {
+        const tx = await this.program.methods
+          .extendLookupTable(params.addresses.slice(i, i + 30))
+          .accounts({
+            authority: params.authority.publicKey,
+            stablecoinState: stablecoin,
+            authorityRole: this.getRolePDA(params.authority.publicKey, params.mint),
+            lookupRegistry: this.getLookupTableRegistryPDA(stablecoin),
+            lookupTable: params.table,
+            addressLookupTableProgram: AddressLookupTableProgram.programId,
+          })
+          .signers([params.authority])
+          .rpc();
+        signatures.push(tx);
+      }
+
+      return { success: true, data: { signatures } };
+    } catch (error: any) {
+      return {
+        success: false,
+        error: error.message || error.toString(),
+      };
+    }
+  }
+
+  /**
+   * Fetch the lookup tables registered for a stablecoin
+   */
+  async getLookupTables(mint: PublicKey): Promise<AddressLookupTableAccount[]> {
+    const registry = this.getLookupTableRegistryPDA(this.getStablecoinPDA(mint));
+    // @ts-ignore
+    const state = await this.program.account.lookupTableRegistry.fetchNullable(
+      registry
+    );
+    if (!state) {
+      return [];
+    }
+    const tables = await Promise.all(
+      (state.tables as PublicKey[]).map((table) =>
+        this.connection.getAddressLookupTable(table)
+      )
+    );
+    return tables
+      .map((table) => table.value)
+      .filter((table): table is AddressLookupTableAccount => table !== null);
+  }
+
+  /**
+   * Pack instructions into a v0 transaction that resolves accounts through
+   * the given lookup tables. The caller signs and sends it.
+   */
+  async buildBatchTransaction(
+    payer: PublicKey,
+    instructions: TransactionInstruction[],
+    lookupTables: AddressLookupTableAccount[]
+  ): Promise<VersionedTransaction> {
+    const { blockhash } = await this.connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+      payerKey: payer,
+      recentBlockhash: blockhash,
+      instructions,
+    }).compileToV0Message(lookupTables);
+    return new VersionedTransaction(message);
+  }
+
   /**
    * Batch mint tokens to multiple recipients
    */
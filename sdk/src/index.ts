@@ -25,6 +25,10 @@
 // Core SDK
 export { SolanaStablecoin } from "./SolanaStablecoin";
 
+// SSS-2 Compliance Module
+export { ComplianceModule } from "./ComplianceModule";
+export type { ReasonCode } from "./ComplianceModule";
+
 // SSS-3 Privacy Module
 export { PrivacyModule, generateElGamalKeypair } from "./PrivacyModule";
 
@@ -168,6 +172,10 @@ export {
   decodeRoles,
   encodeRoles,
 
+  // State helpers
+  decodeStablecoinState,
+  issuerRegistryAccounts,
+
   // Feature helpers
   hasFeature,
   decodeFeatures,
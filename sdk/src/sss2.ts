@@ -11,9 +11,9 @@ import {
 import { BN, AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
 import type { SSS2HookConfig, SDKResult, FeeCalculation } from "./types";
 
-// SSS-2 Program ID (Devnet)
+// SSS-2 transfer hook program ID (sss-transfer-hook, Devnet)
 export const SSS2_PROGRAM_ID = new PublicKey(
-  "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+  "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
 );
 
 // Instruction discriminators (Anchor: sha256("global:instruction_name")[:8])
@@ -39,21 +39,38 @@ export class SSS2Hook {
   private connection: Connection;
   private payer: Keypair;
   private programId: PublicKey;
+  private mint?: PublicKey;
 
   constructor(
     connection: Connection,
     payer: Keypair,
-    programId = SSS2_PROGRAM_ID
+    programId = SSS2_PROGRAM_ID,
+    mint?: PublicKey
   ) {
     this.connection = connection;
     this.payer = payer;
     this.programId = programId;
+    this.mint = mint;
   }
 
   /**
-   * Get config PDA for the hook
+   * Get config PDA for the hook.
+   * Keyed by mint when one is set, otherwise falls back to the legacy
+   * per-authority seeds.
    */
   getConfigPDA(): PublicKey {
+    if (!this.mint) return this.getLegacyConfigPDA();
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("hook_config"), this.mint.toBuffer()],
+      this.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Get legacy config PDA (`["config", authority]`), used for migration
+   */
+  getLegacyConfigPDA(): PublicKey {
     const [pda] = PublicKey.findProgramAddressSync(
       [Buffer.from("config"), this.payer.publicKey.toBuffer()],
       this.programId
@@ -61,6 +78,16 @@ export class SSS2Hook {
     return pda;
   }
 
+  /**
+   * Seed prefix shared by list PDAs: the config when keyed by mint,
+   * the authority for legacy configs
+   */
+  private getListSeed(): Buffer {
+    return this.mint
+      ? this.getConfigPDA().toBuffer()
+      : this.payer.publicKey.toBuffer();
+  }
+
   /**
    * Get whitelist PDA for an address
    */
@@ -68,7 +95,7 @@ export class SSS2Hook {
     const [pda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("whitelist"),
-        this.payer.publicKey.toBuffer(),
+        this.getListSeed(),
         address.toBuffer(),
       ],
       this.programId
@@ -83,7 +110,7 @@ export class SSS2Hook {
     const [pda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("blacklist"),
-        this.payer.publicKey.toBuffer(),
+        this.getListSeed(),
         address.toBuffer(),
       ],
       this.programId
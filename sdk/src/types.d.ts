@@ -7,7 +7,7 @@ import { BN } from "@coral-xyz/anchor";
 export declare const SSS_TOKEN_PROGRAM_ID: PublicKey;
 /**
  * SSS Transfer Hook program ID
- * @default "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+ * @default "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
  */
 export declare const SSS_TRANSFER_HOOK_PROGRAM_ID: PublicKey;
 /** Full control - can do everything */
@@ -342,7 +342,13 @@ export declare enum StablecoinError {
     AlreadyInitialized = 6008,
     InsufficientBalance = 6009,
     SupplyCapExceeded = 6010,
-    EpochQuotaExceeded = 6011
+    EpochQuotaExceeded = 6011,
+    NameTooLong = 6012,
+    SymbolTooLong = 6013,
+    ProposalExpired = 6028,
+    AlreadyExecuted = 6029,
+    ThresholdNotMet = 6030,
+    AlreadyApproved = 6075
 }
 /** Error codes from Transfer Hook program */
 export declare enum TransferHookError {
@@ -357,7 +363,12 @@ export declare enum TransferHookError {
     ComplianceNotEnabled = 8008,
     InvalidInstruction = 8009,
     MathOverflow = 8010,
-    SelfSeizure = 8011
+    SelfSeizure = 8011,
+    ProposalExpired = 8075,
+    ThresholdNotMet = 8076,
+    FeeTooHigh = 8079,
+    InvalidMaxFee = 8080,
+    MinTransferTooHigh = 8081
 }
 /** Decode error code to message */
 export declare function decodeError(code: number): string;
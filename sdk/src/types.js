@@ -20,9 +20,9 @@ const anchor_1 = require("@coral-xyz/anchor");
 exports.SSS_TOKEN_PROGRAM_ID = new web3_js_1.PublicKey("b3AxhgSuNvjsv2F4XmuXYJbBCRcTT1XPXQvRe77NbrK");
 /**
  * SSS Transfer Hook program ID
- * @default "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+ * @default "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
  */
-exports.SSS_TRANSFER_HOOK_PROGRAM_ID = new web3_js_1.PublicKey("97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx");
+exports.SSS_TRANSFER_HOOK_PROGRAM_ID = new web3_js_1.PublicKey("By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB");
 // ============================================
 // ROLE CONSTANTS
 // ============================================
@@ -82,6 +82,12 @@ var StablecoinError;
     StablecoinError[StablecoinError["InsufficientBalance"] = 6009] = "InsufficientBalance";
     StablecoinError[StablecoinError["SupplyCapExceeded"] = 6010] = "SupplyCapExceeded";
     StablecoinError[StablecoinError["EpochQuotaExceeded"] = 6011] = "EpochQuotaExceeded";
+    StablecoinError[StablecoinError["NameTooLong"] = 6012] = "NameTooLong";
+    StablecoinError[StablecoinError["SymbolTooLong"] = 6013] = "SymbolTooLong";
+    StablecoinError[StablecoinError["ProposalExpired"] = 6028] = "ProposalExpired";
+    StablecoinError[StablecoinError["AlreadyExecuted"] = 6029] = "AlreadyExecuted";
+    StablecoinError[StablecoinError["ThresholdNotMet"] = 6030] = "ThresholdNotMet";
+    StablecoinError[StablecoinError["AlreadyApproved"] = 6075] = "AlreadyApproved";
 })(StablecoinError || (exports.StablecoinError = StablecoinError = {}));
 /** Error codes from Transfer Hook program */
 var TransferHookError;
@@ -98,6 +104,11 @@ var TransferHookError;
     TransferHookError[TransferHookError["InvalidInstruction"] = 8009] = "InvalidInstruction";
     TransferHookError[TransferHookError["MathOverflow"] = 8010] = "MathOverflow";
     TransferHookError[TransferHookError["SelfSeizure"] = 8011] = "SelfSeizure";
+    TransferHookError[TransferHookError["ProposalExpired"] = 8075] = "ProposalExpired";
+    TransferHookError[TransferHookError["ThresholdNotMet"] = 8076] = "ThresholdNotMet";
+    TransferHookError[TransferHookError["FeeTooHigh"] = 8079] = "FeeTooHigh";
+    TransferHookError[TransferHookError["InvalidMaxFee"] = 8080] = "InvalidMaxFee";
+    TransferHookError[TransferHookError["MinTransferTooHigh"] = 8081] = "MinTransferTooHigh";
 })(TransferHookError || (exports.TransferHookError = TransferHookError = {}));
 /** Decode error code to message */
 function decodeError(code) {
@@ -114,6 +125,12 @@ function decodeError(code) {
         [StablecoinError.InsufficientBalance]: "Insufficient balance",
         [StablecoinError.SupplyCapExceeded]: "Supply cap exceeded",
         [StablecoinError.EpochQuotaExceeded]: "Epoch quota exceeded",
+        [StablecoinError.NameTooLong]: "Token name too long (max 32 chars)",
+        [StablecoinError.SymbolTooLong]: "Token symbol too long (max 10 chars)",
+        [StablecoinError.ProposalExpired]: "Proposal has expired",
+        [StablecoinError.AlreadyExecuted]: "Proposal already executed",
+        [StablecoinError.ThresholdNotMet]: "Approval threshold not met",
+        [StablecoinError.AlreadyApproved]: "Signer already approved this proposal",
         [TransferHookError.HookPaused]: "Transfer hook paused",
         [TransferHookError.SourceBlacklisted]: "Source address blacklisted",
         [TransferHookError.DestinationBlacklisted]: "Destination address blacklisted",
@@ -124,6 +141,11 @@ function decodeError(code) {
         [TransferHookError.AlreadyWhitelisted]: "Address already whitelisted",
         [TransferHookError.ComplianceNotEnabled]: "Compliance not enabled",
         [TransferHookError.SelfSeizure]: "Cannot seize from self",
+        [TransferHookError.ProposalExpired]: "Multisig proposal has expired",
+        [TransferHookError.ThresholdNotMet]: "Multisig proposal has not reached its approval threshold",
+        [TransferHookError.FeeTooHigh]: "Fee exceeds the 10% cap",
+        [TransferHookError.InvalidMaxFee]: "Max transfer fee must be nonzero while a fee rate is set",
+        [TransferHookError.MinTransferTooHigh]: "Minimum transfer amount is too high",
     };
     return errors[code] || `Unknown error: ${code}`;
 }
@@ -21,10 +21,10 @@ export const SSS_TOKEN_PROGRAM_ID = new PublicKey(
 
 /**
  * SSS Transfer Hook program ID
- * @default "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+ * @default "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
  */
 export const SSS_TRANSFER_HOOK_PROGRAM_ID = new PublicKey(
-  "97WYcUSr6Y9YaDTM55PJYuAXpLL552HS6WXxVBmxAGmx"
+  "By3BWwxkz7uFMRw1bD63VUnVMysMh79A3A6D58cHaXmB"
 );
 
 // ============================================
@@ -49,6 +49,9 @@ export const ROLE_BLACKLISTER = 16;
 /** Can seize tokens (SSS-2) */
 export const ROLE_SEIZER = 32;
 
+/** Can tag addresses with counterparty categories (SSS-2) */
+export const ROLE_LABELER = 128;
+
 /** Human-readable role names */
 export const ROLE_NAMES: Record<number, string> = {
   1: "MASTER",
@@ -57,6 +60,7 @@ export const ROLE_NAMES: Record<number, string> = {
   8: "PAUSER",
   16: "BLACKLISTER",
   32: "SEIZER",
+  128: "LABELER",
 };
 
 // ============================================
@@ -476,6 +480,12 @@ export enum StablecoinError {
   InsufficientBalance = 6009,
   SupplyCapExceeded = 6010,
   EpochQuotaExceeded = 6011,
+  NameTooLong = 6012,
+  SymbolTooLong = 6013,
+  ProposalExpired = 6028,
+  AlreadyExecuted = 6029,
+  ThresholdNotMet = 6030,
+  AlreadyApproved = 6075,
 }
 
 /** Error codes from Transfer Hook program */
@@ -492,6 +502,11 @@ export enum TransferHookError {
   InvalidInstruction = 8009,
   MathOverflow = 8010,
   SelfSeizure = 8011,
+  ProposalExpired = 8075,
+  ThresholdNotMet = 8076,
+  FeeTooHigh = 8079,
+  InvalidMaxFee = 8080,
+  MinTransferTooHigh = 8081,
 }
 
 /** Decode error code to message */
@@ -510,6 +525,12 @@ export function decodeError(code: number): string {
     [StablecoinError.InsufficientBalance]: "Insufficient balance",
     [StablecoinError.SupplyCapExceeded]: "Supply cap exceeded",
     [StablecoinError.EpochQuotaExceeded]: "Epoch quota exceeded",
+    [StablecoinError.NameTooLong]: "Token name too long (max 32 chars)",
+    [StablecoinError.SymbolTooLong]: "Token symbol too long (max 10 chars)",
+    [StablecoinError.ProposalExpired]: "Proposal has expired",
+    [StablecoinError.AlreadyExecuted]: "Proposal already executed",
+    [StablecoinError.ThresholdNotMet]: "Approval threshold not met",
+    [StablecoinError.AlreadyApproved]: "Signer already approved this proposal",
     [TransferHookError.HookPaused]: "Transfer hook paused",
     [TransferHookError.SourceBlacklisted]: "Source address blacklisted",
     [TransferHookError.DestinationBlacklisted]:
@@ -521,6 +542,11 @@ export function decodeError(code: number): string {
     [TransferHookError.AlreadyWhitelisted]: "Address already whitelisted",
     [TransferHookError.ComplianceNotEnabled]: "Compliance not enabled",
     [TransferHookError.SelfSeizure]: "Cannot seize from self",
+    [TransferHookError.ProposalExpired]: "Multisig proposal has expired",
+    [TransferHookError.ThresholdNotMet]: "Multisig proposal has not reached its approval threshold",
+    [TransferHookError.FeeTooHigh]: "Fee exceeds the 10% cap",
+    [TransferHookError.InvalidMaxFee]: "Max transfer fee must be nonzero while a fee rate is set",
+    [TransferHookError.MinTransferTooHigh]: "Minimum transfer amount is too high",
   };
 
   return errors[code] || `Unknown error: ${code}`;
@@ -5,7 +5,7 @@
  */
 
 import { PublicKey, Connection, Commitment } from "@solana/web3.js";
-import { BN } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import {
   SSS_TOKEN_PROGRAM_ID,
   SSS_TRANSFER_HOOK_PROGRAM_ID,
@@ -294,6 +294,52 @@ export function encodeRoles(roleNames: string[]): number {
   return roles;
 }
 
+// ============================================
+// STATE HELPERS
+// ============================================
+
+/**
+ * Decode a fetched StablecoinState. The account is zero-copy: name and symbol
+ * are zero-padded bytes and flags are 0/1 bytes.
+ */
+export function decodeStablecoinState(raw: any): StablecoinState {
+  const text = (bytes: number[]) =>
+    Buffer.from(bytes).toString("utf8").replace(/\0+$/, "");
+  return {
+    ...raw,
+    name: text(raw.name),
+    symbol: text(raw.symbol),
+    isPaused: raw.isPaused !== 0,
+  };
+}
+
+/**
+ * Issuer registry and the entry PDA the next `initialize` by `authority`
+ * appends, as passed to initialize and initialize_v2
+ */
+export async function issuerRegistryAccounts(
+  program: Program<any>,
+  authority: PublicKey
+): Promise<{ issuerRegistry: PublicKey; registryEntry: PublicKey }> {
+  const [issuerRegistry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("issuer_registry"), authority.toBuffer()],
+    program.programId
+  );
+  // @ts-ignore
+  const registry = await program.account.issuerRegistry.fetchNullable(
+    issuerRegistry
+  );
+  const [registryEntry] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("issuer_registry_entry"),
+      issuerRegistry.toBuffer(),
+      (registry ? (registry.count as BN) : new BN(0)).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+  return { issuerRegistry, registryEntry };
+}
+
 // ============================================
 // FEATURE HELPERS
 // ============================================
@@ -0,0 +1,221 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import * as path from "path";
+import {
+  PublicKey,
+  SystemProgram,
+  Keypair,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMintInstruction,
+  createInitializeTransferHookInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { issuerRegistryAccounts } from "../sdk/src/utils";
+
+// Compute units of a mint and of a hooked transfer, for comparing builds.
+// CU_IDL_DIR points the test at another build's IDLs, so
+// scripts/measure-cu.sh can run it unchanged against an older revision.
+describe("Compute Units", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const idlDir = path.resolve(process.env.CU_IDL_DIR ?? "target/idl");
+  const tokenProgram = new Program(
+    require(path.join(idlDir, "sss_token.json")),
+    provider
+  );
+  const hookProgram = new Program(
+    require(path.join(idlDir, "sss_transfer_hook.json")),
+    provider
+  );
+
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const mint = Keypair.generate();
+  const holder = Keypair.generate();
+  const recipient = Keypair.generate();
+  const decimals = 6;
+  const results: Record<string, number | null> = {};
+
+  const pda = (seeds: Buffer[], programId: PublicKey) =>
+    PublicKey.findProgramAddressSync(seeds, programId)[0];
+  const ata = (owner: PublicKey) =>
+    getAssociatedTokenAddressSync(
+      mint.publicKey,
+      owner,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+  let stablecoinState: PublicKey;
+
+  // Units the whole transaction consumed, from a simulation
+  const simulateUnits = async (tx: Transaction, signers: Keypair[]) => {
+    tx.feePayer = payer.publicKey;
+    tx.recentBlockhash = (
+      await provider.connection.getLatestBlockhash()
+    ).blockhash;
+    tx.sign(...signers);
+    const simulation = await provider.connection.simulateTransaction(tx);
+    assert.isNull(simulation.value.err, JSON.stringify(simulation.value.logs));
+    return simulation.value;
+  };
+
+  before(async () => {
+    stablecoinState = pda(
+      [Buffer.from("stablecoin"), mint.publicKey.toBuffer()],
+      tokenProgram.programId
+    );
+    const config = pda(
+      [Buffer.from("hook_config"), mint.publicKey.toBuffer()],
+      hookProgram.programId
+    );
+
+    const mintLen = getMintLen([ExtensionType.TransferHook]);
+    await sendAndConfirmTransaction(
+      provider.connection,
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space: mintLen,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(
+            mintLen
+          ),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferHookInstruction(
+          mint.publicKey,
+          payer.publicKey,
+          hookProgram.programId,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          mint.publicKey,
+          decimals,
+          pda([Buffer.from("mint_authority"), stablecoinState.toBuffer()], tokenProgram.programId),
+          pda([Buffer.from("freeze_authority"), stablecoinState.toBuffer()], tokenProgram.programId),
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [payer, mint]
+    );
+
+    await tokenProgram.methods
+      .initialize("Compute USD", "CUSD", decimals, true, false)
+      .accounts({
+        authority: payer.publicKey,
+        stablecoinState,
+        mint: mint.publicKey,
+        ...(await issuerRegistryAccounts(tokenProgram, payer.publicKey)),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+    // Blacklist on, as for an SSS-2 stablecoin
+    await hookProgram.methods
+      .initialize(0, new anchor.BN(0), new anchor.BN(0), true)
+      .accounts({
+        authority: payer.publicKey,
+        stablecoin: mint.publicKey,
+        stablecoinState,
+        config,
+      })
+      .rpc();
+    await hookProgram.methods
+      .initializeExtraAccountMetaList()
+      .accounts({ payer: payer.publicKey, mint: mint.publicKey })
+      .rpc();
+
+    await sendAndConfirmTransaction(
+      provider.connection,
+      new Transaction().add(
+        ...[holder, recipient].map((owner) =>
+          createAssociatedTokenAccountIdempotentInstruction(
+            payer.publicKey,
+            ata(owner.publicKey),
+            owner.publicKey,
+            mint.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      ),
+      [payer]
+    );
+  });
+
+  after(() => {
+    console.log("CU", JSON.stringify(results));
+  });
+
+  it("Should measure a mint", async () => {
+    const tx = await tokenProgram.methods
+      .mint(new anchor.BN(1_000_000))
+      .accounts({
+        minter: payer.publicKey,
+        stablecoinState,
+        mint: mint.publicKey,
+        recipientAccount: ata(holder.publicKey),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .transaction();
+    const simulation = await simulateUnits(tx, [payer]);
+    results.mint = simulation.unitsConsumed ?? null;
+    assert.isAbove(results.mint ?? 0, 0);
+
+    // Funds the transfer measured next
+    await sendAndConfirmTransaction(
+      provider.connection,
+      (await tokenProgram.methods
+        .mint(new anchor.BN(1_000_000))
+        .accounts({
+          minter: payer.publicKey,
+          stablecoinState,
+          mint: mint.publicKey,
+          recipientAccount: ata(holder.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .transaction()),
+      [payer]
+    );
+  });
+
+  it("Should measure a hooked transfer", async () => {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      ata(holder.publicKey),
+      mint.publicKey,
+      ata(recipient.publicKey),
+      holder.publicKey,
+      BigInt(100_000),
+      decimals,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    const simulation = await simulateUnits(new Transaction().add(ix), [
+      payer,
+      holder,
+    ]);
+    results.transfer = simulation.unitsConsumed ?? null;
+    assert.isAbove(results.transfer ?? 0, 0);
+
+    // The hook's own share, only reported by cu-trace builds
+    const parser = new anchor.EventParser(
+      hookProgram.programId,
+      new anchor.BorshCoder(hookProgram.idl)
+    );
+    results.hook = null;
+    for (const event of parser.parseLogs(simulation.logs ?? [])) {
+      if (event.name === "hookComputeUnits" || event.name === "HookComputeUnits") {
+        results.hook = Number(event.data.consumed);
+      }
+    }
+  });
+});
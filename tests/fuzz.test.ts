@@ -6,6 +6,7 @@ import { Program } from "@coral-xyz/anchor";
 import { SssToken } from "../target/types/sss_token";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { assert } from "chai";
+import { decodeStablecoinState, issuerRegistryAccounts } from "../sdk/src/utils";
 import * as fuzz from "../trident_tests/fuzz";
 
 describe("Fuzz Tests - SSS Token", () => {
@@ -103,6 +104,7 @@ describe("Fuzz Tests - SSS Token", () => {
               stablecoinState: testStablecoin,
               masterRole: testMasterRole,
               mint: testMint,
+              ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
               tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
               rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -110,8 +112,8 @@ describe("Fuzz Tests - SSS Token", () => {
             .rpc();
 
           // Verify state
-          const state = await program.account.stablecoinState.fetch(
-            testStablecoin
+          const state = decodeStablecoinState(
+            await program.account.stablecoinState.fetch(testStablecoin)
           );
           assert.ok(state.name === name, `Fuzz iteration ${i}: Name mismatch`);
           assert.ok(
@@ -133,7 +135,10 @@ describe("Fuzz Tests - SSS Token", () => {
           );
         } catch (e) {
           // Expected failures for edge cases
-          if (e.toString().includes("InvalidAmount")) {
+          if (
+            e.toString().includes("NameTooLong") ||
+            e.toString().includes("SymbolTooLong")
+          ) {
             // Name/symbol too long - expected
             continue;
           }
@@ -157,6 +162,7 @@ describe("Fuzz Tests - SSS Token", () => {
           stablecoinState: stablecoinPDA,
           masterRole: masterRolePDA,
           mint: mintPDA,
+          ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
           tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -240,6 +246,7 @@ describe("Fuzz Tests - SSS Token", () => {
           stablecoinState: testStablecoin,
           masterRole: testMasterRole,
           mint: testMint,
+          ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
           tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -391,6 +398,7 @@ describe("Fuzz Tests - SSS Token", () => {
           stablecoinState: testStablecoin,
           masterRole: testMasterRole,
           mint: testMint,
+          ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
           tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -15,6 +15,7 @@ import {
   getMint,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { decodeStablecoinState, issuerRegistryAccounts } from "../sdk/src/utils";
 
 describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
   const provider = anchor.AnchorProvider.env();
@@ -114,6 +115,7 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
           stablecoinState: stablecoinPDA,
           masterRole: masterRolePDA,
           mint: mintKeypair.publicKey,
+          ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -121,7 +123,9 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
         .signers([mintKeypair])
         .rpc();
 
-      const state = await program.account.stablecoinState.fetch(stablecoinPDA);
+      const state = decodeStablecoinState(
+        await program.account.stablecoinState.fetch(stablecoinPDA)
+      );
       assert.equal(state.name, name, "name should match");
       assert.equal(state.symbol, symbol, "symbol should match");
       assert.equal(state.decimals, decimals, "decimals should match");
@@ -140,6 +144,29 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
       console.log("Initialize tx:", tx);
     });
 
+    it("Should list the stablecoin in the issuer registry", async () => {
+      const [issuerRegistry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("issuer_registry"), provider.wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const registry = await program.account.issuerRegistry.fetch(
+        issuerRegistry
+      );
+      const [registryEntry] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("issuer_registry_entry"),
+          issuerRegistry.toBuffer(),
+          registry.count.subn(1).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const entry = await program.account.issuerRegistryEntry.fetch(
+        registryEntry
+      );
+      assert.ok(entry.mint.equals(mintKeypair.publicKey), "entry mint should match");
+      assert.ok(entry.stablecoin.equals(stablecoinPDA), "entry state should match");
+    });
+
     it("Should fail to initialize the same mint twice", async () => {
       try {
         await program.methods
@@ -149,6 +176,7 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
             stablecoinState: stablecoinPDA,
             masterRole: masterRolePDA,
             mint: mintKeypair.publicKey,
+            ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -427,7 +455,9 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
         .rpc();
       console.log("Pause tx:", tx);
 
-      const state = await program.account.stablecoinState.fetch(stablecoinPDA);
+      const state = decodeStablecoinState(
+        await program.account.stablecoinState.fetch(stablecoinPDA)
+      );
       assert.equal(state.isPaused, true, "should be paused");
     });
 
@@ -467,7 +497,9 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
         .rpc();
       console.log("Unpause tx:", tx);
 
-      const state = await program.account.stablecoinState.fetch(stablecoinPDA);
+      const state = decodeStablecoinState(
+        await program.account.stablecoinState.fetch(stablecoinPDA)
+      );
       assert.equal(state.isPaused, false, "should be unpaused");
     });
 
@@ -707,6 +739,7 @@ describe("SSS Token - SSS-1 (Minimal Stablecoin)", () => {
           stablecoinState: batchStablecoinPDA,
           masterRole: batchMasterRolePDA,
           mint: batchMintKeypair.publicKey,
+          ...(await issuerRegistryAccounts(program, provider.wallet.publicKey)),
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -2,13 +2,28 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SssToken } from "../target/types/sss_token";
 import { SssTransferHook } from "../target/types/sss_transfer_hook";
-import { PublicKey, SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  PublicKey,
+  SystemProgram,
+  Keypair,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferHookInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+} from "@solana/spl-token";
 import { assert } from "chai";
-
-// Token-2022 program ID
-const TOKEN_2022_PROGRAM_ID = new PublicKey(
-  "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-);
+import { decodeStablecoinState, issuerRegistryAccounts } from "../sdk/src/utils";
 
 describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
   const provider = anchor.AnchorProvider.env();
@@ -99,6 +114,7 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
           stablecoinState: stablecoinPDA,
           masterRole: masterRolePDA,
           mint: mintKeypair.publicKey,
+          ...(await issuerRegistryAccounts(tokenProgram, provider.wallet.publicKey)),
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
@@ -109,8 +125,8 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
       console.log("SSS-2 Initialize tx:", tx);
 
       // Verify state
-      const state = await tokenProgram.account.stablecoinState.fetch(
-        stablecoinPDA
+      const state = decodeStablecoinState(
+        await tokenProgram.account.stablecoinState.fetch(stablecoinPDA)
       );
       assert.equal(state.name, name);
       assert.equal(state.symbol, symbol);
@@ -137,7 +153,7 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
       );
       assert.equal(config.transferFeeBasisPoints, 100);
       assert.equal(config.maxTransferFee.toNumber(), 100000);
-      assert.equal(config.blacklistEnabled, true);
+      assert.equal(config.blacklistEnabled, 1);
     });
   });
 
@@ -210,7 +226,7 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
   describe("Blacklist (SSS-2)", () => {
     it("Should add address to blacklist", async () => {
       await hookProgram.methods
-        .addToBlacklist("Compliance violation test")
+        .addToBlacklist({ fraudInvestigation: {} }, null, { owner: {} }, null, { blocked: {} })
         .accounts({
           authority: provider.wallet.publicKey,
           config: hookConfigPDA,
@@ -282,10 +298,597 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
   });
 
   describe("Seize Tokens (SSS-2)", () => {
-    it("Should seize tokens from blacklisted account", async () => {
-      // This requires permanent delegate authority
-      // In real scenario, this would work after setting permanent delegate
-      console.log("Seize test placeholder - requires permanent delegate setup");
+    // A fresh mint whose transfer hook and permanent delegate are both live,
+    // so the seizure transfer really runs the hook
+    const hookedMint = Keypair.generate();
+    const victim = Keypair.generate();
+    const recipient = Keypair.generate();
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const caseId = new anchor.BN(1);
+    const seizeAmount = 400000;
+
+    const pda = (seeds: Buffer[], programId: PublicKey) =>
+      PublicKey.findProgramAddressSync(seeds, programId)[0];
+    let hookedState: PublicKey;
+    let hookedConfig: PublicKey;
+    let permanentDelegate: PublicKey;
+    let victimAccount: PublicKey;
+    let recipientAccount: PublicKey;
+    let seizureCase: PublicKey;
+
+    before(async () => {
+      hookedState = pda(
+        [Buffer.from("stablecoin"), hookedMint.publicKey.toBuffer()],
+        tokenProgram.programId
+      );
+      hookedConfig = pda(
+        [Buffer.from("hook_config"), hookedMint.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+      permanentDelegate = pda(
+        [Buffer.from("permanent_delegate"), hookedState.toBuffer()],
+        tokenProgram.programId
+      );
+      seizureCase = pda(
+        [
+          Buffer.from("seizure_case"),
+          hookedConfig.toBuffer(),
+          caseId.toArrayLike(Buffer, "le", 8),
+        ],
+        hookProgram.programId
+      );
+
+      const mintLen = getMintLen([
+        ExtensionType.TransferHook,
+        ExtensionType.PermanentDelegate,
+      ]);
+      const mintTx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: hookedMint.publicKey,
+          space: mintLen,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(
+            mintLen
+          ),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferHookInstruction(
+          hookedMint.publicKey,
+          payer.publicKey,
+          hookProgram.programId,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializePermanentDelegateInstruction(
+          hookedMint.publicKey,
+          permanentDelegate,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          hookedMint.publicKey,
+          decimals,
+          pda([Buffer.from("mint_authority"), hookedState.toBuffer()], tokenProgram.programId),
+          pda([Buffer.from("freeze_authority"), hookedState.toBuffer()], tokenProgram.programId),
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+      await sendAndConfirmTransaction(provider.connection, mintTx, [
+        payer,
+        hookedMint,
+      ]);
+
+      await tokenProgram.methods
+        .initialize(name, symbol, decimals, true, true)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoinState: hookedState,
+          mint: hookedMint.publicKey,
+          ...(await issuerRegistryAccounts(tokenProgram, provider.wallet.publicKey)),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initialize(0, new anchor.BN(0), new anchor.BN(0), true)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoin: hookedMint.publicKey,
+          stablecoinState: hookedState,
+          config: hookedConfig,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({ payer: payer.publicKey, mint: hookedMint.publicKey })
+        .rpc();
+      await hookProgram.methods
+        .updateConfig(null, null, null, null, null, permanentDelegate)
+        .accounts({ authority: payer.publicKey, config: hookedConfig })
+        .rpc();
+
+      victimAccount = getAssociatedTokenAddressSync(
+        hookedMint.publicKey,
+        victim.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      recipientAccount = getAssociatedTokenAddressSync(
+        hookedMint.publicKey,
+        recipient.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          createAssociatedTokenAccountIdempotentInstruction(
+            payer.publicKey,
+            victimAccount,
+            victim.publicKey,
+            hookedMint.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createAssociatedTokenAccountIdempotentInstruction(
+            payer.publicKey,
+            recipientAccount,
+            recipient.publicKey,
+            hookedMint.publicKey,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [payer]
+      );
+      await tokenProgram.methods
+        .mint(new anchor.BN(seizeAmount))
+        .accounts({
+          minter: payer.publicKey,
+          stablecoinState: hookedState,
+          mint: hookedMint.publicKey,
+          recipientAccount: victimAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      // Blacklisted, so the victim can no longer move the funds itself
+      await hookProgram.methods
+        .addToBlacklist({ courtOrder: {} }, null, { owner: {} }, null, { blocked: {} })
+        .accounts({
+          authority: payer.publicKey,
+          config: hookedConfig,
+          targetAddress: victim.publicKey,
+        })
+        .rpc();
+
+      // Open the case and pass it through a 1-of-1 multisig
+      await hookProgram.methods
+        .openSeizureCase(
+          caseId,
+          new anchor.BN(seizeAmount),
+          { courtOrder: {} },
+          Array(32).fill(7),
+          false
+        )
+        .accounts({
+          authority: payer.publicKey,
+          config: hookedConfig,
+          targetAccount: victimAccount,
+          seizureCase,
+          roleAccount: null,
+        })
+        .rpc();
+      await tokenProgram.methods
+        .initializeMultisig(1, [payer.publicKey])
+        .accounts({ authority: payer.publicKey, stablecoinState: hookedState })
+        .rpc();
+      const multisig = pda(
+        [Buffer.from("multisig"), hookedState.toBuffer()],
+        tokenProgram.programId
+      );
+      const proposal = pda(
+        [Buffer.from("proposal"), multisig.toBuffer(), payer.publicKey.toBuffer()],
+        tokenProgram.programId
+      );
+      // ProposalAction::ApproveSeizure { case }
+      const action = Buffer.concat([Buffer.from([1]), seizureCase.toBuffer()]);
+      await tokenProgram.methods
+        .createProposal(action, new anchor.BN(3600))
+        .accounts({ proposer: payer.publicKey, stablecoinState: hookedState })
+        .rpc();
+      await tokenProgram.methods
+        .approveProposal()
+        .accounts({
+          signer: payer.publicKey,
+          stablecoinState: hookedState,
+          proposal,
+        })
+        .rpc();
+      await hookProgram.methods
+        .approveSeizureCase()
+        .accounts({ config: hookedConfig, seizureCase, proposal })
+        .rpc();
+
+      await hookProgram.methods
+        .addRestitutionAccount()
+        .accounts({
+          authority: payer.publicKey,
+          config: hookedConfig,
+          tokenAccount: recipientAccount,
+        })
+        .rpc();
+    });
+
+    it("Should reject settling a case without sss-token's permanent delegate", async () => {
+      try {
+        await hookProgram.methods
+          .consumeSeizureCase()
+          .accounts({
+            permanentDelegate: payer.publicKey,
+            seizer: payer.publicKey,
+            config: hookedConfig,
+            seizureCase,
+            clawbackHold: null,
+            clawbackInitiator: null,
+          })
+          .rpc();
+        assert.fail("Expected InvalidAuthority");
+      } catch (e: any) {
+        assert.include(e.message, "InvalidAuthority");
+      }
+    });
+
+    it("Should seize from a blacklisted account through the transfer hook", async () => {
+      // The hook's extra accounts, resolved as for any transfer the
+      // permanent delegate signs
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        victimAccount,
+        hookedMint.publicKey,
+        recipientAccount,
+        permanentDelegate,
+        BigInt(seizeAmount),
+        decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const hookAccounts = transferIx.keys
+        .slice(4)
+        .map((key) => ({ ...key, isSigner: false }));
+
+      await tokenProgram.methods
+        .seizeTokens()
+        .accounts({
+          seizer: payer.publicKey,
+          stablecoinState: hookedState,
+          mint: hookedMint.publicKey,
+          sourceAccount: victimAccount,
+          destination: recipientAccount,
+          restitutionEntry: pda(
+            [
+              Buffer.from("restitution"),
+              hookedConfig.toBuffer(),
+              recipientAccount.toBuffer(),
+            ],
+            hookProgram.programId
+          ),
+          seizureCase,
+          clawbackHold: null,
+          clawbackInitiator: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(hookAccounts)
+        .rpc();
+
+      const seized = await getAccount(
+        provider.connection,
+        victimAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const received = await getAccount(
+        provider.connection,
+        recipientAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(seized.amount), 0);
+      assert.equal(Number(received.amount), seizeAmount);
+      // The executed case is closed by the hook
+      assert.isNull(await provider.connection.getAccountInfo(seizureCase));
+    });
+  });
+
+  describe("Treasury Claims (SSS-2)", () => {
+    // A mint withholding a 1% TransferFee into the treasury, with a trader
+    // whose fees are shared 50% with a referrer
+    const feeMint = Keypair.generate();
+    const trader = Keypair.generate();
+    const referrer = Keypair.generate();
+    const harvestedSink = Keypair.generate();
+    const unharvestedSink = Keypair.generate();
+    const payer = (provider.wallet as anchor.Wallet).payer;
+
+    const pda = (seeds: Buffer[], programId: PublicKey) =>
+      PublicKey.findProgramAddressSync(seeds, programId)[0];
+    const ata = (owner: PublicKey, allowOwnerOffCurve = false) =>
+      getAssociatedTokenAddressSync(
+        feeMint.publicKey,
+        owner,
+        allowOwnerOffCurve,
+        TOKEN_2022_PROGRAM_ID
+      );
+    let feeState: PublicKey;
+    let feeConfig: PublicKey;
+    let treasury: PublicKey;
+    let treasuryAuthority: PublicKey;
+    let referrerAccount: PublicKey;
+    let referralLink: PublicKey;
+
+    const transferWithHook = async (
+      owner: Keypair,
+      destination: PublicKey,
+      amount: number
+    ) => {
+      const ix = await createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        ata(owner.publicKey),
+        feeMint.publicKey,
+        destination,
+        owner.publicKey,
+        BigInt(amount),
+        decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(ix),
+        [payer, owner]
+      );
+    };
+
+    before(async () => {
+      await provider.connection.requestAirdrop(
+        referrer.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      feeState = pda(
+        [Buffer.from("stablecoin"), feeMint.publicKey.toBuffer()],
+        tokenProgram.programId
+      );
+      feeConfig = pda(
+        [Buffer.from("hook_config"), feeMint.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+      treasury = pda(
+        [Buffer.from("treasury"), feeState.toBuffer()],
+        tokenProgram.programId
+      );
+      treasuryAuthority = pda(
+        [Buffer.from("treasury_authority"), feeState.toBuffer()],
+        tokenProgram.programId
+      );
+      referrerAccount = pda(
+        [Buffer.from("referrer"), feeConfig.toBuffer(), referrer.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+      referralLink = pda(
+        [Buffer.from("referral"), feeConfig.toBuffer(), trader.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+
+      const mintLen = getMintLen([
+        ExtensionType.TransferHook,
+        ExtensionType.TransferFeeConfig,
+      ]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: feeMint.publicKey,
+            space: mintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(
+              mintLen
+            ),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeTransferHookInstruction(
+            feeMint.publicKey,
+            payer.publicKey,
+            hookProgram.programId,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeTransferFeeConfigInstruction(
+            feeMint.publicKey,
+            payer.publicKey,
+            treasuryAuthority,
+            100,
+            BigInt(1_000_000),
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            feeMint.publicKey,
+            decimals,
+            pda([Buffer.from("mint_authority"), feeState.toBuffer()], tokenProgram.programId),
+            pda([Buffer.from("freeze_authority"), feeState.toBuffer()], tokenProgram.programId),
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [payer, feeMint]
+      );
+
+      await tokenProgram.methods
+        .initialize(name, symbol, decimals, true, false)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoinState: feeState,
+          mint: feeMint.publicKey,
+          ...(await issuerRegistryAccounts(tokenProgram, provider.wallet.publicKey)),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initialize(0, new anchor.BN(0), new anchor.BN(0), false)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoin: feeMint.publicKey,
+          stablecoinState: feeState,
+          config: feeConfig,
+        })
+        .rpc();
+      await tokenProgram.methods
+        .initializeTreasury(new anchor.BN(0))
+        .accounts({
+          authority: payer.publicKey,
+          stablecoinState: feeState,
+          treasury,
+          treasuryAuthority,
+          treasuryTokenAccount: ata(treasuryAuthority, true),
+          mint: feeMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      await hookProgram.methods
+        .setReferrer(5_000)
+        .accounts({
+          authority: payer.publicKey,
+          config: feeConfig,
+          referrer: referrer.publicKey,
+          referrerAccount,
+        })
+        .rpc();
+      await hookProgram.methods
+        .linkReferral()
+        .accounts({
+          authority: payer.publicKey,
+          config: feeConfig,
+          address: trader.publicKey,
+          referrerAccount,
+          referralLink,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({ payer: payer.publicKey, mint: feeMint.publicKey })
+        .rpc();
+
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          ...[trader, referrer, harvestedSink, unharvestedSink].map((owner) =>
+            createAssociatedTokenAccountIdempotentInstruction(
+              payer.publicKey,
+              ata(owner.publicKey),
+              owner.publicKey,
+              feeMint.publicKey,
+              TOKEN_2022_PROGRAM_ID
+            )
+          )
+        ),
+        [payer]
+      );
+      await tokenProgram.methods
+        .mint(new anchor.BN(10_000_000))
+        .accounts({
+          minter: payer.publicKey,
+          stablecoinState: feeState,
+          mint: feeMint.publicKey,
+          recipientAccount: ata(trader.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      // 10_000 and 90_000 withheld; the referrer's half of both is earned
+      await transferWithHook(trader, ata(harvestedSink.publicKey), 1_000_000);
+      await transferWithHook(trader, ata(unharvestedSink.publicKey), 9_000_000);
+      await hookProgram.methods
+        .settleReferral()
+        .accounts({ config: feeConfig, referralLink, referrerAccount })
+        .rpc();
+
+      // Only the first transfer's fee reaches the treasury
+      await tokenProgram.methods
+        .harvestFees()
+        .accounts({
+          cranker: payer.publicKey,
+          stablecoinState: feeState,
+          treasury,
+          treasuryAuthority,
+          treasuryTokenAccount: ata(treasuryAuthority, true),
+          mint: feeMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: ata(harvestedSink.publicKey), isSigner: false, isWritable: true },
+        ])
+        .rpc();
+    });
+
+    const claimReferralFees = async () => {
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        ata(treasuryAuthority, true),
+        feeMint.publicKey,
+        ata(referrer.publicKey),
+        treasuryAuthority,
+        BigInt(1),
+        decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await tokenProgram.methods
+        .claimReferralFees()
+        .accounts({
+          referrer: referrer.publicKey,
+          stablecoinState: feeState,
+          referrerAccount,
+          treasury,
+          treasuryAuthority,
+          treasuryTokenAccount: ata(treasuryAuthority, true),
+          destination: ata(referrer.publicKey),
+          mint: feeMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          transferIx.keys.slice(4).map((key) => ({ ...key, isSigner: false }))
+        )
+        .signers([referrer])
+        .rpc();
+    };
+
+    it("Should cap a referral claim at the treasury's pending fees", async () => {
+      const earned = (
+        await hookProgram.account.referrer.fetch(referrerAccount)
+      ).earned.toNumber();
+      const pending = (
+        await tokenProgram.account.treasury.fetch(treasury)
+      ).feesPending.toNumber();
+      assert.equal(earned, 50_000);
+      assert.equal(pending, 10_000);
+
+      await claimReferralFees();
+
+      const claim = await tokenProgram.account.referralClaim.fetch(
+        pda(
+          [Buffer.from("referral_claim"), feeState.toBuffer(), referrer.publicKey.toBuffer()],
+          tokenProgram.programId
+        )
+      );
+      const after = await tokenProgram.account.treasury.fetch(treasury);
+      assert.equal(claim.claimed.toNumber(), pending);
+      assert.equal(after.feesPending.toNumber(), 0);
+      assert.equal(after.totalFeesDistributed.toNumber(), pending);
+    });
+
+    it("Should leave the uncovered remainder claimable but not payable", async () => {
+      try {
+        await claimReferralFees();
+        assert.fail("Expected NoReferralFeesDue");
+      } catch (e: any) {
+        assert.include(e.message, "NoReferralFeesDue");
+      }
     });
   });
 
@@ -331,7 +934,7 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
       const config = await hookProgram.account.transferHookConfig.fetch(
         hookConfigPDA
       );
-      assert.equal(config.isPaused, true);
+      assert.equal(config.isPaused, 1);
 
       // Unpause for next tests
       await hookProgram.methods
@@ -402,27 +1005,266 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
       const role = await tokenProgram.account.roleAccount.fetch(seizerRolePDA);
       assert.equal(role.roles, 32);
     });
-  });
 
-  describe("Whitelist (SSS-2)", () => {
-    it("Should add address to whitelist for fee bypass", async () => {
-      const whitelistedAddr = Keypair.generate().publicKey;
+    it("Should let a blacklister role holder manage the hook blacklist", async () => {
+      const officer = Keypair.generate();
+      const target = Keypair.generate().publicKey;
+      const sig = await provider.connection.requestAirdrop(
+        officer.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
 
-      const [whitelistPDA] = PublicKey.findProgramAddressSync(
+      const [officerRolePDA] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("whitelist"),
-          hookConfigPDA.toBuffer(),
-          whitelistedAddr.toBuffer(),
+          Buffer.from("role"),
+          officer.publicKey.toBuffer(),
+          mintKeypair.publicKey.toBuffer(),
         ],
-        hookProgram.programId
+        tokenProgram.programId
       );
+      await tokenProgram.methods
+        .updateRoles(16) // ROLE_BLACKLISTER = 16
+        .accounts({
+          authority: provider.wallet.publicKey,
+          stablecoinState: stablecoinPDA,
+          authorityRole: masterRolePDA,
+          target: officer.publicKey,
+          targetRole: officerRolePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
 
+      const [entryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("blacklist"), hookConfigPDA.toBuffer(), target.toBuffer()],
+        hookProgram.programId
+      );
       await hookProgram.methods
-        .addToWhitelist()
+        .addToBlacklist({ courtOrder: {} }, null, { owner: {} }, null, { blocked: {} })
         .accounts({
-          authority: provider.wallet.publicKey,
+          authority: officer.publicKey,
           config: hookConfigPDA,
-          targetAddress: whitelistedAddr,
+          targetAddress: target,
+          blacklistEntry: entryPDA,
+          systemProgram: SystemProgram.programId,
+          roleAccount: officerRolePDA,
+        })
+        .signers([officer])
+        .rpc();
+
+      const entry = await hookProgram.account.blacklistEntry.fetch(entryPDA);
+      assert.equal(entry.blacklistedBy.toBase58(), officer.publicKey.toBase58());
+    });
+
+    it("Should reject blacklisting without the authority or a role", async () => {
+      const outsider = Keypair.generate();
+      const target = Keypair.generate().publicKey;
+      const sig = await provider.connection.requestAirdrop(
+        outsider.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+
+      const [entryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("blacklist"), hookConfigPDA.toBuffer(), target.toBuffer()],
+        hookProgram.programId
+      );
+      try {
+        await hookProgram.methods
+          .addToBlacklist({ other: {} }, null, { owner: {} }, null, { blocked: {} })
+          .accounts({
+            authority: outsider.publicKey,
+            config: hookConfigPDA,
+            targetAddress: target,
+            blacklistEntry: entryPDA,
+            systemProgram: SystemProgram.programId,
+            roleAccount: null,
+          })
+          .signers([outsider])
+          .rpc();
+        assert.fail("Expected InvalidAuthority");
+      } catch (e: any) {
+        assert.include(e.message, "InvalidAuthority");
+      }
+    });
+  });
+
+  describe("Whitelist (SSS-2)", () => {
+    // A fresh hooked mint with the blacklist on and a 1000 minimum, so real
+    // transfers show which checks each whitelist type waives
+    const listMint = Keypair.generate();
+    const exemptUser = Keypair.generate();
+    const bypassUser = Keypair.generate();
+    const blockedUser = Keypair.generate();
+    const recipient = Keypair.generate();
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const minTransfer = 1000;
+
+    const pda = (seeds: Buffer[], programId: PublicKey) =>
+      PublicKey.findProgramAddressSync(seeds, programId)[0];
+    const ata = (owner: PublicKey) =>
+      getAssociatedTokenAddressSync(
+        listMint.publicKey,
+        owner,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+    let listState: PublicKey;
+    let listConfig: PublicKey;
+
+    const whitelistPDAFor = (address: PublicKey) =>
+      pda(
+        [Buffer.from("whitelist"), listConfig.toBuffer(), address.toBuffer()],
+        hookProgram.programId
+      );
+
+    const transferWithHook = async (
+      owner: Keypair,
+      destination: Keypair,
+      amount: number
+    ) => {
+      const ix = await createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        ata(owner.publicKey),
+        listMint.publicKey,
+        ata(destination.publicKey),
+        owner.publicKey,
+        BigInt(amount),
+        decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(ix),
+        [payer, owner]
+      );
+    };
+
+    const expectHookError = async (transfer: Promise<void>, error: string) => {
+      try {
+        await transfer;
+        assert.fail(`Expected ${error}`);
+      } catch (e: any) {
+        assert.include((e.logs ?? []).join("\n") || e.message, error);
+      }
+    };
+
+    before(async () => {
+      listState = pda(
+        [Buffer.from("stablecoin"), listMint.publicKey.toBuffer()],
+        tokenProgram.programId
+      );
+      listConfig = pda(
+        [Buffer.from("hook_config"), listMint.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+
+      const mintLen = getMintLen([ExtensionType.TransferHook]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: listMint.publicKey,
+            space: mintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(
+              mintLen
+            ),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeTransferHookInstruction(
+            listMint.publicKey,
+            payer.publicKey,
+            hookProgram.programId,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            listMint.publicKey,
+            decimals,
+            pda([Buffer.from("mint_authority"), listState.toBuffer()], tokenProgram.programId),
+            pda([Buffer.from("freeze_authority"), listState.toBuffer()], tokenProgram.programId),
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [payer, listMint]
+      );
+
+      await tokenProgram.methods
+        .initialize(name, symbol, decimals, true, false)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoinState: listState,
+          mint: listMint.publicKey,
+          ...(await issuerRegistryAccounts(tokenProgram, provider.wallet.publicKey)),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initialize(0, new anchor.BN(0), new anchor.BN(minTransfer), true)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoin: listMint.publicKey,
+          stablecoinState: listState,
+          config: listConfig,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({ payer: payer.publicKey, mint: listMint.publicKey })
+        .rpc();
+
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          ...[exemptUser, bypassUser, blockedUser, recipient].map((owner) =>
+            createAssociatedTokenAccountIdempotentInstruction(
+              payer.publicKey,
+              ata(owner.publicKey),
+              owner.publicKey,
+              listMint.publicKey,
+              TOKEN_2022_PROGRAM_ID
+            )
+          )
+        ),
+        [payer]
+      );
+      for (const owner of [exemptUser, bypassUser]) {
+        await tokenProgram.methods
+          .mint(new anchor.BN(1_000_000))
+          .accounts({
+            minter: payer.publicKey,
+            stablecoinState: listState,
+            mint: listMint.publicKey,
+            recipientAccount: ata(owner.publicKey),
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc();
+      }
+
+      // The bypass holder is itself blacklisted: FullBypass skips that too
+      for (const target of [blockedUser, bypassUser]) {
+        await hookProgram.methods
+          .addToBlacklist({ fraudInvestigation: {} }, null, { owner: {} }, null, { blocked: {} })
+          .accounts({
+            authority: payer.publicKey,
+            config: listConfig,
+            targetAddress: target.publicKey,
+          })
+          .rpc();
+      }
+    });
+
+    it("Should add address to whitelist for fee bypass", async () => {
+      const whitelistPDA = whitelistPDAFor(exemptUser.publicKey);
+
+      await hookProgram.methods
+        .addToWhitelist({ feeExempt: {} })
+        .accounts({
+          authority: payer.publicKey,
+          config: listConfig,
+          targetAddress: exemptUser.publicKey,
           whitelistEntry: whitelistPDA,
           systemProgram: SystemProgram.programId,
         })
@@ -431,7 +1273,264 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
       const entry = await hookProgram.account.whitelistEntry.fetch(
         whitelistPDA
       );
-      assert.equal(entry.address.toBase58(), whitelistedAddr.toBase58());
+      assert.equal(entry.address.toBase58(), exemptUser.publicKey.toBase58());
+      assert.deepEqual(entry.whitelistType, { feeExempt: {} });
+    });
+
+    it("Should still apply the blacklist and minimum to a fee-exempt sender", async () => {
+      // FeeExempt only waives fees; blacklist and min amount still apply
+      await expectHookError(
+        transferWithHook(exemptUser, blockedUser, minTransfer),
+        "DestinationBlacklisted"
+      );
+      await expectHookError(
+        transferWithHook(exemptUser, recipient, minTransfer - 1),
+        "AmountTooLow"
+      );
+
+      await transferWithHook(exemptUser, recipient, minTransfer);
+      const received = await getAccount(
+        provider.connection,
+        ata(recipient.publicKey),
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(received.amount), minTransfer);
+    });
+
+    it("Should add address to whitelist for full bypass", async () => {
+      const whitelistPDA = whitelistPDAFor(bypassUser.publicKey);
+
+      // Blacklisted and below the minimum, so the transfer fails until whitelisted
+      await expectHookError(
+        transferWithHook(bypassUser, recipient, minTransfer - 1),
+        "SourceBlacklisted"
+      );
+
+      await hookProgram.methods
+        .addToWhitelist({ fullBypass: {} })
+        .accounts({
+          authority: payer.publicKey,
+          config: listConfig,
+          targetAddress: bypassUser.publicKey,
+          whitelistEntry: whitelistPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const entry = await hookProgram.account.whitelistEntry.fetch(
+        whitelistPDA
+      );
+      assert.equal(entry.address.toBase58(), bypassUser.publicKey.toBase58());
+      assert.deepEqual(entry.whitelistType, { fullBypass: {} });
+    });
+
+    it("Should skip the blacklist and minimum for a full-bypass sender", async () => {
+      const before = await getAccount(
+        provider.connection,
+        ata(recipient.publicKey),
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // FullBypass skips every hook check for this address
+      await transferWithHook(bypassUser, recipient, minTransfer - 1);
+
+      const after = await getAccount(
+        provider.connection,
+        ata(recipient.publicKey),
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(
+        Number(after.amount) - Number(before.amount),
+        minTransfer - 1
+      );
+    });
+  });
+
+  describe("Extra Account Metas (SSS-2)", () => {
+    // A fresh hooked mint using no optional feature, so every group of
+    // extra metas starts out resolved to the hook program
+    const plainMint = Keypair.generate();
+    const sender = Keypair.generate();
+    const receiver = Keypair.generate();
+    const payer = (provider.wallet as anchor.Wallet).payer;
+
+    const pda = (seeds: Buffer[], programId: PublicKey) =>
+      PublicKey.findProgramAddressSync(seeds, programId)[0];
+    const ata = (owner: PublicKey) =>
+      getAssociatedTokenAddressSync(
+        plainMint.publicKey,
+        owner,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+    let plainState: PublicKey;
+    let plainConfig: PublicKey;
+
+    const transferIx = (amount: number) =>
+      createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        ata(sender.publicKey),
+        plainMint.publicKey,
+        ata(receiver.publicKey),
+        sender.publicKey,
+        BigInt(amount),
+        decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+    const uniqueKeys = (ix: anchor.web3.TransactionInstruction) =>
+      new Set(ix.keys.map((key) => key.pubkey.toBase58())).size;
+
+    before(async () => {
+      plainState = pda(
+        [Buffer.from("stablecoin"), plainMint.publicKey.toBuffer()],
+        tokenProgram.programId
+      );
+      plainConfig = pda(
+        [Buffer.from("hook_config"), plainMint.publicKey.toBuffer()],
+        hookProgram.programId
+      );
+
+      const mintLen = getMintLen([ExtensionType.TransferHook]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: plainMint.publicKey,
+            space: mintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(
+              mintLen
+            ),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeTransferHookInstruction(
+            plainMint.publicKey,
+            payer.publicKey,
+            hookProgram.programId,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            plainMint.publicKey,
+            decimals,
+            pda([Buffer.from("mint_authority"), plainState.toBuffer()], tokenProgram.programId),
+            pda([Buffer.from("freeze_authority"), plainState.toBuffer()], tokenProgram.programId),
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [payer, plainMint]
+      );
+
+      await tokenProgram.methods
+        .initialize(name, symbol, decimals, true, false)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoinState: plainState,
+          mint: plainMint.publicKey,
+          ...(await issuerRegistryAccounts(tokenProgram, provider.wallet.publicKey)),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initialize(0, new anchor.BN(0), new anchor.BN(0), false)
+        .accounts({
+          authority: payer.publicKey,
+          stablecoin: plainMint.publicKey,
+          stablecoinState: plainState,
+          config: plainConfig,
+        })
+        .rpc();
+      await hookProgram.methods
+        .initializeExtraAccountMetaList()
+        .accounts({ payer: payer.publicKey, mint: plainMint.publicKey })
+        .rpc();
+
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          ...[sender, receiver].map((owner) =>
+            createAssociatedTokenAccountIdempotentInstruction(
+              payer.publicKey,
+              ata(owner.publicKey),
+              owner.publicKey,
+              plainMint.publicKey,
+              TOKEN_2022_PROGRAM_ID
+            )
+          )
+        ),
+        [payer]
+      );
+      await tokenProgram.methods
+        .mint(new anchor.BN(1_000_000))
+        .accounts({
+          minter: payer.publicKey,
+          stablecoinState: plainState,
+          mint: plainMint.publicKey,
+          recipientAccount: ata(sender.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+    });
+
+    it("Should send a plain transfer in a legacy transaction", async () => {
+      const ix = await transferIx(1_000);
+      // Source, mint, destination, authority, meta list, hook program, then
+      // only config, both whitelist entries, sss-token, its state and the
+      // instructions sysvar
+      assert.isAtMost(uniqueKeys(ix), 12);
+
+      const tx = new Transaction().add(ix);
+      await sendAndConfirmTransaction(provider.connection, tx, [payer, sender]);
+      assert.isAtMost(tx.serialize().length, 1232);
+
+      const received = await getAccount(
+        provider.connection,
+        ata(receiver.publicKey),
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(received.amount), 1_000);
+    });
+
+    it("Should require a sync once a feature comes into use", async () => {
+      const keysBefore = uniqueKeys(await transferIx(1_000));
+      await hookProgram.methods
+        .initFeeCredit()
+        .accounts({
+          payer: payer.publicKey,
+          config: plainConfig,
+          owner: sender.publicKey,
+        })
+        .rpc();
+
+      try {
+        await sendAndConfirmTransaction(
+          provider.connection,
+          new Transaction().add(await transferIx(1_000)),
+          [payer, sender]
+        );
+        assert.fail("Expected StaleExtraAccountMetas");
+      } catch (e: any) {
+        assert.include((e.logs ?? []).join("\n") || e.message, "StaleExtraAccountMetas");
+      }
+
+      await hookProgram.methods
+        .syncExtraAccountMetaList()
+        .accounts({ mint: plainMint.publicKey })
+        .rpc();
+
+      // Only the sender's fee credit joins the transfer
+      const ix = await transferIx(1_000);
+      assert.equal(uniqueKeys(ix), keysBefore + 1);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(ix),
+        [payer, sender]
+      );
     });
   });
 
@@ -439,21 +1538,32 @@ describe("SSS Token - SSS-2 (Compliant Stablecoin)", () => {
     it("Should batch blacklist multiple addresses", async () => {
       const addr1 = Keypair.generate().publicKey;
       const addr2 = Keypair.generate().publicKey;
+      const entryPDAs = [addr1, addr2].map(
+        (addr) =>
+          PublicKey.findProgramAddressSync(
+            [Buffer.from("blacklist"), hookConfigPDA.toBuffer(), addr.toBuffer()],
+            hookProgram.programId
+          )[0]
+      );
 
-      try {
-        await hookProgram.methods
-          .batchBlacklist([addr1, addr2], ["Batch reason 1", "Batch reason 2"])
-          .accounts({
-            authority: provider.wallet.publicKey,
-            config: hookConfigPDA,
-            systemProgram: SystemProgram.programId,
-          })
-          .rpc();
+      await hookProgram.methods
+        .batchBlacklist([
+          { address: addr1, reasonCode: { sanctionsMatch: {} }, reference: null, entryKind: { owner: {} }, expiresAt: null, restrictionLevel: { blocked: {} } },
+          { address: addr2, reasonCode: { sanctionsMatch: {} }, reference: null, entryKind: { owner: {} }, expiresAt: null, restrictionLevel: { blocked: {} } },
+        ])
+        .accounts({
+          authority: provider.wallet.publicKey,
+          config: hookConfigPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          entryPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .rpc();
 
-        console.log("Batch blacklist executed");
-      } catch (e: any) {
-        // May fail if accounts aren't properly set as remaining accounts
-        console.log("Batch blacklist expected behavior:", e.message);
+      for (const pda of entryPDAs) {
+        const entry = await hookProgram.account.blacklistEntry.fetch(pda);
+        assert.equal(entry.isActive, true);
       }
     });
   });